@@ -0,0 +1,245 @@
+//! Arrow IPC stream format decoding into log events
+//!
+//! Provides the inverse of the Arrow IPC stream encoder: each row of the decoded record batches
+//! becomes a log event, with one field per column.
+
+use std::io::Cursor;
+
+use arrow::{
+    array::{Array, ArrayRef, AsArray},
+    compute::cast,
+    datatypes::{DataType, Field, Float64Type, Int64Type, TimeUnit, TimestampNanosecondType},
+    error::ArrowError,
+    ipc::reader::StreamReader,
+    record_batch::RecordBatch,
+};
+use bytes::Bytes;
+use chrono::{TimeZone, Utc};
+use ordered_float::NotNan;
+use snafu::{ResultExt, Snafu};
+use vector_core::event::{LogEvent, Value};
+
+use crate::encoding::ARROW_JSON_FIELD_METADATA_KEY;
+
+/// Errors that can occur during Arrow decoding
+#[derive(Debug, Snafu)]
+pub enum ArrowDecodingError {
+    /// Failed to read Arrow IPC data
+    #[snafu(display("Failed to read Arrow IPC data: {}", source))]
+    IpcRead {
+        /// The underlying Arrow error
+        source: ArrowError,
+    },
+
+    /// Failed to convert the values of a field
+    #[snafu(display("Failed to convert Arrow field '{}': {}", field_name, source))]
+    Conversion {
+        /// The field name
+        field_name: String,
+        /// The underlying Arrow error
+        source: ArrowError,
+    },
+
+    /// Unsupported Arrow data type for field
+    #[snafu(display(
+        "Unsupported Arrow data type for field '{}': {:?}",
+        field_name,
+        data_type
+    ))]
+    UnsupportedType {
+        /// The field name
+        field_name: String,
+        /// The unsupported data type
+        data_type: DataType,
+    },
+}
+
+/// Decodes all record batches of an Arrow IPC stream into log events.
+pub fn decode_arrow_ipc_stream(bytes: Bytes) -> Result<Vec<LogEvent>, ArrowDecodingError> {
+    let reader = StreamReader::try_new(Cursor::new(bytes), None).context(IpcReadSnafu)?;
+
+    let mut events = Vec::new();
+    for batch in reader {
+        let batch = batch.context(IpcReadSnafu)?;
+        events.extend(decode_record_batch(&batch)?);
+    }
+    Ok(events)
+}
+
+/// Decodes an Arrow record batch into log events, one per row.
+///
+/// Columns are inserted at the event path given by their field name, and null values are
+/// skipped. `Utf8` fields marked with [`ARROW_JSON_FIELD_METADATA_KEY`] are parsed back into
+/// objects or arrays.
+pub fn decode_record_batch(batch: &RecordBatch) -> Result<Vec<LogEvent>, ArrowDecodingError> {
+    let mut events = vec![LogEvent::default(); batch.num_rows()];
+
+    for (field, column) in batch.schema().fields().iter().zip(batch.columns()) {
+        let values = decode_column(field, column)?;
+        for (event, value) in events.iter_mut().zip(values) {
+            if let Some(value) = value {
+                event.insert(field.name().as_str(), value);
+            }
+        }
+    }
+
+    Ok(events)
+}
+
+fn decode_column(field: &Field, column: &ArrayRef) -> Result<Vec<Option<Value>>, ArrowDecodingError> {
+    let field_name = field.name();
+    let cast_to = |data_type: &DataType| {
+        cast(column, data_type).context(ConversionSnafu {
+            field_name: field_name.as_str(),
+        })
+    };
+
+    let values = match column.data_type() {
+        DataType::Null => vec![None; column.len()],
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => {
+            let is_json = field
+                .metadata()
+                .get(ARROW_JSON_FIELD_METADATA_KEY)
+                .is_some_and(|value| value == "true");
+            let column = cast_to(&DataType::Utf8)?;
+            column
+                .as_string::<i32>()
+                .iter()
+                .map(|value| value.map(|value| decode_string(value, is_json)))
+                .collect()
+        }
+        DataType::Binary | DataType::LargeBinary | DataType::BinaryView => {
+            let column = cast_to(&DataType::Binary)?;
+            column
+                .as_binary::<i32>()
+                .iter()
+                .map(|value| value.map(|value| Value::Bytes(Bytes::copy_from_slice(value))))
+                .collect()
+        }
+        DataType::Boolean => column
+            .as_boolean()
+            .iter()
+            .map(|value| value.map(Value::Boolean))
+            .collect(),
+        DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64 => {
+            let column = cast_to(&DataType::Int64)?;
+            column
+                .as_primitive::<Int64Type>()
+                .iter()
+                .map(|value| value.map(Value::Integer))
+                .collect()
+        }
+        DataType::Float16
+        | DataType::Float32
+        | DataType::Float64
+        | DataType::Decimal128(_, _)
+        | DataType::Decimal256(_, _) => {
+            let column = cast_to(&DataType::Float64)?;
+            column
+                .as_primitive::<Float64Type>()
+                .iter()
+                .map(|value| value.and_then(|value| NotNan::new(value).ok().map(Value::Float)))
+                .collect()
+        }
+        DataType::Timestamp(_, _) | DataType::Date32 | DataType::Date64 => {
+            let column = cast_to(&DataType::Timestamp(TimeUnit::Nanosecond, None))?;
+            column
+                .as_primitive::<TimestampNanosecondType>()
+                .iter()
+                .map(|value| value.map(|nanos| Value::Timestamp(Utc.timestamp_nanos(nanos))))
+                .collect()
+        }
+        data_type => {
+            return Err(ArrowDecodingError::UnsupportedType {
+                field_name: field_name.clone(),
+                data_type: data_type.clone(),
+            });
+        }
+    };
+
+    Ok(values)
+}
+
+fn decode_string(value: &str, is_json: bool) -> Value {
+    if is_json && let Ok(json) = serde_json::from_str::<serde_json::Value>(value) {
+        return Value::from(json);
+    }
+    Value::from(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use vector_core::event::Event;
+    use vrl::btreemap;
+
+    use super::*;
+    use crate::encoding::{encode_events_to_arrow_ipc_stream, infer_schema};
+
+    #[test]
+    fn round_trip_inferred_schema() {
+        let timestamp = Utc.timestamp_nanos(1_700_000_000_123_456_789);
+        let mut first = LogEvent::default();
+        first.insert("message", "hello");
+        first.insert("count", 3);
+        first.insert("ratio", 0.5);
+        first.insert("ok", true);
+        first.insert("timestamp", timestamp);
+        first.insert(
+            "attributes",
+            Value::from(btreemap! {
+                "http.route" => "/users",
+                "retries" => 2,
+            }),
+        );
+        first.insert("\"service.name\"", "users");
+
+        let mut second = LogEvent::default();
+        second.insert("message", "world");
+        second.insert("ratio", 2);
+        second.insert("mixed", "text");
+
+        let mut third = LogEvent::default();
+        third.insert("mixed", 1);
+
+        let events = vec![
+            Event::Log(first.clone()),
+            Event::Log(second.clone()),
+            Event::Log(third),
+        ];
+        let schema = Arc::new(infer_schema(&events));
+        assert_eq!(
+            schema.field_with_name("ratio").unwrap().data_type(),
+            &DataType::Float64
+        );
+        assert_eq!(
+            schema.field_with_name("mixed").unwrap().data_type(),
+            &DataType::Utf8
+        );
+
+        let bytes = encode_events_to_arrow_ipc_stream(&events, Some(schema)).unwrap();
+        let decoded = decode_arrow_ipc_stream(bytes).unwrap();
+
+        assert_eq!(decoded.len(), 3);
+        assert_eq!(decoded[0].get("message"), Some(&Value::from("hello")));
+        assert_eq!(decoded[0].get("count"), Some(&Value::from(3)));
+        assert_eq!(decoded[0].get("ok"), Some(&Value::from(true)));
+        assert_eq!(decoded[0].get("timestamp"), Some(&Value::from(timestamp)));
+        assert_eq!(decoded[0].get("attributes"), first.get("attributes"));
+        assert_eq!(
+            decoded[0].get("\"service.name\""),
+            Some(&Value::from("users"))
+        );
+        assert_eq!(decoded[1].get("ratio"), Some(&Value::from(2.0)));
+        assert_eq!(decoded[1].get("count"), None);
+        assert_eq!(decoded[2].get("mixed"), Some(&Value::from("1")));
+    }
+}
@@ -3,6 +3,8 @@
 
 #![deny(missing_docs)]
 
+#[cfg(feature = "arrow")]
+mod arrow;
 mod avro;
 mod bytes;
 mod gelf;
@@ -18,6 +20,8 @@ mod syslog;
 mod vrl;
 
 use ::bytes::Bytes;
+#[cfg(feature = "arrow")]
+pub use arrow::{ArrowDecodingError, decode_arrow_ipc_stream, decode_record_batch};
 pub use avro::{AvroDeserializer, AvroDeserializerConfig, AvroDeserializerOptions};
 use dyn_clone::DynClone;
 pub use gelf::{GelfDeserializer, GelfDeserializerConfig, GelfDeserializerOptions};
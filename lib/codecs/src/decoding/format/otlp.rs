@@ -1,8 +1,8 @@
 use bytes::Bytes;
 use opentelemetry_proto::proto::{
     DESCRIPTOR_BYTES, LOGS_REQUEST_MESSAGE_TYPE, METRICS_REQUEST_MESSAGE_TYPE,
-    RESOURCE_LOGS_JSON_FIELD, RESOURCE_METRICS_JSON_FIELD, RESOURCE_SPANS_JSON_FIELD,
-    TRACES_REQUEST_MESSAGE_TYPE,
+    PROFILES_REQUEST_MESSAGE_TYPE, RESOURCE_LOGS_JSON_FIELD, RESOURCE_METRICS_JSON_FIELD,
+    RESOURCE_PROFILES_JSON_FIELD, RESOURCE_SPANS_JSON_FIELD, TRACES_REQUEST_MESSAGE_TYPE,
 };
 use smallvec::{SmallVec, smallvec};
 use vector_config::{configurable_component, indexmap::IndexSet};
@@ -26,6 +26,8 @@ pub enum OtlpSignalType {
     Metrics,
     /// OTLP traces signal (ExportTraceServiceRequest)
     Traces,
+    /// OTLP profiles signal (ExportProfilesServiceRequest)
+    Profiles,
 }
 
 /// Config used to build an `OtlpDeserializer`.
@@ -38,7 +40,8 @@ pub struct OtlpDeserializerConfig {
     /// performance when you know the expected signal types. For example, if you only receive
     /// traces, set this to `["traces"]` to avoid attempting to parse as logs or metrics first.
     ///
-    /// If not specified, defaults to trying all types in order: logs, metrics, traces.
+    /// If not specified, defaults to trying logs, metrics and traces, in that order. Profiles are
+    /// only parsed when explicitly listed.
     /// Duplicate signal types are automatically removed while preserving order.
     #[serde(default = "default_signal_types")]
     pub signal_types: IndexSet<OtlpSignalType>,
@@ -86,15 +89,16 @@ impl OtlpDeserializerConfig {
 
 /// Deserializer that builds `Event`s from a byte frame containing [OTLP](https://opentelemetry.io/docs/specs/otlp/) protobuf data.
 ///
-/// This deserializer decodes events using the OTLP protobuf specification. It handles the three
-/// OTLP signal types: logs, metrics, and traces.
+/// This deserializer decodes events using the OTLP protobuf specification. It handles the four
+/// OTLP signal types: logs, metrics, traces, and profiles.
 ///
-/// The implementation supports three OTLP message types:
+/// The implementation supports four OTLP message types:
 /// - `ExportLogsServiceRequest` → Log events with `resourceLogs` field
 /// - `ExportMetricsServiceRequest` → Log events with `resourceMetrics` field
 /// - `ExportTraceServiceRequest` → Trace events with `resourceSpans` field
+/// - `ExportProfilesServiceRequest` → Log events with `resourceProfiles` field
 ///
-/// One major caveat here is that the incoming metrics will be parsed as logs but they will preserve the OTLP format.
+/// One major caveat here is that the incoming metrics and profiles will be parsed as logs but they will preserve the OTLP format.
 /// This means that components that work on metrics, will not be compatible with this output.
 /// However, these events can be forwarded directly to a downstream OTEL collector.
 ///
@@ -105,6 +109,7 @@ pub struct OtlpDeserializer {
     logs_deserializer: ProtobufDeserializer,
     metrics_deserializer: ProtobufDeserializer,
     traces_deserializer: ProtobufDeserializer,
+    profiles_deserializer: ProtobufDeserializer,
     /// Signal types to parse, in priority order
     signals: IndexSet<OtlpSignalType>,
 }
@@ -140,14 +145,22 @@ impl OtlpDeserializer {
         let traces_deserializer = ProtobufDeserializer::new_from_bytes(
             DESCRIPTOR_BYTES,
             TRACES_REQUEST_MESSAGE_TYPE,
-            options,
+            options.clone(),
         )
         .expect("Failed to create traces deserializer");
 
+        let profiles_deserializer = ProtobufDeserializer::new_from_bytes(
+            DESCRIPTOR_BYTES,
+            PROFILES_REQUEST_MESSAGE_TYPE,
+            options,
+        )
+        .expect("Failed to create profiles deserializer");
+
         Self {
             logs_deserializer,
             metrics_deserializer,
             traces_deserializer,
+            profiles_deserializer,
             signals,
         }
     }
@@ -193,6 +206,16 @@ impl Deserializer for OtlpDeserializer {
                         }
                     }
                 }
+                OtlpSignalType::Profiles => {
+                    if let Ok(events) = self
+                        .profiles_deserializer
+                        .parse(bytes.clone(), log_namespace)
+                        && let Some(Event::Log(log)) = events.first()
+                        && log.get(RESOURCE_PROFILES_JSON_FIELD).is_some()
+                    {
+                        return Ok(events);
+                    }
+                }
             }
         }
 
@@ -205,10 +228,12 @@ mod tests {
     use opentelemetry_proto::proto::{
         collector::{
             logs::v1::ExportLogsServiceRequest, metrics::v1::ExportMetricsServiceRequest,
+            profiles::v1development::ExportProfilesServiceRequest,
             trace::v1::ExportTraceServiceRequest,
         },
         logs::v1::{LogRecord, ResourceLogs, ScopeLogs},
         metrics::v1::{Metric, ResourceMetrics, ScopeMetrics},
+        profiles::v1development::{Profile, ResourceProfiles, ScopeProfiles},
         resource::v1::Resource,
         trace::v1::{ResourceSpans, ScopeSpans, Span},
     };
@@ -313,6 +338,31 @@ mod tests {
         Bytes::from(request.encode_to_vec())
     }
 
+    fn create_profiles_request_bytes() -> Bytes {
+        let request = ExportProfilesServiceRequest {
+            resource_profiles: vec![ResourceProfiles {
+                resource: Some(Resource {
+                    attributes: vec![],
+                    dropped_attributes_count: 0,
+                }),
+                scope_profiles: vec![ScopeProfiles {
+                    scope: None,
+                    profiles: vec![Profile {
+                        string_table: vec![String::new(), "cpu".to_string()],
+                        time_nanos: 1234567890,
+                        duration_nanos: 10_000_000_000,
+                        profile_id: TEST_TRACE_ID.to_vec(),
+                        ..Default::default()
+                    }],
+                    schema_url: String::new(),
+                }],
+                schema_url: String::new(),
+            }],
+        };
+
+        Bytes::from(request.encode_to_vec())
+    }
+
     fn validate_trace_ids(trace: &vrl::value::Value) {
         // Navigate to the span and check traceId and spanId
         let resource_spans = trace
@@ -403,6 +453,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn deserialize_otlp_profiles() {
+        let deserializer = OtlpDeserializer::new_with_signals(IndexSet::from([
+            OtlpSignalType::Traces,
+            OtlpSignalType::Profiles,
+        ]));
+        let events = deserializer
+            .parse(create_profiles_request_bytes(), LogNamespace::Legacy)
+            .unwrap();
+
+        assert_eq!(events.len(), 1);
+        assert!(
+            events[0]
+                .as_log()
+                .get(RESOURCE_PROFILES_JSON_FIELD)
+                .is_some()
+        );
+
+        // Profiles are only parsed when explicitly requested.
+        let result = OtlpDeserializer::default()
+            .parse(create_profiles_request_bytes(), LogNamespace::Legacy);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn deserialize_invalid_otlp() {
         let deserializer = OtlpDeserializer::default();
@@ -9,6 +9,8 @@ use std::fmt::Debug;
 
 use bytes::{Bytes, BytesMut};
 pub use error::StreamDecodingError;
+#[cfg(feature = "arrow")]
+pub use format::{ArrowDecodingError, decode_arrow_ipc_stream, decode_record_batch};
 pub use format::{
     BoxedDeserializer, BytesDeserializer, BytesDeserializerConfig, GelfDeserializer,
     GelfDeserializerConfig, GelfDeserializerOptions, InfluxdbDeserializer,
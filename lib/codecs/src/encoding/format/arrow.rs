@@ -12,7 +12,7 @@ use arrow::{
         TimestampNanosecondBuilder, TimestampSecondBuilder, UInt8Builder, UInt16Builder,
         UInt32Builder, UInt64Builder,
     },
-    datatypes::{DataType, Schema, TimeUnit, i256},
+    datatypes::{DataType, Field, Schema, TimeUnit, i256},
     ipc::writer::StreamWriter,
     record_batch::RecordBatch,
 };
@@ -20,10 +20,14 @@ use bytes::{BufMut, Bytes, BytesMut};
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use snafu::Snafu;
-use std::sync::Arc;
-use vector_config::configurable_component;
+use std::{collections::HashMap, sync::Arc};
+use vector_config::{configurable_component, indexmap::IndexMap};
 
 use vector_core::event::{Event, Value};
+use vrl::path::{OwnedSegment, OwnedValuePath};
+
+/// Field metadata key marking a `Utf8` field whose values are JSON-encoded objects or arrays.
+pub const ARROW_JSON_FIELD_METADATA_KEY: &str = "vector.json";
 
 /// Configuration for Arrow IPC stream serialization
 #[configurable_component]
@@ -184,8 +188,95 @@ pub fn encode_events_to_arrow_ipc_stream(
     Ok(buffer.into_inner().freeze())
 }
 
+/// The Arrow type inferred for the values of a field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum InferredType {
+    Utf8,
+    Int64,
+    Float64,
+    Boolean,
+    Timestamp,
+    Json,
+}
+
+impl InferredType {
+    fn of(value: &Value) -> Option<Self> {
+        match value {
+            Value::Bytes(_) | Value::Regex(_) => Some(Self::Utf8),
+            Value::Integer(_) => Some(Self::Int64),
+            Value::Float(_) => Some(Self::Float64),
+            Value::Boolean(_) => Some(Self::Boolean),
+            Value::Timestamp(_) => Some(Self::Timestamp),
+            Value::Object(_) | Value::Array(_) => Some(Self::Json),
+            Value::Null => None,
+        }
+    }
+
+    fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (a, b) if a == b => a,
+            (Self::Int64, Self::Float64) | (Self::Float64, Self::Int64) => Self::Float64,
+            _ => Self::Utf8,
+        }
+    }
+
+    fn into_field(self, name: String) -> Field {
+        let data_type = match self {
+            Self::Utf8 | Self::Json => DataType::Utf8,
+            Self::Int64 => DataType::Int64,
+            Self::Float64 => DataType::Float64,
+            Self::Boolean => DataType::Boolean,
+            Self::Timestamp => DataType::Timestamp(TimeUnit::Nanosecond, Some("+00:00".into())),
+        };
+        let field = Field::new(name, data_type, true);
+        if self == Self::Json {
+            field.with_metadata(HashMap::from([(
+                ARROW_JSON_FIELD_METADATA_KEY.to_string(),
+                "true".to_string(),
+            )]))
+        } else {
+            field
+        }
+    }
+}
+
+/// Infers an Arrow schema from the top-level fields of a batch of log events.
+///
+/// Every field is nullable and named after its event path, quoted when necessary. Fields holding
+/// objects or arrays are encoded as JSON strings and marked with [`ARROW_JSON_FIELD_METADATA_KEY`],
+/// and fields whose values have incompatible types across events are encoded as strings.
+pub fn infer_schema(events: &[Event]) -> Schema {
+    let mut fields: IndexMap<String, Option<InferredType>> = IndexMap::new();
+
+    for event in events {
+        let Event::Log(log) = event else { continue };
+        let Some(object) = log.as_map() else { continue };
+
+        for (key, value) in object {
+            let inferred = InferredType::of(value);
+            let path = OwnedValuePath::from(vec![OwnedSegment::field(key.as_str())]).to_string();
+            fields
+                .entry(path)
+                .and_modify(|existing| {
+                    *existing = match (*existing, inferred) {
+                        (Some(a), Some(b)) => Some(a.merge(b)),
+                        (a, b) => a.or(b),
+                    }
+                })
+                .or_insert(inferred);
+        }
+    }
+
+    Schema::new(
+        fields
+            .into_iter()
+            .map(|(name, inferred)| inferred.unwrap_or(InferredType::Utf8).into_field(name))
+            .collect::<Vec<_>>(),
+    )
+}
+
 /// Builds an Arrow RecordBatch from events
-fn build_record_batch(
+pub fn build_record_batch(
     schema: Arc<Schema>,
     events: &[Event],
 ) -> Result<RecordBatch, ArrowEncodingError> {
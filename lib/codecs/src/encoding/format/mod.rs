@@ -23,7 +23,11 @@ mod text;
 use std::fmt::Debug;
 
 #[cfg(feature = "arrow")]
-pub use arrow::{ArrowEncodingError, ArrowStreamSerializer, ArrowStreamSerializerConfig};
+pub use arrow::{
+    ARROW_JSON_FIELD_METADATA_KEY, ArrowEncodingError, ArrowStreamSerializer,
+    ArrowStreamSerializerConfig, build_record_batch, encode_events_to_arrow_ipc_stream,
+    infer_schema,
+};
 pub use avro::{AvroSerializer, AvroSerializerConfig, AvroSerializerOptions};
 pub use cef::{CefSerializer, CefSerializerConfig};
 use dyn_clone::DynClone;
@@ -2,8 +2,8 @@ use crate::encoding::ProtobufSerializer;
 use bytes::BytesMut;
 use opentelemetry_proto::proto::{
     DESCRIPTOR_BYTES, LOGS_REQUEST_MESSAGE_TYPE, METRICS_REQUEST_MESSAGE_TYPE,
-    RESOURCE_LOGS_JSON_FIELD, RESOURCE_METRICS_JSON_FIELD, RESOURCE_SPANS_JSON_FIELD,
-    TRACES_REQUEST_MESSAGE_TYPE,
+    PROFILES_REQUEST_MESSAGE_TYPE, RESOURCE_LOGS_JSON_FIELD, RESOURCE_METRICS_JSON_FIELD,
+    RESOURCE_PROFILES_JSON_FIELD, RESOURCE_SPANS_JSON_FIELD, TRACES_REQUEST_MESSAGE_TYPE,
 };
 use tokio_util::codec::Encoder;
 use vector_config_macros::configurable_component;
@@ -47,6 +47,7 @@ impl OtlpSerializerConfig {
 /// - `resourceLogs` → `ExportLogsServiceRequest`
 /// - `resourceMetrics` → `ExportMetricsServiceRequest`
 /// - `resourceSpans` → `ExportTraceServiceRequest`
+/// - `resourceProfiles` → `ExportProfilesServiceRequest`
 ///
 /// The implementation is the inverse of what the `opentelemetry` source does when decoding,
 /// ensuring round-trip compatibility.
@@ -56,6 +57,7 @@ pub struct OtlpSerializer {
     logs_descriptor: ProtobufSerializer,
     metrics_descriptor: ProtobufSerializer,
     traces_descriptor: ProtobufSerializer,
+    profiles_descriptor: ProtobufSerializer,
     options: Options,
 }
 
@@ -84,10 +86,17 @@ impl OtlpSerializer {
             &options,
         )?;
 
+        let profiles_descriptor = ProtobufSerializer::new_from_bytes(
+            DESCRIPTOR_BYTES,
+            PROFILES_REQUEST_MESSAGE_TYPE,
+            &options,
+        )?;
+
         Ok(Self {
             logs_descriptor,
             metrics_descriptor,
             traces_descriptor,
+            profiles_descriptor,
             options,
         })
     }
@@ -107,9 +116,12 @@ impl Encoder<Event> for OtlpSerializer {
                 } else if log.contains(RESOURCE_METRICS_JSON_FIELD) {
                     // Currently the OTLP metrics are Vector logs (not metrics).
                     self.metrics_descriptor.encode(event, buffer)
+                } else if log.contains(RESOURCE_PROFILES_JSON_FIELD) {
+                    // OTLP profiles have no native representation and are always Vector logs.
+                    self.profiles_descriptor.encode(event, buffer)
                 } else {
                     Err(format!(
-                        "Log event does not contain OTLP top-level fields ({RESOURCE_LOGS_JSON_FIELD} or {RESOURCE_METRICS_JSON_FIELD})",
+                        "Log event does not contain OTLP top-level fields ({RESOURCE_LOGS_JSON_FIELD}, {RESOURCE_METRICS_JSON_FIELD} or {RESOURCE_PROFILES_JSON_FIELD})",
                     )
                         .into())
                 }
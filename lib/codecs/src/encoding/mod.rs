@@ -7,7 +7,11 @@ pub mod framing;
 pub mod serializer;
 pub use chunking::{Chunker, Chunking, GelfChunker};
 #[cfg(feature = "arrow")]
-pub use format::{ArrowEncodingError, ArrowStreamSerializer, ArrowStreamSerializerConfig};
+pub use format::{
+    ARROW_JSON_FIELD_METADATA_KEY, ArrowEncodingError, ArrowStreamSerializer,
+    ArrowStreamSerializerConfig, build_record_batch, encode_events_to_arrow_ipc_stream,
+    infer_schema,
+};
 pub use format::{
     AvroSerializer, AvroSerializerConfig, AvroSerializerOptions, CefSerializer,
     CefSerializerConfig, CsvSerializer, CsvSerializerConfig, GelfSerializer, GelfSerializerConfig,
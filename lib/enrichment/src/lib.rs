@@ -8,6 +8,8 @@ pub mod tables;
 mod test_util;
 mod vrl_util;
 
+use std::net::IpAddr;
+
 use dyn_clone::DynClone;
 pub use tables::{TableRegistry, TableSearch};
 use vrl::{
@@ -38,6 +40,25 @@ pub enum Condition<'a> {
         field: &'a str,
         to: chrono::DateTime<chrono::Utc>,
     },
+    /// The field holds an IP network, in CIDR notation, that contains the IP address.
+    Cidr { field: &'a str, ip: IpAddr },
+    /// The field holds a prefix of the value.
+    Prefix { field: &'a str, value: String },
+    /// The number in the field is greater than or equal to `from` and less than or equal to `to`,
+    /// either of which can be omitted. Bounds are integer or float values.
+    Range {
+        field: &'a str,
+        from: Option<Value>,
+        to: Option<Value>,
+    },
+}
+
+/// The kinds of conditions, other than equality, that tables can index fields for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ConditionKind {
+    Cidr,
+    Prefix,
+    Range,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -85,6 +106,21 @@ pub trait Table: DynClone {
     /// Returns a list of the field names that are in each index
     fn index_fields(&self) -> Vec<(Case, Vec<String>)>;
 
+    /// Hints to the enrichment table that the field is going to be searched with conditions of the
+    /// given kind, to allow it to index the data in advance. Tables that can't index such
+    /// conditions search through all of their data instead.
+    ///
+    /// # Errors
+    /// Errors if the field is not in the table.
+    fn add_condition_index(&mut self, _field: &str, _kind: ConditionKind) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Returns a list of the fields indexed for conditions other than equality.
+    fn condition_index_fields(&self) -> Vec<(String, ConditionKind)> {
+        Vec::new()
+    }
+
     /// Returns true if the underlying data has changed and the table needs reloading.
     fn needs_reload(&self) -> bool;
 }
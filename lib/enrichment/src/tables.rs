@@ -37,7 +37,7 @@ use std::{
 use arc_swap::ArcSwap;
 use vrl::value::{ObjectMap, Value};
 
-use super::{Condition, IndexHandle, Table};
+use super::{Condition, ConditionKind, IndexHandle, Table};
 use crate::Case;
 
 /// A hashmap of name => implementation of an enrichment table.
@@ -163,6 +163,30 @@ impl TableRegistry {
         }
     }
 
+    /// Adds an index to the given Enrichment Table, for conditions of the given kind on the field.
+    ///
+    /// If we are in the reading stage, this function will error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the Mutex is poisoned.
+    pub fn add_condition_index(
+        &mut self,
+        table: &str,
+        field: &str,
+        kind: ConditionKind,
+    ) -> Result<(), String> {
+        let mut locked = self.loading.lock().unwrap();
+
+        match *locked {
+            None => Err("finish_load has been called".to_string()),
+            Some(ref mut tables) => match tables.get_mut(table) {
+                None => Err(format!("table '{table}' not loaded")),
+                Some(table) => table.add_condition_index(field, kind),
+            },
+        }
+    }
+
     /// Returns a cheaply clonable struct through that provides lock free read
     /// access to the enrichment tables.
     pub fn as_readonly(&self) -> TableSearch {
@@ -181,6 +205,18 @@ impl TableRegistry {
         }
     }
 
+    /// Returns the condition indexes that have been applied to the given table, to reapply them
+    /// to the table when it is reloaded.
+    pub fn condition_index_fields(&self, table: &str) -> Vec<(String, ConditionKind)> {
+        match &**self.tables.load() {
+            Some(tables) => tables
+                .get(table)
+                .map(|table| table.condition_index_fields())
+                .unwrap_or_default(),
+            None => Vec::new(),
+        }
+    }
+
     /// Checks if the table needs reloading.
     /// If in doubt (the table isn't in our list) we return true.
     pub fn needs_reload(&self, table: &str) -> bool {
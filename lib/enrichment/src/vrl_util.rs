@@ -6,7 +6,7 @@ use vrl::{
     prelude::*,
 };
 
-use crate::{Case, Condition, IndexHandle, TableRegistry};
+use crate::{Case, Condition, ConditionKind, IndexHandle, TableRegistry};
 
 #[derive(Debug)]
 pub enum Error {
@@ -43,6 +43,41 @@ impl DiagnosticMessage for Error {
 /// Evaluates the condition object to search the enrichment tables with.
 pub(crate) fn evaluate_condition(key: &str, value: Value) -> ExpressionResult<Condition<'_>> {
     Ok(match value {
+        Value::Object(map) if map.contains_key("cidr") => Condition::Cidr {
+            field: key,
+            ip: map
+                .get("cidr")
+                .expect("should contain cidr")
+                .as_str()
+                .and_then(|ip| ip.parse().ok())
+                .ok_or("cidr in condition must be an IP address")?,
+        },
+        Value::Object(map) if map.contains_key("prefix") => Condition::Prefix {
+            field: key,
+            value: match map.get("prefix").expect("should contain prefix") {
+                Value::Bytes(bytes) => String::from_utf8_lossy(bytes).into_owned(),
+                Value::Integer(value) => value.to_string(),
+                _ => return Err("prefix in condition must be a string or an integer".into()),
+            },
+        },
+        Value::Object(map) if map.contains_key("range") => {
+            let range = map
+                .get("range")
+                .expect("should contain range")
+                .as_object()
+                .ok_or("range in condition must be an object")?;
+            let bound = |name: &str| match range.get(name) {
+                None => Ok(None),
+                Some(value @ (Value::Integer(_) | Value::Float(_))) => Ok(Some(value.clone())),
+                Some(_) => Err(format!("{name} in range condition must be a number")),
+            };
+
+            Condition::Range {
+                field: key,
+                from: bound("from")?,
+                to: bound("to")?,
+            }
+        }
         Value::Object(map) if map.contains_key("from") && map.contains_key("to") => {
             Condition::BetweenDates {
                 field: key,
@@ -78,6 +113,22 @@ pub(crate) fn evaluate_condition(key: &str, value: Value) -> ExpressionResult<Co
     })
 }
 
+/// Returns the kind of the condition, if it isn't an equality or date range condition.
+fn condition_kind(value: &expression::Expr) -> Option<ConditionKind> {
+    match value {
+        expression::Expr::Container(expression::Container {
+            variant: expression::Variant::Object(map),
+        }) => [
+            ("cidr", ConditionKind::Cidr),
+            ("prefix", ConditionKind::Prefix),
+            ("range", ConditionKind::Range),
+        ]
+        .into_iter()
+        .find_map(|(key, kind)| map.contains_key(key).then_some(kind)),
+        _ => None,
+    }
+}
+
 /// Add an index for the given condition to the given enrichment table.
 pub(crate) fn add_index(
     registry: &mut TableRegistry,
@@ -85,9 +136,16 @@ pub(crate) fn add_index(
     case: Case,
     condition: &BTreeMap<KeyString, expression::Expr>,
 ) -> std::result::Result<IndexHandle, ExpressionError> {
+    for (field, value) in condition {
+        if let Some(kind) = condition_kind(value) {
+            registry.add_condition_index(tablename, field, kind)?;
+        }
+    }
+
     let fields = condition
         .iter()
         .filter_map(|(field, value)| match value {
+            _ if condition_kind(value).is_some() => None,
             expression::Expr::Container(expression::Container {
                 variant: expression::Variant::Object(map),
             }) if (map.contains_key("from") && map.contains_key("to"))
@@ -130,6 +188,7 @@ mod tests {
     use std::sync::{Arc, Mutex};
 
     use chrono::{TimeZone, Utc};
+    use vrl::value;
 
     use super::*;
     use crate::test_util;
@@ -191,4 +250,63 @@ mod tests {
         let indexes = indexes.lock().unwrap();
         assert_eq!(vec![vec!["field1".to_string()]], *indexes);
     }
+
+    #[test]
+    fn add_indexes_with_cidr() {
+        let indexes = Arc::new(Mutex::new(Vec::new()));
+        let dummy = test_util::DummyEnrichmentTable::new_with_index(indexes.clone());
+
+        let mut registry =
+            test_util::get_table_registry_with_tables(vec![("dummy1".to_string(), dummy)]);
+
+        let conditions = BTreeMap::from([
+            ("field1".into(), (expression::Literal::from("value")).into()),
+            (
+                "field2".into(),
+                (expression::Container::new(expression::Variant::Object(
+                    BTreeMap::from([(
+                        "cidr".into(),
+                        (expression::Literal::from("10.0.0.1")).into(),
+                    )])
+                    .into(),
+                )))
+                .into(),
+            ),
+        ]);
+
+        let index = add_index(&mut registry, "dummy1", Case::Sensitive, &conditions).unwrap();
+
+        assert_eq!(IndexHandle(0), index);
+
+        // Ensure only the exact match has been added as an index.
+        let indexes = indexes.lock().unwrap();
+        assert_eq!(vec![vec!["field1".to_string()]], *indexes);
+    }
+
+    #[test]
+    fn evaluate_conditions() {
+        assert_eq!(
+            Condition::Cidr {
+                field: "network",
+                ip: "10.0.0.1".parse().unwrap(),
+            },
+            evaluate_condition("network", value!({"cidr": "10.0.0.1"})).unwrap()
+        );
+        assert_eq!(
+            Condition::Prefix {
+                field: "code",
+                value: "4420".to_string(),
+            },
+            evaluate_condition("code", value!({"prefix": 4420})).unwrap()
+        );
+        assert_eq!(
+            Condition::Range {
+                field: "port",
+                from: Some(Value::from(1024)),
+                to: None,
+            },
+            evaluate_condition("port", value!({"range": {"from": 1024}})).unwrap()
+        );
+        assert!(evaluate_condition("network", value!({"cidr": "nope"})).is_err());
+    }
 }
@@ -4,11 +4,14 @@ pub const TRACES_REQUEST_MESSAGE_TYPE: &str =
     "opentelemetry.proto.collector.trace.v1.ExportTraceServiceRequest";
 pub const METRICS_REQUEST_MESSAGE_TYPE: &str =
     "opentelemetry.proto.collector.metrics.v1.ExportMetricsServiceRequest";
+pub const PROFILES_REQUEST_MESSAGE_TYPE: &str =
+    "opentelemetry.proto.collector.profiles.v1development.ExportProfilesServiceRequest";
 
 // JSON names (camelCase) for the same fields, used when use_json_names is enabled
 pub const RESOURCE_LOGS_JSON_FIELD: &str = "resourceLogs";
 pub const RESOURCE_METRICS_JSON_FIELD: &str = "resourceMetrics";
 pub const RESOURCE_SPANS_JSON_FIELD: &str = "resourceSpans";
+pub const RESOURCE_PROFILES_JSON_FIELD: &str = "resourceProfiles";
 
 /// Service stub and clients.
 pub mod collector {
@@ -27,6 +30,11 @@ pub mod collector {
             tonic::include_proto!("opentelemetry.proto.collector.metrics.v1");
         }
     }
+    pub mod profiles {
+        pub mod v1development {
+            tonic::include_proto!("opentelemetry.proto.collector.profiles.v1development");
+        }
+    }
 }
 
 /// Common types used across all event types.
@@ -57,6 +65,13 @@ pub mod trace {
     }
 }
 
+/// Generated types used for profiles.
+pub mod profiles {
+    pub mod v1development {
+        tonic::include_proto!("opentelemetry.proto.profiles.v1development");
+    }
+}
+
 /// Generated types used in resources.
 pub mod resource {
     pub mod v1 {
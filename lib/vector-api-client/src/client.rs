@@ -1,4 +1,7 @@
+use std::{fmt, fs, io, path::PathBuf};
+
 use anyhow::Context;
+use base64::prelude::{BASE64_STANDARD, Engine as _};
 use graphql_client::GraphQLQuery;
 use url::Url;
 
@@ -8,16 +11,170 @@ use crate::gql::HealthQueryExt;
 pub type QueryResult<T> =
     anyhow::Result<graphql_client::Response<<T as GraphQLQuery>::ResponseData>>;
 
+/// Credentials used to authenticate with the GraphQL API server.
+#[derive(Clone)]
+pub enum Auth {
+    /// Basic authentication, with a username and password.
+    Basic {
+        /// The username.
+        username: String,
+        /// The password.
+        password: String,
+    },
+
+    /// Bearer authentication, with a token.
+    Bearer {
+        /// The token.
+        token: String,
+    },
+}
+
+impl Auth {
+    /// Returns the value of the `Authorization` header for these credentials.
+    pub fn header_value(&self) -> String {
+        match self {
+            Self::Basic { username, password } => format!(
+                "Basic {}",
+                BASE64_STANDARD.encode(format!("{username}:{password}"))
+            ),
+            Self::Bearer { token } => format!("Bearer {token}"),
+        }
+    }
+}
+
+impl fmt::Debug for Auth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Basic { username, .. } => f
+                .debug_struct("Basic")
+                .field("username", username)
+                .field("password", &"**REDACTED**")
+                .finish(),
+            Self::Bearer { .. } => f
+                .debug_struct("Bearer")
+                .field("token", &"**REDACTED**")
+                .finish(),
+        }
+    }
+}
+
+/// Options for connecting to the GraphQL API server, shared by the query and subscription
+/// clients.
+#[derive(Clone, Debug, Default)]
+pub struct ClientOptions {
+    /// Credentials used to authenticate with the API server.
+    pub auth: Option<Auth>,
+
+    /// Path to a PEM-encoded CA certificate used to verify the API server's certificate, in
+    /// addition to the system's trusted certificates.
+    pub ca_file: Option<PathBuf>,
+}
+
+impl ClientOptions {
+    /// Builds the TLS connector to use for connecting to the API server, if the default one
+    /// isn't suitable.
+    pub(crate) fn tls_connector(&self) -> io::Result<Option<native_tls::TlsConnector>> {
+        let Some(ca_file) = &self.ca_file else {
+            return Ok(None);
+        };
+
+        let ca = fs::read(ca_file)?;
+        let ca = native_tls::Certificate::from_pem(&ca).map_err(io::Error::other)?;
+        native_tls::TlsConnector::builder()
+            .add_root_certificate(ca)
+            .build()
+            .map(Some)
+            .map_err(io::Error::other)
+    }
+}
+
+/// Command-line arguments for connecting to the GraphQL API server.
+#[derive(clap::Args, Clone, Debug, Default)]
+#[command(rename_all = "kebab-case")]
+pub struct ClientArgs {
+    /// Bearer token used to authenticate with the API server
+    #[arg(
+        long,
+        env = "VECTOR_API_TOKEN",
+        hide_env_values = true,
+        conflicts_with = "username"
+    )]
+    pub token: Option<String>,
+
+    /// Username used to authenticate with the API server, using basic authentication
+    #[arg(long, env = "VECTOR_API_USERNAME", requires = "password")]
+    pub username: Option<String>,
+
+    /// Password used to authenticate with the API server, using basic authentication
+    #[arg(
+        long,
+        env = "VECTOR_API_PASSWORD",
+        hide_env_values = true,
+        requires = "username"
+    )]
+    pub password: Option<String>,
+
+    /// Path to a PEM-encoded CA certificate used to verify the API server's certificate, in
+    /// addition to the system's trusted certificates
+    #[arg(long)]
+    pub ca_file: Option<PathBuf>,
+}
+
+impl ClientArgs {
+    /// Returns the client options for these arguments.
+    pub fn options(&self) -> ClientOptions {
+        let auth = match (&self.token, &self.username, &self.password) {
+            (Some(token), _, _) => Some(Auth::Bearer {
+                token: token.clone(),
+            }),
+            (None, Some(username), Some(password)) => Some(Auth::Basic {
+                username: username.clone(),
+                password: password.clone(),
+            }),
+            _ => None,
+        };
+
+        ClientOptions {
+            auth,
+            ca_file: self.ca_file.clone(),
+        }
+    }
+}
+
 /// GraphQL query client over HTTP.
 #[derive(Debug)]
 pub struct Client {
     url: Url,
+    client: reqwest::Client,
+    auth: Option<Auth>,
 }
 
 impl Client {
     /// Returns a new GraphQL query client, bound to the provided URL.
     pub fn new(url: Url) -> Self {
-        Self { url }
+        Self {
+            url,
+            client: reqwest::Client::new(),
+            auth: None,
+        }
+    }
+
+    /// Returns a new GraphQL query client, bound to the provided URL, and connecting with the
+    /// provided options.
+    pub fn new_with_options(url: Url, options: &ClientOptions) -> anyhow::Result<Self> {
+        let mut builder = reqwest::Client::builder();
+        if let Some(connector) = options
+            .tls_connector()
+            .context("Couldn't load the CA certificate")?
+        {
+            builder = builder.use_preconfigured_tls(connector);
+        }
+
+        Ok(Self {
+            url,
+            client: builder.build().context("Couldn't build the HTTP client")?,
+            auth: options.auth.clone(),
+        })
     }
 
     /// Send a health query
@@ -31,13 +188,15 @@ impl Client {
         &self,
         request_body: &graphql_client::QueryBody<T::Variables>,
     ) -> QueryResult<T> {
-        let client = reqwest::Client::new();
+        let mut request = self.client.post(self.url.clone()).json(request_body);
+        if let Some(auth) = &self.auth {
+            request = request.header(reqwest::header::AUTHORIZATION, auth.header_value());
+        }
 
-        client
-            .post(self.url.clone())
-            .json(request_body)
+        request
             .send()
             .await
+            .and_then(reqwest::Response::error_for_status)
             .with_context(|| {
                 format!(
                     "Couldn't send '{}' query to {}",
@@ -147,6 +147,35 @@ impl component_sent_events_throughputs_subscription::ComponentSentEventsThroughp
 )]
 pub struct ComponentErrorsTotalsSubscription;
 
+/// ComponentDiscardedEventsTotalsSubscription contains metrics on the number of events
+/// discarded by specific components.
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/subscriptions/component_discarded_events_totals.graphql",
+    response_derives = "Debug"
+)]
+pub struct ComponentDiscardedEventsTotalsSubscription;
+
+/// ComponentUtilizationsSubscription contains the `utilization` gauge of specific components.
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/subscriptions/component_utilizations.graphql",
+    response_derives = "Debug"
+)]
+pub struct ComponentUtilizationsSubscription;
+
+/// ComponentBufferUtilizationsSubscription contains the fraction of the buffer capacity of
+/// specific components in use.
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/subscriptions/component_buffer_utilizations.graphql",
+    response_derives = "Debug"
+)]
+pub struct ComponentBufferUtilizationsSubscription;
+
 /// Extension methods for metrics subscriptions
 pub trait MetricsSubscriptionExt {
     /// Executes an uptime metrics subscription.
@@ -210,6 +239,24 @@ pub trait MetricsSubscriptionExt {
         &self,
         interval: i64,
     ) -> crate::BoxedSubscription<ComponentErrorsTotalsSubscription>;
+
+    /// Executes a component discarded events totals subscription.
+    fn component_discarded_events_totals_subscription(
+        &self,
+        interval: i64,
+    ) -> crate::BoxedSubscription<ComponentDiscardedEventsTotalsSubscription>;
+
+    /// Executes a component utilization subscription.
+    fn component_utilizations_subscription(
+        &self,
+        interval: i64,
+    ) -> crate::BoxedSubscription<ComponentUtilizationsSubscription>;
+
+    /// Executes a component buffer utilization subscription.
+    fn component_buffer_utilizations_subscription(
+        &self,
+        interval: i64,
+    ) -> crate::BoxedSubscription<ComponentBufferUtilizationsSubscription>;
 }
 
 impl MetricsSubscriptionExt for crate::SubscriptionClient {
@@ -338,4 +385,40 @@ impl MetricsSubscriptionExt for crate::SubscriptionClient {
 
         self.start::<ComponentErrorsTotalsSubscription>(&request_body)
     }
+
+    /// Executes a component discarded events totals subscription.
+    fn component_discarded_events_totals_subscription(
+        &self,
+        interval: i64,
+    ) -> BoxedSubscription<ComponentDiscardedEventsTotalsSubscription> {
+        let request_body = ComponentDiscardedEventsTotalsSubscription::build_query(
+            component_discarded_events_totals_subscription::Variables { interval },
+        );
+
+        self.start::<ComponentDiscardedEventsTotalsSubscription>(&request_body)
+    }
+
+    /// Executes a component utilization subscription.
+    fn component_utilizations_subscription(
+        &self,
+        interval: i64,
+    ) -> BoxedSubscription<ComponentUtilizationsSubscription> {
+        let request_body = ComponentUtilizationsSubscription::build_query(
+            component_utilizations_subscription::Variables { interval },
+        );
+
+        self.start::<ComponentUtilizationsSubscription>(&request_body)
+    }
+
+    /// Executes a component buffer utilization subscription.
+    fn component_buffer_utilizations_subscription(
+        &self,
+        interval: i64,
+    ) -> BoxedSubscription<ComponentBufferUtilizationsSubscription> {
+        let request_body = ComponentBufferUtilizationsSubscription::build_query(
+            component_buffer_utilizations_subscription::Variables { interval },
+        );
+
+        self.start::<ComponentBufferUtilizationsSubscription>(&request_body)
+    }
 }
@@ -24,6 +24,9 @@ pub enum TapEncodingFormat {
     Json,
     Yaml,
     Logfmt,
+    /// Vector's native protobuf encoding, as base64, which is lossless. Used to record events.
+    #[value(skip)]
+    Native,
 }
 
 /// String -> TapEncodingFormat, typically for parsing user input.
@@ -35,6 +38,7 @@ impl std::str::FromStr for TapEncodingFormat {
             "json" => Ok(Self::Json),
             "yaml" => Ok(Self::Yaml),
             "logfmt" => Ok(Self::Logfmt),
+            "native" => Ok(Self::Native),
             _ => Err("Invalid encoding format".to_string()),
         }
     }
@@ -49,12 +53,15 @@ impl From<TapEncodingFormat>
             TapEncodingFormat::Json => Self::JSON,
             TapEncodingFormat::Yaml => Self::YAML,
             TapEncodingFormat::Logfmt => Self::LOGFMT,
+            TapEncodingFormat::Native => Self::NATIVE,
         }
     }
 }
 
 pub trait TapSubscriptionExt {
-    /// Executes an output events subscription.
+    /// Executes an output events subscription. Events are filtered with the `filter` VRL
+    /// condition, and reshaped by the `transform` VRL program, server-side.
+    #[allow(clippy::too_many_arguments)]
     fn output_events_by_component_id_patterns_subscription(
         &self,
         outputs_patterns: Vec<String>,
@@ -62,11 +69,14 @@ pub trait TapSubscriptionExt {
         encoding: TapEncodingFormat,
         limit: i64,
         interval: i64,
+        filter: Option<String>,
+        transform: Option<String>,
     ) -> crate::BoxedSubscription<OutputEventsByComponentIdPatternsSubscription>;
 }
 
 impl TapSubscriptionExt for crate::SubscriptionClient {
     /// Executes an output events subscription.
+    #[allow(clippy::too_many_arguments)]
     fn output_events_by_component_id_patterns_subscription(
         &self,
         outputs_patterns: Vec<String>,
@@ -74,6 +84,8 @@ impl TapSubscriptionExt for crate::SubscriptionClient {
         encoding: TapEncodingFormat,
         limit: i64,
         interval: i64,
+        filter: Option<String>,
+        transform: Option<String>,
     ) -> BoxedSubscription<OutputEventsByComponentIdPatternsSubscription> {
         let request_body = OutputEventsByComponentIdPatternsSubscription::build_query(
             output_events_by_component_id_patterns_subscription::Variables {
@@ -82,6 +94,8 @@ impl TapSubscriptionExt for crate::SubscriptionClient {
                 limit,
                 interval,
                 encoding: encoding.into(),
+                filter,
+                transform,
             },
         );
 
@@ -13,10 +13,19 @@ use tokio::sync::{
     mpsc, oneshot,
 };
 use tokio_stream::{Stream, StreamExt, wrappers::BroadcastStream};
-use tokio_tungstenite::{connect_async, tungstenite::Message};
+use tokio_tungstenite::{
+    Connector, connect_async_tls_with_config,
+    tungstenite::{
+        self, Message,
+        client::IntoClientRequest,
+        http::{HeaderValue, header::AUTHORIZATION},
+    },
+};
 use url::Url;
 use uuid::Uuid;
 
+use crate::ClientOptions;
+
 /// Subscription GraphQL response, returned from an active stream.
 pub type BoxedSubscription<T> = Pin<
     Box<
@@ -165,8 +174,25 @@ impl SubscriptionClient {
 /// set up channel forwarding to expose just the returned `Payload`s to the client.
 pub async fn connect_subscription_client(
     url: Url,
-) -> Result<SubscriptionClient, tokio_tungstenite::tungstenite::Error> {
-    let (ws, _) = connect_async(url).await?;
+) -> Result<SubscriptionClient, tungstenite::Error> {
+    connect_subscription_client_with_options(url, &ClientOptions::default()).await
+}
+
+/// Connect to a new WebSocket GraphQL server endpoint with the provided options, and return a
+/// `SubscriptionClient`. See [`connect_subscription_client`].
+pub async fn connect_subscription_client_with_options(
+    url: Url,
+    options: &ClientOptions,
+) -> Result<SubscriptionClient, tungstenite::Error> {
+    let mut request = url.as_str().into_client_request()?;
+    if let Some(auth) = &options.auth {
+        let value =
+            HeaderValue::from_str(&auth.header_value()).map_err(tungstenite::http::Error::from)?;
+        request.headers_mut().insert(AUTHORIZATION, value);
+    }
+    let connector = options.tls_connector()?.map(Connector::NativeTls);
+
+    let (ws, _) = connect_async_tls_with_config(request, None, false, connector).await?;
     let (mut ws_tx, mut ws_rx) = futures::StreamExt::split(ws);
 
     let (send_tx, mut send_rx) = mpsc::unbounded_channel::<Payload>();
@@ -275,6 +275,8 @@ where
             BufferType::DiskV2 {
                 max_size: max_size_bytes,
                 when_full,
+                compression: Default::default(),
+                encryption: None,
             }
         }
         s => panic!(
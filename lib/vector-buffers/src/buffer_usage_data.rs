@@ -185,6 +185,15 @@ impl BufferUsageHandle {
             self.state.current.decrement(count, byte_size);
         }
     }
+
+    /// Increment the number of events (and their total size) evicted from this buffer component to
+    /// make room for newer events.
+    pub fn increment_evicted_event_count_and_byte_size(&self, count: u64, byte_size: u64) {
+        if count > 0 || byte_size > 0 {
+            self.state.evicted.increment(count, byte_size);
+            self.state.current.decrement(count, byte_size);
+        }
+    }
 }
 
 #[derive(Debug, Default)]
@@ -194,6 +203,7 @@ struct BufferUsageData {
     sent: CategoryMetrics,
     dropped: CategoryMetrics,
     dropped_intentional: CategoryMetrics,
+    evicted: CategoryMetrics,
     max_size: CategoryMetrics,
     current: CategoryMetrics,
 }
@@ -211,6 +221,7 @@ impl BufferUsageData {
         let sent = self.sent.get();
         let dropped = self.dropped.get();
         let dropped_intentional = self.dropped_intentional.get();
+        let evicted = self.evicted.get();
         let max_size = self.max_size.get();
 
         BufferUsageSnapshot {
@@ -220,8 +231,9 @@ impl BufferUsageData {
             sent_byte_size: sent.event_byte_size,
             dropped_event_count: dropped.event_count,
             dropped_event_byte_size: dropped.event_byte_size,
-            dropped_event_count_intentional: dropped_intentional.event_count,
-            dropped_event_byte_size_intentional: dropped_intentional.event_byte_size,
+            dropped_event_count_intentional: dropped_intentional.event_count + evicted.event_count,
+            dropped_event_byte_size_intentional: dropped_intentional.event_byte_size
+                + evicted.event_byte_size,
             max_size_bytes: max_size.event_byte_size,
             max_size_events: max_size
                 .event_count
@@ -362,6 +374,20 @@ impl BufferUsage {
                             total_byte_size: current.event_byte_size,
                         });
                     }
+
+                    let evicted = stage.evicted.consume();
+                    if evicted.has_updates() {
+                        emit(BufferEventsDropped {
+                            buffer_id: buffer_id.clone(),
+                            idx: stage.idx,
+                            intentional: true,
+                            reason: "drop_oldest",
+                            count: evicted.event_count,
+                            byte_size: evicted.event_byte_size,
+                            total_count: current.event_count,
+                            total_byte_size: current.event_byte_size,
+                        });
+                    }
                 }
             }
         };
@@ -5,10 +5,13 @@ use std::{
     slice,
 };
 
+use base64::prelude::{BASE64_STANDARD, Engine as _};
 use serde::{Deserialize, Deserializer, Serialize, de};
 use snafu::{ResultExt, Snafu};
 use tracing::Span;
-use vector_common::{config::ComponentKey, finalization::Finalizable};
+use vector_common::{
+    config::ComponentKey, finalization::Finalizable, sensitive_string::SensitiveString,
+};
 use vector_config::configurable_component;
 
 use crate::{
@@ -17,7 +20,10 @@ use crate::{
         builder::{TopologyBuilder, TopologyError},
         channel::{BufferReceiver, BufferSender},
     },
-    variants::{DiskV2Buffer, MemoryBuffer},
+    variants::{
+        DiskV2Buffer, MemoryBuffer,
+        disk_v2::{ENCRYPTION_KEY_LEN, PayloadCodec},
+    },
 };
 
 #[derive(Debug, Snafu)]
@@ -28,6 +34,11 @@ pub enum BufferBuildError {
     FailedToBuildTopology { source: TopologyError },
     #[snafu(display("`max_events` must be greater than zero"))]
     InvalidMaxEvents,
+    #[snafu(display(
+        "the disk buffer encryption key must be a base64-encoded {} byte key",
+        ENCRYPTION_KEY_LEN
+    ))]
+    InvalidEncryptionKey,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -38,7 +49,14 @@ enum BufferTypeKind {
     DiskV2,
 }
 
-const ALL_FIELDS: [&str; 4] = ["type", "max_events", "max_size", "when_full"];
+const ALL_FIELDS: [&str; 6] = [
+    "type",
+    "max_events",
+    "max_size",
+    "when_full",
+    "compression",
+    "encryption",
+];
 
 struct BufferTypeVisitor;
 
@@ -51,6 +69,8 @@ impl BufferTypeVisitor {
         let mut max_events: Option<NonZeroUsize> = None;
         let mut max_size: Option<NonZeroU64> = None;
         let mut when_full: Option<WhenFull> = None;
+        let mut compression: Option<DiskBufferCompression> = None;
+        let mut encryption: Option<DiskBufferEncryption> = None;
         while let Some(key) = map.next_key::<String>()? {
             match key.as_str() {
                 "type" => {
@@ -77,6 +97,18 @@ impl BufferTypeVisitor {
                     }
                     when_full = Some(map.next_value()?);
                 }
+                "compression" => {
+                    if compression.is_some() {
+                        return Err(de::Error::duplicate_field("compression"));
+                    }
+                    compression = Some(map.next_value()?);
+                }
+                "encryption" => {
+                    if encryption.is_some() {
+                        return Err(de::Error::duplicate_field("encryption"));
+                    }
+                    encryption = Some(map.next_value()?);
+                }
                 other => {
                     return Err(de::Error::unknown_field(other, &ALL_FIELDS));
                 }
@@ -86,6 +118,18 @@ impl BufferTypeVisitor {
         let when_full = when_full.unwrap_or_default();
         match kind {
             BufferTypeKind::Memory => {
+                if compression.is_some() {
+                    return Err(de::Error::unknown_field(
+                        "compression",
+                        &["type", "max_events", "max_size", "when_full"],
+                    ));
+                }
+                if encryption.is_some() {
+                    return Err(de::Error::unknown_field(
+                        "encryption",
+                        &["type", "max_events", "max_size", "when_full"],
+                    ));
+                }
                 let size = match (max_events, max_size) {
                     (Some(_), Some(_)) => {
                         return Err(de::Error::unknown_field(
@@ -117,12 +161,14 @@ impl BufferTypeVisitor {
                 if max_events.is_some() {
                     return Err(de::Error::unknown_field(
                         "max_events",
-                        &["type", "max_size", "when_full"],
+                        &["type", "max_size", "when_full", "compression", "encryption"],
                     ));
                 }
                 Ok(BufferType::DiskV2 {
                     max_size: max_size.ok_or_else(|| de::Error::missing_field("max_size"))?,
                     when_full,
+                    compression: compression.unwrap_or_default(),
+                    encryption,
                 })
             }
         }
@@ -208,9 +254,50 @@ pub enum MemoryBufferSize {
     MaxSize(#[configurable(metadata(docs::type_unit = "bytes"))] NonZeroUsize),
 }
 
+/// Compression applied to the records written to a disk buffer.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DiskBufferCompression {
+    /// Records are written uncompressed.
+    #[default]
+    None,
+
+    /// Records are compressed with [Zstandard][zstd].
+    ///
+    /// [zstd]: https://facebook.github.io/zstd/
+    Zstd,
+}
+
+/// At-rest encryption of the records written to a disk buffer.
+#[configurable_component]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct DiskBufferEncryption {
+    /// The key used to encrypt records with AES-256-GCM, as a base64-encoded 256-bit key.
+    ///
+    /// A secret reference, such as `SECRET[backend.buffer_key]`, can be used to load the key from
+    /// a secrets backend.
+    ///
+    /// The key must not change while encrypted records are still in the buffer, as they can no
+    /// longer be read after the key has changed.
+    #[configurable(metadata(docs::examples = "SECRET[backend.buffer_key]"))]
+    pub key: SensitiveString,
+}
+
+impl DiskBufferEncryption {
+    pub(crate) fn decode_key(&self) -> Result<[u8; ENCRYPTION_KEY_LEN], BufferBuildError> {
+        BASE64_STANDARD
+            .decode(self.key.inner())
+            .ok()
+            .and_then(|key| key.try_into().ok())
+            .ok_or(BufferBuildError::InvalidEncryptionKey)
+    }
+}
+
 /// A specific type of buffer stage.
 #[configurable_component(no_deser)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[serde(rename_all = "snake_case", tag = "type")]
 #[configurable(metadata(docs::enum_tag_description = "The type of buffer to use."))]
 pub enum BufferType {
@@ -250,6 +337,14 @@ pub enum BufferType {
         #[configurable(derived)]
         #[serde(default)]
         when_full: WhenFull,
+
+        #[configurable(derived)]
+        #[serde(default)]
+        compression: DiskBufferCompression,
+
+        #[configurable(derived)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        encryption: Option<DiskBufferEncryption>,
     },
 }
 
@@ -308,16 +403,29 @@ impl BufferType {
     where
         T: Bufferable + Clone + Finalizable,
     {
-        match *self {
+        match self {
             BufferType::Memory { size, when_full } => {
-                builder.stage(MemoryBuffer::new(size), when_full);
+                builder.stage(MemoryBuffer::new(*size), *when_full);
             }
             BufferType::DiskV2 {
                 when_full,
                 max_size,
+                compression,
+                encryption,
             } => {
                 let data_dir = data_dir.ok_or(BufferBuildError::RequiresDataDir)?;
-                builder.stage(DiskV2Buffer::new(id, data_dir, max_size), when_full);
+                let encryption_key = encryption
+                    .as_ref()
+                    .map(DiskBufferEncryption::decode_key)
+                    .transpose()?;
+                let payload_codec = PayloadCodec::new(
+                    *compression == DiskBufferCompression::Zstd,
+                    encryption_key.as_ref(),
+                );
+                builder.stage(
+                    DiskV2Buffer::new(id, data_dir, *max_size, payload_codec),
+                    *when_full,
+                );
             }
         }
 
@@ -418,7 +526,10 @@ impl BufferConfig {
 mod test {
     use std::num::{NonZeroU64, NonZeroUsize};
 
-    use crate::{BufferConfig, BufferType, MemoryBufferSize, WhenFull};
+    use crate::{
+        BufferConfig, BufferType, DiskBufferCompression, DiskBufferEncryption, MemoryBufferSize,
+        WhenFull,
+    };
 
     fn check_single_stage(source: &str, expected: BufferType) {
         let config: BufferConfig = serde_yaml::from_str(source).unwrap();
@@ -561,7 +672,37 @@ max_events: 42
             BufferType::DiskV2 {
                 max_size: NonZeroU64::new(1024).unwrap(),
                 when_full: WhenFull::Block,
+                compression: DiskBufferCompression::None,
+                encryption: None,
+            },
+        );
+    }
+
+    #[test]
+    fn parse_disk_compression_and_encryption() {
+        check_single_stage(
+            r"
+          type: disk
+          max_size: 1024
+          compression: zstd
+          encryption:
+            key: c2VjcmV0
+          ",
+            BufferType::DiskV2 {
+                max_size: NonZeroU64::new(1024).unwrap(),
+                when_full: WhenFull::Block,
+                compression: DiskBufferCompression::Zstd,
+                encryption: Some(DiskBufferEncryption {
+                    key: "c2VjcmV0".to_string().into(),
+                }),
             },
         );
+
+        let source = r"
+          type: memory
+          compression: zstd
+          ";
+        let error = serde_yaml::from_str::<BufferConfig>(source).unwrap_err();
+        assert_eq!(error.to_string(), BUFFER_CONFIG_NO_MATCH_ERR);
     }
 }
@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::{path::PathBuf, time::Duration};
 
 use metrics::{Histogram, counter, gauge, histogram};
 use vector_common::{
@@ -203,6 +203,28 @@ impl InternalEvent for BufferReadError {
     }
 }
 
+pub struct BufferEvictedEventCountUnknown {
+    pub data_file_path: PathBuf,
+}
+
+impl InternalEvent for BufferEvictedEventCountUnknown {
+    fn emit(self) {
+        error!(
+            message = "Evicted data file with an unknown number of events. Evicted events will be reported as dropped instead.",
+            data_file_path = %self.data_file_path.display(),
+            error_code = "evicted_event_count_unknown",
+            error_type = error_type::WRITER_FAILED,
+            stage = "processing",
+        );
+        counter!(
+            "buffer_errors_total", "error_code" => "evicted_event_count_unknown",
+            "error_type" => "writer_failed",
+            "stage" => "processing",
+        )
+        .increment(1);
+    }
+}
+
 registered_event! {
     BufferSendDuration {
         stage: usize,
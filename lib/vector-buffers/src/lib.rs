@@ -17,7 +17,9 @@ extern crate tracing;
 mod buffer_usage_data;
 
 pub mod config;
-pub use config::{BufferConfig, BufferType, MemoryBufferSize};
+pub use config::{
+    BufferConfig, BufferType, DiskBufferCompression, DiskBufferEncryption, MemoryBufferSize,
+};
 use encoding::Encodable;
 pub(crate) use vector_common::Result;
 use vector_config::configurable_component;
@@ -31,6 +33,10 @@ pub mod test;
 pub mod topology;
 
 pub(crate) mod variants;
+pub use variants::disk_v2::{
+    BufferInspector, DataFileSummary, InspectError, InspectedRecord, LedgerLoadCreateError,
+    LedgerSummary, ReaderError,
+};
 
 use std::fmt::Debug;
 
@@ -58,6 +64,18 @@ pub enum WhenFull {
     /// slowdown in the acceptance/consumption of events.
     DropNewest,
 
+    /// Evicts the oldest buffered events to make room for the event.
+    ///
+    /// The oldest events that have not yet been read out of the buffer are intentionally dropped.
+    /// This mode is typically used when fresh data is more valuable than stale data, such that a
+    /// sink recovering from an outage resumes with recent events instead of working through a
+    /// backlog of old ones.
+    ///
+    /// Disk buffers evict entire data files at a time, and only those that have not yet started
+    /// being read. If no such data files exist, the event waits for free space as it would when
+    /// blocking.
+    DropOldest,
+
     /// Overflows to the next stage in the buffer topology.
     ///
     /// If the current buffer stage is full, attempt to send this event to the next buffer stage.
@@ -76,10 +94,10 @@ impl Arbitrary for WhenFull {
         // TODO: We explicitly avoid generating "overflow" as a possible value because nothing yet
         // supports handling it, and will be defaulted to using "block" if they encounter
         // "overflow".  Thus, there's no reason to emit it here... yet.
-        if bool::arbitrary(g) {
-            WhenFull::Block
-        } else {
-            WhenFull::DropNewest
+        match u8::arbitrary(g) % 3 {
+            0 => WhenFull::Block,
+            1 => WhenFull::DropNewest,
+            _ => WhenFull::DropOldest,
         }
     }
 }
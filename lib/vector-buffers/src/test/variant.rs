@@ -14,7 +14,7 @@ use crate::{
         builder::TopologyBuilder,
         channel::{BufferReceiver, BufferSender},
     },
-    variants::{DiskV2Buffer, MemoryBuffer},
+    variants::{DiskV2Buffer, MemoryBuffer, disk_v2::PayloadCodec},
 };
 
 #[cfg(test)]
@@ -61,7 +61,12 @@ impl Variant {
                 id,
             } => {
                 builder.stage(
-                    DiskV2Buffer::new(id.clone(), data_dir.clone(), *max_size),
+                    DiskV2Buffer::new(
+                        id.clone(),
+                        data_dir.clone(),
+                        *max_size,
+                        PayloadCodec::default(),
+                    ),
                     *when_full,
                 );
             }
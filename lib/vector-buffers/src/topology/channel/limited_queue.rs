@@ -261,6 +261,44 @@ impl<T: InMemoryBufferable> LimitedSender<T> {
             Err(TryAcquireError::Closed) => Err(TrySendError::Disconnected(item)),
         }
     }
+
+    /// Sends an item into the channel, evicting the oldest items in the channel to make room for
+    /// it if necessary.
+    ///
+    /// Items are evicted from the head of the channel until there is enough capacity for the item,
+    /// and are handed back to the caller. Items that were already received are not affected, so if
+    /// the channel runs out of items to evict, this waits for capacity like `send` does.
+    ///
+    /// # Errors
+    ///
+    /// If the receiver has disconnected (does not exist anymore), then `Err(SendError)` be returned
+    /// with the given `item`.
+    pub async fn send_evicting_oldest(&mut self, item: T) -> Result<Vec<T>, SendError<T>> {
+        let mut evicted = Vec::new();
+
+        let (limit, count, permits_required) = self.calc_required_permits(&item);
+        loop {
+            let in_use = limit.saturating_sub(self.available_capacity());
+            match self
+                .inner
+                .limiter
+                .clone()
+                .try_acquire_many_owned(permits_required)
+            {
+                Ok(permits) => {
+                    self.inner.send_with_permit(in_use + count, permits, item);
+                    trace!(evicted = evicted.len(), "Sent item.");
+                    return Ok(evicted);
+                }
+                Err(TryAcquireError::NoPermits) => match self.inner.data.pop() {
+                    // Dropping the permits of the evicted item frees up its capacity.
+                    Some((_permits, oldest)) => evicted.push(oldest),
+                    None => return self.send(item).await.map(|()| evicted),
+                },
+                Err(TryAcquireError::Closed) => return Err(SendError(item)),
+            }
+        }
+    }
 }
 
 impl<T> Clone for LimitedSender<T> {
@@ -742,4 +780,25 @@ mod tests {
 
         assert_eq!(2, tx.available_capacity());
     }
+
+    #[tokio::test]
+    async fn sender_evicts_oldest_items_when_full() {
+        let limit = MemoryBufferSize::MaxEvents(NonZeroUsize::new(2).unwrap());
+        let (mut tx, mut rx) = limited(limit, None);
+
+        assert_eq!(Ok(vec![]), tx.send_evicting_oldest(Sample::new(1)).await);
+        assert_eq!(Ok(vec![]), tx.send_evicting_oldest(Sample::new(2)).await);
+        assert_eq!(0, tx.available_capacity());
+
+        // The channel is full, so the oldest item should be evicted to make room.
+        assert_eq!(
+            Ok(vec![Sample::new(1)]),
+            tx.send_evicting_oldest(Sample::new(3)).await
+        );
+        assert_eq!(0, tx.available_capacity());
+
+        assert_eq!(Some(Sample::new(2)), rx.next().await);
+        assert_eq!(Some(Sample::new(3)), rx.next().await);
+        assert_eq!(2, tx.available_capacity());
+    }
 }
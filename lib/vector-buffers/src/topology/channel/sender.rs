@@ -83,6 +83,34 @@ where
         }
     }
 
+    /// Sends an item, evicting the oldest buffered items to make room for it if necessary.
+    ///
+    /// Items evicted from an in-memory channel are handed back so that they can be accounted for,
+    /// while the disk v2 buffer accounts for the events it evicts itself.
+    pub(crate) async fn send_evicting_oldest(&mut self, item: T) -> crate::Result<Vec<T>> {
+        match self {
+            Self::InMemory(tx) => tx.send_evicting_oldest(item).await.map_err(Into::into),
+            Self::DiskV2(writer) => {
+                let mut writer = writer.lock().await;
+
+                writer
+                    .write_record_evicting_oldest(item)
+                    .await
+                    .map(|_| Vec::new())
+                    .map_err(|e| {
+                        // TODO: Could some errors be handled and not be unrecoverable? Right now,
+                        // encoding should theoretically be recoverable -- encoded value was too big, or
+                        // error during encoding -- but the traits don't allow for recovering the
+                        // original event value because we have to consume it to do the encoding... but
+                        // that might not always be the case.
+                        error!("Disk buffer writer has encountered an unrecoverable error.");
+
+                        e.into()
+                    })
+            }
+        }
+    }
+
     pub(crate) async fn flush(&mut self) -> crate::Result<()> {
         match self {
             Self::InMemory(_) => Ok(()),
@@ -112,16 +140,19 @@ where
 /// events when the internal channel is full.
 ///
 /// When creating a buffer sender/receiver pair, callers can specify the "when full" behavior of the
-/// sender.  This controls how events are handled when the internal channel is full.  Three modes
+/// sender.  This controls how events are handled when the internal channel is full.  Four modes
 /// are possible:
 /// - block
 /// - drop newest
+/// - drop oldest
 /// - overflow
 ///
 /// In "block" mode, callers are simply forced to wait until the channel has enough capacity to
 /// accept the event.  In "drop newest" mode, any event being sent when the channel is full will be
-/// dropped and proceed no further. In "overflow" mode, events will be sent to another buffer
-/// sender.  Callers can specify the overflow sender to use when constructing their buffers initially.
+/// dropped and proceed no further. In "drop oldest" mode, the oldest events in the channel are
+/// dropped to make room for the event being sent. In "overflow" mode, events will be sent to
+/// another buffer sender.  Callers can specify the overflow sender to use when constructing their
+/// buffers initially.
 ///
 /// TODO: We should eventually rework `BufferSender`/`BufferReceiver` so that they contain a vector
 /// of the fields we already have here, but instead of cascading via calling into `overflow`, we'd
@@ -217,6 +248,17 @@ impl<T: Bufferable> BufferSender<T> {
                     was_dropped = true;
                 }
             }
+            WhenFull::DropOldest => {
+                let evicted = self.base.send_evicting_oldest(item).await?;
+                if let Some(instrumentation) = self.instrumentation.as_ref() {
+                    for item in evicted {
+                        instrumentation.increment_evicted_event_count_and_byte_size(
+                            item.event_count() as u64,
+                            item.size_of() as u64,
+                        );
+                    }
+                }
+            }
             WhenFull::Overflow => {
                 if let Some(item) = self.base.try_send(item).await? {
                     was_dropped = true;
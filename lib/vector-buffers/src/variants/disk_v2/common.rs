@@ -9,6 +9,7 @@ use snafu::Snafu;
 use super::{
     io::{Filesystem, ProductionFilesystem},
     ledger::LEDGER_LEN,
+    payload::PayloadCodec,
     record::RECORD_HEADER_LEN,
 };
 
@@ -153,6 +154,12 @@ pub struct DiskBufferConfig<FS> {
     /// amount of data written since the last flush would be lost.
     pub(crate) flush_interval: Duration,
 
+    /// Transformations applied to record payloads before they are written to disk.
+    ///
+    /// This controls whether records are compressed and/or encrypted, and holds the key used to
+    /// decrypt encrypted records when reading them back.
+    pub(crate) payload_codec: PayloadCodec,
+
     /// Filesystem implementation for opening data files.
     ///
     /// We allow parameterizing the filesystem implementation for ease of testing.  The "filesystem"
@@ -173,6 +180,7 @@ where
     pub(crate) max_record_size: Option<usize>,
     pub(crate) write_buffer_size: Option<usize>,
     pub(crate) flush_interval: Option<Duration>,
+    pub(crate) payload_codec: PayloadCodec,
     pub(crate) filesystem: FS,
 }
 
@@ -188,6 +196,7 @@ impl DiskBufferConfigBuilder {
             max_record_size: None,
             write_buffer_size: None,
             flush_interval: None,
+            payload_codec: PayloadCodec::default(),
             filesystem: ProductionFilesystem,
         }
     }
@@ -268,6 +277,14 @@ where
         self
     }
 
+    /// Sets the transformations applied to record payloads before they are written to disk.
+    ///
+    /// Defaults to writing record payloads as-is.
+    pub fn payload_codec(mut self, payload_codec: PayloadCodec) -> Self {
+        self.payload_codec = payload_codec;
+        self
+    }
+
     /// Filesystem implementation for opening data files.
     ///
     /// We allow parameterizing the filesystem implementation for ease of testing.  The "filesystem"
@@ -287,6 +304,7 @@ where
             max_record_size: self.max_record_size,
             write_buffer_size: self.write_buffer_size,
             flush_interval: self.flush_interval,
+            payload_codec: self.payload_codec,
             filesystem,
         }
     }
@@ -301,6 +319,7 @@ where
         let max_record_size = self.max_record_size.unwrap_or(DEFAULT_MAX_RECORD_SIZE);
         let write_buffer_size = self.write_buffer_size.unwrap_or(DEFAULT_WRITE_BUFFER_SIZE);
         let flush_interval = self.flush_interval.unwrap_or(DEFAULT_FLUSH_INTERVAL);
+        let payload_codec = self.payload_codec;
         let filesystem = self.filesystem;
 
         // Validate the input parameters.
@@ -412,6 +431,7 @@ where
             max_record_size,
             write_buffer_size,
             flush_interval,
+            payload_codec,
             filesystem,
         })
     }
@@ -0,0 +1,277 @@
+use std::{
+    io::{self, ErrorKind},
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+use async_stream::stream;
+use futures::Stream;
+use snafu::{ResultExt, Snafu};
+
+use super::{
+    DiskBufferConfigBuilder, Filesystem, ProductionFilesystem,
+    common::BuildError,
+    get_disk_v2_data_dir_path,
+    io::AsyncFile,
+    ledger::{Ledger, LedgerLoadCreateError},
+    payload::PayloadCodec,
+    reader::{ReaderError, RecordReader},
+};
+use crate::{
+    Bufferable, DiskBufferEncryption, buffer_usage_data::BufferUsageHandle,
+    config::BufferBuildError,
+};
+
+/// Error that occurred while opening a disk buffer for inspection.
+#[derive(Debug, Snafu)]
+pub enum InspectError {
+    /// There is no disk buffer at the given location.
+    #[snafu(display("no disk buffer found at {}", path.display()))]
+    NotFound { path: PathBuf },
+
+    /// The buffer configuration was invalid.
+    #[snafu(display("invalid buffer configuration: {}", source))]
+    InvalidConfig { source: BuildError },
+
+    /// The encryption key was invalid.
+    #[snafu(display("{}", source))]
+    InvalidEncryptionKey { source: BufferBuildError },
+
+    /// The ledger could not be loaded.
+    ///
+    /// This includes the ledger being locked by a running Vector process.
+    #[snafu(display("failed to load ledger: {}", source))]
+    Ledger { source: LedgerLoadCreateError },
+
+    /// A general I/O error occurred.
+    #[snafu(display("I/O error: {}", source))]
+    Io { source: io::Error },
+}
+
+/// State of a disk buffer, as tracked by its ledger.
+#[derive(Clone, Debug)]
+pub struct LedgerSummary {
+    /// ID of the data file the reader is currently reading.
+    pub reader_file_id: u16,
+
+    /// ID of the data file the writer is currently writing.
+    pub writer_file_id: u16,
+
+    /// ID of the last record acknowledged by the reader.
+    pub last_reader_record_id: u64,
+
+    /// ID the writer will use for the next record.
+    pub next_writer_record_id: u64,
+
+    /// Number of events written to the buffer that the reader has not acknowledged yet.
+    ///
+    /// Record IDs advance by the number of events in each record, so this counts events, not
+    /// records.
+    pub unacknowledged_events: u64,
+
+    /// Total size, in bytes, of all data files in the buffer.
+    pub total_bytes: u64,
+}
+
+/// A data file of a disk buffer.
+#[derive(Clone, Debug)]
+pub struct DataFileSummary {
+    /// ID of the data file.
+    pub file_id: u16,
+
+    /// Path to the data file.
+    pub path: PathBuf,
+
+    /// Size of the data file, in bytes.
+    pub size: u64,
+}
+
+/// A record read from a data file while inspecting a disk buffer.
+#[derive(Debug)]
+pub struct InspectedRecord<T: Bufferable> {
+    /// ID of the data file the record was read from.
+    pub file_id: u16,
+
+    /// ID of the record, if the record itself could be read.
+    pub record_id: Option<u64>,
+
+    /// Whether the record was already acknowledged by the reader.
+    pub acknowledged: bool,
+
+    /// The decoded record, or the error encountered while reading it.
+    ///
+    /// When the record itself is invalid, such as when its checksum doesn't match, the rest of the
+    /// data file is skipped, just like the buffer reader does.
+    pub item: Result<T, ReaderError<T>>,
+}
+
+/// Read-only view of a disk buffer.
+///
+/// Opening a buffer for inspection takes the same lock that Vector takes when running with the
+/// buffer, so a buffer can only be inspected while Vector is stopped, and Vector can't start using
+/// the buffer while it's being inspected.  Nothing is written to the buffer: records are read
+/// directly from the data files, without being acknowledged.
+#[derive(Debug)]
+pub struct BufferInspector<T> {
+    ledger: Ledger<ProductionFilesystem>,
+    payload_codec: PayloadCodec,
+    _t: PhantomData<T>,
+}
+
+impl<T> BufferInspector<T>
+where
+    T: Bufferable,
+{
+    /// Opens the disk buffer with the given ID, under the given data directory.
+    ///
+    /// If the buffer is encrypted, the encryption key it was configured with must be given in
+    /// order to read its records.
+    ///
+    /// # Errors
+    ///
+    /// If the buffer does not exist, is in use, or its ledger cannot be loaded, an error variant
+    /// will be returned describing the error.
+    pub async fn open(
+        data_dir: &Path,
+        buffer_id: &str,
+        encryption: Option<&DiskBufferEncryption>,
+    ) -> Result<Self, InspectError> {
+        let buffer_path = get_disk_v2_data_dir_path(data_dir, buffer_id);
+
+        // Loading the ledger creates the buffer if it doesn't exist, which is not what we want.
+        if !buffer_path.join("buffer.db").is_file() {
+            return Err(InspectError::NotFound { path: buffer_path });
+        }
+
+        let encryption_key = encryption
+            .map(DiskBufferEncryption::decode_key)
+            .transpose()
+            .context(InvalidEncryptionKeySnafu)?;
+        let payload_codec = PayloadCodec::new(false, encryption_key.as_ref());
+
+        let config = DiskBufferConfigBuilder::from_path(buffer_path)
+            .payload_codec(payload_codec.clone())
+            .build()
+            .context(InvalidConfigSnafu)?;
+        let ledger = Ledger::load_or_create(config, BufferUsageHandle::noop())
+            .await
+            .context(LedgerSnafu)?;
+
+        Ok(Self {
+            ledger,
+            payload_codec,
+            _t: PhantomData,
+        })
+    }
+
+    /// Gets the state of the buffer, as tracked by its ledger.
+    pub fn ledger_summary(&self) -> LedgerSummary {
+        let (reader_file_id, writer_file_id) = self.ledger.get_current_reader_writer_file_id();
+
+        LedgerSummary {
+            reader_file_id,
+            writer_file_id,
+            last_reader_record_id: self.ledger.state().get_last_reader_record_id(),
+            next_writer_record_id: self.ledger.state().get_next_writer_record_id(),
+            unacknowledged_events: self.ledger.get_total_records(),
+            total_bytes: self.ledger.get_total_buffer_size(),
+        }
+    }
+
+    /// Gets the data files of the buffer, from oldest to newest.
+    ///
+    /// # Errors
+    ///
+    /// If there is an I/O error while looking up the data files, an error variant will be returned
+    /// describing the error.
+    pub async fn data_files(&self) -> Result<Vec<DataFileSummary>, InspectError> {
+        let (reader_file_id, writer_file_id) = self.ledger.get_current_reader_writer_file_id();
+
+        let mut data_files = Vec::new();
+        let mut file_id = reader_file_id;
+        loop {
+            let path = self.ledger.get_data_file_path(file_id);
+            match self.ledger.filesystem().open_file_readable(&path).await {
+                Ok(data_file) => {
+                    let size = data_file.metadata().await.context(IoSnafu)?.len();
+                    data_files.push(DataFileSummary {
+                        file_id,
+                        path,
+                        size,
+                    });
+                }
+                // Data files may be missing if they were skipped over, or never created.
+                Err(e) if e.kind() == ErrorKind::NotFound => {}
+                Err(e) => return Err(InspectError::Io { source: e }),
+            }
+
+            if file_id == writer_file_id {
+                break;
+            }
+            file_id = file_id.wrapping_add(1);
+        }
+
+        Ok(data_files)
+    }
+
+    /// Reads all records in the buffer, from oldest to newest.
+    ///
+    /// Records that were already acknowledged, but whose data file has not yet been deleted, are
+    /// included, and marked as such.
+    pub fn records(
+        &self,
+        data_files: Vec<DataFileSummary>,
+    ) -> impl Stream<Item = InspectedRecord<T>> + '_ {
+        let last_reader_record_id = self.ledger.state().get_last_reader_record_id();
+
+        stream! {
+            for data_file in data_files {
+                let file_id = data_file.file_id;
+                let file = match self
+                    .ledger
+                    .filesystem()
+                    .open_file_readable(&data_file.path)
+                    .await
+                {
+                    Ok(file) => file,
+                    Err(source) => {
+                        yield InspectedRecord {
+                            file_id,
+                            record_id: None,
+                            acknowledged: false,
+                            item: Err(ReaderError::Io { source }),
+                        };
+                        continue;
+                    }
+                };
+
+                let mut reader = RecordReader::<_, T>::new(file, self.payload_codec.clone());
+                loop {
+                    let token = match reader.try_next_record(true).await {
+                        Ok(Some(token)) => token,
+                        Ok(None) => break,
+                        // We can't trust anything past an invalid record, so move on to the next
+                        // data file.
+                        Err(e) => {
+                            yield InspectedRecord {
+                                file_id,
+                                record_id: None,
+                                acknowledged: false,
+                                item: Err(e),
+                            };
+                            break;
+                        }
+                    };
+
+                    let record_id = token.record_id();
+                    yield InspectedRecord {
+                        file_id,
+                        record_id: Some(record_id),
+                        acknowledged: record_id <= last_reader_record_id,
+                        item: reader.read_record(token),
+                    };
+                }
+            }
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use std::{
+    collections::HashMap,
     fmt, io, mem,
     path::PathBuf,
     sync::{
@@ -15,7 +16,11 @@ use fslock::LockFile;
 use futures::StreamExt;
 use rkyv::{Archive, Serialize, with::Atomic};
 use snafu::{ResultExt, Snafu};
-use tokio::{fs, io::AsyncWriteExt, sync::Notify};
+use tokio::{
+    fs,
+    io::AsyncWriteExt,
+    sync::{Mutex, MutexGuard, Notify},
+};
 use vector_common::finalizer::OrderedFinalizer;
 
 use super::{
@@ -227,6 +232,12 @@ where
     pending_acks: AtomicU64,
     // The file ID offset of the reader past the acknowledged reader file ID.
     unacked_reader_file_id_offset: AtomicU16,
+    // Number of events evicted by the writer that the reader has not yet skipped over.
+    evicted_events: AtomicU64,
+    // Guards against the writer evicting a data file while the reader is opening it.
+    data_file_eviction: Mutex<()>,
+    // Number of events written to each data file, for data files the writer created itself.
+    data_file_event_counts: std::sync::Mutex<HashMap<u16, u64>>,
     // Last flush of all unflushed files: ledger, data file, etc.
     last_flush: AtomicCell<Instant>,
     // Tracks usage data about the buffer.
@@ -523,7 +534,77 @@ where
             );
     }
 
+    /// Locks out data file eviction.
+    ///
+    /// The writer holds this lock while evicting a data file, and the reader holds it while opening
+    /// a data file, which ensures that a data file is never evicted out from under the reader.
+    pub async fn lock_data_file_eviction(&self) -> MutexGuard<'_, ()> {
+        self.data_file_eviction.lock().await
+    }
+
+    /// Starts tracking the number of events written to the given data file.
+    ///
+    /// This should be called whenever the writer opens a data file.  If the data file already holds
+    /// records written before this ledger was loaded, the number of events in it is unknown, and
+    /// it's not tracked at all.
+    pub fn reset_data_file_event_count(&self, file_id: u16, known_empty: bool) {
+        let mut counts = self
+            .data_file_event_counts
+            .lock()
+            .expect("data file event counts lock poisoned");
+        if known_empty {
+            counts.insert(file_id, 0);
+        } else {
+            counts.remove(&file_id);
+        }
+    }
+
+    /// Tracks events written to the given data file.
+    pub fn track_data_file_events(&self, file_id: u16, event_count: u64) {
+        if let Some(count) = self
+            .data_file_event_counts
+            .lock()
+            .expect("data file event counts lock poisoned")
+            .get_mut(&file_id)
+        {
+            *count += event_count;
+        }
+    }
+
+    /// Takes the number of events written to the given data file.
+    ///
+    /// Returns `None` if the number of events in the data file is not known.
+    pub fn take_data_file_event_count(&self, file_id: u16) -> Option<u64> {
+        self.data_file_event_counts
+            .lock()
+            .expect("data file event counts lock poisoned")
+            .remove(&file_id)
+    }
+
+    /// Tracks the statistics of events evicted from the buffer to make room for newer events.
+    ///
+    /// The reader eventually skips over the record IDs of evicted events, so we hold on to the
+    /// number of evicted events in order to avoid also counting them as dropped at that point.
+    pub fn track_evicted_events(&self, event_count: u64, total_record_size: u64) {
+        self.decrement_total_buffer_size(total_record_size);
+        self.evicted_events.fetch_add(event_count, Ordering::AcqRel);
+        self.usage_handle
+            .increment_evicted_event_count_and_byte_size(event_count, total_record_size);
+    }
+
     pub fn track_dropped_events(&self, count: u64) {
+        // Any skipped events that were evicted by the writer have already been accounted for.
+        let evicted = self
+            .evicted_events
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |n| {
+                Some(n.saturating_sub(count))
+            })
+            .unwrap_or_else(|n| n);
+        let count = count.saturating_sub(evicted);
+        if count == 0 {
+            return;
+        }
+
         // We don't know how many bytes are represented by dropped events because we never actually had a chance to read
         // them, so we have to use a byte size of 0 here.
         //
@@ -642,6 +723,9 @@ where
             writer_done: AtomicBool::new(false),
             pending_acks: AtomicU64::new(0),
             unacked_reader_file_id_offset: AtomicU16::new(0),
+            evicted_events: AtomicU64::new(0),
+            data_file_eviction: Mutex::new(()),
+            data_file_event_counts: std::sync::Mutex::new(HashMap::new()),
             last_flush: AtomicCell::new(Instant::now()),
             usage_handle,
         };
@@ -727,6 +811,10 @@ where
                 "unacked_reader_file_id_offset",
                 &self.unacked_reader_file_id_offset.load(Ordering::Acquire),
             )
+            .field(
+                "evicted_events",
+                &self.evicted_events.load(Ordering::Acquire),
+            )
             .field("writer_done", &self.writer_done.load(Ordering::Acquire))
             .field("last_flush", &self.last_flush.load())
             .finish_non_exhaustive()
@@ -42,6 +42,13 @@
 //!   payload:    uint8[record_len]
 //! ```
 //!
+//! Payloads can optionally be compressed with zstd and/or encrypted with AES-256-GCM before being
+//! written. Which transformations were applied to a given payload, and the version of the payload
+//! format, are tracked in the upper byte of the record metadata, which is covered by the checksum,
+//! so records written with different settings, or before these transformations existed, can all
+//! be read back from the same buffer, while records written with an unknown version of the format
+//! are rejected.
+//!
 //! We say "pseudo-structure" as a helper serialization library, [`rkyv`][rkyv], is used to handle
 //! serialization, and zero-copy deserialization, of records. This effectively adds some amount of
 //! padding to record fields, due to the need to structure record field data in a way that makes it
@@ -184,8 +191,10 @@ use vector_common::finalization::Finalizable;
 
 mod backed_archive;
 mod common;
+mod inspect;
 mod io;
 mod ledger;
+mod payload;
 mod reader;
 mod record;
 mod ser;
@@ -197,8 +206,10 @@ mod tests;
 use self::ledger::Ledger;
 pub use self::{
     common::{DiskBufferConfig, DiskBufferConfigBuilder},
+    inspect::{BufferInspector, DataFileSummary, InspectError, InspectedRecord, LedgerSummary},
     io::{Filesystem, ProductionFilesystem},
     ledger::LedgerLoadCreateError,
+    payload::{ENCRYPTION_KEY_LEN, PayloadCodec, PayloadError},
     reader::{BufferReader, ReaderError},
     writer::{BufferWriter, WriterError},
 };
@@ -301,14 +312,21 @@ pub struct DiskV2Buffer {
     id: String,
     data_dir: PathBuf,
     max_size: NonZeroU64,
+    payload_codec: PayloadCodec,
 }
 
 impl DiskV2Buffer {
-    pub fn new(id: String, data_dir: PathBuf, max_size: NonZeroU64) -> Self {
+    pub fn new(
+        id: String,
+        data_dir: PathBuf,
+        max_size: NonZeroU64,
+        payload_codec: PayloadCodec,
+    ) -> Self {
         Self {
             id,
             data_dir,
             max_size,
+            payload_codec,
         }
     }
 }
@@ -331,6 +349,7 @@ where
             &self.data_dir,
             self.id.as_str(),
             self.max_size,
+            self.payload_codec,
         )
         .await?;
 
@@ -343,6 +362,7 @@ async fn build_disk_v2_buffer<T>(
     data_dir: &Path,
     id: &str,
     max_size: NonZeroU64,
+    payload_codec: PayloadCodec,
 ) -> Result<
     (
         BufferWriter<T, ProductionFilesystem>,
@@ -358,6 +378,7 @@ where
     let buffer_path = get_disk_v2_data_dir_path(data_dir, id);
     let config = DiskBufferConfigBuilder::from_path(buffer_path)
         .max_buffer_size(max_size.get())
+        .payload_codec(payload_codec)
         .build()?;
     Buffer::from_config(config, usage_handle)
         .await
@@ -0,0 +1,364 @@
+use std::{borrow::Cow, fmt, io};
+
+use aes_gcm::{
+    Aes256Gcm, KeyInit, Nonce,
+    aead::{Aead, AeadInPlace, Payload},
+};
+use snafu::{OptionExt, ResultExt, Snafu};
+
+/// Bits of the record metadata reserved for describing the payload format.
+///
+/// Encoders only ever use the lower bits of the record metadata for their own purposes, so the
+/// upper byte is used to track which transformations were applied to the encoded payload before
+/// it was written to disk: the upper four bits hold the version of the payload format, and the
+/// lower four bits the transformations that were applied. Records written before payload
+/// transformations existed have none of these bits set, and are read back as-is.
+pub const PAYLOAD_FORMAT_MASK: u32 = 0xFF00_0000;
+
+/// Bits of the record metadata holding the version of the payload format.
+const PAYLOAD_FORMAT_VERSION_MASK: u32 = 0xF000_0000;
+
+/// Offset of the version of the payload format in the record metadata.
+const PAYLOAD_FORMAT_VERSION_SHIFT: u32 = 28;
+
+/// Version of the payload format written by this version of the buffer.
+///
+/// This must be bumped whenever the layout of transformed payloads changes, so that older versions
+/// of Vector refuse to read payloads they would misinterpret.
+pub const PAYLOAD_FORMAT_VERSION: u32 = 1;
+
+/// The payload was compressed with zstd.
+const PAYLOAD_COMPRESSED_ZSTD: u32 = 1 << 24;
+
+/// The payload was encrypted with AES-256-GCM.
+///
+/// The encrypted payload is prefixed with the nonce used to encrypt it, and suffixed with the
+/// authentication tag. The record ID is used as the associated data, so that an encrypted payload
+/// cannot be passed off as the payload of another record.
+const PAYLOAD_ENCRYPTED_AES_256_GCM: u32 = 1 << 25;
+
+/// All payload format bits understood by this version of the buffer.
+const PAYLOAD_FORMAT_KNOWN: u32 = PAYLOAD_COMPRESSED_ZSTD | PAYLOAD_ENCRYPTED_AES_256_GCM;
+
+/// Length, in bytes, of the keys used to encrypt payloads.
+pub const ENCRYPTION_KEY_LEN: usize = 32;
+
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+
+// zstd's own default level, which is a good tradeoff between speed and compression ratio.
+const ZSTD_COMPRESSION_LEVEL: i32 = 3;
+
+/// Error that occurred while transforming a record payload.
+#[derive(Debug, Snafu)]
+pub enum PayloadError {
+    /// The payload could not be compressed.
+    #[snafu(display("failed to compress payload: {}", source))]
+    Compress { source: io::Error },
+
+    /// The payload could not be decompressed.
+    #[snafu(display("failed to decompress payload: {}", source))]
+    Decompress { source: io::Error },
+
+    /// The payload could not be encrypted.
+    #[snafu(display("failed to encrypt payload"))]
+    Encrypt,
+
+    /// The payload could not be decrypted.
+    ///
+    /// This generally indicates that the payload was encrypted with a different key than the one
+    /// currently configured.
+    #[snafu(display("failed to decrypt payload, the encryption key may have changed"))]
+    Decrypt,
+
+    /// The payload is encrypted, but no encryption key is configured.
+    #[snafu(display("payload is encrypted but no encryption key is configured"))]
+    MissingKey,
+
+    /// The payload was transformed in a way that this version of Vector does not support.
+    #[snafu(display("unsupported payload format (metadata: {:#034b})", metadata))]
+    UnsupportedFormat { metadata: u32 },
+
+    /// The payload was written with a version of the payload format that this version of Vector
+    /// does not support.
+    #[snafu(display(
+        "unsupported payload format version {} (supported: {})",
+        version,
+        PAYLOAD_FORMAT_VERSION
+    ))]
+    UnsupportedVersion { version: u32 },
+
+    /// The encoder metadata uses bits reserved for describing the payload format.
+    ///
+    /// The payload format could not be told apart from the encoder metadata, so the payload can
+    /// neither be transformed nor restored.
+    #[snafu(display(
+        "encoder metadata uses bits reserved for the payload format (metadata: {:#034b})",
+        metadata
+    ))]
+    ReservedMetadata { metadata: u32 },
+}
+
+/// Ensures that the given encoder metadata leaves the payload format bits unset.
+///
+/// # Errors
+///
+/// If any of the bits reserved for the payload format are set, an error variant will be returned.
+pub fn check_encoder_metadata(metadata: u32) -> Result<(), PayloadError> {
+    if metadata & PAYLOAD_FORMAT_MASK == 0 {
+        Ok(())
+    } else {
+        Err(PayloadError::ReservedMetadata { metadata })
+    }
+}
+
+/// Transformations applied to record payloads before they are written to disk.
+///
+/// Payloads are transformed after being encoded, and before being wrapped in a record, so the
+/// record checksum covers the payload as it was written to disk. Payloads are first compressed,
+/// and then encrypted, if configured to do so.
+///
+/// Reading a payload only depends on the transformations recorded in the record metadata, and not
+/// on the current configuration, aside from the encryption key. This means that compression and
+/// encryption can be enabled or disabled on an existing buffer, as long as the encryption key is
+/// kept around until encrypted records have been read.
+#[derive(Clone, Default)]
+pub struct PayloadCodec {
+    compress: bool,
+    cipher: Option<Aes256Gcm>,
+}
+
+impl PayloadCodec {
+    /// Creates a new `PayloadCodec`.
+    ///
+    /// If `compress` is `true`, payloads are compressed with zstd. If an encryption key is given,
+    /// payloads are encrypted with AES-256-GCM.
+    pub fn new(compress: bool, encryption_key: Option<&[u8; ENCRYPTION_KEY_LEN]>) -> Self {
+        Self {
+            compress,
+            cipher: encryption_key.map(|key| Aes256Gcm::new(key.into())),
+        }
+    }
+
+    /// Transforms the given encoded payload.
+    ///
+    /// The transformed payload is returned alongside the payload format bits that must be added to
+    /// the record metadata, including the version of the payload format. When no transformations are configured, the payload is returned as-is,
+    /// otherwise `buf` is used to hold the transformed payload.
+    ///
+    /// # Errors
+    ///
+    /// If the payload cannot be compressed or encrypted, an error variant will be returned
+    /// describing the error.
+    pub fn encode<'a>(
+        &self,
+        record_id: u64,
+        payload: &'a [u8],
+        buf: &'a mut Vec<u8>,
+    ) -> Result<(u32, &'a [u8]), PayloadError> {
+        if !self.compress && self.cipher.is_none() {
+            return Ok((0, payload));
+        }
+
+        let mut format = 0;
+        buf.clear();
+
+        // Leave room for the nonce up front, so that we can encrypt the payload in place.
+        let nonce = self
+            .cipher
+            .as_ref()
+            .map(|_| rand::random::<[u8; NONCE_LEN]>());
+        if let Some(nonce) = &nonce {
+            buf.extend_from_slice(nonce);
+        }
+
+        if self.compress {
+            zstd::stream::copy_encode(payload, &mut *buf, ZSTD_COMPRESSION_LEVEL)
+                .context(CompressSnafu)?;
+            format |= PAYLOAD_COMPRESSED_ZSTD;
+        } else {
+            buf.extend_from_slice(payload);
+        }
+
+        if let (Some(cipher), Some(nonce)) = (&self.cipher, nonce) {
+            let tag = cipher
+                .encrypt_in_place_detached(
+                    Nonce::from_slice(&nonce),
+                    &record_id.to_be_bytes(),
+                    &mut buf[NONCE_LEN..],
+                )
+                .map_err(|_| PayloadError::Encrypt)?;
+            buf.extend_from_slice(&tag);
+            format |= PAYLOAD_ENCRYPTED_AES_256_GCM;
+        }
+
+        Ok((
+            format | (PAYLOAD_FORMAT_VERSION << PAYLOAD_FORMAT_VERSION_SHIFT),
+            &buf[..],
+        ))
+    }
+
+    /// Reverses the transformations applied to the given payload.
+    ///
+    /// The transformations to reverse are determined from the payload format bits of the record
+    /// metadata.
+    ///
+    /// # Errors
+    ///
+    /// If the payload format or its version is not supported, or the payload cannot be decrypted or
+    /// decompressed, an error variant will be returned describing the error.
+    pub fn decode<'a>(
+        &self,
+        metadata: u32,
+        record_id: u64,
+        payload: &'a [u8],
+    ) -> Result<Cow<'a, [u8]>, PayloadError> {
+        let format = metadata & PAYLOAD_FORMAT_MASK;
+        if format == 0 {
+            return Ok(Cow::Borrowed(payload));
+        }
+
+        let version = (format & PAYLOAD_FORMAT_VERSION_MASK) >> PAYLOAD_FORMAT_VERSION_SHIFT;
+        if version != PAYLOAD_FORMAT_VERSION {
+            return Err(PayloadError::UnsupportedVersion { version });
+        }
+        let format = format & !PAYLOAD_FORMAT_VERSION_MASK;
+        if format & !PAYLOAD_FORMAT_KNOWN != 0 {
+            return Err(PayloadError::UnsupportedFormat { metadata });
+        }
+
+        let mut payload = Cow::Borrowed(payload);
+
+        if format & PAYLOAD_ENCRYPTED_AES_256_GCM != 0 {
+            let cipher = self.cipher.as_ref().context(MissingKeySnafu)?;
+            if payload.len() < NONCE_LEN + TAG_LEN {
+                return Err(PayloadError::Decrypt);
+            }
+
+            let (nonce, ciphertext) = payload.split_at(NONCE_LEN);
+            let plaintext = cipher
+                .decrypt(
+                    Nonce::from_slice(nonce),
+                    Payload {
+                        msg: ciphertext,
+                        aad: &record_id.to_be_bytes(),
+                    },
+                )
+                .map_err(|_| PayloadError::Decrypt)?;
+            payload = Cow::Owned(plaintext);
+        }
+
+        if format & PAYLOAD_COMPRESSED_ZSTD != 0 {
+            let decompressed = zstd::stream::decode_all(&payload[..]).context(DecompressSnafu)?;
+            payload = Cow::Owned(decompressed);
+        }
+
+        Ok(payload)
+    }
+}
+
+impl fmt::Debug for PayloadCodec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PayloadCodec")
+            .field("compress", &self.compress)
+            .field("encrypt", &self.cipher.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: [u8; ENCRYPTION_KEY_LEN] = [42; ENCRYPTION_KEY_LEN];
+
+    fn roundtrip(codec: &PayloadCodec, payload: &[u8]) -> (u32, Vec<u8>) {
+        let mut buf = Vec::new();
+        let (format, encoded) = codec.encode(7, payload, &mut buf).unwrap();
+        let encoded = encoded.to_vec();
+        let decoded = codec.decode(format, 7, &encoded).unwrap();
+        assert_eq!(&decoded[..], payload);
+        (format, encoded)
+    }
+
+    #[test]
+    fn passthrough() {
+        let payload = b"hello world";
+        let (format, encoded) = roundtrip(&PayloadCodec::default(), payload);
+        assert_eq!(format, 0);
+        assert_eq!(&encoded[..], payload);
+    }
+
+    #[test]
+    fn compressed_and_encrypted() {
+        let payload = b"hello world".repeat(100);
+        for (compress, key) in [(true, None), (false, Some(&KEY)), (true, Some(&KEY))] {
+            let codec = PayloadCodec::new(compress, key);
+            let (format, encoded) = roundtrip(&codec, &payload);
+            assert_eq!(format & PAYLOAD_FORMAT_MASK, format);
+            assert_ne!(encoded, payload);
+        }
+    }
+
+    #[test]
+    fn encrypted_payload_is_bound_to_record_id() {
+        let codec = PayloadCodec::new(false, Some(&KEY));
+        let mut buf = Vec::new();
+        let (format, encoded) = codec.encode(7, b"hello world", &mut buf).unwrap();
+        let encoded = encoded.to_vec();
+
+        assert!(matches!(
+            codec.decode(format, 8, &encoded),
+            Err(PayloadError::Decrypt)
+        ));
+        assert!(matches!(
+            PayloadCodec::new(false, Some(&[7; ENCRYPTION_KEY_LEN])).decode(format, 7, &encoded),
+            Err(PayloadError::Decrypt)
+        ));
+        assert!(matches!(
+            PayloadCodec::default().decode(format, 7, &encoded),
+            Err(PayloadError::MissingKey)
+        ));
+    }
+
+    #[test]
+    fn unknown_format_is_rejected() {
+        let version = PAYLOAD_FORMAT_VERSION << PAYLOAD_FORMAT_VERSION_SHIFT;
+        assert!(matches!(
+            PayloadCodec::default().decode(version | (1 << 27), 7, b"hello world"),
+            Err(PayloadError::UnsupportedFormat { .. })
+        ));
+    }
+
+    #[test]
+    fn unknown_version_is_rejected() {
+        let mut buf = Vec::new();
+        let (format, encoded) = PayloadCodec::new(true, None)
+            .encode(7, b"hello world", &mut buf)
+            .unwrap();
+        assert_eq!(
+            format & PAYLOAD_FORMAT_VERSION_MASK,
+            PAYLOAD_FORMAT_VERSION << PAYLOAD_FORMAT_VERSION_SHIFT
+        );
+
+        let newer = (format & !PAYLOAD_FORMAT_VERSION_MASK)
+            | ((PAYLOAD_FORMAT_VERSION + 1) << PAYLOAD_FORMAT_VERSION_SHIFT);
+        assert!(matches!(
+            PayloadCodec::default().decode(newer, 7, encoded),
+            Err(PayloadError::UnsupportedVersion { version }) if version == PAYLOAD_FORMAT_VERSION + 1
+        ));
+        assert!(matches!(
+            PayloadCodec::default().decode(format & !PAYLOAD_FORMAT_VERSION_MASK, 7, encoded),
+            Err(PayloadError::UnsupportedVersion { version: 0 })
+        ));
+    }
+
+    #[test]
+    fn encoder_metadata_must_not_use_format_bits() {
+        assert!(check_encoder_metadata(0b1101).is_ok());
+        assert!(matches!(
+            check_encoder_metadata(PAYLOAD_COMPRESSED_ZSTD | 0b1101),
+            Err(PayloadError::ReservedMetadata { metadata }) if metadata == PAYLOAD_COMPRESSED_ZSTD | 0b1101
+        ));
+    }
+}
@@ -17,6 +17,7 @@ use super::{
     Filesystem,
     common::create_crc32c_hasher,
     ledger::Ledger,
+    payload::{PAYLOAD_FORMAT_MASK, PayloadCodec, PayloadError, check_encoder_metadata},
     record::{ArchivedRecord, Record, RecordStatus, validate_record_archive},
 };
 use crate::{
@@ -108,6 +109,14 @@ where
     #[snafu(display("record version not compatible: {}", reason))]
     Incompatible { reason: String },
 
+    /// The record payload could not be decompressed or decrypted.
+    ///
+    /// At this stage, the record can be assumed to have been written correctly, and read correctly
+    /// from disk, as the checksum was also validated.  This most likely indicates that the record
+    /// was encrypted with a different key than the one currently configured.
+    #[snafu(display("failed to restore record payload: {}", source))]
+    Payload { source: PayloadError },
+
     /// The reader detected that a data file contains a partially-written record.
     ///
     /// Records should never be partially written to a data file (we don't split records across data
@@ -145,6 +154,7 @@ where
             ReaderError::Checksum { .. } => "checksum_mismatch",
             ReaderError::Decode { .. } => "decode_failed",
             ReaderError::Incompatible { .. } => "incompatible_record_version",
+            ReaderError::Payload { .. } => "payload_failed",
             ReaderError::PartialWrite => "partial_write",
             ReaderError::EmptyRecord => "empty_record",
         }
@@ -160,6 +170,7 @@ where
             | ReaderError::Checksum { .. }
             | ReaderError::Decode { .. }
             | ReaderError::Incompatible { .. }
+            | ReaderError::Payload { .. }
             | ReaderError::PartialWrite => Some(BufferReadError { error_code, error }),
         }
     }
@@ -199,6 +210,7 @@ pub(super) struct RecordReader<R, T> {
     reader: BufReader<R>,
     aligned_buf: AlignedVec,
     checksummer: Hasher,
+    payload_codec: PayloadCodec,
     current_record_id: u64,
     _t: PhantomData<T>,
 }
@@ -212,11 +224,12 @@ where
     ///
     /// Internally, the reader is wrapped in a [`BufReader`], so callers should not pass in an
     /// already buffered reader.
-    pub fn new(reader: R) -> Self {
+    pub fn new(reader: R, payload_codec: PayloadCodec) -> Self {
         Self {
             reader: BufReader::with_capacity(256 * 1024, reader),
             aligned_buf: AlignedVec::new(),
             checksummer: create_crc32c_hasher(),
+            payload_codec,
             current_record_id: 0,
             _t: PhantomData,
         }
@@ -374,7 +387,7 @@ where
         // - `try_next_record` does all the archive checks, checksum validation, etc
         let record = unsafe { archived_root::<Record<'_>>(&self.aligned_buf) };
 
-        decode_record_payload(record)
+        decode_record_payload(record, &self.payload_codec)
     }
 }
 
@@ -755,12 +768,16 @@ where
         loop {
             let (reader_file_id, writer_file_id) = self.ledger.get_current_reader_writer_file_id();
             let data_file_path = self.ledger.get_current_reader_data_file_path();
-            let data_file = match self
-                .ledger
-                .filesystem()
-                .open_file_readable(&data_file_path)
-                .await
-            {
+            let maybe_data_file = {
+                // Make sure the writer doesn't evict the data file while we're opening it. Once
+                // it's open, it's our current data file, which the writer never evicts.
+                let _eviction_guard = self.ledger.lock_data_file_eviction().await;
+                self.ledger
+                    .filesystem()
+                    .open_file_readable(&data_file_path)
+                    .await
+            };
+            let data_file = match maybe_data_file {
                 Ok(data_file) => data_file,
                 Err(e) => match e.kind() {
                     ErrorKind::NotFound => {
@@ -788,7 +805,10 @@ where
                 "Opened data file for reading."
             );
 
-            self.reader = Some(RecordReader::new(data_file));
+            self.reader = Some(RecordReader::new(
+                data_file,
+                self.ledger.config().payload_codec.clone(),
+            ));
             return Ok(());
         }
     }
@@ -840,6 +860,27 @@ where
         while self.ledger.get_current_reader_file_id() != self.ledger.get_current_writer_file_id() {
             let data_file_path = self.ledger.get_current_reader_data_file_path();
             self.ensure_ready_for_read().await.context(IoSnafu)?;
+
+            // Data files evicted by the writer are left behind empty, so there's nothing to seek
+            // through, and we can simply delete them and move on.
+            let data_file_size = self
+                .ledger
+                .filesystem()
+                .open_file_readable(&data_file_path)
+                .await
+                .context(IoSnafu)?
+                .metadata()
+                .await
+                .context(IoSnafu)?
+                .len();
+            if data_file_size == 0 {
+                self.delete_completed_data_file(data_file_path, None)
+                    .await
+                    .context(IoSnafu)?;
+                self.reset();
+                continue;
+            }
+
             let data_file_mmap = self
                 .ledger
                 .filesystem()
@@ -854,7 +895,9 @@ where
                     let record = try_as_record_archive(data_file_mmap.as_ref())
                         .expect("record was already validated");
 
-                    let Ok(item) = decode_record_payload::<T>(record) else {
+                    let Ok(item) =
+                        decode_record_payload::<T>(record, &self.ledger.config().payload_codec)
+                    else {
                         // If there's an error decoding the item, just fall back to the slow path,
                         // because this file might actually be where we left off, so we don't want
                         // to incorrectly skip ahead or anything.
@@ -1134,12 +1177,36 @@ where
 
 pub(crate) fn decode_record_payload<T: Bufferable>(
     record: &ArchivedRecord<'_>,
+    payload_codec: &PayloadCodec,
 ) -> Result<T, ReaderError<T>> {
+    // The upper bits of the record metadata describe the payload format, so if `T` uses them for
+    // its own metadata, we can't tell the two apart and can't safely decode the record.
+    check_encoder_metadata(T::get_metadata().into_u32()).map_err(|e| {
+        ReaderError::Incompatible {
+            reason: e.to_string(),
+        }
+    })?;
+
+    // Reverse any compression/encryption applied to the payload before it was written, as described
+    // by the upper bits of the record metadata.
+    let payload = payload_codec
+        .decode(record.metadata(), record.id(), record.payload())
+        .map_err(|e| match e {
+            PayloadError::UnsupportedFormat { .. } | PayloadError::UnsupportedVersion { .. } => {
+                ReaderError::Incompatible {
+                    reason: e.to_string(),
+                }
+            }
+            source => ReaderError::Payload { source },
+        })?;
+
     // Try and convert the raw record metadata into the true metadata type used by `T`, and then
     // also verify that `T` is able to decode records with the metadata used for this record in particular.
-    let metadata = T::Metadata::from_u32(record.metadata()).ok_or(ReaderError::Incompatible {
-        reason: format!("invalid metadata for {}", std::any::type_name::<T>()),
-    })?;
+    let metadata = T::Metadata::from_u32(record.metadata() & !PAYLOAD_FORMAT_MASK).ok_or(
+        ReaderError::Incompatible {
+            reason: format!("invalid metadata for {}", std::any::type_name::<T>()),
+        },
+    )?;
 
     if !T::can_decode(metadata) {
         return Err(ReaderError::Incompatible {
@@ -1151,5 +1218,5 @@ pub(crate) fn decode_record_payload<T: Bufferable>(
     }
 
     // Now we can finally try decoding.
-    T::decode(metadata, record.payload()).context(DecodeSnafu)
+    T::decode(metadata, &payload[..]).context(DecodeSnafu)
 }
@@ -130,6 +130,11 @@ impl<'a> Record<'a> {
 }
 
 impl ArchivedRecord<'_> {
+    /// Gets the ID of this record.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
     /// Gets the metadata of this record.
     pub fn metadata(&self) -> u32 {
         self.metadata
@@ -10,7 +10,9 @@ use super::{create_default_buffer_v2, read_next, read_next_some};
 use crate::{
     EventCount, assert_buffer_is_empty, assert_buffer_records,
     test::{MultiEventRecord, SizedRecord, acknowledge, install_tracing_helpers, with_temp_dir},
-    variants::disk_v2::{tests::create_default_buffer_v2_with_usage, writer::RecordWriter},
+    variants::disk_v2::{
+        PayloadCodec, tests::create_default_buffer_v2_with_usage, writer::RecordWriter,
+    },
 };
 
 #[tokio::test]
@@ -178,8 +180,14 @@ async fn initial_size_correct_with_multievents() {
             // are identical:
             let expected_bytes = stream::iter(input_items.iter().cloned())
                 .filter_map(|record| async move {
-                    let mut record_writer =
-                        RecordWriter::new(Cursor::new(Vec::new()), 0, 16_384, u64::MAX, usize::MAX);
+                    let mut record_writer = RecordWriter::new(
+                        Cursor::new(Vec::new()),
+                        0,
+                        16_384,
+                        u64::MAX,
+                        usize::MAX,
+                        PayloadCodec::default(),
+                    );
                     let (bytes_written, flush_result) = record_writer
                         .write_record(0, record)
                         .await
@@ -0,0 +1,93 @@
+use futures::StreamExt;
+
+use super::create_default_buffer_v2;
+use crate::{
+    test::{SizedRecord, acknowledge, with_temp_dir},
+    variants::disk_v2::{
+        BufferInspector, InspectError, LedgerLoadCreateError, get_disk_v2_data_dir_path,
+    },
+};
+
+#[tokio::test]
+async fn inspector_reads_records_without_consuming_them() {
+    with_temp_dir(|dir| {
+        let base_dir = dir.to_path_buf();
+        let data_dir = get_disk_v2_data_dir_path(&base_dir, "inspect");
+
+        async move {
+            // Inspecting a buffer that doesn't exist shouldn't create it.
+            let result = BufferInspector::<SizedRecord>::open(&base_dir, "inspect", None).await;
+            assert!(matches!(result, Err(InspectError::NotFound { .. })));
+            assert!(!data_dir.exists());
+
+            // Write three records, and read and acknowledge the first one.
+            let (mut writer, mut reader, ledger) = create_default_buffer_v2(data_dir).await;
+            for size in [64, 65, 66] {
+                writer
+                    .write_record(SizedRecord::new(size))
+                    .await
+                    .expect("write should not fail");
+            }
+            writer.flush().await.expect("flush should not fail");
+
+            let first_read = reader
+                .next()
+                .await
+                .expect("read should not fail")
+                .expect("should contain first record");
+            acknowledge(first_read).await;
+            let _ = reader.next().await.expect("read should not fail");
+            ledger.flush().expect("ledger flush should not fail");
+
+            // The buffer is still in use, so it can't be inspected.
+            let result = BufferInspector::<SizedRecord>::open(&base_dir, "inspect", None).await;
+            assert!(matches!(
+                result,
+                Err(InspectError::Ledger {
+                    source: LedgerLoadCreateError::LedgerLockAlreadyHeld
+                })
+            ));
+
+            drop(reader);
+            drop(writer);
+            drop(ledger);
+
+            let inspector = BufferInspector::<SizedRecord>::open(&base_dir, "inspect", None)
+                .await
+                .expect("should not fail to open buffer");
+
+            let summary = inspector.ledger_summary();
+            assert_eq!(summary.last_reader_record_id, 1);
+            assert_eq!(summary.next_writer_record_id, 4);
+            assert_eq!(summary.unacknowledged_events, 2);
+
+            let data_files = inspector
+                .data_files()
+                .await
+                .expect("should not fail to list data files");
+            assert_eq!(data_files.len(), 1);
+
+            let records = inspector
+                .records(data_files.clone())
+                .map(|record| {
+                    let item = record.item.expect("record should be valid");
+                    (record.record_id, record.acknowledged, item)
+                })
+                .collect::<Vec<_>>()
+                .await;
+            assert_eq!(
+                records,
+                vec![
+                    (Some(1), true, SizedRecord::new(64)),
+                    (Some(2), false, SizedRecord::new(65)),
+                    (Some(3), false, SizedRecord::new(66)),
+                ]
+            );
+
+            // Inspecting the buffer doesn't consume anything.
+            let records = inspector.records(data_files).count().await;
+            assert_eq!(records, 3);
+        }
+    })
+    .await;
+}
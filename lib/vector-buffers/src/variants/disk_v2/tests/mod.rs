@@ -25,6 +25,7 @@ type FilesystemUnderTest = ProductionFilesystem;
 mod acknowledgements;
 mod basic;
 mod initialization;
+mod inspect;
 mod invariants;
 mod known_errors;
 mod model;
@@ -2,7 +2,9 @@ use std::io::Cursor;
 
 use crate::{
     test::SizedRecord,
-    variants::disk_v2::{reader::RecordReader, writer::RecordWriter},
+    variants::disk_v2::{
+        ENCRYPTION_KEY_LEN, PayloadCodec, ReaderError, reader::RecordReader, writer::RecordWriter,
+    },
 };
 
 #[tokio::test]
@@ -10,8 +12,15 @@ async fn roundtrip_through_record_writer_and_record_reader() {
     // Create a duplex stream that's more than big enough to ship a record through.
     let (writer_io, reader_io) = tokio::io::duplex(4096);
 
-    let mut record_writer = RecordWriter::new(writer_io, 0, 16_384, u64::MAX, 2048);
-    let mut record_reader = RecordReader::new(reader_io);
+    let mut record_writer = RecordWriter::new(
+        writer_io,
+        0,
+        16_384,
+        u64::MAX,
+        2048,
+        PayloadCodec::default(),
+    );
+    let mut record_reader = RecordReader::new(reader_io, PayloadCodec::default());
 
     let record = SizedRecord::new(73);
 
@@ -42,10 +51,73 @@ async fn roundtrip_through_record_writer_and_record_reader() {
 async fn record_reader_always_returns_none_when_no_data() {
     let reader_io = Cursor::new(Vec::new());
 
-    let mut record_reader = RecordReader::<_, SizedRecord>::new(reader_io);
+    let mut record_reader = RecordReader::<_, SizedRecord>::new(reader_io, PayloadCodec::default());
     let read_token = record_reader
         .try_next_record(false)
         .await
         .expect("read should not fail");
     assert!(read_token.is_none());
 }
+
+#[tokio::test]
+async fn roundtrip_through_record_writer_and_record_reader_with_payload_codec() {
+    let (writer_io, reader_io) = tokio::io::duplex(4096);
+
+    let key = [42; ENCRYPTION_KEY_LEN];
+    let payload_codec = PayloadCodec::new(true, Some(&key));
+    let mut record_writer =
+        RecordWriter::new(writer_io, 0, 16_384, u64::MAX, 2048, payload_codec.clone());
+    let mut record_reader = RecordReader::new(reader_io, payload_codec);
+
+    let record = SizedRecord::new(73);
+
+    record_writer
+        .write_record(314, record.clone())
+        .await
+        .expect("write should not fail");
+    record_writer.flush().await.expect("flush should not fail");
+
+    let read_token = record_reader
+        .try_next_record(false)
+        .await
+        .expect("read should not fail")
+        .expect("record should be present");
+    assert_eq!(314, read_token.record_id());
+
+    let roundtrip_record = record_reader
+        .read_record(read_token)
+        .expect("read should not fail");
+    assert_eq!(record, roundtrip_record);
+}
+
+#[tokio::test]
+async fn record_reader_fails_to_read_encrypted_record_without_key() {
+    let (writer_io, reader_io) = tokio::io::duplex(4096);
+
+    let key = [42; ENCRYPTION_KEY_LEN];
+    let mut record_writer = RecordWriter::new(
+        writer_io,
+        0,
+        16_384,
+        u64::MAX,
+        2048,
+        PayloadCodec::new(false, Some(&key)),
+    );
+    let mut record_reader = RecordReader::new(reader_io, PayloadCodec::default());
+
+    record_writer
+        .write_record(314, SizedRecord::new(73))
+        .await
+        .expect("write should not fail");
+    record_writer.flush().await.expect("flush should not fail");
+
+    let read_token = record_reader
+        .try_next_record(false)
+        .await
+        .expect("read should not fail")
+        .expect("record should be present");
+    assert!(matches!(
+        record_reader.read_record(read_token),
+        Err(ReaderError::Payload { .. })
+    ));
+}
@@ -481,3 +481,132 @@ async fn writer_can_validate_last_write_when_buffer_is_full() {
     })
     .await;
 }
+
+#[tokio::test]
+async fn writer_evicts_oldest_unread_data_file_when_buffer_is_full() {
+    let _a = install_tracing_helpers();
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            // Create our buffer such that every record fills up an entire data file, and such that
+            // the buffer is full once three data files have been written.
+            let write_size = 96;
+            let record = SizedRecord::new(write_size);
+
+            let max_data_file_size = get_minimum_data_file_size_for_record_payload(&record);
+            let (mut writer, mut reader, ledger) =
+                create_buffer_v2_with_data_file_count_limit(data_dir, max_data_file_size, 4).await;
+
+            assert_buffer_is_empty!(ledger);
+
+            let mut bytes_written = 0;
+            for _ in 0..3 {
+                bytes_written = writer
+                    .write_record(record.clone())
+                    .await
+                    .expect("write should not fail");
+                writer.flush().await.expect("flush should not fail");
+            }
+
+            assert_buffer_size!(ledger, 3, bytes_written * 3);
+            assert_reader_writer_v2_file_positions!(ledger, 0, 2);
+
+            // The buffer is now full, so a regular write can't complete, but evicting the oldest data
+            // file that the reader hasn't started reading yet -- data file 1 -- makes room for it.
+            let result = writer
+                .try_write_record(record.clone())
+                .await
+                .expect("write should not fail");
+            assert_eq!(result, Some(record.clone()));
+
+            let evicting_bytes_written = writer
+                .write_record_evicting_oldest(record.clone())
+                .await
+                .expect("write should not fail");
+            assert_eq!(evicting_bytes_written, bytes_written);
+            writer.flush().await.expect("flush should not fail");
+            writer.close();
+
+            assert_eq!(ledger.get_total_buffer_size(), bytes_written as u64 * 3);
+            assert_reader_writer_v2_file_positions!(ledger, 0, 3);
+
+            // We should only be able to read back three records, skipping over the evicted one.
+            for _ in 0..3 {
+                let record_read = read_next_some(&mut reader).await;
+                assert_eq!(record_read, record);
+                acknowledge(record_read).await;
+            }
+
+            let final_empty_read = read_next(&mut reader).await;
+            assert_eq!(final_empty_read, None);
+
+            assert_buffer_is_empty!(ledger);
+        }
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn writer_evicts_data_file_written_before_buffer_was_reopened() {
+    let _a = install_tracing_helpers();
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            // Same setup as above, except that we reopen the buffer before evicting, so that the
+            // writer doesn't know how many events are in the data file it evicts.
+            let write_size = 96;
+            let record = SizedRecord::new(write_size);
+
+            let max_data_file_size = get_minimum_data_file_size_for_record_payload(&record);
+            let (mut writer, reader, ledger) = create_buffer_v2_with_data_file_count_limit(
+                data_dir.clone(),
+                max_data_file_size,
+                4,
+            )
+            .await;
+
+            let mut bytes_written = 0;
+            for _ in 0..3 {
+                bytes_written = writer
+                    .write_record(record.clone())
+                    .await
+                    .expect("write should not fail");
+                writer.flush().await.expect("flush should not fail");
+            }
+
+            ledger.flush().expect("should not fail to flush ledger");
+            drop(reader);
+            drop(writer);
+            drop(ledger);
+
+            let (mut writer, mut reader, ledger) =
+                create_buffer_v2_with_data_file_count_limit(data_dir, max_data_file_size, 4).await;
+            assert_reader_writer_v2_file_positions!(ledger, 0, 2);
+
+            let evicting_bytes_written = writer
+                .write_record_evicting_oldest(record.clone())
+                .await
+                .expect("write should not fail");
+            assert_eq!(evicting_bytes_written, bytes_written);
+            writer.flush().await.expect("flush should not fail");
+            writer.close();
+
+            assert_eq!(ledger.get_total_buffer_size(), bytes_written as u64 * 3);
+
+            // The evicted record is skipped over all the same.
+            for _ in 0..3 {
+                let record_read = read_next_some(&mut reader).await;
+                assert_eq!(record_read, record);
+                acknowledge(record_read).await;
+            }
+
+            let final_empty_read = read_next(&mut reader).await;
+            assert_eq!(final_empty_read, None);
+
+            assert_buffer_is_empty!(ledger);
+        }
+    })
+    .await;
+}
@@ -22,16 +22,19 @@ use rkyv::{
 };
 use snafu::{ResultExt, Snafu};
 use tokio::io::{AsyncWrite, AsyncWriteExt};
+use vector_common::internal_event::emit;
 
 use super::{
     common::{DiskBufferConfig, create_crc32c_hasher},
     io::Filesystem,
     ledger::Ledger,
+    payload::{PAYLOAD_FORMAT_MASK, PayloadCodec, PayloadError, check_encoder_metadata},
     record::{Record, RecordStatus, validate_record_archive},
 };
 use crate::{
     Bufferable,
     encoding::{AsMetadata, Encodable},
+    internal_events::BufferEvictedEventCountUnknown,
     variants::disk_v2::{
         io::AsyncFile,
         reader::decode_record_payload,
@@ -95,6 +98,10 @@ where
         source: <T as Encodable>::EncodeError,
     },
 
+    /// The writer failed to compress or encrypt the encoded record.
+    #[snafu(display("failed to transform encoded record: {}", source))]
+    FailedToTransform { source: PayloadError },
+
     /// The writer failed to serialize the record.
     ///
     /// As records are encoded and then wrapped in a container which carries metadata about the size
@@ -360,6 +367,8 @@ impl<W: fmt::Debug> fmt::Debug for TrackingBufWriter<W> {
 pub(super) struct RecordWriter<W, T> {
     writer: TrackingBufWriter<W>,
     encode_buf: Vec<u8>,
+    payload_buf: Vec<u8>,
+    payload_codec: PayloadCodec,
     ser_buf: AlignedVec,
     ser_scratch: AlignedVec,
     checksummer: Hasher,
@@ -384,6 +393,7 @@ where
         write_buffer_size: usize,
         max_data_file_size: u64,
         max_record_size: usize,
+        payload_codec: PayloadCodec,
     ) -> Self {
         // These should also be getting checked at a higher level, but we're double-checking them here to be absolutely sure.
         let max_record_size_converted = u64::try_from(max_record_size)
@@ -410,6 +420,8 @@ where
         Self {
             writer: TrackingBufWriter::with_capacity(write_buffer_size, writer),
             encode_buf: Vec::with_capacity(16_384),
+            payload_buf: Vec::new(),
+            payload_codec,
             ser_buf: AlignedVec::with_capacity(16_384),
             ser_scratch: AlignedVec::with_capacity(16_384),
             checksummer: create_crc32c_hasher(),
@@ -480,9 +492,22 @@ where
             });
         }
 
+        // Compress and/or encrypt the encoded record, if configured to do so.  The upper bits of the
+        // record metadata are reserved for tracking which transformations were applied.
         let metadata = T::get_metadata().into_u32();
+        check_encoder_metadata(metadata).context(FailedToTransformSnafu)?;
+        let (payload_format, payload) = self
+            .payload_codec
+            .encode(id, &self.encode_buf, &mut self.payload_buf)
+            .context(FailedToTransformSnafu)?;
+        if payload.len() > self.max_record_size {
+            return Err(WriterError::RecordTooLarge {
+                limit: self.max_record_size,
+            });
+        }
+
         let wrapped_record =
-            Record::with_checksum(id, metadata, &self.encode_buf, &self.checksummer);
+            Record::with_checksum(id, metadata | payload_format, payload, &self.checksummer);
 
         // Push 8 dummy bytes where our length delimiter will sit.  We'll fix this up after
         // serialization.  Notably, `AlignedSerializer` will report the serializer position as
@@ -665,18 +690,27 @@ where
             }
         })?;
 
-        // Now we can actually decode it as `T`.
-        let record_metadata = T::Metadata::from_u32(wrapped_record.metadata()).ok_or(
-            WriterError::InconsistentState {
-                reason: "failed to decode record metadata immediately after encoding it"
+        // Reverse any transformations applied to the payload, and now we can actually decode it as `T`.
+        let payload = self
+            .payload_codec
+            .decode(
+                wrapped_record.metadata(),
+                wrapped_record.id(),
+                wrapped_record.payload(),
+            )
+            .map_err(|_| WriterError::InconsistentState {
+                reason: "failed to restore record payload immediately after transforming it"
                     .to_string(),
-            },
-        )?;
+            })?;
+        let record_metadata = T::Metadata::from_u32(
+            wrapped_record.metadata() & !PAYLOAD_FORMAT_MASK,
+        )
+        .ok_or(WriterError::InconsistentState {
+            reason: "failed to decode record metadata immediately after encoding it".to_string(),
+        })?;
 
-        T::decode(record_metadata, wrapped_record.payload()).map_err(|_| {
-            WriterError::InconsistentState {
-                reason: "failed to decode record immediately after encoding it".to_string(),
-            }
+        T::decode(record_metadata, &payload[..]).map_err(|_| WriterError::InconsistentState {
+            reason: "failed to decode record immediately after encoding it".to_string(),
         })
     }
 
@@ -882,11 +916,11 @@ where
                 // next writer record ID should be.
                 let record = try_as_record_archive(data_file_mmap.as_ref())
                     .expect("record was already validated");
-                let item = decode_record_payload::<T>(record).map_err(|e| {
-                    WriterError::FailedToValidate {
+                let item = decode_record_payload::<T>(record, &self.config.payload_codec).map_err(
+                    |e| WriterError::FailedToValidate {
                         reason: e.to_string(),
-                    }
-                })?;
+                    },
+                )?;
 
                 // Since we have a valid record, checksum and all, see if the writer record ID
                 // in the ledger lines up with the record ID we have here.  Specifically, the record
@@ -996,6 +1030,86 @@ where
         total_buffer_size >= max_buffer_size
     }
 
+    /// Evicts the oldest data file that the reader has not yet started reading.
+    ///
+    /// Rather than deleting the data file outright, it's replaced with an empty data file, so that
+    /// the reader simply finds no records in it when it gets there, and rolls past it like any
+    /// other fully-read data file.  The evicted events are tracked as such, and the reader skips
+    /// over their record IDs once it reads the next record.
+    ///
+    /// Returns `true` if a data file was evicted, or `false` if there was no data file eligible for
+    /// eviction.
+    #[instrument(skip(self), level = "debug")]
+    async fn evict_oldest_data_file(&mut self) -> io::Result<bool> {
+        // Hold the eviction lock so that the reader can't open the data file we're evicting.
+        let _eviction_guard = self.ledger.lock_data_file_eviction().await;
+
+        let (reader_file_id, writer_file_id) = self.ledger.get_current_reader_writer_file_id();
+        if reader_file_id == writer_file_id {
+            return Ok(false);
+        }
+
+        // The reader's current data file might already be partially read, or waiting on
+        // acknowledgements, so the oldest data file we can evict is the one after it.  We skip over
+        // any data files that were already evicted but that the reader hasn't yet gotten to.
+        let mut data_file_id = reader_file_id.wrapping_add(1);
+        while data_file_id != writer_file_id {
+            let evicted_file_id = data_file_id;
+            let data_file_path = self.ledger.get_data_file_path(evicted_file_id);
+            data_file_id = data_file_id.wrapping_add(1);
+
+            let data_file_size = match self
+                .ledger
+                .filesystem()
+                .open_file_readable(&data_file_path)
+                .await
+            {
+                Ok(data_file) => data_file.metadata().await?.len(),
+                Err(e) if e.kind() == ErrorKind::NotFound => continue,
+                Err(e) => return Err(e),
+            };
+            if data_file_size == 0 {
+                continue;
+            }
+
+            self.ledger
+                .filesystem()
+                .delete_file(&data_file_path)
+                .await?;
+            self.ledger
+                .filesystem()
+                .open_file_writable_atomic(&data_file_path)
+                .await?
+                .sync_all()
+                .await?;
+
+            // We only know how many events are in the data file if we wrote it ourselves.  If we
+            // don't, the reader will still skip over their record IDs, and track them as dropped at
+            // that point.
+            let event_count = self.ledger.take_data_file_event_count(evicted_file_id);
+            match event_count {
+                Some(event_count) => self
+                    .ledger
+                    .track_evicted_events(event_count, data_file_size),
+                None => {
+                    self.ledger.track_evicted_events(0, data_file_size);
+                    emit(BufferEvictedEventCountUnknown {
+                        data_file_path: data_file_path.clone(),
+                    });
+                }
+            }
+
+            debug!(
+                data_file_path = data_file_path.to_string_lossy().as_ref(),
+                data_file_size, event_count, "Evicted data file to make room for newer records."
+            );
+
+            return Ok(true);
+        }
+
+        Ok(false)
+    }
+
     /// Ensures this writer is ready to attempt writer the next record.
     #[instrument(skip(self), level = "debug")]
     async fn ensure_ready_for_write(&mut self) -> io::Result<()> {
@@ -1129,6 +1243,7 @@ where
                     self.config.write_buffer_size,
                     self.config.max_data_file_size,
                     self.config.max_record_size,
+                    self.config.payload_codec.clone(),
                 ));
                 self.data_file_size = data_file_size;
 
@@ -1144,6 +1259,13 @@ where
                     );
                 }
 
+                // Keep count of the events we write to the data file, so that we know how many
+                // events are lost if it ever gets evicted.
+                self.ledger.reset_data_file_event_count(
+                    self.ledger.get_current_writer_file_id(),
+                    data_file_size == 0,
+                );
+
                 return Ok(());
             }
 
@@ -1253,6 +1375,10 @@ where
         // setting the ledger state to a record ID that we may never have actually written, which
         // could lead to record ID gaps.
         self.track_write(record_events.get(), bytes_written as u64);
+        self.ledger.track_data_file_events(
+            self.ledger.get_current_writer_file_id(),
+            record_events.get() as u64,
+        );
 
         // If we did flush some buffered writes during this write, however, we now compensate for
         // that after updating our internal state.  We'll also notify the reader, too, since the
@@ -1295,6 +1421,37 @@ where
         }
     }
 
+    /// Writes a record, evicting the oldest records in the buffer to make room for it if necessary.
+    ///
+    /// Records are evicted an entire data file at a time, and only from data files the reader has
+    /// not yet started reading.  If there are no such data files, this waits for the reader to make
+    /// progress, just like `write_record`.
+    ///
+    /// If the record was written successfully, the number of bytes written to the data file will be
+    /// returned.
+    ///
+    /// # Errors
+    ///
+    /// If an error occurred while writing the record, an error variant will be returned describing
+    /// the error.
+    #[instrument(skip_all, level = "debug")]
+    pub async fn write_record_evicting_oldest(
+        &mut self,
+        mut record: T,
+    ) -> Result<usize, WriterError<T>> {
+        loop {
+            match self.try_write_record_inner(record).await? {
+                Ok(bytes_written) => return Ok(bytes_written),
+                Err(old_record) => {
+                    record = old_record;
+                    if !self.evict_oldest_data_file().await.context(IoSnafu)? {
+                        self.ledger.wait_for_reader().await;
+                    }
+                }
+            }
+        }
+    }
+
     #[instrument(skip(self), level = "debug")]
     async fn flush_inner(&mut self, force_full_flush: bool) -> io::Result<()> {
         // We always flush the `BufWriter` when this is called, but we don't always flush to disk or
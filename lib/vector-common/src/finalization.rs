@@ -3,7 +3,14 @@
 //! as it flows through transforms, being duplicated and merged, and
 //! then report its status when the last copy is delivered or dropped.
 
-use std::{cmp, future::Future, mem, pin::Pin, sync::Arc, task::Poll};
+use std::{
+    cmp,
+    future::Future,
+    mem,
+    pin::Pin,
+    sync::{Arc, OnceLock},
+    task::Poll,
+};
 
 use crossbeam_utils::atomic::AtomicCell;
 use futures::future::FutureExt;
@@ -86,6 +93,16 @@ impl EventFinalizers {
         }
     }
 
+    /// Records the details of a delivery failure on the batches of all event finalizers in the
+    /// collection.
+    ///
+    /// Only the first failure recorded for a batch is kept.
+    pub fn record_failure(&self, failure: &DeliveryFailure) {
+        for finalizer in &self.0 {
+            finalizer.batch.record_failure(failure);
+        }
+    }
+
     /// Consumes all event finalizers and updates their underlying batches immediately.
     pub fn update_sources(&mut self) {
         let finalizers = mem::take(&mut self.0);
@@ -157,22 +174,26 @@ impl Drop for EventFinalizer {
     }
 }
 
+/// The details of a failed delivery attempt, recorded by the sink that failed to deliver a batch.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DeliveryFailure {
+    /// A description of the error that caused the failure.
+    pub reason: String,
+    /// The HTTP status code of the last response, if the sink received one.
+    pub http_status: Option<u16>,
+    /// The number of attempts made to deliver the events, if known.
+    pub attempts: Option<usize>,
+}
+
 /// A convenience newtype wrapper for the one-shot receiver for an
 /// individual batch status.
 #[pin_project::pin_project]
-pub struct BatchStatusReceiver(oneshot::Receiver<BatchStatus>);
+pub struct BatchStatusReceiver(oneshot::Receiver<(BatchStatus, Option<DeliveryFailure>)>);
 
 impl Future for BatchStatusReceiver {
     type Output = BatchStatus;
-    fn poll(mut self: Pin<&mut Self>, ctx: &mut std::task::Context<'_>) -> Poll<Self::Output> {
-        match self.0.poll_unpin(ctx) {
-            Poll::Pending => Poll::Pending,
-            Poll::Ready(Ok(status)) => Poll::Ready(status),
-            Poll::Ready(Err(error)) => {
-                error!(%error, "Batch status receiver dropped before sending.");
-                Poll::Ready(BatchStatus::Errored)
-            }
-        }
+    fn poll(self: Pin<&mut Self>, ctx: &mut std::task::Context<'_>) -> Poll<Self::Output> {
+        self.poll_with_failure(ctx).map(|(status, _)| status)
     }
 }
 
@@ -184,7 +205,27 @@ impl BatchStatusReceiver {
     /// - `TryRecvError::Empty` if no value has been sent yet.
     /// - `TryRecvError::Closed` if the sender has dropped without sending a value.
     pub fn try_recv(&mut self) -> Result<BatchStatus, oneshot::error::TryRecvError> {
-        self.0.try_recv()
+        self.0.try_recv().map(|(status, _)| status)
+    }
+
+    /// Waits for the status of the batch, along with the details of the first delivery failure
+    /// recorded for it, if any.
+    pub async fn recv_with_failure(mut self) -> (BatchStatus, Option<DeliveryFailure>) {
+        std::future::poll_fn(|ctx| Pin::new(&mut self).poll_with_failure(ctx)).await
+    }
+
+    fn poll_with_failure(
+        mut self: Pin<&mut Self>,
+        ctx: &mut std::task::Context<'_>,
+    ) -> Poll<(BatchStatus, Option<DeliveryFailure>)> {
+        match self.0.poll_unpin(ctx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(Ok(result)) => Poll::Ready(result),
+            Poll::Ready(Err(error)) => {
+                error!(%error, "Batch status receiver dropped before sending.");
+                Poll::Ready((BatchStatus::Errored, None))
+            }
+        }
     }
 }
 
@@ -198,9 +239,25 @@ impl BatchNotifier {
     /// Creates a new `BatchNotifier` along with the receiver used to await its finalization status.
     #[must_use]
     pub fn new_with_receiver() -> (Self, BatchStatusReceiver) {
+        Self::new_with_receiver_inner(false)
+    }
+
+    /// Creates a new `BatchNotifier` along with the receiver used to await its finalization status,
+    /// for which events dropped without being finalized count as rejected rather than delivered.
+    ///
+    /// This is used to detect the events a sink dropped without delivering them, for example
+    /// because they failed to be encoded.
+    #[must_use]
+    pub fn new_rejecting_dropped_with_receiver() -> (Self, BatchStatusReceiver) {
+        Self::new_with_receiver_inner(true)
+    }
+
+    fn new_with_receiver_inner(reject_dropped: bool) -> (Self, BatchStatusReceiver) {
         let (sender, receiver) = oneshot::channel();
         let notifier = OwnedBatchNotifier {
             status: AtomicCell::new(BatchStatus::Delivered),
+            failure: OnceLock::new(),
+            reject_dropped,
             notifier: Some(sender),
         };
         (Self(Arc::new(notifier)), BatchStatusReceiver(receiver))
@@ -241,6 +298,11 @@ impl BatchNotifier {
 
     /// Updates the status of the notifier.
     fn update_status(&self, status: EventStatus) {
+        let status = if status == EventStatus::Dropped && self.0.reject_dropped {
+            EventStatus::Rejected
+        } else {
+            status
+        };
         // The status starts as Delivered and can only change if the new
         // status is different than that.
         if status != EventStatus::Delivered && status != EventStatus::Dropped {
@@ -250,13 +312,23 @@ impl BatchNotifier {
                 .unwrap_or_else(|_| unreachable!());
         }
     }
+
+    /// Records the details of a delivery failure, unless one was already recorded.
+    fn record_failure(&self, failure: &DeliveryFailure) {
+        if self.0.failure.get().is_none() {
+            _ = self.0.failure.set(failure.clone());
+        }
+    }
 }
 
 /// The non-shared data underlying the shared `BatchNotifier`
 #[derive(Debug)]
 pub struct OwnedBatchNotifier {
     status: AtomicCell<BatchStatus>,
-    notifier: Option<oneshot::Sender<BatchStatus>>,
+    failure: OnceLock<DeliveryFailure>,
+    /// Whether events dropped without being finalized count as rejected.
+    reject_dropped: bool,
+    notifier: Option<oneshot::Sender<(BatchStatus, Option<DeliveryFailure>)>>,
 }
 
 impl OwnedBatchNotifier {
@@ -264,9 +336,10 @@ impl OwnedBatchNotifier {
     fn send_status(&mut self) {
         if let Some(notifier) = self.notifier.take() {
             let status = self.status.load();
+            let failure = self.failure.take();
             // Ignore the error case, as it will happen during normal
             // source shutdown and we can't detect that here.
-            _ = notifier.send(status);
+            _ = notifier.send((status, failure));
         }
     }
 }
@@ -454,6 +527,25 @@ mod tests {
         assert_eq!(receiver2.try_recv(), Ok(BatchStatus::Delivered));
     }
 
+    #[tokio::test]
+    async fn records_first_failure() {
+        let (fin, receiver) = make_finalizer();
+        let failure = DeliveryFailure {
+            reason: "Request rejected.".to_string(),
+            http_status: Some(400),
+            attempts: Some(1),
+        };
+        fin.update_status(EventStatus::Rejected);
+        fin.record_failure(&failure);
+        fin.record_failure(&DeliveryFailure::default());
+        drop(fin);
+
+        assert_eq!(
+            receiver.recv_with_failure().await,
+            (BatchStatus::Rejected, Some(failure))
+        );
+    }
+
     #[ignore = "The current implementation does not deduplicate finalizers"]
     #[test]
     fn clone_and_merge_events() {
@@ -499,6 +591,19 @@ mod tests {
         assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
     }
 
+    #[test]
+    fn rejects_dropped_events() {
+        let (batch, mut receiver) = BatchNotifier::new_rejecting_dropped_with_receiver();
+        drop(EventFinalizers::new(EventFinalizer::new(batch)));
+        assert_eq!(receiver.try_recv(), Ok(BatchStatus::Rejected));
+
+        let (batch, mut receiver) = BatchNotifier::new_rejecting_dropped_with_receiver();
+        let finalizer = EventFinalizers::new(EventFinalizer::new(batch));
+        finalizer.update_status(EventStatus::Delivered);
+        drop(finalizer);
+        assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
+    }
+
     fn make_finalizer() -> (EventFinalizers, BatchStatusReceiver) {
         let (batch, receiver) = BatchNotifier::new_with_receiver();
         let finalizer = EventFinalizers::new(EventFinalizer::new(batch));
@@ -1,4 +1,4 @@
-use std::{collections::VecDeque, fmt, future::poll_fn, task::Poll};
+use std::{any::Any, cell::Cell, collections::VecDeque, fmt, future::poll_fn, task::Poll};
 
 use futures::{FutureExt, Stream, StreamExt, TryFutureExt, poll};
 use tokio::{pin, select};
@@ -11,7 +11,7 @@ use vector_common::{
     },
     request_metadata::{GroupedCountByteSize, MetaDescriptive},
 };
-use vector_core::event::{EventFinalizers, EventStatus, Finalizable};
+use vector_core::event::{DeliveryFailure, EventFinalizers, EventStatus, Finalizable};
 
 use super::FuturesUnorderedCount;
 
@@ -25,6 +25,26 @@ pub trait DriverResponse {
     fn bytes_sent(&self) -> Option<usize> {
         None
     }
+
+    /// Return the HTTP status code of the response, for services sending requests over HTTP.
+    ///
+    /// This is attached to the events of requests that failed to be delivered.
+    fn http_status(&self) -> Option<u16> {
+        None
+    }
+}
+
+tokio::task_local! {
+    /// The number of attempts made so far for the request being sent by a [`Driver`].
+    static REQUEST_ATTEMPTS: Cell<usize>;
+}
+
+/// Records that the request currently being sent by a [`Driver`] is about to be retried.
+///
+/// Retry policies call this so that the number of attempts can be reported for requests that
+/// ultimately fail. This has no effect when called outside of a request sent by a `Driver`.
+pub fn record_retry() {
+    _ = REQUEST_ATTEMPTS.try_with(|attempts| attempts.set(attempts.get() + 1));
 }
 
 /// Drives the interaction between a stream of items and a service which processes them
@@ -168,13 +188,16 @@ where
                         let events_sent = events_sent.clone();
                         let event_count = req.get_metadata().event_count();
 
-                        let fut = svc.call(req)
+                        let call = svc.call(req)
                             .err_into()
-                            .map(move |result| Self::handle_response(
+                            .map(|result| (result, REQUEST_ATTEMPTS.get()));
+                        let fut = REQUEST_ATTEMPTS.scope(Cell::new(1), call)
+                            .map(move |(result, attempts)| Self::handle_response(
                                 result,
                                 request_id,
                                 finalizers,
                                 event_count,
+                                attempts,
                                 bytes_sent.as_ref(),
                                 &events_sent,
                             ))
@@ -201,11 +224,17 @@ where
         request_id: usize,
         finalizers: EventFinalizers,
         event_count: usize,
+        attempts: usize,
         bytes_sent: Option<&Registered<BytesSent>>,
         events_sent: &RegisteredEventCache<(), TaggedEventsSent>,
     ) {
         match result {
             Err(error) => {
+                finalizers.record_failure(&DeliveryFailure {
+                    reason: Self::error_reason(&error),
+                    http_status: None,
+                    attempts: Some(attempts),
+                });
                 Self::emit_call_error(Some(error), request_id, event_count);
                 finalizers.update_status(EventStatus::Rejected);
             }
@@ -220,17 +249,36 @@ where
                     }
 
                     response.events_sent().emit_event(events_sent);
-
-                // This condition occurs specifically when the `HttpBatchService::call()` is called *within* the `Service::call()`
-                } else if response.event_status() == EventStatus::Rejected {
-                    Self::emit_call_error(None, request_id, event_count);
-                    finalizers.update_status(EventStatus::Rejected);
+                } else {
+                    let http_status = response.http_status();
+                    finalizers.record_failure(&DeliveryFailure {
+                        reason: http_status.map_or_else(
+                            || "Service failed to process the request.".to_string(),
+                            |status| format!("Service responded with HTTP status {status}."),
+                        ),
+                        http_status,
+                        attempts: Some(attempts),
+                    });
+
+                    // This condition occurs specifically when the `HttpBatchService::call()` is called *within* the `Service::call()`
+                    if response.event_status() == EventStatus::Rejected {
+                        Self::emit_call_error(None, request_id, event_count);
+                        finalizers.update_status(EventStatus::Rejected);
+                    }
                 }
             }
         }
         drop(finalizers); // suppress "argument not consumed" warning
     }
 
+    /// Describes the error a request failed with.
+    fn error_reason(error: &Svc::Error) -> String {
+        // Most services return boxed errors, whose `Display` output is more readable.
+        (error as &dyn Any)
+            .downcast_ref::<Box<dyn std::error::Error + Send + Sync>>()
+            .map_or_else(|| format!("{error:?}"), ToString::to_string)
+    }
+
     /// Emit the `Error` and `EventsDropped` internal events.
     /// This scenario occurs after retries have been attempted.
     fn emit_call_error(error: Option<Svc::Error>, request_id: usize, count: usize) {
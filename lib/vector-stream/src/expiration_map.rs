@@ -15,6 +15,9 @@ impl<T> Emitter<T> {
     pub fn emit(&mut self, value: T) {
         self.values.push(value);
     }
+    pub fn into_values(self) -> Vec<T> {
+        self.values
+    }
 }
 
 /// Similar to `stream.filter_map(..).flatten(..)` but also allows checking for expired events
@@ -18,7 +18,7 @@ mod futures_unordered_count;
 mod partitioned_batcher;
 
 pub use concurrent_map::ConcurrentMap;
-pub use driver::{Driver, DriverResponse};
+pub use driver::{Driver, DriverResponse, record_retry};
 use futures_unordered_count::FuturesUnorderedCount;
 pub use partitioned_batcher::{BatcherSettings, ExpirationQueue, PartitionedBatcher};
 
@@ -0,0 +1,325 @@
+//! Capture files hold events recorded by `vector tap --record`, so that they can be replayed later
+//! on, such as by the `tap_replay` source or as unit test inputs.
+//!
+//! A capture file is made of newline-delimited JSON records, each holding a single event encoded
+//! with Vector's native protobuf encoding, so that events, along with their metadata, are captured
+//! losslessly. Each record holds the version of the capture format it was written with, and records
+//! of other versions are rejected when read.
+
+use std::{
+    fmt,
+    io::{self, BufRead},
+    path::Path,
+    sync::Arc,
+};
+
+use base64::prelude::{BASE64_STANDARD, Engine as _};
+use bytes::BytesMut;
+use codecs::{NativeDeserializer, NativeSerializer, decoding::format::Deserializer as _};
+use serde::{Deserialize, Serialize};
+use tokio::{
+    fs::File,
+    io::{AsyncBufRead, AsyncBufReadExt, AsyncWriteExt, BufReader, BufWriter, Lines},
+    sync::Mutex,
+    time::Instant,
+};
+use tokio_util::codec::Encoder as _;
+use vector_core::{config::LogNamespace, event::Event};
+
+/// Version of the capture format written by this version of Vector.
+pub const CAPTURE_VERSION: u32 = 1;
+
+/// A single captured event.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct CaptureRecord {
+    /// Version of the capture format the record was written with.
+    pub version: u32,
+
+    /// Time the event was captured at, in milliseconds since the capture started.
+    pub offset_ms: u64,
+
+    /// ID of the component the event was captured from.
+    pub component_id: String,
+
+    /// Kind of the component the event was captured from.
+    pub component_kind: String,
+
+    /// Type of the component the event was captured from.
+    pub component_type: String,
+
+    /// Name of the component output the event was captured from, if not the default output.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+
+    /// The event, in Vector's native protobuf encoding, as base64.
+    pub event: String,
+}
+
+impl CaptureRecord {
+    /// Decodes the captured event.
+    pub fn decode(&self) -> Result<Vec<Event>, CaptureError> {
+        let bytes = BASE64_STANDARD
+            .decode(&self.event)
+            .map_err(|error| CaptureError::Decode(error.to_string()))?;
+
+        NativeDeserializer
+            .parse(bytes.into(), LogNamespace::Legacy)
+            .map(|events| events.into_vec())
+            .map_err(|error| CaptureError::Decode(error.to_string()))
+    }
+}
+
+/// Errors raised while reading a capture file.
+#[derive(Debug)]
+pub enum CaptureError {
+    Io(io::Error),
+    Parse {
+        line: usize,
+        error: serde_json::Error,
+    },
+    Decode(String),
+    UnsupportedVersion {
+        line: usize,
+        version: u32,
+    },
+}
+
+impl fmt::Display for CaptureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "Couldn't read capture file: {error}"),
+            Self::Parse { line, error } => {
+                write!(f, "Invalid capture record on line {line}: {error}")
+            }
+            Self::Decode(error) => write!(f, "Couldn't decode captured event: {error}"),
+            Self::UnsupportedVersion { line, version } => write!(
+                f,
+                "Unsupported capture format version {version} on line {line}, expected version {CAPTURE_VERSION}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CaptureError {}
+
+/// Encodes an event with Vector's native protobuf encoding, as base64, as stored in capture files.
+pub fn encode_event(event: Event) -> String {
+    let mut buffer = BytesMut::new();
+    NativeSerializer
+        .encode(event, &mut buffer)
+        .expect("Native serialization of event failed. Please report.");
+    BASE64_STANDARD.encode(buffer)
+}
+
+/// Parses a single line of a capture file, returning `None` for blank lines.
+fn parse_line(index: usize, line: &str) -> Result<Option<CaptureRecord>, CaptureError> {
+    if line.trim().is_empty() {
+        return Ok(None);
+    }
+
+    let record: CaptureRecord =
+        serde_json::from_str(line).map_err(|error| CaptureError::Parse {
+            line: index + 1,
+            error,
+        })?;
+    if record.version != CAPTURE_VERSION {
+        return Err(CaptureError::UnsupportedVersion {
+            line: index + 1,
+            version: record.version,
+        });
+    }
+    Ok(Some(record))
+}
+
+/// Parses the records of a capture file, skipping blank lines.
+pub fn parse_capture(reader: impl BufRead) -> Result<Vec<CaptureRecord>, CaptureError> {
+    reader
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            line.map_err(CaptureError::Io)
+                .and_then(|line| parse_line(index, &line))
+                .transpose()
+        })
+        .collect()
+}
+
+/// Reads the records of the capture file at the given path.
+pub fn read_capture(path: &Path) -> Result<Vec<CaptureRecord>, CaptureError> {
+    let file = std::fs::File::open(path).map_err(CaptureError::Io)?;
+    parse_capture(io::BufReader::new(file))
+}
+
+/// Reads the records of a capture file one at a time, without holding the whole file in memory.
+pub struct CaptureReader<R> {
+    lines: Lines<R>,
+    index: usize,
+}
+
+impl CaptureReader<BufReader<File>> {
+    /// Opens the capture file at the given path.
+    pub async fn open(path: &Path) -> Result<Self, CaptureError> {
+        let file = File::open(path).await.map_err(CaptureError::Io)?;
+        Ok(Self::new(BufReader::new(file)))
+    }
+}
+
+impl<R: AsyncBufRead + Unpin> CaptureReader<R> {
+    /// Creates a reader of the capture records read from the given reader.
+    pub fn new(reader: R) -> Self {
+        Self {
+            lines: reader.lines(),
+            index: 0,
+        }
+    }
+
+    /// Reads the next record, skipping blank lines, or returns `None` at the end of the capture.
+    pub async fn next_record(&mut self) -> Result<Option<CaptureRecord>, CaptureError> {
+        while let Some(line) = self.lines.next_line().await.map_err(CaptureError::Io)? {
+            let index = self.index;
+            self.index += 1;
+            if let Some(record) = parse_line(index, &line)? {
+                return Ok(Some(record));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// A tapped event to write to a capture file.
+pub struct CapturedEvent<'a> {
+    pub component_id: &'a str,
+    pub component_kind: &'a str,
+    pub component_type: &'a str,
+    pub output: Option<&'a str>,
+    /// The event, native encoded as by [`encode_event`].
+    pub event: &'a str,
+}
+
+/// Writes tapped events to a capture file. Cloned writers share the same file, and start time.
+#[derive(Clone, Debug)]
+pub struct CaptureWriter {
+    file: Arc<Mutex<BufWriter<File>>>,
+    start: Instant,
+}
+
+impl CaptureWriter {
+    /// Creates the capture file at the given path, truncating it if it already exists.
+    pub async fn create(path: &Path) -> io::Result<Self> {
+        Ok(Self {
+            file: Arc::new(Mutex::new(BufWriter::new(File::create(path).await?))),
+            start: Instant::now(),
+        })
+    }
+
+    /// Writes a batch of captured events.
+    pub async fn write<'a>(
+        &self,
+        events: impl IntoIterator<Item = CapturedEvent<'a>>,
+    ) -> io::Result<()> {
+        let offset_ms = self.start.elapsed().as_millis() as u64;
+
+        // Records are serialized upfront, so that the file is only locked while writing them.
+        let mut buffer = Vec::new();
+        for event in events {
+            let record = CaptureRecord {
+                version: CAPTURE_VERSION,
+                offset_ms,
+                component_id: event.component_id.to_string(),
+                component_kind: event.component_kind.to_string(),
+                component_type: event.component_type.to_string(),
+                output: event.output.map(ToString::to_string),
+                event: event.event.to_string(),
+            };
+            serde_json::to_writer(&mut buffer, &record)?;
+            buffer.push(b'\n');
+        }
+
+        let mut file = self.file.lock().await;
+        file.write_all(&buffer).await?;
+        file.flush().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_core::event::LogEvent;
+
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let mut log = LogEvent::from("hello");
+        log.metadata_mut().set_source_type("demo_logs");
+        let event = Event::from(log);
+
+        let contents = serde_json::to_string(&CaptureRecord {
+            version: CAPTURE_VERSION,
+            offset_ms: 10,
+            component_id: "in".to_string(),
+            component_kind: "source".to_string(),
+            component_type: "demo_logs".to_string(),
+            output: None,
+            event: encode_event(event.clone()),
+        })
+        .unwrap();
+
+        let records = parse_capture(format!("{contents}\n\n").as_bytes()).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].offset_ms, 10);
+        assert_eq!(records[0].output, None);
+        assert_eq!(records[0].decode().unwrap(), vec![event]);
+    }
+
+    #[test]
+    fn invalid_record() {
+        let error = parse_capture("{}\n".as_bytes()).unwrap_err();
+        assert!(matches!(error, CaptureError::Parse { line: 1, .. }));
+    }
+
+    #[test]
+    fn unsupported_version() {
+        let record = CaptureRecord {
+            version: CAPTURE_VERSION + 1,
+            offset_ms: 0,
+            component_id: "in".to_string(),
+            component_kind: "source".to_string(),
+            component_type: "demo_logs".to_string(),
+            output: None,
+            event: encode_event(LogEvent::from("hello").into()),
+        };
+        let contents = format!("\n{}\n", serde_json::to_string(&record).unwrap());
+
+        let error = parse_capture(contents.as_bytes()).unwrap_err();
+        assert!(matches!(
+            error,
+            CaptureError::UnsupportedVersion { line: 2, version } if version == CAPTURE_VERSION + 1
+        ));
+    }
+
+    #[tokio::test]
+    async fn writes_and_reads_records() {
+        let path = std::env::temp_dir().join(format!("vector-tap-capture-{}", std::process::id()));
+        let event = encode_event(LogEvent::from("hello").into());
+
+        let writer = CaptureWriter::create(&path).await.unwrap();
+        writer
+            .write([CapturedEvent {
+                component_id: "route",
+                component_kind: "transform",
+                component_type: "route",
+                output: Some("errors"),
+                event: &event,
+            }])
+            .await
+            .unwrap();
+
+        let mut reader = CaptureReader::open(&path).await.unwrap();
+        let record = reader.next_record().await.unwrap().unwrap();
+        assert_eq!(record.component_id, "route");
+        assert_eq!(record.output.as_deref(), Some("errors"));
+        assert!(reader.next_record().await.unwrap().is_none());
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
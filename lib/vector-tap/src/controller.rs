@@ -1,6 +1,7 @@
 use std::{
     collections::{HashMap, HashSet},
     num::NonZeroUsize,
+    sync::Arc,
 };
 
 use futures::{FutureExt, future::try_join_all};
@@ -14,7 +15,7 @@ use uuid::Uuid;
 use vector_buffers::{WhenFull, topology::builder::TopologyBuilder};
 use vector_common::config::ComponentKey;
 use vector_core::{
-    event::{EventArray, LogArray, MetricArray, TraceArray},
+    event::{EventArray, EventContainer, LogArray, MetricArray, TraceArray},
     fanout,
 };
 
@@ -26,6 +27,10 @@ use crate::{
 /// A tap sender is the control channel used to surface tap payloads to a client.
 type TapSender = tokio_mpsc::Sender<TapPayload>;
 
+/// A tap processor is applied to tapped events before they're surfaced to a client, for example
+/// to filter them by content. Returns `None` if none of the events should be surfaced.
+pub type TapProcessor = Arc<dyn Fn(EventArray) -> Option<EventArray> + Send + Sync>;
+
 // Shutdown channel types
 type ShutdownTx = oneshot::Sender<()>;
 type ShutdownRx = oneshot::Receiver<()>;
@@ -159,14 +164,33 @@ impl TapPayload {
 pub struct TapTransformer {
     tap_tx: TapSender,
     output: TapOutput,
+    processor: Option<TapProcessor>,
 }
 
 impl TapTransformer {
     pub const fn new(tap_tx: TapSender, output: TapOutput) -> Self {
-        Self { tap_tx, output }
+        Self {
+            tap_tx,
+            output,
+            processor: None,
+        }
+    }
+
+    /// Applies the given processor to events before shipping them.
+    pub fn with_processor(mut self, processor: Option<TapProcessor>) -> Self {
+        self.processor = processor;
+        self
     }
 
     pub fn try_send(&mut self, events: EventArray) {
+        let events = match &self.processor {
+            Some(processor) => match processor(events) {
+                Some(events) if !events.is_empty() => events,
+                _ => return,
+            },
+            None => events,
+        };
+
         let payload = match events {
             EventArray::Logs(logs) => TapPayload::Log(self.output.clone(), logs),
             EventArray::Metrics(metrics) => TapPayload::Metric(self.output.clone(), metrics),
@@ -195,15 +219,28 @@ impl TapController {
     /// and a separate inner handler for events. Uses a oneshot channel to trigger shutdown
     /// of handlers when the `TapSink` drops out of scope.
     pub fn new(watch_rx: WatchRx, tap_tx: TapSender, patterns: TapPatterns) -> Self {
+        Self::new_with_processor(watch_rx, tap_tx, patterns, None)
+    }
+
+    /// Creates a new tap sink, like [`TapController::new`], whose events are passed through the
+    /// given processor before being sent.
+    pub fn new_with_processor(
+        watch_rx: WatchRx,
+        tap_tx: TapSender,
+        patterns: TapPatterns,
+        processor: Option<TapProcessor>,
+    ) -> Self {
         let (_shutdown, shutdown_rx) = oneshot::channel();
 
         tokio::spawn(
-            tap_handler(patterns, tap_tx, watch_rx, shutdown_rx).instrument(error_span!(
-                "tap_handler",
-                component_kind = "sink",
-                component_id = "_tap", // It isn't clear what the component_id should be here other than "_tap"
-                component_type = "tap",
-            )),
+            tap_handler(patterns, tap_tx, watch_rx, shutdown_rx, processor).instrument(
+                error_span!(
+                    "tap_handler",
+                    component_kind = "sink",
+                    component_id = "_tap", // It isn't clear what the component_id should be here other than "_tap"
+                    component_type = "tap",
+                ),
+            ),
         );
 
         Self { _shutdown }
@@ -276,6 +313,7 @@ async fn tap_handler(
     tx: TapSender,
     mut watch_rx: WatchRx,
     mut shutdown_rx: ShutdownRx,
+    processor: Option<TapProcessor>,
 ) {
     debug!(message = "Started tap.", outputs_patterns = ?patterns.for_outputs, inputs_patterns = ?patterns.for_inputs);
 
@@ -357,7 +395,8 @@ async fn tap_handler(
                             // wrap each event payload with the necessary metadata before forwarding
                             // it to our global tap receiver.
                             let (tap_buffer_tx, mut tap_buffer_rx) = TopologyBuilder::standalone_memory(TAP_BUFFER_SIZE, WhenFull::DropNewest, &Span::current()).await;
-                            let mut tap_transformer = TapTransformer::new(tx.clone(), output.clone());
+                            let mut tap_transformer = TapTransformer::new(tx.clone(), output.clone())
+                                .with_processor(processor.clone());
 
                             tokio::spawn(async move {
                                 while let Some(events) = tap_buffer_rx.next().await {
@@ -3,6 +3,7 @@
 #[macro_use]
 extern crate tracing;
 
+pub mod capture;
 pub mod controller;
 pub mod notification;
 pub mod topology;
@@ -17,13 +18,15 @@ use tokio::{
 use tokio_stream::StreamExt;
 use url::Url;
 use vector_api_client::{
-    connect_subscription_client,
+    ClientOptions, connect_subscription_client_with_options,
     gql::{
         TapEncodingFormat, TapSubscriptionExt,
         output_events_by_component_id_patterns_subscription::OutputEventsByComponentIdPatternsSubscriptionOutputEventsByComponentIdPatterns as GraphQLTapOutputEvent,
     },
 };
 
+use crate::capture::{CaptureWriter, CapturedEvent};
+
 #[derive(Clone, Debug)]
 pub struct EventFormatter {
     meta: bool,
@@ -82,6 +85,17 @@ impl EventFormatter {
                     )
                     .into()
                 }
+                TapEncodingFormat::Native => format!(
+                    r#"{{"{}":"{}","{}":"{}","{}":"{}","event":"{}"}}"#,
+                    self.component_id_label,
+                    component_id.green(),
+                    self.component_kind_label,
+                    component_kind.green(),
+                    self.component_type_label,
+                    component_type.green(),
+                    event
+                )
+                .into(),
                 TapEncodingFormat::Logfmt => format!(
                     "{}={} {}={} {}={} {}",
                     self.component_id_label,
@@ -104,6 +118,8 @@ impl EventFormatter {
 pub enum OutputChannel {
     Stdout(EventFormatter),
     AsyncChannel(tokio_mpsc::Sender<Vec<GraphQLTapOutputEvent>>),
+    /// Records events to a capture file. Events must be tapped with the native encoding.
+    Record(CaptureWriter),
 }
 
 /// Error type for DNS message parsing
@@ -120,6 +136,9 @@ pub struct TapRunner<'a> {
     output_patterns: Vec<String>,
     output_channel: &'a OutputChannel,
     format: TapEncodingFormat,
+    client_options: &'a ClientOptions,
+    filter: Option<String>,
+    transform: Option<String>,
 }
 
 impl<'a> TapRunner<'a> {
@@ -129,6 +148,7 @@ impl<'a> TapRunner<'a> {
         output_patterns: Vec<String>,
         output_channel: &'a OutputChannel,
         format: TapEncodingFormat,
+        client_options: &'a ClientOptions,
     ) -> Self {
         TapRunner {
             url,
@@ -136,9 +156,20 @@ impl<'a> TapRunner<'a> {
             output_patterns,
             output_channel,
             format,
+            client_options,
+            filter: None,
+            transform: None,
         }
     }
 
+    /// Sets the VRL boolean expression tapped events are filtered with, and the VRL program
+    /// they're transformed by, before being sent from the server.
+    pub fn with_programs(mut self, filter: Option<String>, transform: Option<String>) -> Self {
+        self.filter = filter;
+        self.transform = transform;
+        self
+    }
+
     pub async fn run_tap(
         &self,
         interval: i64,
@@ -146,9 +177,10 @@ impl<'a> TapRunner<'a> {
         duration_ms: Option<u64>,
         quiet: bool,
     ) -> Result<(), TapExecutorError> {
-        let subscription_client = connect_subscription_client((*self.url).clone())
-            .await
-            .map_err(TapExecutorError::ConnectionFailure)?;
+        let subscription_client =
+            connect_subscription_client_with_options((*self.url).clone(), self.client_options)
+                .await
+                .map_err(TapExecutorError::ConnectionFailure)?;
 
         tokio::pin! {
             let stream = subscription_client.output_events_by_component_id_patterns_subscription(
@@ -157,6 +189,8 @@ impl<'a> TapRunner<'a> {
                 self.format,
                 limit,
                 interval,
+                self.filter.clone(),
+                self.transform.clone(),
             );
         }
 
@@ -191,6 +225,9 @@ impl<'a> TapRunner<'a> {
                             OutputChannel::Stdout(formatter) => {
                                 self.output_event_stdout(&output_events, formatter);
                             }
+                            OutputChannel::Record(writer) => {
+                                self.output_event_record(&output_events, writer).await;
+                            }
                             OutputChannel::AsyncChannel(sender_tx) => {
                                 if let Err(error) = sender_tx.send(output_events).await {
                                     error!("Could not send tap events: {error}");
@@ -258,4 +295,45 @@ impl<'a> TapRunner<'a> {
             }
         }
     }
+
+    async fn output_event_record(
+        &self,
+        output_events: &[GraphQLTapOutputEvent],
+        writer: &CaptureWriter,
+    ) {
+        let events = output_events
+            .iter()
+            .filter_map(|tap_event| match tap_event {
+                GraphQLTapOutputEvent::Log(ev) => Some(CapturedEvent {
+                    component_id: &ev.component_id,
+                    component_kind: &ev.component_kind,
+                    component_type: &ev.component_type,
+                    output: ev.output.as_deref(),
+                    event: &ev.string,
+                }),
+                GraphQLTapOutputEvent::Metric(ev) => Some(CapturedEvent {
+                    component_id: &ev.component_id,
+                    component_kind: &ev.component_kind,
+                    component_type: &ev.component_type,
+                    output: ev.output.as_deref(),
+                    event: &ev.string,
+                }),
+                GraphQLTapOutputEvent::Trace(ev) => Some(CapturedEvent {
+                    component_id: &ev.component_id,
+                    component_kind: &ev.component_kind,
+                    component_type: &ev.component_type,
+                    output: ev.output.as_deref(),
+                    event: &ev.string,
+                }),
+                #[allow(clippy::print_stderr)]
+                GraphQLTapOutputEvent::EventNotification(ev) => {
+                    eprintln!("{}", ev.message);
+                    None
+                }
+            });
+
+        if let Err(error) = writer.write(events).await {
+            error!("Could not record tap events: {error}");
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use std::{io::stdout, time::Duration};
+use std::{cmp::Ordering, collections::VecDeque, io::stdout, time::Duration};
 
 use crossterm::{
     ExecutableCommand,
@@ -12,17 +12,19 @@ use num_format::{Locale, ToFormattedString};
 use ratatui::{
     Frame, Terminal,
     backend::CrosstermBackend,
-    layout::{Alignment, Constraint, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
+    widgets::{Block, Borders, Cell, Gauge, Paragraph, Row, Sparkline, Table, TableState, Wrap},
 };
+use regex::Regex;
 use tokio::sync::oneshot;
 use unit_prefix::NumberPrefix;
+use vector_common::config::ComponentKey;
 
 use super::{
     events::capture_key_press,
-    state::{self, ConnectionStatus},
+    state::{self, ComponentRow, ConnectionStatus},
 };
 
 pub const fn is_allocation_tracing_enabled() -> bool {
@@ -123,10 +125,43 @@ fn format_metric_bytes(total: i64, throughput: i64, human_metrics: bool) -> Stri
     }
 }
 
+fn format_count(count: i64, human_metrics: bool) -> String {
+    if human_metrics {
+        count.human_format()
+    } else {
+        count.thousands_format()
+    }
+}
+
+/// Format a ratio between 0 and 1 as a percentage, returning `--` if it isn't known yet
+fn format_ratio(ratio: Option<f64>) -> String {
+    match ratio {
+        Some(ratio) => format!("{:.0}%", ratio * 100.0),
+        None => "--".into(),
+    }
+}
+
+static SPARKLINE_BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Render the last `width` samples of a series as a line of bars, scaled to the largest of them.
+/// Returns `--` if there are no samples yet.
+fn sparkline(series: &VecDeque<u64>, width: usize) -> String {
+    let samples = series.iter().skip(series.len().saturating_sub(width));
+    let max = samples.clone().max().copied().unwrap_or_default();
+    let bars = samples
+        .map(|&v| match max {
+            0 => SPARKLINE_BARS[0],
+            max => SPARKLINE_BARS[(v as f64 / max as f64 * 7.0).round() as usize],
+        })
+        .collect::<String>();
+
+    if bars.is_empty() { "--".into() } else { bars }
+}
+
 const NUM_COLUMNS: usize = if is_allocation_tracing_enabled() {
-    10
+    14
 } else {
-    9
+    13
 };
 
 static HEADER: [&str; NUM_COLUMNS] = [
@@ -138,11 +173,218 @@ static HEADER: [&str; NUM_COLUMNS] = [
     "Bytes In",
     "Events Out",
     "Bytes Out",
+    "Trend",
+    "Util",
+    "Buffer",
     "Errors",
+    "Discarded",
     #[cfg(feature = "allocation-tracing")]
     "Memory Used",
 ];
 
+/// Index of the "Output" column, in which output rows show the output ID
+const OUTPUT_COLUMN: usize = 1;
+
+/// Index of the "Events Out" column, in which output rows show the sent events of the output
+const EVENTS_OUT_COLUMN: usize = 6;
+
+/// Number of samples shown in the "Trend" column
+const TREND_WIDTH: usize = 10;
+
+/// Columns the components table can be sorted by
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum SortColumn {
+    #[default]
+    Id,
+    Kind,
+    Type,
+    EventsIn,
+    BytesIn,
+    EventsOut,
+    BytesOut,
+    Utilization,
+    BufferUtilization,
+    Errors,
+    DiscardedEvents,
+    #[cfg(feature = "allocation-tracing")]
+    MemoryUsed,
+}
+
+impl SortColumn {
+    const ALL: &[Self] = &[
+        Self::Id,
+        Self::Kind,
+        Self::Type,
+        Self::EventsIn,
+        Self::BytesIn,
+        Self::EventsOut,
+        Self::BytesOut,
+        Self::Utilization,
+        Self::BufferUtilization,
+        Self::Errors,
+        Self::DiscardedEvents,
+        #[cfg(feature = "allocation-tracing")]
+        Self::MemoryUsed,
+    ];
+
+    fn position(self) -> usize {
+        Self::ALL
+            .iter()
+            .position(|&column| column == self)
+            .expect("Sort column is missing. Please report.")
+    }
+
+    fn next(self) -> Self {
+        Self::ALL[(self.position() + 1) % Self::ALL.len()]
+    }
+
+    fn previous(self) -> Self {
+        Self::ALL[(self.position() + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
+    /// Index of the column in `HEADER`
+    const fn header_index(self) -> usize {
+        match self {
+            Self::Id => 0,
+            Self::Kind => 2,
+            Self::Type => 3,
+            Self::EventsIn => 4,
+            Self::BytesIn => 5,
+            Self::EventsOut => 6,
+            Self::BytesOut => 7,
+            Self::Utilization => 9,
+            Self::BufferUtilization => 10,
+            Self::Errors => 11,
+            Self::DiscardedEvents => 12,
+            #[cfg(feature = "allocation-tracing")]
+            Self::MemoryUsed => 13,
+        }
+    }
+
+    /// Compare two components by this column, in ascending order. Components that compare equal
+    /// are ordered by ID, so that rows don't jump around between renders.
+    fn compare(self, a: &ComponentRow, b: &ComponentRow) -> Ordering {
+        let ratio = |r: Option<f64>| r.unwrap_or(-1.0);
+        match self {
+            Self::Id => Ordering::Equal,
+            Self::Kind => a.kind.cmp(&b.kind),
+            Self::Type => a.component_type.cmp(&b.component_type),
+            Self::EventsIn => a.received_events_total.cmp(&b.received_events_total),
+            Self::BytesIn => a.received_bytes_total.cmp(&b.received_bytes_total),
+            Self::EventsOut => a.sent_events_total.cmp(&b.sent_events_total),
+            Self::BytesOut => a.sent_bytes_total.cmp(&b.sent_bytes_total),
+            Self::Utilization => ratio(a.utilization).total_cmp(&ratio(b.utilization)),
+            Self::BufferUtilization => {
+                ratio(a.buffer_utilization).total_cmp(&ratio(b.buffer_utilization))
+            }
+            Self::Errors => a.errors.cmp(&b.errors),
+            Self::DiscardedEvents => a.discarded_events.cmp(&b.discarded_events),
+            #[cfg(feature = "allocation-tracing")]
+            Self::MemoryUsed => a.allocated_bytes.cmp(&b.allocated_bytes),
+        }
+        .then_with(|| a.key.cmp(&b.key))
+    }
+}
+
+/// Interactive state of the dashboard, changed by key presses
+#[derive(Debug, Default)]
+struct View {
+    sort: SortColumn,
+    descending: bool,
+    /// Only components whose ID or kind match are shown
+    filter: Option<Regex>,
+    /// Filter being typed in, if any
+    filter_input: Option<String>,
+    filter_error: Option<String>,
+    /// Position of the selected component in the table
+    selected: usize,
+    /// Component shown in detail, instead of the components table
+    detail: Option<ComponentKey>,
+}
+
+impl View {
+    /// Returns the components to show in the table, filtered and sorted
+    fn rows<'s>(&self, state: &'s state::State) -> Vec<&'s ComponentRow> {
+        let mut rows = state
+            .components
+            .values()
+            .filter(|r| {
+                self.filter
+                    .as_ref()
+                    .is_none_or(|filter| filter.is_match(r.key.id()) || filter.is_match(&r.kind))
+            })
+            .collect::<Vec<_>>();
+
+        rows.sort_by(|a, b| {
+            let ordering = self.sort.compare(a, b);
+            if self.descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+        rows
+    }
+
+    /// Applies a key press, returning whether the dashboard should quit
+    fn handle_key(&mut self, key: KeyCode, state: &state::State) -> bool {
+        if let Some(input) = self.filter_input.as_mut() {
+            match key {
+                KeyCode::Char(c) => input.push(c),
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Esc => self.filter_input = None,
+                KeyCode::Enter => {
+                    let input = self.filter_input.take().unwrap_or_default();
+                    self.filter_error = None;
+                    if input.is_empty() {
+                        self.filter = None;
+                    } else {
+                        match Regex::new(&input) {
+                            Ok(filter) => self.filter = Some(filter),
+                            Err(error) => self.filter_error = Some(error.to_string()),
+                        }
+                    }
+                    self.selected = 0;
+                }
+                _ => {}
+            }
+            return false;
+        }
+
+        match key {
+            KeyCode::Char('q') => return true,
+            KeyCode::Esc => {
+                if self.detail.take().is_none() {
+                    return true;
+                }
+            }
+            KeyCode::Char('/') => {
+                self.filter_input = Some(
+                    self.filter
+                        .as_ref()
+                        .map(|filter| filter.as_str().to_string())
+                        .unwrap_or_default(),
+                );
+            }
+            KeyCode::Left | KeyCode::Char('<') => self.sort = self.sort.previous(),
+            KeyCode::Right | KeyCode::Char('>') => self.sort = self.sort.next(),
+            KeyCode::Char('r') => self.descending = !self.descending,
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => {
+                let len = self.rows(state).len();
+                self.selected = (self.selected + 1).min(len.saturating_sub(1));
+            }
+            KeyCode::Enter => {
+                self.detail = self.rows(state).get(self.selected).map(|r| r.key.clone());
+            }
+            _ => {}
+        }
+        false
+    }
+}
+
 struct Widgets<'a> {
     constraints: Vec<Constraint>,
     url_string: &'a str,
@@ -206,16 +448,32 @@ impl<'a> Widgets<'a> {
 
     /// Renders a components table, showing sources, transforms and sinks in tabular form, with
     /// statistics pulled from `ComponentsState`,
-    fn components_table(&self, f: &mut Frame, state: &state::State, area: Rect) {
-        // Header columns
+    fn components_table(&self, f: &mut Frame, state: &state::State, view: &View, area: Rect) {
+        // Header columns, marking the column components are sorted by
+        let sort_index = view.sort.header_index();
         let header = HEADER
             .iter()
-            .map(|s| Cell::from(*s).style(Style::default().add_modifier(Modifier::BOLD)))
+            .enumerate()
+            .map(|(i, s)| {
+                let s = match i {
+                    i if i == sort_index && view.descending => format!("{s} ▼"),
+                    i if i == sort_index => format!("{s} ▲"),
+                    _ => s.to_string(),
+                };
+                Cell::from(s).style(Style::default().add_modifier(Modifier::BOLD))
+            })
             .collect::<Vec<_>>();
 
         // Data columns
+        let rows = view.rows(state);
+        let selected = view.selected.min(rows.len().saturating_sub(1));
+        let mut selected_item = None;
         let mut items = Vec::new();
-        for (_, r) in state.components.iter() {
+        for (i, r) in rows.into_iter().enumerate() {
+            if i == selected {
+                selected_item = Some(items.len());
+            }
+
             let mut data = vec![
                 r.key.id().to_string(),
                 if !r.has_displayable_outputs() {
@@ -249,11 +507,11 @@ impl<'a> Widgets<'a> {
                     r.sent_bytes_throughput_sec,
                     self.human_metrics,
                 ),
-                if self.human_metrics {
-                    r.errors.human_format()
-                } else {
-                    r.errors.thousands_format()
-                },
+                sparkline(&r.history.sent_events, TREND_WIDTH),
+                format_ratio(r.utilization),
+                format_ratio(r.buffer_utilization),
+                format_count(r.errors, self.human_metrics),
+                format_count(r.discarded_events, self.human_metrics),
                 #[cfg(feature = "allocation-tracing")]
                 r.allocated_bytes.human_format_bytes(),
             ];
@@ -273,8 +531,8 @@ impl<'a> Widgets<'a> {
                         .into_iter()
                         .map(Cell::from)
                         .collect::<Vec<_>>();
-                    data[1] = Cell::from(id.as_str());
-                    data[5] = Cell::from(sent_events_metric);
+                    data[OUTPUT_COLUMN] = Cell::from(id.as_str());
+                    data[EVENTS_OUT_COLUMN] = Cell::from(sent_events_metric);
                     items.push(Row::new(data).style(Style::default()));
                 }
             }
@@ -282,35 +540,182 @@ impl<'a> Widgets<'a> {
 
         let widths: &[Constraint] = if is_allocation_tracing_enabled() {
             &[
-                Constraint::Percentage(13), // ID
-                Constraint::Percentage(8),  // Output
-                Constraint::Percentage(4),  // Kind
-                Constraint::Percentage(9),  // Type
-                Constraint::Percentage(10), // Events In
-                Constraint::Percentage(12), // Bytes In
-                Constraint::Percentage(10), // Events Out
-                Constraint::Percentage(12), // Bytes Out
-                Constraint::Percentage(8),  // Errors
-                Constraint::Percentage(14), // Allocated Bytes
+                Constraint::Percentage(10), // ID
+                Constraint::Percentage(6),  // Output
+                Constraint::Percentage(5),  // Kind
+                Constraint::Percentage(7),  // Type
+                Constraint::Percentage(9),  // Events In
+                Constraint::Percentage(9),  // Bytes In
+                Constraint::Percentage(9),  // Events Out
+                Constraint::Percentage(9),  // Bytes Out
+                Constraint::Percentage(8),  // Trend
+                Constraint::Percentage(5),  // Util
+                Constraint::Percentage(5),  // Buffer
+                Constraint::Percentage(5),  // Errors
+                Constraint::Percentage(5),  // Discarded
+                Constraint::Percentage(8),  // Allocated Bytes
             ]
         } else {
             &[
-                Constraint::Percentage(13), // ID
-                Constraint::Percentage(12), // Output
-                Constraint::Percentage(9),  // Kind
+                Constraint::Percentage(11), // ID
+                Constraint::Percentage(8),  // Output
+                Constraint::Percentage(6),  // Kind
                 Constraint::Percentage(6),  // Type
-                Constraint::Percentage(12), // Events In
-                Constraint::Percentage(14), // Bytes In
-                Constraint::Percentage(12), // Events Out
-                Constraint::Percentage(14), // Bytes Out
-                Constraint::Percentage(8),  // Errors
+                Constraint::Percentage(10), // Events In
+                Constraint::Percentage(10), // Bytes In
+                Constraint::Percentage(10), // Events Out
+                Constraint::Percentage(10), // Bytes Out
+                Constraint::Percentage(8),  // Trend
+                Constraint::Percentage(5),  // Util
+                Constraint::Percentage(5),  // Buffer
+                Constraint::Percentage(5),  // Errors
+                Constraint::Percentage(6),  // Discarded
             ]
         };
+
+        let mut title = String::from("Components");
+        if let Some(filter) = &view.filter {
+            title.push_str(&format!(" matching /{}/", filter.as_str()));
+        }
+
         let w = Table::new(items, widths)
             .header(Row::new(header).bottom_margin(1))
-            .block(Block::default().borders(Borders::ALL).title("Components"))
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED))
             .column_spacing(2);
-        f.render_widget(w, area);
+        let mut table_state = TableState::default().with_selected(selected_item);
+        f.render_stateful_widget(w, area, &mut table_state);
+    }
+
+    /// Renders a detailed view of a single component, with the history of its throughput and
+    /// metrics for each of its outputs.
+    fn component_detail(&self, f: &mut Frame, r: &ComponentRow, area: Rect) {
+        let block = Block::default().borders(Borders::ALL).title(Span::styled(
+            format!("{} ({} {})", r.key.id(), r.component_type, r.kind),
+            Style::default().add_modifier(Modifier::BOLD),
+        ));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let outputs_height = if r.has_displayable_outputs() {
+            r.outputs.len() as u16 + 4
+        } else {
+            0
+        };
+        let rects = Layout::default()
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Length(3),
+                Constraint::Min(8),
+                Constraint::Length(outputs_height),
+            ])
+            .split(inner);
+
+        let text = Line::from(format!(
+            "Errors: {} | Discarded: {}",
+            format_count(r.errors, self.human_metrics),
+            format_count(r.discarded_events, self.human_metrics),
+        ));
+        f.render_widget(Paragraph::new(text), rects[0]);
+
+        // Gauges
+        let gauges = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(rects[1]);
+        for (i, (title, ratio)) in [
+            ("Utilization", r.utilization),
+            ("Buffer", r.buffer_utilization),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let w = Gauge::default()
+                .block(Block::default().borders(Borders::ALL).title(title))
+                .gauge_style(Style::default().fg(Color::Green))
+                .ratio(ratio.unwrap_or_default().clamp(0.0, 1.0))
+                .label(format_ratio(ratio));
+            f.render_widget(w, gauges[i]);
+        }
+
+        // Sparklines
+        let rows = Layout::default()
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(rects[2]);
+        let sparklines = [
+            (
+                "Events In",
+                format!("{}/s", r.received_events_throughput_sec.human_format()),
+                &r.history.received_events,
+            ),
+            (
+                "Events Out",
+                format!("{}/s", r.sent_events_throughput_sec.human_format()),
+                &r.history.sent_events,
+            ),
+            (
+                "Bytes In",
+                format!("{}/s", r.received_bytes_throughput_sec.human_format_bytes()),
+                &r.history.received_bytes,
+            ),
+            (
+                "Bytes Out",
+                format!("{}/s", r.sent_bytes_throughput_sec.human_format_bytes()),
+                &r.history.sent_bytes,
+            ),
+        ];
+        for (i, (title, current, series)) in sparklines.into_iter().enumerate() {
+            let cells = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(rows[i / 2]);
+            let area = cells[i % 2];
+
+            // Only show as many samples as fit, without the borders
+            let width = area.width.saturating_sub(2) as usize;
+            let data = series
+                .iter()
+                .skip(series.len().saturating_sub(width))
+                .copied()
+                .collect::<Vec<_>>();
+            let w = Sparkline::default()
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(format!("{title} ({current})")),
+                )
+                .style(Style::default().fg(Color::Cyan))
+                .data(&data);
+            f.render_widget(w, area);
+        }
+
+        // Outputs
+        if r.has_displayable_outputs() {
+            let header = ["Output", "Events Out"]
+                .iter()
+                .map(|s| Cell::from(*s).style(Style::default().add_modifier(Modifier::BOLD)))
+                .collect::<Vec<_>>();
+            let mut outputs = r.outputs.iter().collect::<Vec<_>>();
+            outputs.sort_by(|a, b| a.0.cmp(b.0));
+            let items = outputs.into_iter().map(|(id, output)| {
+                Row::new([
+                    id.clone(),
+                    format_metric(
+                        output.sent_events_total,
+                        output.sent_events_throughput_sec,
+                        self.human_metrics,
+                    ),
+                ])
+            });
+            let w = Table::new(
+                items,
+                [Constraint::Percentage(50), Constraint::Percentage(50)],
+            )
+            .header(Row::new(header).bottom_margin(1))
+            .block(Block::default().borders(Borders::ALL).title("Outputs"))
+            .column_spacing(2);
+            f.render_widget(w, rects[3]);
+        }
     }
 
     /// Alerts the user to resize the window to view columns
@@ -323,9 +728,26 @@ impl<'a> Widgets<'a> {
         f.render_widget(w, area);
     }
 
-    /// Renders a box showing instructions on how to exit from `vector top`.
-    fn quit_box(&self, f: &mut Frame, area: Rect) {
-        let text = vec![Line::from("To quit, press ESC or 'q'")];
+    /// Renders a box showing instructions on how to interact with, and exit from `vector top`.
+    fn quit_box(&self, f: &mut Frame, view: &View, area: Rect) {
+        let text = if let Some(input) = &view.filter_input {
+            vec![Line::from(vec![
+                Span::from("Filter (regex on ID or kind): /"),
+                Span::styled(input.as_str(), Style::default().fg(Color::White)),
+                Span::from("/ | Apply: Enter | Cancel: ESC"),
+            ])]
+        } else if let Some(error) = &view.filter_error {
+            vec![Line::from(Span::styled(
+                format!("Invalid filter: {error}"),
+                Style::default().fg(Color::Red),
+            ))]
+        } else if view.detail.is_some() {
+            vec![Line::from("Back: ESC | Quit: 'q'")]
+        } else {
+            vec![Line::from(
+                "Quit: ESC or 'q' | Sort: ←/→, reverse: 'r' | Filter: '/' | Select: ↑/↓, details: Enter",
+            )]
+        };
 
         let block = Block::default()
             .borders(Borders::ALL)
@@ -339,7 +761,7 @@ impl<'a> Widgets<'a> {
     }
 
     /// Draw a single frame. Creates a layout and renders widgets into it.
-    fn draw(&self, f: &mut Frame, state: state::State) {
+    fn draw(&self, f: &mut Frame, state: &state::State, view: &View) {
         let size = f.area();
         let rects = Layout::default()
             .constraints(self.constraints.clone())
@@ -347,14 +769,21 @@ impl<'a> Widgets<'a> {
 
         self.title(f, rects[0], &state.connection_status, state.uptime);
 
+        let detail = view
+            .detail
+            .as_ref()
+            .and_then(|key| state.components.get(key));
+
         // Require a minimum of 80 chars of line width to display the table
-        if size.width >= 80 {
-            self.components_table(f, &state, rects[1]);
-        } else {
+        if size.width < 80 {
             self.components_resize_window(f, rects[1]);
+        } else if let Some(r) = detail {
+            self.component_detail(f, r, rects[1]);
+        } else {
+            self.components_table(f, state, view, rects[1]);
         }
 
-        self.quit_box(f, rects[2]);
+        self.quit_box(f, view, rects[2]);
     }
 }
 
@@ -395,14 +824,20 @@ pub async fn init_dashboard<'a>(
     terminal.clear()?;
 
     let widgets = Widgets::new(title, url, interval, human_metrics);
+    let mut view = View::default();
+    let mut state = None;
 
     loop {
         tokio::select! {
-            Some(state) = state_rx.recv() => {
-                terminal.draw(|f| widgets.draw(f, state))?;
+            Some(new_state) = state_rx.recv() => {
+                state = Some(new_state);
             },
             k = key_press_rx.recv() => {
-                if let KeyCode::Esc | KeyCode::Char('q') = k.unwrap() {
+                let quit = match (k.unwrap(), &state) {
+                    (k, Some(state)) => view.handle_key(k, state),
+                    (k, None) => matches!(k, KeyCode::Esc | KeyCode::Char('q')),
+                };
+                if quit {
                     _ = key_press_kill_tx.send(());
                     break
                 }
@@ -412,6 +847,11 @@ pub async fn init_dashboard<'a>(
                 break
             }
         }
+
+        // Redraw on both state changes and key presses, so that the view reacts immediately
+        if let Some(state) = &state {
+            terminal.draw(|f| widgets.draw(f, state, &view))?;
+        }
     }
 
     // Clean-up terminal
@@ -509,6 +949,104 @@ mod tests {
         assert_eq!(N.human_format(), "1.10 T");
     }
 
+    fn component(id: &str, kind: &str, errors: i64) -> ComponentRow {
+        ComponentRow {
+            key: ComponentKey::from(id),
+            kind: kind.to_string(),
+            component_type: "demo".to_string(),
+            outputs: Default::default(),
+            received_bytes_total: 0,
+            received_bytes_throughput_sec: 0,
+            received_events_total: 0,
+            received_events_throughput_sec: 0,
+            sent_bytes_total: 0,
+            sent_bytes_throughput_sec: 0,
+            sent_events_total: 0,
+            sent_events_throughput_sec: 0,
+            #[cfg(feature = "allocation-tracing")]
+            allocated_bytes: 0,
+            errors,
+            discarded_events: 0,
+            utilization: None,
+            buffer_utilization: None,
+            history: Default::default(),
+        }
+    }
+
+    fn test_state() -> state::State {
+        state::State::new(
+            [
+                component("in", "source", 1),
+                component("parse", "transform", 5),
+                component("out", "sink", 3),
+            ]
+            .into_iter()
+            .map(|r| (r.key.clone(), r))
+            .collect(),
+        )
+    }
+
+    fn row_ids(view: &View, state: &state::State) -> Vec<String> {
+        view.rows(state)
+            .into_iter()
+            .map(|r| r.key.id().to_string())
+            .collect()
+    }
+
+    #[test]
+    /// Sparklines should be scaled to the largest of the last samples
+    fn format_sparkline() {
+        assert_eq!(sparkline(&VecDeque::new(), 5), "--");
+        assert_eq!(sparkline(&VecDeque::from([0, 0]), 5), "▁▁");
+        assert_eq!(sparkline(&VecDeque::from([100, 0, 7, 14]), 3), "▁▅█");
+    }
+
+    #[test]
+    /// Components should be sorted by the selected column, in either order
+    fn sort_components() {
+        let state = test_state();
+        let mut view = View::default();
+        assert_eq!(row_ids(&view, &state), ["in", "out", "parse"]);
+
+        view.sort = SortColumn::Errors;
+        assert_eq!(row_ids(&view, &state), ["in", "out", "parse"]);
+
+        view.handle_key(KeyCode::Char('r'), &state);
+        assert_eq!(row_ids(&view, &state), ["parse", "out", "in"]);
+    }
+
+    #[test]
+    /// Components should be filtered by ID or kind, and invalid filters reported
+    fn filter_components() {
+        let state = test_state();
+        let mut view = View::default();
+
+        view.handle_key(KeyCode::Char('/'), &state);
+        for c in "^s".chars() {
+            view.handle_key(KeyCode::Char(c), &state);
+        }
+        view.handle_key(KeyCode::Enter, &state);
+        assert_eq!(row_ids(&view, &state), ["in", "out"]);
+
+        for key in [KeyCode::Char('/'), KeyCode::Char('('), KeyCode::Enter] {
+            view.handle_key(key, &state);
+        }
+        assert!(view.filter_error.is_some());
+    }
+
+    #[test]
+    /// Esc should leave the detail view before quitting
+    fn quit_from_detail() {
+        let state = test_state();
+        let mut view = View::default();
+
+        assert!(!view.handle_key(KeyCode::Down, &state));
+        assert!(!view.handle_key(KeyCode::Enter, &state));
+        assert_eq!(view.detail, Some(ComponentKey::from("out")));
+        assert!(!view.handle_key(KeyCode::Esc, &state));
+        assert!(view.handle_key(KeyCode::Esc, &state));
+    }
+
     #[test]
     /// Should format bytes
     fn format_bytes() {
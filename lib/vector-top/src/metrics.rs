@@ -59,6 +59,10 @@ async fn component_added(
                     #[cfg(feature = "allocation-tracing")]
                     allocated_bytes: 0,
                     errors: 0,
+                    discarded_events: 0,
+                    utilization: None,
+                    buffer_utilization: None,
+                    history: state::History::default(),
                 }))
                 .await;
         }
@@ -397,6 +401,92 @@ async fn errors_totals(
     }
 }
 
+async fn discarded_events_totals(
+    client: Arc<SubscriptionClient>,
+    tx: state::EventTx,
+    interval: i64,
+    components_patterns: Arc<Vec<Pattern>>,
+) {
+    tokio::pin! {
+        let stream = client.component_discarded_events_totals_subscription(interval);
+    };
+
+    while let Some(Some(res)) = stream.next().await {
+        if let Some(d) = res.data {
+            let c = d.component_discarded_events_totals;
+            _ = tx
+                .send(state::EventType::DiscardedEventsTotals(
+                    c.into_iter()
+                        .filter(|c| {
+                            component_matches_patterns(&c.component_id, &components_patterns)
+                        })
+                        .map(|c| {
+                            (
+                                ComponentKey::from(c.component_id.as_str()),
+                                c.metric.discarded_events_total as i64,
+                            )
+                        })
+                        .collect(),
+                ))
+                .await;
+        }
+    }
+}
+
+async fn utilizations(
+    client: Arc<SubscriptionClient>,
+    tx: state::EventTx,
+    interval: i64,
+    components_patterns: Arc<Vec<Pattern>>,
+) {
+    tokio::pin! {
+        let stream = client.component_utilizations_subscription(interval);
+    };
+
+    while let Some(Some(res)) = stream.next().await {
+        if let Some(d) = res.data {
+            let c = d.component_utilizations;
+            _ = tx
+                .send(state::EventType::Utilizations(
+                    c.into_iter()
+                        .filter(|c| {
+                            component_matches_patterns(&c.component_id, &components_patterns)
+                        })
+                        .map(|c| (ComponentKey::from(c.component_id), c.utilization))
+                        .collect(),
+                ))
+                .await;
+        }
+    }
+}
+
+async fn buffer_utilizations(
+    client: Arc<SubscriptionClient>,
+    tx: state::EventTx,
+    interval: i64,
+    components_patterns: Arc<Vec<Pattern>>,
+) {
+    tokio::pin! {
+        let stream = client.component_buffer_utilizations_subscription(interval);
+    };
+
+    while let Some(Some(res)) = stream.next().await {
+        if let Some(d) = res.data {
+            let c = d.component_buffer_utilizations;
+            _ = tx
+                .send(state::EventType::BufferUtilizations(
+                    c.into_iter()
+                        .filter(|c| {
+                            component_matches_patterns(&c.component_id, &components_patterns)
+                        })
+                        .map(|c| (ComponentKey::from(c.component_id), c.utilization))
+                        .collect(),
+                ))
+                .await;
+        }
+    }
+}
+
 async fn uptime_changed(client: Arc<SubscriptionClient>, tx: state::EventTx) {
     tokio::pin! {
         let stream = client.uptime_subscription();
@@ -493,6 +583,24 @@ pub fn subscribe(
             interval,
             Arc::clone(&components_patterns),
         )),
+        tokio::spawn(discarded_events_totals(
+            Arc::clone(&client),
+            tx.clone(),
+            interval,
+            Arc::clone(&components_patterns),
+        )),
+        tokio::spawn(utilizations(
+            Arc::clone(&client),
+            tx.clone(),
+            interval,
+            Arc::clone(&components_patterns),
+        )),
+        tokio::spawn(buffer_utilizations(
+            Arc::clone(&client),
+            tx.clone(),
+            interval,
+            Arc::clone(&components_patterns),
+        )),
         tokio::spawn(uptime_changed(Arc::clone(&client), tx)),
     ]
 }
@@ -542,6 +650,10 @@ pub async fn init_components(
                     #[cfg(feature = "allocation-tracing")]
                     allocated_bytes: 0,
                     errors: 0,
+                    discarded_events: 0,
+                    utilization: None,
+                    buffer_utilization: None,
+                    history: state::History::default(),
                 },
             )
         })
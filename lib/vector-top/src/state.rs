@@ -1,5 +1,5 @@
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, HashMap, VecDeque},
     time::Duration,
 };
 
@@ -14,6 +14,7 @@ use vector_common::internal_event::DEFAULT_OUTPUT;
 use vector_common::config::ComponentKey;
 
 type IdentifiedMetric = (ComponentKey, i64);
+type IdentifiedRatio = (ComponentKey, f64);
 
 #[derive(Debug)]
 pub struct SentEventsMetric {
@@ -40,6 +41,9 @@ pub enum EventType {
     /// Interval in ms + identified overall metric + output-specific metrics
     SentEventsThroughputs(i64, Vec<SentEventsMetric>),
     ErrorsTotals(Vec<IdentifiedMetric>),
+    DiscardedEventsTotals(Vec<IdentifiedMetric>),
+    Utilizations(Vec<IdentifiedRatio>),
+    BufferUtilizations(Vec<IdentifiedRatio>),
     #[cfg(feature = "allocation-tracing")]
     AllocatedBytes(Vec<IdentifiedMetric>),
     ComponentAdded(ComponentRow),
@@ -112,6 +116,25 @@ impl From<i64> for OutputMetrics {
     }
 }
 
+/// Recent throughput samples of a component, oldest first, from which sparklines are drawn.
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    pub received_events: VecDeque<u64>,
+    pub received_bytes: VecDeque<u64>,
+    pub sent_events: VecDeque<u64>,
+    pub sent_bytes: VecDeque<u64>,
+}
+
+impl History {
+    /// Records a throughput sample, dropping the oldest samples beyond `len`.
+    fn push(series: &mut VecDeque<u64>, throughput: i64, len: usize) {
+        series.push_back(throughput.max(0) as u64);
+        while series.len() > len {
+            series.pop_front();
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ComponentRow {
     pub key: ComponentKey,
@@ -129,6 +152,10 @@ pub struct ComponentRow {
     #[cfg(feature = "allocation-tracing")]
     pub allocated_bytes: i64,
     pub errors: i64,
+    pub discarded_events: i64,
+    pub utilization: Option<f64>,
+    pub buffer_utilization: Option<f64>,
+    pub history: History,
 }
 
 impl ComponentRow {
@@ -143,14 +170,22 @@ impl ComponentRow {
 /// Takes the receiver `EventRx` channel, and returns a `StateRx` state receiver. This
 /// represents the single destination for handling subscriptions and returning 'immutable' state
 /// for re-rendering the dashboard. This approach uses channels vs. mutexes.
-pub async fn updater(mut event_rx: EventRx) -> StateRx {
+///
+/// Up to `history_len` throughput samples are kept for each component.
+pub async fn updater(mut event_rx: EventRx, history_len: usize) -> StateRx {
     let (tx, rx) = mpsc::channel(20);
 
     let mut state = State::new(BTreeMap::new());
     tokio::spawn(async move {
         while let Some(event_type) = event_rx.recv().await {
             match event_type {
-                EventType::InitializeState(new_state) => {
+                EventType::InitializeState(mut new_state) => {
+                    // Keep the history of components that are still running, such as on reconnect
+                    for (key, r) in new_state.components.iter_mut() {
+                        if let Some(previous) = state.components.remove(key) {
+                            r.history = previous.history;
+                        }
+                    }
                     state = new_state;
                 }
                 EventType::ReceivedBytesTotals(rows) => {
@@ -165,6 +200,11 @@ pub async fn updater(mut event_rx: EventRx) -> StateRx {
                         if let Some(r) = state.components.get_mut(&key) {
                             r.received_bytes_throughput_sec =
                                 (v as f64 * (1000.0 / interval as f64)) as i64;
+                            History::push(
+                                &mut r.history.received_bytes,
+                                r.received_bytes_throughput_sec,
+                                history_len,
+                            );
                         }
                     }
                 }
@@ -180,6 +220,11 @@ pub async fn updater(mut event_rx: EventRx) -> StateRx {
                         if let Some(r) = state.components.get_mut(&key) {
                             r.received_events_throughput_sec =
                                 (v as f64 * (1000.0 / interval as f64)) as i64;
+                            History::push(
+                                &mut r.history.received_events,
+                                r.received_events_throughput_sec,
+                                history_len,
+                            );
                         }
                     }
                 }
@@ -195,6 +240,11 @@ pub async fn updater(mut event_rx: EventRx) -> StateRx {
                         if let Some(r) = state.components.get_mut(&key) {
                             r.sent_bytes_throughput_sec =
                                 (v as f64 * (1000.0 / interval as f64)) as i64;
+                            History::push(
+                                &mut r.history.sent_bytes,
+                                r.sent_bytes_throughput_sec,
+                                history_len,
+                            );
                         }
                     }
                 }
@@ -216,6 +266,11 @@ pub async fn updater(mut event_rx: EventRx) -> StateRx {
                         if let Some(r) = state.components.get_mut(&m.key) {
                             r.sent_events_throughput_sec =
                                 (m.total as f64 * (1000.0 / interval as f64)) as i64;
+                            History::push(
+                                &mut r.history.sent_events,
+                                r.sent_events_throughput_sec,
+                                history_len,
+                            );
                             for (id, v) in m.outputs {
                                 let throughput = (v as f64 * (1000.0 / interval as f64)) as i64;
                                 r.outputs
@@ -233,6 +288,27 @@ pub async fn updater(mut event_rx: EventRx) -> StateRx {
                         }
                     }
                 }
+                EventType::DiscardedEventsTotals(rows) => {
+                    for (key, v) in rows {
+                        if let Some(r) = state.components.get_mut(&key) {
+                            r.discarded_events = v;
+                        }
+                    }
+                }
+                EventType::Utilizations(rows) => {
+                    for (key, v) in rows {
+                        if let Some(r) = state.components.get_mut(&key) {
+                            r.utilization = Some(v);
+                        }
+                    }
+                }
+                EventType::BufferUtilizations(rows) => {
+                    for (key, v) in rows {
+                        if let Some(r) = state.components.get_mut(&key) {
+                            r.buffer_utilization = Some(v);
+                        }
+                    }
+                }
                 #[cfg(feature = "allocation-tracing")]
                 EventType::AllocatedBytes(rows) => {
                     for (key, v) in rows {
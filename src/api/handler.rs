@@ -6,6 +6,8 @@ use std::sync::{
 use serde_json::json;
 use warp::{Rejection, Reply, reply::json};
 
+use crate::common::http::ErrorMessage;
+
 // Health handler, responds with '{ ok: true }' when running and '{ ok: false}'
 // when shutting down
 pub(super) async fn health(running: Arc<AtomicBool>) -> Result<impl Reply, Rejection> {
@@ -21,3 +23,12 @@ pub(super) async fn health(running: Arc<AtomicBool>) -> Result<impl Reply, Rejec
         ))
     }
 }
+
+// Rejection handler, responds with the status and message of rejections raised by Vector, such as
+// failed authentication, and leaves all others to Warp
+pub(super) async fn handle_rejection(rejection: Rejection) -> Result<impl Reply, Rejection> {
+    match rejection.find::<ErrorMessage>() {
+        Some(error) => Ok(warp::reply::with_status(json(error), error.status_code())),
+        None => Err(rejection),
+    }
+}
@@ -0,0 +1,77 @@
+use async_graphql::{Context, Object};
+
+use crate::{
+    config::ComponentKey,
+    topology::{ControlCommand, ControlRequest, ControlTx},
+};
+
+/// Sends the given command to the running topology, returning `true` once it has been carried out.
+async fn send(ctx: &Context<'_>, command: ControlCommand) -> async_graphql::Result<bool> {
+    // The control channel is only made available to the schema if the API requires authentication.
+    let tx = ctx
+        .data_opt::<ControlTx>()
+        .ok_or("Mutations are only available when authentication is configured for the API.")?;
+
+    ControlRequest::send(tx, command).await?;
+    Ok(true)
+}
+
+#[derive(Default)]
+pub struct ControlMutation;
+
+#[Object]
+impl ControlMutation {
+    /// Reloads the configuration from disk, as on `SIGHUP`. Returns once the reload is requested,
+    /// and, as with `SIGHUP`, the previous configuration keeps running if the new one can't be
+    /// loaded or applied.
+    async fn reload_config(&self, ctx: &Context<'_>) -> async_graphql::Result<bool> {
+        send(ctx, ControlCommand::ReloadConfig).await
+    }
+
+    /// Reloads all enrichment tables
+    async fn reload_enrichment_tables(&self, ctx: &Context<'_>) -> async_graphql::Result<bool> {
+        send(ctx, ControlCommand::ReloadEnrichmentTables).await
+    }
+
+    /// Pauses a source. The events produced by a paused source are held back, applying
+    /// backpressure to it, until it's resumed or reloaded.
+    async fn pause_source(
+        &self,
+        ctx: &Context<'_>,
+        component_id: String,
+    ) -> async_graphql::Result<bool> {
+        send(
+            ctx,
+            ControlCommand::PauseSource(ComponentKey::from(component_id)),
+        )
+        .await
+    }
+
+    /// Resumes a paused source
+    async fn resume_source(
+        &self,
+        ctx: &Context<'_>,
+        component_id: String,
+    ) -> async_graphql::Result<bool> {
+        send(
+            ctx,
+            ControlCommand::ResumeSource(ComponentKey::from(component_id)),
+        )
+        .await
+    }
+
+    /// Runs the healthcheck of a sink, returning an error if it fails. The healthcheck is shared
+    /// with the one run when the sink was started, so its outcome is returned if it already ran,
+    /// and it's run otherwise, even if healthchecks are disabled for the sink.
+    async fn run_healthcheck(
+        &self,
+        ctx: &Context<'_>,
+        component_id: String,
+    ) -> async_graphql::Result<bool> {
+        send(
+            ctx,
+            ControlCommand::Healthcheck(ComponentKey::from(component_id)),
+        )
+        .await
+    }
+}
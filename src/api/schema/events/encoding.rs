@@ -6,4 +6,7 @@ pub enum EventEncodingType {
     Json,
     Yaml,
     Logfmt,
+    /// Vector's native protobuf encoding, as base64. Events are encoded losslessly, along with
+    /// their metadata.
+    Native,
 }
@@ -2,7 +2,10 @@ use std::borrow::Cow;
 
 use async_graphql::Object;
 use chrono::{DateTime, Utc};
-use vector_lib::{encode_logfmt, event, tap::topology::TapOutput};
+use vector_lib::{
+    encode_logfmt, event,
+    tap::{capture, topology::TapOutput},
+};
 use vrl::event_path;
 
 use super::EventEncodingType;
@@ -45,6 +48,11 @@ impl Log {
         self.output.component_kind
     }
 
+    /// Name of the component output associated with the log event, if not the default output
+    async fn output(&self) -> Option<&str> {
+        self.output.output_id.port.as_deref()
+    }
+
     /// Log message
     async fn message(&self) -> Option<String> {
         self.get_message().map(Into::into)
@@ -64,6 +72,7 @@ impl Log {
                 .expect("YAML serialization of log event failed. Please report."),
             EventEncodingType::Logfmt => encode_logfmt::encode_value(self.event.value())
                 .expect("logfmt serialization of log event failed. Please report."),
+            EventEncodingType::Native => capture::encode_event(self.event.clone().into()),
         }
     }
 
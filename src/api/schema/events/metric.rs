@@ -1,7 +1,10 @@
 use async_graphql::{Enum, Object};
 use chrono::{DateTime, Utc};
 use serde_json::Value;
-use vector_lib::{encode_logfmt, event, tap::topology::TapOutput};
+use vector_lib::{
+    encode_logfmt, event,
+    tap::{capture, topology::TapOutput},
+};
 
 use super::EventEncodingType;
 
@@ -70,6 +73,11 @@ impl Metric {
         self.output.component_kind
     }
 
+    /// Name of the component output associated with the metric event, if not the default output
+    async fn output(&self) -> Option<&str> {
+        self.output.output_id.port.as_deref()
+    }
+
     /// Metric timestamp
     async fn timestamp(&self) -> Option<&DateTime<Utc>> {
         self.event.data().timestamp()
@@ -134,6 +142,7 @@ impl Metric {
                     ),
                 }
             }
+            EventEncodingType::Native => capture::encode_event(self.event.clone().into()),
         }
     }
 }
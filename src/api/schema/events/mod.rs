@@ -2,6 +2,7 @@ pub mod encoding;
 pub mod log;
 pub mod metric;
 pub mod output;
+mod program;
 pub mod trace;
 
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -14,16 +15,22 @@ use rand::{Rng, SeedableRng, rngs::SmallRng};
 use tokio::{select, sync::mpsc, time};
 use tokio_stream::wrappers::ReceiverStream;
 use vector_lib::tap::{
-    controller::{TapController, TapPatterns},
+    controller::{TapController, TapPatterns, TapProcessor},
     topology::WatchRx,
 };
 
+use super::Authenticated;
+
 #[derive(Debug, Default)]
 pub struct EventsSubscription;
 
 #[Subscription]
 impl EventsSubscription {
     /// A stream of events emitted from matched component ID patterns
+    ///
+    /// Events can be filtered with a VRL boolean expression (`filter`), and reshaped with a VRL
+    /// program (`transform`), before they're sampled and sent. Both are only available when
+    /// authentication is configured for the API.
     pub async fn output_events_by_component_id_patterns<'a>(
         &'a self,
         ctx: &'a Context<'a>,
@@ -31,26 +38,44 @@ impl EventsSubscription {
         inputs_patterns: Option<Vec<String>>,
         #[graphql(default = 500)] interval: u32,
         #[graphql(default = 100, validator(minimum = 1, maximum = 10_000))] limit: u32,
-    ) -> impl Stream<Item = Vec<OutputEventsPayload>> + 'a {
+        filter: Option<String>,
+        transform: Option<String>,
+    ) -> async_graphql::Result<impl Stream<Item = Vec<OutputEventsPayload>> + 'a> {
         let watch_rx = ctx.data_unchecked::<WatchRx>().clone();
+        if (filter.is_some() || transform.is_some()) && ctx.data_opt::<Authenticated>().is_none() {
+            return Err(
+                "Filtering and transforming events is only available when authentication is \
+                 configured for the API."
+                    .into(),
+            );
+        }
+        let processor = program::build_processor(filter.as_deref(), transform.as_deref())?;
 
         let patterns = TapPatterns {
             for_outputs: outputs_patterns.into_iter().collect(),
             for_inputs: inputs_patterns.unwrap_or_default().into_iter().collect(),
         };
         // Client input is confined to `u32` to provide sensible bounds.
-        create_events_stream(watch_rx, patterns, interval as u64, limit as usize)
+        Ok(create_events_stream(
+            watch_rx,
+            patterns,
+            interval as u64,
+            limit as usize,
+            processor,
+        ))
     }
 }
 
 /// Creates an events stream based on component ids, and a provided interval. Will emit
 /// control messages that bubble up the application if the sink goes away. The stream contains
-/// all matching events; filtering should be done at the caller level.
+/// all matching events accepted by the processor, if any; further filtering should be done at the
+/// caller level.
 pub(crate) fn create_events_stream(
     watch_rx: WatchRx,
     patterns: TapPatterns,
     interval: u64,
     limit: usize,
+    processor: Option<TapProcessor>,
 ) -> impl Stream<Item = Vec<OutputEventsPayload>> {
     // Channel for receiving individual tap payloads. Since we can process at most `limit` per
     // interval, this is capped to the same value.
@@ -66,7 +91,8 @@ pub(crate) fn create_events_stream(
     tokio::spawn(async move {
         // Create a tap controller. When this drops out of scope, clean up will be performed on the
         // event handlers and topology observation that the tap controller provides.
-        let _tap_controller = TapController::new(watch_rx, tap_tx, patterns);
+        let _tap_controller =
+            TapController::new_with_processor(watch_rx, tap_tx, patterns, processor);
 
         // A tick interval to represent when to 'cut' the results back to the client.
         let mut interval = time::interval(time::Duration::from_millis(interval));
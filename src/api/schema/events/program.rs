@@ -0,0 +1,238 @@
+use std::sync::Arc;
+
+use vector_lib::{TimeZone, compile_vrl, tap::controller::TapProcessor};
+use vrl::{
+    compiler::{
+        CompilationResult, CompileConfig, Function, Program, TypeState,
+        runtime::{Runtime, RuntimeResult},
+    },
+    value::Value,
+};
+
+use crate::{
+    config::LogNamespace,
+    event::{Event, EventArray, EventContainer, TargetEvents, VrlTarget},
+    format_vrl_diagnostics,
+};
+
+/// VRL programs applied to tapped events server-side, so that only the relevant events, and
+/// fields, are sent to the client.
+struct TapPrograms {
+    /// A VRL boolean expression, events for which it doesn't resolve to `true` are dropped.
+    filter: Option<Program>,
+    /// A VRL program run against events that passed the filter.
+    transform: Option<Program>,
+}
+
+/// Builds a tap processor that filters events with the `filter` VRL condition, and then runs the
+/// `transform` VRL program against them. Returns `None` if neither is given.
+pub(crate) fn build_processor(
+    filter: Option<&str>,
+    transform: Option<&str>,
+) -> Result<Option<TapProcessor>, String> {
+    if filter.is_none() && transform.is_none() {
+        return Ok(None);
+    }
+
+    let filter = filter
+        .map(|source| {
+            let program = compile(source, true)?;
+            if !program.final_type_info().result.is_boolean() {
+                return Err("The filter must be a VRL boolean expression.".to_string());
+            }
+            Ok(program)
+        })
+        .transpose()?;
+    let transform = transform.map(|source| compile(source, false)).transpose()?;
+
+    let programs = TapPrograms { filter, transform };
+    Ok(Some(Arc::new(move |events| programs.process(events))))
+}
+
+/// VRL functions that read the environment, the filesystem or the network, or have other side
+/// effects. Tap programs are supplied by API clients, so these aren't available to them.
+const IMPURE_FUNCTIONS: &[&str] = &[
+    "dns_lookup",
+    "get_env_var",
+    "get_hostname",
+    "http_request",
+    "log",
+    "parse_groks",
+    "reverse_dns",
+    "validate_json_schema",
+];
+
+/// Returns the VRL functions available to tap programs.
+fn functions() -> Vec<Box<dyn Function>> {
+    // Only the VRL standard library is available: Vector's own functions access the secrets of
+    // events, and enrichment tables aren't available to tap.
+    vrl::stdlib::all()
+        .into_iter()
+        .filter(|function| !IMPURE_FUNCTIONS.contains(&function.identifier()))
+        .collect()
+}
+
+fn compile(source: &str, read_only: bool) -> Result<Program, String> {
+    let functions = functions();
+
+    let mut config = CompileConfig::default();
+    if read_only {
+        config.set_read_only();
+    }
+
+    let CompilationResult {
+        program,
+        warnings: _,
+        config: _,
+    } = compile_vrl(source, &functions, &TypeState::default(), config)
+        .map_err(|diagnostics| format_vrl_diagnostics(source, diagnostics))?;
+
+    Ok(program)
+}
+
+fn run(program: &Program, event: Event) -> (RuntimeResult, TargetEvents) {
+    let log_namespace = event
+        .maybe_as_log()
+        .map(|log| log.namespace())
+        .unwrap_or(LogNamespace::Legacy);
+    let mut target = VrlTarget::new(event, program.info(), false);
+
+    let result = Runtime::default().resolve(&mut target, program, &TimeZone::default());
+    (result, target.into_events(log_namespace))
+}
+
+impl TapPrograms {
+    fn process(&self, events: EventArray) -> Option<EventArray> {
+        let mut processed = Vec::with_capacity(events.len());
+        let to_array: fn(Vec<Event>) -> EventArray = match &events {
+            EventArray::Logs(_) => |events| {
+                EventArray::Logs(events.into_iter().filter_map(Event::try_into_log).collect())
+            },
+            EventArray::Metrics(_) => |events| {
+                EventArray::Metrics(
+                    events
+                        .into_iter()
+                        .filter_map(Event::try_into_metric)
+                        .collect(),
+                )
+            },
+            EventArray::Traces(_) => |events| {
+                EventArray::Traces(
+                    events
+                        .into_iter()
+                        .filter_map(Event::try_into_trace)
+                        .collect(),
+                )
+            },
+        };
+
+        for event in events.into_events() {
+            let event = match &self.filter {
+                Some(filter) => match run(filter, event) {
+                    (Ok(Value::Boolean(true)), TargetEvents::One(event)) => event,
+                    _ => continue,
+                },
+                None => event,
+            };
+
+            match &self.transform {
+                Some(transform) => match run(transform, event) {
+                    (Ok(_), TargetEvents::One(event)) => processed.push(event),
+                    (Ok(_), TargetEvents::Logs(events)) => processed.extend(events),
+                    (Ok(_), TargetEvents::Traces(events)) => processed.extend(events),
+                    (Err(error), _) => {
+                        debug!(message = "Tap transform failed, dropping event.", %error);
+                    }
+                },
+                None => processed.push(event),
+            }
+        }
+
+        (!processed.is_empty()).then(|| to_array(processed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::LogEvent;
+
+    fn logs(messages: &[&str]) -> EventArray {
+        EventArray::Logs(
+            messages
+                .iter()
+                .map(|&message| LogEvent::from(message))
+                .collect(),
+        )
+    }
+
+    fn messages(events: EventArray) -> Vec<String> {
+        events
+            .into_events()
+            .map(|event| {
+                event
+                    .as_log()
+                    .get("message")
+                    .unwrap()
+                    .to_string_lossy()
+                    .into_owned()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn no_programs() {
+        assert!(build_processor(None, None).unwrap().is_none());
+    }
+
+    #[test]
+    fn filters_events() {
+        let processor = build_processor(Some(r#".message != "drop""#), None)
+            .unwrap()
+            .unwrap();
+
+        let events = processor(logs(&["keep", "drop", "keep too"])).unwrap();
+        assert_eq!(messages(events), vec!["keep", "keep too"]);
+
+        assert!(processor(logs(&["drop"])).is_none());
+    }
+
+    #[test]
+    fn transforms_filtered_events() {
+        let processor = build_processor(
+            Some(r#".message != "drop""#),
+            Some(r#".message = upcase!(.message)"#),
+        )
+        .unwrap()
+        .unwrap();
+
+        let events = processor(logs(&["keep", "drop"])).unwrap();
+        assert_eq!(messages(events), vec!["KEEP"]);
+    }
+
+    #[test]
+    fn impure_functions_are_unavailable() {
+        for source in [
+            r#".message = get_env_var!("HOME")"#,
+            r#".message = get_hostname!()"#,
+            r#".message = dns_lookup!("localhost")"#,
+            r#".message = get_secret("datadog_api_key")"#,
+            r#"log("tapped")"#,
+        ] {
+            assert!(build_processor(None, Some(source)).is_err(), "{source}");
+        }
+
+        let names = functions()
+            .iter()
+            .map(|function| function.identifier())
+            .collect::<Vec<_>>();
+        assert!(names.contains(&"upcase"));
+        assert!(!names.iter().any(|name| IMPURE_FUNCTIONS.contains(name)));
+    }
+
+    #[test]
+    fn filter_must_be_boolean() {
+        assert!(build_processor(Some(".message"), None).is_err());
+        assert!(build_processor(Some(".message = 1"), None).is_err());
+    }
+}
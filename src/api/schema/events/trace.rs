@@ -1,5 +1,8 @@
 use async_graphql::Object;
-use vector_lib::{encode_logfmt, event, tap::topology::TapOutput};
+use vector_lib::{
+    encode_logfmt, event,
+    tap::{capture, topology::TapOutput},
+};
 use vrl::event_path;
 
 use super::EventEncodingType;
@@ -34,6 +37,11 @@ impl Trace {
         self.output.component_kind
     }
 
+    /// Name of the component output associated with the trace event, if not the default output
+    async fn output(&self) -> Option<&str> {
+        self.output.output_id.port.as_deref()
+    }
+
     /// Trace event as an encoded string format
     async fn string(&self, encoding: EventEncodingType) -> String {
         match encoding {
@@ -43,6 +51,7 @@ impl Trace {
                 .expect("YAML serialization of log event failed. Please report."),
             EventEncodingType::Logfmt => encode_logfmt::encode_map(self.event.as_map())
                 .expect("logfmt serialization of log event failed. Please report."),
+            EventEncodingType::Native => capture::encode_event(self.event.clone().into()),
         }
     }
 
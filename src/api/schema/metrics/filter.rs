@@ -225,6 +225,67 @@ pub fn component_gauge_metrics(
     })
 }
 
+/// Returns a stream of the value of a 'gauge' metric for each component, sampled at `interval`
+/// milliseconds and filtered by the provided `filter_fn`. Unlike `component_gauge_metrics`, values
+/// are returned on every iteration, since gauges such as `utilization` go down as well as up.
+pub fn component_gauge_values(
+    interval: i32,
+    filter_fn: &'static MetricFilterFn,
+) -> impl Stream<Item = Vec<(ComponentKey, f64)>> {
+    component_to_filtered_metrics(interval, filter_fn).map(|map| {
+        map.into_iter()
+            .filter_map(|(id, metrics)| match sum_metrics_owned(metrics)?.value() {
+                MetricValue::Gauge { value } => Some((ComponentKey::from(id), *value)),
+                _ => None,
+            })
+            .collect()
+    })
+}
+
+/// Returns a stream of the utilization of each component's buffer, as the fraction of its
+/// capacity in use, sampled at `interval` milliseconds. Buffers made of several stages report the
+/// utilization of their most utilized stage.
+pub fn component_buffer_utilizations(
+    interval: i32,
+) -> impl Stream<Item = Vec<(ComponentKey, f64)>> {
+    component_to_filtered_metrics(interval, &|m| m.name().starts_with("buffer_")).map(|map| {
+        map.into_iter()
+            .filter_map(|(id, metrics)| {
+                // Current and maximum sizes of each buffer stage, in events and in bytes.
+                let mut stages: BTreeMap<String, [f64; 4]> = BTreeMap::new();
+                for m in metrics {
+                    let index = match m.name() {
+                        "buffer_events" => 0,
+                        "buffer_max_event_size" => 1,
+                        "buffer_byte_size" => 2,
+                        "buffer_max_byte_size" => 3,
+                        _ => continue,
+                    };
+                    if let MetricValue::Gauge { value } = m.value() {
+                        stages
+                            .entry(m.tag_value("stage").unwrap_or_default())
+                            .or_default()[index] = *value;
+                    }
+                }
+
+                stages
+                    .into_values()
+                    .filter_map(|[events, max_events, bytes, max_bytes]| {
+                        if max_events > 0.0 {
+                            Some(events / max_events)
+                        } else if max_bytes > 0.0 {
+                            Some(bytes / max_bytes)
+                        } else {
+                            None
+                        }
+                    })
+                    .reduce(f64::max)
+                    .map(|utilization| (ComponentKey::from(id), utilization.clamp(0.0, 1.0)))
+            })
+            .collect()
+    })
+}
+
 /// Returns the throughput of a 'counter' metric, sampled over `interval` milliseconds
 /// and filtered by the provided `filter_fn`.
 pub fn counter_throughput(
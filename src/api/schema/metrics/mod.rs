@@ -1,4 +1,5 @@
 mod allocated_bytes;
+mod discarded_events;
 mod errors;
 pub mod filter;
 mod output;
@@ -10,6 +11,7 @@ mod sink;
 pub mod source;
 mod transform;
 mod uptime;
+mod utilization;
 
 #[cfg(feature = "sources-host_metrics")]
 mod host;
@@ -17,6 +19,7 @@ mod host;
 pub use allocated_bytes::{AllocatedBytes, ComponentAllocatedBytes};
 use async_graphql::{Interface, Subscription};
 use chrono::{DateTime, Utc};
+pub use discarded_events::{ComponentDiscardedEventsTotal, DiscardedEventsTotal};
 pub use errors::{ComponentErrorsTotal, ErrorsTotal};
 pub use filter::*;
 pub use output::*;
@@ -33,6 +36,7 @@ pub use source::{IntoSourceMetrics, SourceMetrics};
 use tokio_stream::{Stream, StreamExt};
 pub use transform::{IntoTransformMetrics, TransformMetrics};
 pub use uptime::Uptime;
+pub use utilization::{ComponentBufferUtilization, ComponentUtilization};
 
 use crate::config::ComponentKey;
 
@@ -271,6 +275,45 @@ impl MetricsSubscription {
             .map(|m| m.into_iter().map(ComponentErrorsTotal::new).collect())
     }
 
+    /// Component discarded events metrics over `interval`.
+    async fn component_discarded_events_totals(
+        &self,
+        #[graphql(default = 1000, validator(minimum = 10, maximum = 60_000))] interval: i32,
+    ) -> impl Stream<Item = Vec<ComponentDiscardedEventsTotal>> + use<> {
+        component_counter_metrics(interval, &|m| {
+            m.name() == "component_discarded_events_total"
+        })
+        .map(|m| {
+            m.into_iter()
+                .map(ComponentDiscardedEventsTotal::new)
+                .collect()
+        })
+    }
+
+    /// Component utilization, sampled every `interval`.
+    async fn component_utilizations(
+        &self,
+        #[graphql(default = 1000, validator(minimum = 10, maximum = 60_000))] interval: i32,
+    ) -> impl Stream<Item = Vec<ComponentUtilization>> + use<> {
+        component_gauge_values(interval, &|m| m.name() == "utilization").map(|m| {
+            m.into_iter()
+                .map(|(key, utilization)| ComponentUtilization::new(key, utilization))
+                .collect()
+        })
+    }
+
+    /// Component buffer utilization, sampled every `interval`.
+    async fn component_buffer_utilizations(
+        &self,
+        #[graphql(default = 1000, validator(minimum = 10, maximum = 60_000))] interval: i32,
+    ) -> impl Stream<Item = Vec<ComponentBufferUtilization>> + use<> {
+        component_buffer_utilizations(interval).map(|m| {
+            m.into_iter()
+                .map(|(key, utilization)| ComponentBufferUtilization::new(key, utilization))
+                .collect()
+        })
+    }
+
     /// All metrics.
     async fn metrics(
         &self,
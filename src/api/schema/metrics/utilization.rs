@@ -0,0 +1,59 @@
+use async_graphql::Object;
+
+use crate::config::ComponentKey;
+
+pub struct ComponentUtilization {
+    component_key: ComponentKey,
+    utilization: f64,
+}
+
+impl ComponentUtilization {
+    /// Returns a new `ComponentUtilization`, set to the provided key and utilization values
+    pub const fn new(component_key: ComponentKey, utilization: f64) -> Self {
+        Self {
+            component_key,
+            utilization,
+        }
+    }
+}
+
+#[Object]
+impl ComponentUtilization {
+    /// Component id
+    async fn component_id(&self) -> &str {
+        self.component_key.id()
+    }
+
+    /// Fraction of time the component spent busy, rather than waiting for input, between 0 and 1
+    async fn utilization(&self) -> f64 {
+        self.utilization
+    }
+}
+
+pub struct ComponentBufferUtilization {
+    component_key: ComponentKey,
+    utilization: f64,
+}
+
+impl ComponentBufferUtilization {
+    /// Returns a new `ComponentBufferUtilization`, set to the provided key and utilization values
+    pub const fn new(component_key: ComponentKey, utilization: f64) -> Self {
+        Self {
+            component_key,
+            utilization,
+        }
+    }
+}
+
+#[Object]
+impl ComponentBufferUtilization {
+    /// Component id
+    async fn component_id(&self) -> &str {
+        self.component_key.id()
+    }
+
+    /// Fraction of the capacity of the component's buffer in use, between 0 and 1
+    async fn utilization(&self) -> f64 {
+        self.utilization
+    }
+}
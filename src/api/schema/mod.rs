@@ -1,4 +1,5 @@
 pub mod components;
+mod control;
 pub mod events;
 pub mod filter;
 mod health;
@@ -7,7 +8,7 @@ mod metrics;
 mod relay;
 pub mod sort;
 
-use async_graphql::{EmptyMutation, MergedObject, MergedSubscription, Schema, SchemaBuilder};
+use async_graphql::{MergedObject, MergedSubscription, Schema, SchemaBuilder};
 
 #[derive(MergedObject, Default)]
 pub struct Query(
@@ -17,6 +18,9 @@ pub struct Query(
     meta::MetaQuery,
 );
 
+#[derive(MergedObject, Default)]
+pub struct Mutation(control::ControlMutation);
+
 #[derive(MergedSubscription, Default)]
 pub struct Subscription(
     health::HealthSubscription,
@@ -25,7 +29,12 @@ pub struct Subscription(
     events::EventsSubscription,
 );
 
+/// Marks the requests to the schema as authenticated. Only available if the API requires
+/// authentication, in which case unauthenticated requests are rejected before reaching the schema.
+#[derive(Clone, Copy, Debug)]
+pub struct Authenticated;
+
 /// Build a new GraphQL schema, comprised of Query, Mutation and Subscription types
-pub fn build_schema() -> SchemaBuilder<Query, EmptyMutation, Subscription> {
-    Schema::build(Query::default(), EmptyMutation, Subscription::default())
+pub fn build_schema() -> SchemaBuilder<Query, Mutation, Subscription> {
+    Schema::build(Query::default(), Mutation::default(), Subscription::default())
 }
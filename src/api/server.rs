@@ -9,18 +9,28 @@ use async_graphql::{
     http::{GraphQLPlaygroundConfig, WebSocketProtocols, playground_source},
 };
 use async_graphql_warp::{GraphQLResponse, GraphQLWebSocket, graphql_protocol};
-use hyper::{Server as HyperServer, server::conn::AddrIncoming, service::make_service_fn};
-use tokio::{runtime::Handle, sync::oneshot};
+use hyper::{Server as HyperServer, service::make_service_fn};
+use tokio::{net::TcpStream, runtime::Handle, sync::oneshot};
 use tower::ServiceBuilder;
 use tracing::Span;
-use vector_lib::tap::topology;
-use warp::{Filter, Reply, filters::BoxedFilter, http::Response, ws::Ws};
+use vector_lib::{
+    tap::topology,
+    tls::{MaybeTlsIncomingStream, MaybeTlsSettings},
+};
+use warp::{
+    Filter, Rejection, Reply,
+    filters::{BoxedFilter, path::FullPath},
+    http::{HeaderMap, Response},
+    ws::Ws,
+};
 
 use super::{handler, schema};
 use crate::{
+    common::http::server_auth::HttpServerAuthMatcher,
     config::{self, api},
     http::build_http_trace_layer,
     internal_events::{SocketBindError, SocketMode},
+    topology::ControlTx,
 };
 
 pub struct Server {
@@ -35,32 +45,55 @@ impl Server {
         config: &config::Config,
         watch_rx: topology::WatchRx,
         running: Arc<AtomicBool>,
+        control_tx: ControlTx,
         handle: &Handle,
     ) -> crate::Result<Self> {
-        let routes = make_routes(config.api, watch_rx, running);
+        let tls = MaybeTlsSettings::from_config(config.api.tls.as_ref(), true)?;
+        let auth_matcher = config
+            .api
+            .auth
+            .as_ref()
+            .map(|auth| auth.build(&Default::default()))
+            .transpose()?;
+        // Mutations change the running topology, so they're only allowed for authenticated
+        // requests.
+        let control_tx = auth_matcher.is_some().then_some(control_tx);
+        let routes = make_routes(&config.api, watch_rx, running, control_tx, auth_matcher);
 
         let (_shutdown, rx) = oneshot::channel();
         // warp uses `tokio::spawn` and so needs us to enter the runtime context.
         let _guard = handle.enter();
 
+        // The listener is bound synchronously, so that bind errors are reported to the caller.
         let addr = config.api.address.expect("No socket address");
-        let incoming = AddrIncoming::bind(&addr).inspect_err(|error| {
-            emit!(SocketBindError {
-                mode: SocketMode::Tcp,
-                error,
-            });
-        })?;
+        let listener = std::net::TcpListener::bind(addr)
+            .and_then(|listener| {
+                listener.set_nonblocking(true)?;
+                tokio::net::TcpListener::from_std(listener)
+            })
+            .inspect_err(|error| {
+                emit!(SocketBindError {
+                    mode: SocketMode::Tcp,
+                    error,
+                });
+            })?;
+        let listener = tls.wrap_listener(listener)?;
 
         let span = Span::current();
-        let make_svc = make_service_fn(move |_conn| {
+        let make_svc = make_service_fn(move |conn: &MaybeTlsIncomingStream<TcpStream>| {
+            let remote_addr = conn.peer_addr();
             let svc = ServiceBuilder::new()
                 .layer(build_http_trace_layer(span.clone()))
+                .map_request(move |mut request: hyper::Request<_>| {
+                    request.extensions_mut().insert(PeerAddr(remote_addr));
+                    request
+                })
                 .service(warp::service(routes.clone()));
             futures_util::future::ok::<_, Infallible>(svc)
         });
 
         let server = async move {
-            HyperServer::builder(incoming)
+            HyperServer::builder(hyper::server::accept::from_stream(listener.accept_stream()))
                 .serve(make_svc)
                 .with_graceful_shutdown(async {
                     rx.await.ok();
@@ -94,9 +127,11 @@ impl Server {
 }
 
 fn make_routes(
-    api: api::Options,
+    api: &api::Options,
     watch_tx: topology::WatchRx,
     running: Arc<AtomicBool>,
+    control_tx: Option<ControlTx>,
+    auth_matcher: Option<HttpServerAuthMatcher>,
 ) -> BoxedFilter<(impl Reply,)> {
     // Routes...
 
@@ -113,16 +148,25 @@ fn make_routes(
     // parses the required headers for GraphQL and builds per-connection context based on the
     // provided `WatchTx` channel sender. This allows GraphQL resolvers to subscribe to
     // topology changes.
+    let ws_control_tx = control_tx.clone();
+    let authenticated = auth_matcher.is_some();
     let graphql_subscription_handler =
         warp::ws()
             .and(graphql_protocol())
             .map(move |ws: Ws, protocol: WebSocketProtocols| {
                 let schema = schema::build_schema().finish();
                 let watch_tx = watch_tx.clone();
+                let control_tx = ws_control_tx.clone();
 
                 let reply = ws.on_upgrade(move |socket| {
                     let mut data = Data::default();
                     data.insert(watch_tx);
+                    if authenticated {
+                        data.insert(schema::Authenticated);
+                    }
+                    if let Some(control_tx) = control_tx {
+                        data.insert(control_tx);
+                    }
 
                     GraphQLWebSocket::new(socket, schema, protocol)
                         .with_data(data)
@@ -139,10 +183,16 @@ fn make_routes(
     // Handle GraphQL queries. Headers will first be parsed to determine whether the query is
     // a subscription and if so, an attempt will be made to upgrade the connection to WebSockets.
     // All other queries will fall back to the default HTTP handler.
+    // The control channel sender allows GraphQL mutations to change the running topology.
+    let graphql_schema = match control_tx {
+        Some(control_tx) => schema::build_schema().data(control_tx).finish(),
+        None => schema::build_schema().finish(),
+    };
     let graphql_handler = if api.graphql {
         warp::path("graphql")
+            .and(with_auth(auth_matcher.clone()))
             .and(graphql_subscription_handler.or(
-                async_graphql_warp::graphql(schema::build_schema().finish()).and_then(
+                async_graphql_warp::graphql(graphql_schema).and_then(
                     |(schema, request): (Schema<_, _, _>, Request)| async move {
                         Ok::<_, Infallible>(GraphQLResponse::from(schema.execute(request).await))
                     },
@@ -156,6 +206,7 @@ fn make_routes(
     // Provide a playground for executing GraphQL queries/mutations/subscriptions.
     let graphql_playground = if api.playground && api.graphql {
         warp::path("playground")
+            .and(with_auth(auth_matcher))
             .map(move || {
                 Response::builder()
                     .header("content-type", "text/html")
@@ -174,6 +225,7 @@ fn make_routes(
         .or(graphql_handler)
         .or(graphql_playground)
         .or(not_found)
+        .recover(handler::handle_rejection)
         .with(
             warp::cors()
                 .allow_any_origin()
@@ -186,6 +238,7 @@ fn make_routes(
                     "Access-Control-Allow-Origin",
                     "Access-Control-Request-Headers",
                     "Content-Type",
+                    "Authorization",
                     "X-Apollo-Tracing", // for Apollo GraphQL clients
                     "Pragma",
                     "Host",
@@ -197,8 +250,217 @@ fn make_routes(
         .boxed()
 }
 
+// Remote address of the connection a request was received on.
+#[derive(Clone, Copy)]
+struct PeerAddr(SocketAddr);
+
+// Requires requests to be authenticated, if authentication is configured.
+fn with_auth(
+    auth_matcher: Option<HttpServerAuthMatcher>,
+) -> impl Filter<Extract = (), Error = Rejection> + Clone {
+    let auth_matcher = auth_matcher.map(Arc::new);
+    warp::header::headers_cloned()
+        .and(warp::path::full())
+        .and(warp::filters::ext::optional::<PeerAddr>())
+        .and_then(
+            move |headers: HeaderMap, path: FullPath, addr: Option<PeerAddr>| {
+                let auth_matcher = auth_matcher.clone();
+                async move {
+                    match auth_matcher {
+                        Some(auth_matcher) => auth_matcher
+                            .handle_auth(addr.map(|addr| addr.0).as_ref(), &headers, path.as_str())
+                            .map_err(warp::reject::custom),
+                        None => Ok(()),
+                    }
+                }
+            },
+        )
+        .untuple_one()
+}
+
 fn with_shared(
     shared: Arc<AtomicBool>,
 ) -> impl Filter<Extract = (Arc<AtomicBool>,), Error = Infallible> + Clone {
     warp::any().map(move || Arc::<AtomicBool>::clone(&shared))
 }
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::watch;
+    use vector_lib::tap::topology::TapResource;
+    use warp::{http::StatusCode, test::request};
+
+    use super::*;
+    use crate::common::http::server_auth::HttpServerAuthConfig;
+
+    fn auth_config() -> HttpServerAuthConfig {
+        HttpServerAuthConfig::Basic {
+            username: "vector".to_string(),
+            password: "secret".to_string().into(),
+        }
+    }
+
+    fn basic_auth(username: &str, password: &str) -> String {
+        use base64::prelude::{BASE64_STANDARD, Engine as _};
+
+        format!(
+            "Basic {}",
+            BASE64_STANDARD.encode(format!("{username}:{password}"))
+        )
+    }
+
+    fn routes() -> BoxedFilter<(impl Reply,)> {
+        let (_watch_tx, watch_rx) = watch::channel(TapResource::default());
+        let auth_matcher = auth_config().build(&Default::default()).unwrap();
+        make_routes(
+            &api::Options::default(),
+            watch_rx,
+            Arc::new(AtomicBool::new(true)),
+            None,
+            Some(auth_matcher),
+        )
+    }
+
+    async fn query_status(authorization: Option<&str>) -> StatusCode {
+        let mut request = request()
+            .method("POST")
+            .path("/graphql")
+            .header("content-type", "application/json")
+            .body(r#"{"query":"{ health }"}"#);
+        if let Some(authorization) = authorization {
+            request = request.header("authorization", authorization);
+        }
+        request.reply(&routes()).await.status()
+    }
+
+    async fn playground_status(authorization: Option<&str>) -> StatusCode {
+        let mut request = request().method("GET").path("/playground");
+        if let Some(authorization) = authorization {
+            request = request.header("authorization", authorization);
+        }
+        request.reply(&routes()).await.status()
+    }
+
+    #[tokio::test]
+    async fn graphql_requires_authentication() {
+        assert_eq!(query_status(None).await, StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            query_status(Some(&basic_auth("vector", "wrong"))).await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            query_status(Some("Bearer secret")).await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            query_status(Some(&basic_auth("vector", "secret"))).await,
+            StatusCode::OK
+        );
+    }
+
+    #[tokio::test]
+    async fn playground_requires_authentication() {
+        assert_eq!(playground_status(None).await, StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            playground_status(Some(&basic_auth("other", "secret"))).await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            playground_status(Some(&basic_auth("vector", "secret"))).await,
+            StatusCode::OK
+        );
+    }
+
+    #[tokio::test]
+    async fn health_does_not_require_authentication() {
+        let status = request()
+            .method("GET")
+            .path("/health")
+            .reply(&routes())
+            .await
+            .status();
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[cfg(feature = "api-client")]
+    #[tokio::test]
+    async fn client_connects_over_tls_with_authentication() {
+        use tokio::sync::mpsc;
+        use vector_lib::{
+            api_client::{Auth, Client, ClientOptions},
+            tls::{TEST_PEM_CA_PATH, TlsEnableableConfig},
+        };
+
+        use crate::test_util::addr::next_addr;
+
+        let (_guard, addr) = next_addr();
+        let mut config = config::Config::default();
+        config.api.address = Some(addr);
+        config.api.tls = Some(TlsEnableableConfig::test_config());
+        config.api.auth = Some(auth_config());
+
+        let (_watch_tx, watch_rx) = watch::channel(TapResource::default());
+        let (control_tx, _control_rx) = mpsc::channel(1);
+        let _server = Server::start(
+            &config,
+            watch_rx,
+            Arc::new(AtomicBool::new(true)),
+            control_tx,
+            &Handle::current(),
+        )
+        .unwrap();
+
+        let url = format!("https://localhost:{}/graphql", addr.port())
+            .parse()
+            .unwrap();
+        let client = |auth| {
+            Client::new_with_options(
+                url.clone(),
+                &ClientOptions {
+                    auth,
+                    ca_file: Some(TEST_PEM_CA_PATH.into()),
+                },
+            )
+            .unwrap()
+        };
+
+        assert!(
+            client(Some(Auth::Basic {
+                username: "vector".to_string(),
+                password: "secret".to_string(),
+            }))
+            .healthcheck()
+            .await
+            .is_ok()
+        );
+        assert!(
+            client(Some(Auth::Basic {
+                username: "vector".to_string(),
+                password: "wrong".to_string(),
+            }))
+            .healthcheck()
+            .await
+            .is_err()
+        );
+        assert!(client(None).healthcheck().await.is_err());
+
+        // Without the CA certificate, the server's certificate can't be verified.
+        let untrusted = Client::new(url);
+        assert!(untrusted.healthcheck().await.is_err());
+    }
+
+    #[cfg(feature = "api-client")]
+    #[test]
+    fn client_options_reject_invalid_ca_file() {
+        use vector_lib::api_client::{Client, ClientOptions};
+
+        let options = ClientOptions {
+            auth: None,
+            ca_file: Some("tests/data/does-not-exist.pem".into()),
+        };
+        let error =
+            Client::new_with_options("https://localhost/graphql".parse().unwrap(), &options)
+                .unwrap_err();
+        assert!(error.to_string().contains("CA certificate"));
+    }
+}
@@ -176,6 +176,7 @@ async fn integration_test_source_log() {
         TapPatterns::new(HashSet::from(["in".to_string()]), HashSet::new()),
         500,
         100,
+        None,
     );
 
     let source_tap_events: Vec<_> = source_tap_stream.take(2).collect().await;
@@ -235,6 +236,7 @@ async fn integration_test_source_metric() {
         TapPatterns::new(HashSet::from(["to_metric".to_string()]), HashSet::new()),
         500,
         100,
+        None,
     );
 
     let source_tap_events: Vec<_> = source_tap_stream.take(2).collect().await;
@@ -285,6 +287,7 @@ async fn integration_test_transform() {
         TapPatterns::new(HashSet::from(["transform".to_string()]), HashSet::new()),
         500,
         100,
+        None,
     );
 
     let transform_tap_events: Vec<_> = transform_tap_stream.take(2).collect().await;
@@ -341,6 +344,7 @@ async fn integration_test_transform_input() {
         ),
         500,
         100,
+        None,
     );
 
     let tap_events: Vec<_> = tap_stream.take(4).collect().await;
@@ -416,6 +420,7 @@ async fn integration_test_sink() {
         TapPatterns::new(HashSet::new(), HashSet::from(["out".to_string()])),
         500,
         100,
+        None,
     );
 
     let tap_events: Vec<_> = tap_stream.take(2).collect().await;
@@ -479,6 +484,7 @@ async fn integration_test_tap_non_default_output() {
         ),
         500,
         100,
+        None,
     );
 
     let transform_tap_events: Vec<_> = transform_tap_remap_dropped_stream.take(2).collect().await;
@@ -551,6 +557,7 @@ async fn integration_test_tap_multiple_outputs() {
         TapPatterns::new(HashSet::from(["transform*".to_string()]), HashSet::new()),
         500,
         100,
+        None,
     );
 
     let transform_tap_notifications = transform_tap_all_outputs_stream.next().await.unwrap();
@@ -4,18 +4,24 @@ use std::os::unix::process::ExitStatusExt;
 #[cfg(windows)]
 use std::os::windows::process::ExitStatusExt;
 use std::{
+    collections::HashMap,
     num::{NonZeroU64, NonZeroUsize},
     path::PathBuf,
     process::ExitStatus,
-    sync::atomic::{AtomicUsize, Ordering},
-    time::Duration,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::{Duration, Instant},
 };
 
 use exitcode::ExitCode;
 use futures::StreamExt;
+use indexmap::IndexMap;
 use tokio::{
     runtime::{self, Handle, Runtime},
     sync::{MutexGuard, broadcast::error::RecvError},
+    time::MissedTickBehavior,
 };
 use tokio_stream::wrappers::UnboundedReceiverStream;
 
@@ -23,20 +29,28 @@ use tokio_stream::wrappers::UnboundedReceiverStream;
 use crate::{api, internal_events::ApiStarted};
 use crate::{
     cli::{LogFormat, Opts, RootOpts, WatchConfigMethod, handle_config_errors},
-    config::{self, ComponentConfig, ComponentType, Config, ConfigPath},
+    config::{self, ComponentConfig, ComponentKey, ComponentType, Config, ConfigPath},
     extra_context::ExtraContext,
     heartbeat,
     internal_events::{VectorConfigLoadError, VectorQuit, VectorStarted, VectorStopped},
-    signal::{SignalHandler, SignalPair, SignalRx, SignalTo},
+    signal::{ConfigStatus, SignalHandler, SignalPair, SignalRx, SignalTo},
     topology::{
-        ReloadOutcome, RunningTopology, SharedTopologyController, ShutdownErrorReceiver,
-        TopologyController,
+        ControlCommand, ControlRequest, ControlRx, ReloadOutcome, RunningTopology,
+        SharedTopologyController, ShutdownErrorReceiver, TopologyController,
+        builder::{
+            build_reloaded_enrichment_tables, enrichment_tables_needing_reload,
+            load_reloaded_enrichment_tables, retain_current_enrichment_tables,
+        },
+        control_channel,
     },
     trace,
 };
 
 static WORKER_THREADS: AtomicUsize = AtomicUsize::new(0);
 
+/// How often enrichment tables that are reloaded periodically are checked for reloading.
+const ENRICHMENT_TABLE_REFRESH_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
 pub fn worker_threads() -> Option<NonZeroUsize> {
     NonZeroUsize::new(WORKER_THREADS.load(Ordering::Relaxed))
 }
@@ -49,6 +63,7 @@ pub struct ApplicationConfig {
     #[cfg(feature = "api")]
     pub api: config::api::Options,
     pub extra_context: ExtraContext,
+    pub enrichment_table_watcher: Option<config::watcher::EnrichmentTableWatcher>,
 }
 
 pub struct Application {
@@ -68,18 +83,14 @@ impl ApplicationConfig {
         let graceful_shutdown_duration = (!opts.no_graceful_shutdown_limit)
             .then(|| Duration::from_secs(u64::from(opts.graceful_shutdown_limit_secs)));
 
-        let watcher_conf = if opts.watch_config {
-            Some(watcher_config(
-                opts.watch_config_method,
-                opts.watch_config_poll_interval_seconds,
-            ))
-        } else {
-            None
-        };
+        let watcher_conf = watcher_config(
+            opts.watch_config_method,
+            opts.watch_config_poll_interval_seconds,
+        );
 
         let config = load_configs(
             &config_paths,
-            watcher_conf,
+            opts.watch_config.then_some(watcher_conf),
             opts.require_healthy,
             opts.allow_empty_config,
             !opts.disable_env_var_interpolation,
@@ -88,7 +99,22 @@ impl ApplicationConfig {
         )
         .await?;
 
-        Self::from_config(config_paths, config, extra_context).await
+        // Enrichment table files are watched even when configuration files aren't, so that tables
+        // are reloaded as soon as their files are updated.
+        let enrichment_table_watcher =
+            watch_enrichment_tables(&config, watcher_conf, signal_handler);
+
+        let result = Self::from_config(config_paths, config, extra_context)
+            .await
+            .map(|config| Self {
+                enrichment_table_watcher,
+                ..config
+            });
+        signal_handler.report_config_status(match result {
+            Ok(_) => ConfigStatus::Loaded,
+            Err(_) => ConfigStatus::Failed,
+        });
+        result
     }
 
     pub async fn from_config(
@@ -97,7 +123,7 @@ impl ApplicationConfig {
         extra_context: ExtraContext,
     ) -> Result<Self, ExitCode> {
         #[cfg(feature = "api")]
-        let api = config.api;
+        let api = config.api.clone();
 
         let (topology, graceful_crash_receiver) =
             RunningTopology::start_init_validated(config, extra_context.clone())
@@ -112,6 +138,7 @@ impl ApplicationConfig {
             #[cfg(feature = "api")]
             api,
             extra_context,
+            enrichment_table_watcher: None,
         })
     }
 
@@ -131,12 +158,17 @@ impl ApplicationConfig {
 
     /// Configure the API server, if applicable
     #[cfg(feature = "api")]
-    pub fn setup_api(&self, handle: &Handle) -> Option<api::Server> {
+    pub fn setup_api(
+        &self,
+        handle: &Handle,
+        control_tx: crate::topology::ControlTx,
+    ) -> Option<api::Server> {
         if self.api.enabled {
             match api::Server::start(
                 self.topology.config(),
                 self.topology.watch(),
                 std::sync::Arc::clone(&self.topology.running),
+                control_tx,
                 handle,
             ) {
                 Ok(api_server) => {
@@ -256,13 +288,16 @@ impl Application {
             signals,
         } = self;
 
+        let (control_tx, control_rx) = control_channel();
         let topology_controller = SharedTopologyController::new(TopologyController {
             #[cfg(feature = "api")]
-            api_server: config.setup_api(handle),
+            api_server: config.setup_api(handle, control_tx.clone()),
             topology: config.topology,
             config_paths: config.config_paths.clone(),
             require_healthy: root_opts.require_healthy,
+            control_tx,
             extra_context: config.extra_context,
+            enrichment_table_watcher: config.enrichment_table_watcher,
         });
 
         Ok(StartedApplication {
@@ -270,6 +305,7 @@ impl Application {
             internal_topologies: config.internal_topologies,
             graceful_crash_receiver: config.graceful_crash_receiver,
             signals,
+            control_rx,
             topology_controller,
             allow_empty_config: root_opts.allow_empty_config,
             interpolate_env: !root_opts.disable_env_var_interpolation,
@@ -282,6 +318,7 @@ pub struct StartedApplication {
     pub internal_topologies: Vec<RunningTopology>,
     pub graceful_crash_receiver: ShutdownErrorReceiver,
     pub signals: SignalPair,
+    pub control_rx: ControlRx,
     pub topology_controller: SharedTopologyController,
     pub allow_empty_config: bool,
     pub interpolate_env: bool,
@@ -297,6 +334,7 @@ impl StartedApplication {
             config_paths,
             graceful_crash_receiver,
             signals,
+            mut control_rx,
             topology_controller,
             internal_topologies,
             allow_empty_config,
@@ -308,8 +346,24 @@ impl StartedApplication {
         let mut signal_handler = signals.handler;
         let mut signal_rx = signals.receiver;
 
+        let mut enrichment_table_refresh =
+            tokio::time::interval(ENRICHMENT_TABLE_REFRESH_CHECK_INTERVAL);
+        enrichment_table_refresh.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        let mut enrichment_table_refresh_task = None;
+        let failed_enrichment_table_refreshes = FailedRefreshes::default();
+
         let signal = loop {
-            let has_sources = !topology_controller.lock().await.topology.config.is_empty();
+            let (has_sources, refreshes_tables) = {
+                let topology_controller = topology_controller.lock().await;
+                let config = &topology_controller.topology.config;
+                (
+                    !config.is_empty(),
+                    config
+                        .enrichment_tables
+                        .values()
+                        .any(|table| table.inner.refresh_interval().is_some()),
+                )
+            };
             tokio::select! {
                 signal = signal_rx.recv() => if let Some(signal) = handle_signal(
                     signal,
@@ -321,6 +375,20 @@ impl StartedApplication {
                 ).await {
                     break signal;
                 },
+                // Commands to control the topology, such as the ones sent by the API.
+                Some(request) = control_rx.recv() => handle_control(
+                    request,
+                    &topology_controller,
+                    &signal_handler,
+                ).await,
+                // Reload the tables that are reloaded periodically, once their interval has elapsed.
+                _ = enrichment_table_refresh.tick(), if refreshes_tables
+                    && enrichment_table_refresh_task.as_ref().is_none_or(|task| task.is_finished()) => {
+                    enrichment_table_refresh_task = Some(tokio::spawn(refresh_enrichment_tables(
+                        topology_controller.clone(),
+                        failed_enrichment_table_refreshes.clone(),
+                    )));
+                },
                 // Trigger graceful shutdown if a component crashed, or all sources have ended.
                 error = graceful_crash.next() => break SignalTo::Shutdown(error),
                 _ = TopologyController::sources_finished(topology_controller.clone()), if has_sources => {
@@ -369,11 +437,12 @@ async fn handle_signal(
             )
             .await;
 
-            reload_config_from_result(topology_controller, new_config).await
+            reload_config_from_result(topology_controller, new_config, signal_handler).await
         }
         Ok(SignalTo::ReloadFromConfigBuilder(config_builder)) => {
             let topology_controller = topology_controller.lock().await;
-            reload_config_from_result(topology_controller, config_builder.build()).await
+            reload_config_from_result(topology_controller, config_builder.build(), signal_handler)
+                .await
         }
         Ok(SignalTo::ReloadFromDisk) => {
             let mut topology_controller = topology_controller.lock().await;
@@ -408,7 +477,7 @@ async fn handle_signal(
                 }
             }
 
-            reload_config_from_result(topology_controller, new_config).await
+            reload_config_from_result(topology_controller, new_config, signal_handler).await
         }
         Ok(SignalTo::ReloadEnrichmentTables) => {
             let topology_controller = topology_controller.lock().await;
@@ -428,16 +497,144 @@ async fn handle_signal(
     }
 }
 
+/// When the last refresh of enrichment tables failed, by table.
+type FailedRefreshes = Arc<std::sync::Mutex<HashMap<ComponentKey, Instant>>>;
+
+/// Reloads the enrichment tables whose refresh interval has elapsed.
+///
+/// Building the tables can take a while, such as when querying a database, so it's done without
+/// holding the topology controller lock, which is only taken to swap the built tables in. Tables
+/// failing to refresh are only retried once their refresh interval elapses again.
+async fn refresh_enrichment_tables(
+    topology_controller: SharedTopologyController,
+    failed_refreshes: FailedRefreshes,
+) {
+    let (enrichment_tables, global) = {
+        let topology_controller = topology_controller.lock().await;
+        let config = &topology_controller.topology.config;
+        let mut failed_refreshes = failed_refreshes.lock().expect("poisoned lock");
+        failed_refreshes.retain(|key, _| config.enrichment_tables.contains_key(key));
+        let enrichment_tables = config
+            .enrichment_tables
+            .iter()
+            .filter(|(key, table)| {
+                match (failed_refreshes.get(*key), table.inner.refresh_interval()) {
+                    (Some(failed_at), Some(interval)) => failed_at.elapsed() >= interval,
+                    _ => true,
+                }
+            })
+            .map(|(key, table)| (key.clone(), table.clone()))
+            .collect::<IndexMap<_, _>>();
+        (enrichment_tables, config.global.clone())
+    };
+
+    let due = enrichment_tables_needing_reload(enrichment_tables.keys());
+    let mut tables = build_reloaded_enrichment_tables(&enrichment_tables, &global).await;
+    {
+        let mut failed_refreshes = failed_refreshes.lock().expect("poisoned lock");
+        for key in due {
+            if tables.contains_key(&key.to_string()) {
+                failed_refreshes.remove(&key);
+            } else {
+                failed_refreshes.insert(key, Instant::now());
+            }
+        }
+    }
+    if tables.is_empty() {
+        return;
+    }
+
+    let topology_controller = topology_controller.lock().await;
+    retain_current_enrichment_tables(
+        &mut tables,
+        &enrichment_tables,
+        &topology_controller.topology.config.enrichment_tables,
+    );
+    load_reloaded_enrichment_tables(tables);
+}
+
+async fn handle_control(
+    request: ControlRequest,
+    topology_controller: &SharedTopologyController,
+    signal_handler: &SignalHandler,
+) {
+    let ControlRequest { command, response } = request;
+    debug!(message = "Received control command.", ?command);
+
+    let result = match command {
+        // Reloads go through the same path as `SIGHUP`, so that everything listening for reload
+        // signals, such as secret backends, sees them.
+        ControlCommand::ReloadConfig => signal_handler
+            .clone_tx()
+            .send(SignalTo::ReloadFromDisk)
+            .map(|_| ())
+            .map_err(|_| "Vector is shutting down.".to_string()),
+        ControlCommand::ReloadEnrichmentTables => {
+            topology_controller
+                .lock()
+                .await
+                .topology
+                .reload_enrichment_tables()
+                .await;
+            Ok(())
+        }
+        ControlCommand::PauseSource(key) => topology_controller
+            .lock()
+            .await
+            .topology
+            .set_source_paused(&key, true),
+        ControlCommand::ResumeSource(key) => topology_controller
+            .lock()
+            .await
+            .topology
+            .set_source_paused(&key, false),
+        ControlCommand::Healthcheck(key) => {
+            let healthcheck = topology_controller
+                .lock()
+                .await
+                .topology
+                .sink_healthcheck(&key);
+
+            match healthcheck {
+                Ok(healthcheck) => {
+                    // Healthchecks can take a while, so they're run in the background to not hold
+                    // up other signals and commands.
+                    tokio::spawn(async move {
+                        _ = response.send(healthcheck.await);
+                    });
+                    return;
+                }
+                Err(error) => Err(error),
+            }
+        }
+    };
+
+    // The requester may have given up on the response, which is fine.
+    _ = response.send(result);
+}
+
 async fn reload_config_from_result(
     mut topology_controller: MutexGuard<'_, TopologyController>,
     config: Result<Config, Vec<String>>,
+    signal_handler: &SignalHandler,
 ) -> Option<SignalTo> {
     match config {
         Ok(new_config) => match topology_controller.reload(new_config).await {
-            ReloadOutcome::FatalError(error) => Some(SignalTo::Shutdown(Some(error))),
-            _ => None,
+            ReloadOutcome::Success => {
+                signal_handler.report_config_status(ConfigStatus::Loaded);
+                None
+            }
+            ReloadOutcome::FatalError(error) => {
+                signal_handler.report_config_status(ConfigStatus::Failed);
+                Some(SignalTo::Shutdown(Some(error)))
+            }
+            ReloadOutcome::MissingApiKey | ReloadOutcome::RolledBack => {
+                signal_handler.report_config_status(ConfigStatus::Failed);
+                None
+            }
         },
         Err(errors) => {
+            signal_handler.report_config_status(ConfigStatus::Invalid(errors.len()));
             handle_config_errors(errors);
             emit!(VectorConfigLoadError);
             None
@@ -595,16 +792,6 @@ pub async fn load_configs(
             watched_component_paths.push(component_config);
         }
 
-        for (name, table) in config.enrichment_tables() {
-            let files = table.inner.files_to_watch();
-            let component_config = ComponentConfig::new(
-                files.into_iter().cloned().collect(),
-                name.clone(),
-                ComponentType::EnrichmentTable,
-            );
-            watched_component_paths.push(component_config);
-        }
-
         info!(
             message = "Starting watcher.",
             paths = ?watched_paths
@@ -640,6 +827,33 @@ pub async fn load_configs(
     Ok(config)
 }
 
+/// Starts watching the files of the enrichment tables of the given config, if possible.
+fn watch_enrichment_tables(
+    config: &Config,
+    watcher_conf: config::watcher::WatcherConfig,
+    signal_handler: &SignalHandler,
+) -> Option<config::watcher::EnrichmentTableWatcher> {
+    let table_paths = config::watcher::enrichment_table_paths(config);
+
+    info!(
+        message = "Starting enrichment table watcher.",
+        paths = ?table_paths
+    );
+    config::watcher::spawn_enrichment_tables_thread(
+        watcher_conf,
+        signal_handler.clone_tx(),
+        table_paths,
+        None,
+    )
+    .map_err(|error| {
+        warn!(
+            message = "Unable to start enrichment table watcher, tables are only reloaded along with the configuration.",
+            %error
+        )
+    })
+    .ok()
+}
+
 pub fn init_logging(color: bool, format: LogFormat, log_level: &str, rate: u64) {
     let level = get_log_levels(log_level);
     let json = match format {
@@ -0,0 +1,603 @@
+#![allow(missing_docs)]
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+};
+
+use clap::{Args, Parser, Subcommand};
+use colored::*;
+use futures::StreamExt;
+use serde::Serialize;
+use vector_lib::{
+    buffers::{
+        BufferInspector, DataFileSummary, DiskBufferEncryption, InspectError, InspectedRecord,
+        LedgerLoadCreateError,
+    },
+    event::{EventArray, EventContainer},
+};
+
+#[derive(Parser, Debug)]
+#[command(rename_all = "kebab-case")]
+pub struct Opts {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+#[command(rename_all = "kebab-case")]
+enum Command {
+    /// Show the state of a disk buffer, as tracked by its ledger.
+    Info(InfoOpts),
+
+    /// Output every record of a disk buffer as JSON, one record per line.
+    ///
+    /// Records that were already acknowledged, but not yet deleted from disk, are included.
+    Dump(DumpOpts),
+
+    /// Check that every record of a disk buffer is valid, exiting with an error if any isn't.
+    Validate(BufferOpts),
+
+    /// Output the unacknowledged events of a disk buffer in the `native_json` format, one event
+    /// per line.
+    ///
+    /// The output can be replayed into Vector with the `stdin` or `file` source, using the
+    /// `native_json` decoding codec.
+    Export(ExportOpts),
+}
+
+/// Options shared by all `buffer` subcommands.
+///
+/// Disk buffers can only be inspected while the Vector process using them is stopped.
+#[derive(Args, Debug)]
+#[command(rename_all = "kebab-case")]
+struct BufferOpts {
+    /// The ID of the sink whose disk buffer should be inspected.
+    sink_id: String,
+
+    /// The data directory of the Vector process the buffer belongs to.
+    #[arg(long, default_value = default_data_dir())]
+    data_dir: PathBuf,
+
+    /// The base64-encoded key the buffer is encrypted with, if any.
+    #[arg(long, env = "VECTOR_BUFFER_ENCRYPTION_KEY", hide_env_values = true)]
+    encryption_key: Option<String>,
+}
+
+#[derive(Args, Debug)]
+#[command(rename_all = "kebab-case")]
+struct InfoOpts {
+    #[command(flatten)]
+    buffer: BufferOpts,
+
+    /// Format of the output.
+    #[arg(long, default_value = "text")]
+    format: Format,
+}
+
+#[derive(Args, Debug)]
+#[command(rename_all = "kebab-case")]
+struct DumpOpts {
+    #[command(flatten)]
+    buffer: BufferOpts,
+
+    /// The file to write the records to. Defaults to stdout.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+#[command(rename_all = "kebab-case")]
+struct ExportOpts {
+    #[command(flatten)]
+    buffer: BufferOpts,
+
+    /// The file to write the events to. Defaults to stdout.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+}
+
+fn default_data_dir() -> String {
+    vector_lib::default_data_dir()
+        .map(|path| path.display().to_string())
+        .unwrap_or_default()
+}
+
+#[derive(Serialize)]
+struct EncodedInfo {
+    reader_file_id: u16,
+    writer_file_id: u16,
+    last_reader_record_id: u64,
+    next_writer_record_id: u64,
+    unacknowledged_events: u64,
+    total_bytes: u64,
+    data_files: Vec<EncodedDataFile>,
+}
+
+#[derive(Serialize)]
+struct EncodedDataFile {
+    file_id: u16,
+    path: PathBuf,
+    size: u64,
+}
+
+#[derive(Serialize)]
+struct EncodedRecord {
+    file_id: u16,
+    record_id: Option<u64>,
+    acknowledged: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    events: Option<Vec<vector_lib::event::Event>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl From<InspectedRecord<EventArray>> for EncodedRecord {
+    fn from(record: InspectedRecord<EventArray>) -> Self {
+        let (events, error) = match record.item {
+            Ok(events) => (Some(events.into_events().collect()), None),
+            Err(error) => (None, Some(error.to_string())),
+        };
+
+        Self {
+            file_id: record.file_id,
+            record_id: record.record_id,
+            acknowledged: record.acknowledged,
+            events,
+            error,
+        }
+    }
+}
+
+pub async fn cmd(opts: &Opts) -> exitcode::ExitCode {
+    let mut stdout = io::stdout();
+    match opts.command {
+        Command::Info(ref opts) => info(opts, &mut stdout).await,
+        Command::Dump(ref opts) => dump(opts).await,
+        Command::Validate(ref opts) => validate(opts, &mut stdout).await,
+        Command::Export(ref opts) => export(opts).await,
+    }
+}
+
+async fn info(opts: &InfoOpts, output: &mut impl Write) -> exitcode::ExitCode {
+    let (inspector, data_files) = match open(&opts.buffer).await {
+        Ok(opened) => opened,
+        Err(code) => return code,
+    };
+
+    let summary = inspector.ledger_summary();
+    let info = EncodedInfo {
+        reader_file_id: summary.reader_file_id,
+        writer_file_id: summary.writer_file_id,
+        last_reader_record_id: summary.last_reader_record_id,
+        next_writer_record_id: summary.next_writer_record_id,
+        unacknowledged_events: summary.unacknowledged_events,
+        total_bytes: summary.total_bytes,
+        data_files: data_files
+            .into_iter()
+            .map(|data_file| EncodedDataFile {
+                file_id: data_file.file_id,
+                path: data_file.path,
+                size: data_file.size,
+            })
+            .collect(),
+    };
+
+    let result = match opts.format {
+        Format::Text => write_info_text(output, &info),
+        Format::Json => serde_json::to_writer(&mut *output, &info)
+            .map_err(io::Error::from)
+            .and_then(|()| output.write_all(b"\n")),
+    };
+
+    match result {
+        Ok(()) => exitcode::OK,
+        Err(error) => write_error(error),
+    }
+}
+
+fn write_info_text(output: &mut impl Write, info: &EncodedInfo) -> io::Result<()> {
+    writeln!(output, "Reader data file ID:   {}", info.reader_file_id)?;
+    writeln!(output, "Writer data file ID:   {}", info.writer_file_id)?;
+    writeln!(
+        output,
+        "Last reader record ID: {}",
+        info.last_reader_record_id
+    )?;
+    writeln!(
+        output,
+        "Next writer record ID: {}",
+        info.next_writer_record_id
+    )?;
+    writeln!(
+        output,
+        "Unacknowledged events: {}",
+        info.unacknowledged_events
+    )?;
+    writeln!(output, "Total size:            {} bytes", info.total_bytes)?;
+
+    writeln!(output, "\nData files:")?;
+    for data_file in &info.data_files {
+        writeln!(
+            output,
+            "- {} ({} bytes)",
+            data_file.path.display(),
+            data_file.size
+        )?;
+    }
+    Ok(())
+}
+
+async fn dump(opts: &DumpOpts) -> exitcode::ExitCode {
+    let (inspector, data_files) = match open(&opts.buffer).await {
+        Ok(opened) => opened,
+        Err(code) => return code,
+    };
+    let mut output = match create_output(opts.output.as_ref()) {
+        Ok(output) => output,
+        Err(code) => return code,
+    };
+
+    let mut records = std::pin::pin!(inspector.records(data_files));
+    while let Some(record) = records.next().await {
+        let result = serde_json::to_writer(&mut output, &EncodedRecord::from(record))
+            .map_err(io::Error::from)
+            .and_then(|()| output.write_all(b"\n"));
+        if let Err(error) = result {
+            return write_error(error);
+        }
+    }
+
+    match output.flush() {
+        Ok(()) => exitcode::OK,
+        Err(error) => write_error(error),
+    }
+}
+
+async fn validate(opts: &BufferOpts, output: &mut impl Write) -> exitcode::ExitCode {
+    let (inspector, data_files) = match open(opts).await {
+        Ok(opened) => opened,
+        Err(code) => return code,
+    };
+
+    let mut valid = 0;
+    let mut invalid = 0;
+    let mut records = std::pin::pin!(inspector.records(data_files));
+    while let Some(record) = records.next().await {
+        match record.item {
+            Ok(_) => valid += 1,
+            Err(error) => {
+                invalid += 1;
+                report_invalid_record(record.file_id, record.record_id, &error);
+            }
+        }
+    }
+
+    if let Err(error) = writeln!(output, "Valid records:   {valid}")
+        .and_then(|()| writeln!(output, "Invalid records: {invalid}"))
+    {
+        return write_error(error);
+    }
+
+    if invalid == 0 {
+        exitcode::OK
+    } else {
+        exitcode::DATAERR
+    }
+}
+
+async fn export(opts: &ExportOpts) -> exitcode::ExitCode {
+    let (inspector, data_files) = match open(&opts.buffer).await {
+        Ok(opened) => opened,
+        Err(code) => return code,
+    };
+    let mut output = match create_output(opts.output.as_ref()) {
+        Ok(output) => output,
+        Err(code) => return code,
+    };
+
+    let mut records = std::pin::pin!(inspector.records(data_files));
+    while let Some(record) = records.next().await {
+        if record.acknowledged {
+            continue;
+        }
+
+        // Invalid records can't be exported, but shouldn't stop the rest of the buffer from being
+        // exported either, which is also what Vector itself would do when reading the buffer.
+        let events = match record.item {
+            Ok(events) => events,
+            Err(error) => {
+                report_invalid_record(record.file_id, record.record_id, &error);
+                continue;
+            }
+        };
+
+        for event in events.into_events() {
+            let result = serde_json::to_writer(&mut output, &event)
+                .map_err(io::Error::from)
+                .and_then(|()| output.write_all(b"\n"));
+            if let Err(error) = result {
+                return write_error(error);
+            }
+        }
+    }
+
+    match output.flush() {
+        Ok(()) => exitcode::OK,
+        Err(error) => write_error(error),
+    }
+}
+
+async fn open(
+    opts: &BufferOpts,
+) -> Result<(BufferInspector<EventArray>, Vec<DataFileSummary>), exitcode::ExitCode> {
+    let encryption = opts
+        .encryption_key
+        .clone()
+        .map(|key| DiskBufferEncryption { key: key.into() });
+
+    let result =
+        match BufferInspector::open(&opts.data_dir, &opts.sink_id, encryption.as_ref()).await {
+            Ok(inspector) => inspector
+                .data_files()
+                .await
+                .map(|data_files| (inspector, data_files)),
+            Err(error) => Err(error),
+        };
+
+    result.map_err(|error| {
+        let code = match error {
+            InspectError::NotFound { .. } => exitcode::NOINPUT,
+            InspectError::InvalidEncryptionKey { .. } => exitcode::CONFIG,
+            InspectError::Ledger {
+                source: LedgerLoadCreateError::LedgerLockAlreadyHeld,
+            } => exitcode::TEMPFAIL,
+            _ => exitcode::IOERR,
+        };
+        let message = match error {
+            InspectError::Ledger {
+                source: LedgerLoadCreateError::LedgerLockAlreadyHeld,
+            } => format!(
+                "Buffer for sink \"{}\" is in use. Make sure Vector is stopped before inspecting it.",
+                opts.sink_id
+            ),
+            error => format!("Failed to open buffer for sink \"{}\": {error}", opts.sink_id),
+        };
+
+        #[allow(clippy::print_stderr)]
+        {
+            eprintln!("{}", message.red());
+        }
+        code
+    })
+}
+
+fn create_output(path: Option<&PathBuf>) -> Result<BufWriter<Box<dyn Write>>, exitcode::ExitCode> {
+    let output: Box<dyn Write> = match path {
+        Some(path) => match File::create(path) {
+            Ok(file) => Box::new(file),
+            Err(error) => {
+                #[allow(clippy::print_stderr)]
+                {
+                    eprintln!(
+                        "{}",
+                        format!("Failed to create {}: {error}", path.display()).red()
+                    );
+                }
+                return Err(exitcode::CANTCREAT);
+            }
+        },
+        None => Box::new(io::stdout().lock()),
+    };
+
+    Ok(BufWriter::new(output))
+}
+
+fn report_invalid_record(file_id: u16, record_id: Option<u64>, error: &impl std::fmt::Display) {
+    let record = record_id.map_or_else(|| "unknown".to_string(), |id| id.to_string());
+
+    #[allow(clippy::print_stderr)]
+    {
+        eprintln!(
+            "{}",
+            format!("Invalid record in data file {file_id} (record ID: {record}): {error}").red()
+        );
+    }
+}
+
+fn write_error(error: io::Error) -> exitcode::ExitCode {
+    #[allow(clippy::print_stderr)]
+    {
+        eprintln!("{}", format!("Failed to write output: {error}").red());
+    }
+    exitcode::IOERR
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{num::NonZeroU64, path::Path, time::Duration};
+
+    use tracing::Span;
+    use vector_lib::{
+        buffers::{BufferConfig, BufferType, WhenFull},
+        event::{Event, LogEvent},
+    };
+
+    use super::*;
+
+    const SINK_ID: &str = "out";
+
+    fn buffer_opts(data_dir: &Path) -> BufferOpts {
+        BufferOpts {
+            sink_id: SINK_ID.to_string(),
+            data_dir: data_dir.to_path_buf(),
+            encryption_key: None,
+        }
+    }
+
+    /// Writes the given messages to a disk buffer, one record per message, and waits for the
+    /// buffer to be released.
+    async fn write_buffer(data_dir: &Path, messages: &[&str]) {
+        let config = BufferConfig::Single(BufferType::DiskV2 {
+            max_size: NonZeroU64::new(268435488).unwrap(),
+            when_full: WhenFull::Block,
+            compression: Default::default(),
+            encryption: None,
+        });
+        let (mut sender, receiver) = config
+            .build::<EventArray>(
+                Some(data_dir.to_path_buf()),
+                SINK_ID.to_string(),
+                Span::none(),
+            )
+            .await
+            .unwrap();
+        for message in messages {
+            let event = Event::Log(LogEvent::from(*message));
+            sender.send(event.into(), None).await.unwrap();
+        }
+        sender.flush().await.unwrap();
+        drop(sender);
+        drop(receiver);
+
+        // The ledger is released once the buffer's background tasks notice it was dropped.
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while BufferInspector::<EventArray>::open(data_dir, SINK_ID, None)
+                .await
+                .is_err()
+            {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("buffer should be released");
+    }
+
+    fn read_lines(path: &Path) -> Vec<serde_json::Value> {
+        std::fs::read_to_string(path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn info_reports_ledger_state() {
+        let data_dir = tempfile::tempdir().unwrap();
+        write_buffer(data_dir.path(), &["a", "b", "c"]).await;
+
+        let opts = InfoOpts {
+            buffer: buffer_opts(data_dir.path()),
+            format: Format::Json,
+        };
+        let mut output = Vec::new();
+        assert_eq!(info(&opts, &mut output).await, exitcode::OK);
+
+        let info: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        assert_eq!(info["last_reader_record_id"], 0);
+        assert_eq!(info["next_writer_record_id"], 4);
+        assert_eq!(info["unacknowledged_events"], 3);
+        assert_eq!(info["data_files"].as_array().unwrap().len(), 1);
+
+        let opts = InfoOpts {
+            buffer: buffer_opts(data_dir.path()),
+            format: Format::Text,
+        };
+        let mut output = Vec::new();
+        assert_eq!(info(&opts, &mut output).await, exitcode::OK);
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Unacknowledged events: 3\n"), "{output}");
+    }
+
+    #[tokio::test]
+    async fn missing_buffer_is_reported() {
+        let data_dir = tempfile::tempdir().unwrap();
+
+        let mut output = Vec::new();
+        let code = validate(&buffer_opts(data_dir.path()), &mut output).await;
+        assert_eq!(code, exitcode::NOINPUT);
+        assert!(output.is_empty());
+    }
+
+    #[tokio::test]
+    async fn dump_and_export_output_every_record() {
+        let data_dir = tempfile::tempdir().unwrap();
+        write_buffer(data_dir.path(), &["a", "b", "c"]).await;
+
+        let dump_path = data_dir.path().join("dump.json");
+        let opts = DumpOpts {
+            buffer: buffer_opts(data_dir.path()),
+            output: Some(dump_path.clone()),
+        };
+        assert_eq!(dump(&opts).await, exitcode::OK);
+
+        let records = read_lines(&dump_path);
+        assert_eq!(records.len(), 3);
+        for (index, (record, message)) in records.iter().zip(["a", "b", "c"]).enumerate() {
+            assert_eq!(record["record_id"], index + 1);
+            assert_eq!(record["acknowledged"], false);
+            assert_eq!(record["events"][0]["log"]["message"], message);
+        }
+
+        let export_path = data_dir.path().join("export.json");
+        let opts = ExportOpts {
+            buffer: buffer_opts(data_dir.path()),
+            output: Some(export_path.clone()),
+        };
+        assert_eq!(export(&opts).await, exitcode::OK);
+
+        let events = read_lines(&export_path)
+            .into_iter()
+            .map(|event| serde_json::from_value::<Event>(event).unwrap())
+            .map(|event| {
+                let log = event.into_log();
+                log.get_message().unwrap().to_string_lossy().into_owned()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(events, ["a", "b", "c"]);
+    }
+
+    #[tokio::test]
+    async fn validate_reports_invalid_records() {
+        let data_dir = tempfile::tempdir().unwrap();
+        write_buffer(data_dir.path(), &["first", "second", "third"]).await;
+
+        let mut output = Vec::new();
+        assert_eq!(
+            validate(&buffer_opts(data_dir.path()), &mut output).await,
+            exitcode::OK
+        );
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Valid records:   3\nInvalid records: 0\n"
+        );
+
+        // Corrupt the payload of the second record, so that it fails its checksum.
+        let inspector = BufferInspector::<EventArray>::open(data_dir.path(), SINK_ID, None)
+            .await
+            .unwrap();
+        let data_file = inspector.data_files().await.unwrap().remove(0);
+        drop(inspector);
+        let mut contents = std::fs::read(&data_file.path).unwrap();
+        let position = contents
+            .windows(b"second".len())
+            .position(|window| window == b"second")
+            .unwrap();
+        contents[position] ^= 0xFF;
+        std::fs::write(&data_file.path, contents).unwrap();
+
+        let mut output = Vec::new();
+        assert_eq!(
+            validate(&buffer_opts(data_dir.path()), &mut output).await,
+            exitcode::DATAERR
+        );
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Invalid records: 1\n"), "{output}");
+    }
+}
@@ -12,8 +12,8 @@ use crate::tap;
 use crate::top;
 
 use crate::{
-    config, convert_config, generate, generate_schema, get_version, graph, list, signal, unit_test,
-    validate,
+    buffer, config, convert_config, generate, generate_schema, get_version, graph, list, signal,
+    unit_test, validate,
 };
 
 #[derive(Parser, Debug)]
@@ -40,6 +40,7 @@ impl Opts {
             | Some(SubCommand::Generate(_))
             | Some(SubCommand::ConvertConfig(_))
             | Some(SubCommand::List(_))
+            | Some(SubCommand::Buffer(_))
             | Some(SubCommand::Test(_)) => {
                 if self.root.verbose == 0 {
                     (self.root.quiet + 1, self.root.verbose)
@@ -171,6 +172,8 @@ pub struct RootOpts {
     /// - `ReadDirectoryChangesWatcher` for windows
     ///
     /// The `poll` watcher can be used in cases where `inotify` doesn't work, e.g., when attaching the configuration via NFS.
+    ///
+    /// The files of enrichment tables are watched with the same method, even when `--watch-config` isn't enabled.
     #[arg(
         long,
         default_value = "recommended",
@@ -340,6 +343,9 @@ pub enum SubCommand {
     #[cfg(feature = "api-client")]
     Tap(tap::Opts),
 
+    /// Inspect, validate, and export the contents of a disk buffer, while Vector is stopped.
+    Buffer(buffer::Opts),
+
     /// Manage the vector service.
     #[cfg(windows)]
     Service(service::Opts),
@@ -355,6 +361,7 @@ impl SubCommand {
         color: bool,
     ) -> exitcode::ExitCode {
         match self {
+            Self::Buffer(b) => buffer::cmd(b).await,
             Self::Config(c) => config::cmd(c),
             Self::ConvertConfig(opts) => convert_config::cmd(opts),
             Self::Generate(g) => generate::cmd(g),
@@ -0,0 +1,261 @@
+//! Conversion between events and the Arrow Flight data exchanged over `DoPut` streams.
+//!
+//! Events are encoded as Arrow IPC streams, whose messages are then carried one per `FlightData`
+//! message. The first message of a stream holds the schema and a path descriptor naming the type
+//! of the events, either [`LOGS_PATH`] or [`TRACES_PATH`].
+
+use std::sync::Arc;
+
+use arrow::ipc::{MessageHeader, root_as_message};
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use snafu::{ResultExt, Snafu};
+use vector_lib::codecs::{
+    decoding::{ArrowDecodingError, decode_arrow_ipc_stream},
+    encoding::{ArrowEncodingError, encode_events_to_arrow_ipc_stream, infer_schema},
+};
+
+use crate::{
+    event::{Event, LogEvent, TraceEvent},
+    proto::arrow_flight::{FlightData, FlightDescriptor, flight_descriptor::DescriptorType},
+};
+
+/// The descriptor path of streams carrying log events.
+pub const LOGS_PATH: &str = "logs";
+
+/// The descriptor path of streams carrying trace events.
+pub const TRACES_PATH: &str = "traces";
+
+/// Marker preceding the length of each message of an Arrow IPC stream.
+const CONTINUATION_MARKER: u32 = 0xFFFF_FFFF;
+
+#[derive(Debug, Snafu)]
+pub enum ArrowFlightError {
+    #[snafu(display("Failed to encode events: {}", source))]
+    Encode { source: ArrowEncodingError },
+
+    #[snafu(display("Failed to decode events: {}", source))]
+    Decode { source: ArrowDecodingError },
+
+    #[snafu(display("Invalid Arrow IPC message: {}", message))]
+    InvalidMessage { message: String },
+
+    #[snafu(display("Unsupported flight descriptor, expected a path of `logs` or `traces`."))]
+    UnsupportedDescriptor,
+}
+
+/// Encodes log or trace events into the messages of a `DoPut` stream.
+pub fn encode_flight_data(
+    events: Vec<Event>,
+    path: &str,
+) -> Result<Vec<FlightData>, ArrowFlightError> {
+    let events = events
+        .into_iter()
+        .map(|event| match event {
+            Event::Trace(trace) => Event::Log(LogEvent::from(trace)),
+            event => event,
+        })
+        .collect::<Vec<_>>();
+    let schema = Arc::new(infer_schema(&events));
+    let stream = encode_events_to_arrow_ipc_stream(&events, Some(schema)).context(EncodeSnafu)?;
+
+    let mut messages = split_ipc_stream(stream)?;
+    if let Some(first) = messages.first_mut() {
+        first.flight_descriptor = Some(FlightDescriptor {
+            r#type: DescriptorType::Path as i32,
+            cmd: Vec::new(),
+            path: vec![path.to_string()],
+        });
+    }
+    Ok(messages)
+}
+
+/// Returns the descriptor path of a `DoPut` stream.
+pub fn descriptor_path(
+    descriptor: Option<&FlightDescriptor>,
+) -> Result<&'static str, ArrowFlightError> {
+    match descriptor.map(|descriptor| descriptor.path.as_slice()) {
+        Some([path]) if path == LOGS_PATH => Ok(LOGS_PATH),
+        Some([path]) if path == TRACES_PATH => Ok(TRACES_PATH),
+        _ => Err(ArrowFlightError::UnsupportedDescriptor),
+    }
+}
+
+/// Splits an Arrow IPC stream into one `FlightData` message per IPC message.
+fn split_ipc_stream(mut stream: Bytes) -> Result<Vec<FlightData>, ArrowFlightError> {
+    let invalid = |message: &str| ArrowFlightError::InvalidMessage {
+        message: message.to_string(),
+    };
+
+    let mut messages = Vec::new();
+    while stream.remaining() >= 8 {
+        if stream.get_u32_le() != CONTINUATION_MARKER {
+            return Err(invalid("missing continuation marker"));
+        }
+        let header_len = stream.get_i32_le();
+        if header_len <= 0 {
+            // End of stream.
+            break;
+        }
+        let header_len = header_len as usize;
+        if stream.remaining() < header_len {
+            return Err(invalid("truncated message header"));
+        }
+        let header = stream.split_to(header_len);
+        let body_len = root_as_message(&header)
+            .map_err(|error| invalid(&error.to_string()))?
+            .bodyLength() as usize;
+        if stream.remaining() < body_len {
+            return Err(invalid("truncated message body"));
+        }
+        let body = stream.split_to(body_len);
+
+        messages.push(FlightData {
+            flight_descriptor: None,
+            data_header: header.to_vec(),
+            app_metadata: Vec::new(),
+            data_body: body.to_vec(),
+        });
+    }
+    Ok(messages)
+}
+
+/// Decodes the messages of a `DoPut` stream into events, one record batch at a time.
+#[derive(Debug)]
+pub struct FlightDataDecoder {
+    path: &'static str,
+    /// The schema and dictionary messages preceding the next record batch.
+    prefix: Vec<FlightData>,
+}
+
+impl FlightDataDecoder {
+    pub const fn new(path: &'static str) -> Self {
+        Self {
+            path,
+            prefix: Vec::new(),
+        }
+    }
+
+    /// Handles the next message of the stream, returning the decoded events if it contained a
+    /// record batch.
+    pub fn push(&mut self, data: FlightData) -> Result<Option<Vec<Event>>, ArrowFlightError> {
+        if data.data_header.is_empty() {
+            // Descriptor-only messages carry no Arrow data.
+            return Ok(None);
+        }
+
+        let header_type = root_as_message(&data.data_header)
+            .map_err(|error| ArrowFlightError::InvalidMessage {
+                message: error.to_string(),
+            })?
+            .header_type();
+
+        match header_type {
+            MessageHeader::Schema => {
+                self.prefix = vec![data];
+                Ok(None)
+            }
+            MessageHeader::DictionaryBatch => {
+                self.prefix.push(data);
+                Ok(None)
+            }
+            MessageHeader::RecordBatch => {
+                let stream = assemble_ipc_stream(self.prefix.iter().chain(std::iter::once(&data)));
+                let logs = decode_arrow_ipc_stream(stream).context(DecodeSnafu)?;
+                let events = if self.path == TRACES_PATH {
+                    logs.into_iter()
+                        .map(|log| Event::Trace(TraceEvent::from(log)))
+                        .collect()
+                } else {
+                    logs.into_iter().map(Event::Log).collect()
+                };
+                Ok(Some(events))
+            }
+            _ => Err(ArrowFlightError::InvalidMessage {
+                message: "unexpected message type".to_string(),
+            }),
+        }
+    }
+}
+
+/// Reassembles `FlightData` messages into an Arrow IPC stream.
+fn assemble_ipc_stream<'a>(messages: impl Iterator<Item = &'a FlightData>) -> Bytes {
+    let mut stream = BytesMut::new();
+    for message in messages {
+        stream.put_u32_le(CONTINUATION_MARKER);
+        stream.put_i32_le(message.data_header.len() as i32);
+        stream.put_slice(&message.data_header);
+        stream.put_slice(&message.data_body);
+    }
+    stream.put_u32_le(CONTINUATION_MARKER);
+    stream.put_i32_le(0);
+    stream.freeze()
+}
+
+#[cfg(test)]
+mod tests {
+    use vrl::btreemap;
+
+    use super::*;
+    use crate::event::Value;
+
+    #[test]
+    fn round_trip_logs() {
+        let mut log = LogEvent::from("hello");
+        log.insert("count", 3);
+        let events = vec![Event::Log(log.clone()), Event::Log(LogEvent::from("world"))];
+
+        let messages = encode_flight_data(events, LOGS_PATH).unwrap();
+        assert_eq!(
+            descriptor_path(messages[0].flight_descriptor.as_ref()).unwrap(),
+            LOGS_PATH
+        );
+
+        let mut decoder = FlightDataDecoder::new(LOGS_PATH);
+        let decoded = messages
+            .into_iter()
+            .filter_map(|message| decoder.push(message).unwrap())
+            .flatten()
+            .collect::<Vec<_>>();
+
+        assert_eq!(decoded.len(), 2);
+        assert_eq!(decoded[0].as_log().get("message"), log.get("message"));
+        assert_eq!(decoded[0].as_log().get("count"), Some(&Value::from(3)));
+        assert_eq!(
+            decoded[1].as_log().get("message"),
+            Some(&Value::from("world"))
+        );
+    }
+
+    #[test]
+    fn round_trip_traces() {
+        let trace = TraceEvent::from(btreemap! {
+            "trace_id" => "abc",
+            "name" => "GET /users",
+            "attributes" => btreemap! { "http.route" => "/users" },
+        });
+
+        let messages = encode_flight_data(vec![Event::Trace(trace.clone())], TRACES_PATH).unwrap();
+        let path = descriptor_path(messages[0].flight_descriptor.as_ref()).unwrap();
+
+        let mut decoder = FlightDataDecoder::new(path);
+        let decoded = messages
+            .into_iter()
+            .filter_map(|message| decoder.push(message).unwrap())
+            .flatten()
+            .collect::<Vec<_>>();
+
+        assert_eq!(decoded.len(), 1);
+        assert_eq!(decoded[0].as_trace().as_map(), trace.as_map());
+    }
+
+    #[test]
+    fn rejects_unknown_descriptor() {
+        let descriptor = FlightDescriptor {
+            r#type: DescriptorType::Path as i32,
+            cmd: Vec::new(),
+            path: vec!["metrics".to_string()],
+        };
+        assert!(descriptor_path(Some(&descriptor)).is_err());
+        assert!(descriptor_path(None).is_err());
+    }
+}
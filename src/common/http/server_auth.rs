@@ -51,6 +51,15 @@ pub enum HttpServerAuthConfig {
         password: SensitiveString,
     },
 
+    /// Bearer authentication.
+    ///
+    /// The token is passed in the `Authorization` header, using the `Bearer` scheme.
+    Bearer {
+        /// The bearer authentication token.
+        #[configurable(metadata(docs::examples = "${TOKEN}"))]
+        token: SensitiveString,
+    },
+
     /// Custom authentication using VRL code.
     ///
     /// Takes in request and validates it using VRL code.
@@ -68,13 +77,13 @@ impl<'de> Deserialize<'de> for HttpServerAuthConfig {
     {
         struct HttpServerAuthConfigVisitor;
 
-        const FIELD_KEYS: [&str; 4] = ["strategy", "username", "password", "source"];
+        const FIELD_KEYS: [&str; 5] = ["strategy", "username", "password", "token", "source"];
 
         impl<'de> Visitor<'de> for HttpServerAuthConfigVisitor {
             type Value = HttpServerAuthConfig;
 
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                formatter.write_str("a valid authentication strategy (basic or custom)")
+                formatter.write_str("a valid authentication strategy (basic, bearer or custom)")
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<HttpServerAuthConfig, A::Error>
@@ -113,13 +122,24 @@ impl<'de> Deserialize<'de> for HttpServerAuthConfig {
                             password: SensitiveString::from(password),
                         })
                     }
+                    "bearer" => {
+                        let token = fields
+                            .remove("token")
+                            .ok_or_else(|| Error::missing_field("token"))?;
+                        Ok(HttpServerAuthConfig::Bearer {
+                            token: SensitiveString::from(token),
+                        })
+                    }
                     "custom" => {
                         let source = fields
                             .remove("source")
                             .ok_or_else(|| Error::missing_field("source"))?;
                         Ok(HttpServerAuthConfig::Custom { source })
                     }
-                    _ => Err(Error::unknown_variant(strategy, &["basic", "custom"])),
+                    _ => Err(Error::unknown_variant(
+                        strategy,
+                        &["basic", "bearer", "custom"],
+                    )),
                 }
             }
         }
@@ -143,6 +163,13 @@ impl HttpServerAuthConfig {
                     "Invalid username/password",
                 ))
             }
+            HttpServerAuthConfig::Bearer { token } => Ok(HttpServerAuthMatcher::AuthHeader(
+                Authorization::bearer(token.inner())
+                    .map_err(|_| "Invalid bearer token")?
+                    .0
+                    .encode(),
+                "Invalid token",
+            )),
             HttpServerAuthConfig::Custom { source } => {
                 let functions = vrl::stdlib::all()
                     .into_iter()
@@ -339,6 +366,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn config_should_support_bearer_strategy() {
+        let config: HttpServerAuthConfig = serde_yaml::from_str(indoc! { r#"
+            strategy: bearer
+            token: foo
+            "#
+        })
+        .unwrap();
+
+        if let HttpServerAuthConfig::Bearer { token } = config {
+            assert_eq!(token.inner(), "foo");
+        } else {
+            panic!("Expected HttpServerAuthConfig::Bearer");
+        }
+    }
+
     #[test]
     fn config_should_support_custom_strategy() {
         let config: HttpServerAuthConfig = serde_yaml::from_str(indoc! { r#"
@@ -493,6 +536,36 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn bearer_auth_matcher_should_only_accept_matching_token() {
+        let token = random_string(16);
+        let bearer_auth = HttpServerAuthConfig::Bearer {
+            token: token.clone().into(),
+        };
+
+        let matcher = bearer_auth.build(&Default::default()).unwrap();
+        let (_guard, addr) = next_addr();
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            AUTHORIZATION,
+            Authorization::bearer(&token).unwrap().0.encode(),
+        );
+        assert!(matcher.handle_auth(Some(&addr), &headers, "/").is_ok());
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            AUTHORIZATION,
+            Authorization::bearer(&random_string(16))
+                .unwrap()
+                .0
+                .encode(),
+        );
+        let error = matcher.handle_auth(Some(&addr), &headers, "/").unwrap_err();
+        assert_eq!(401, error.code());
+        assert_eq!("Invalid token", error.message());
+    }
+
     #[test]
     fn custom_auth_matcher_should_return_ok_for_true_vrl_script_result() {
         let custom_auth = HttpServerAuthConfig::Custom {
@@ -21,6 +21,9 @@ pub(crate) mod s3;
 #[cfg(any(feature = "sources-websocket", feature = "sinks-websocket"))]
 pub(crate) mod websocket;
 
+#[cfg(any(feature = "sources-opentelemetry", feature = "sinks-opentelemetry"))]
+pub(crate) mod arrow_flight;
+
 pub(crate) mod backoff;
 #[cfg(any(feature = "sources-mqtt", feature = "sinks-mqtt",))]
 /// Common MQTT configuration shared by MQTT components.
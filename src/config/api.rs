@@ -1,11 +1,14 @@
 use std::net::{Ipv4Addr, SocketAddr};
 
 use url::Url;
-use vector_lib::configurable::configurable_component;
+use vector_lib::{configurable::configurable_component, tls::TlsEnableableConfig};
+
+#[cfg(feature = "api")]
+use crate::common::http::server_auth::HttpServerAuthConfig;
 
 /// API options.
 #[configurable_component(api("api"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[serde(default, deny_unknown_fields)]
 pub struct Options {
     /// Whether the GraphQL API is enabled for this Vector instance.
@@ -36,6 +39,25 @@ pub struct Options {
     #[serde(default = "default_graphql", skip_serializing_if = "is_true")]
     #[configurable(metadata(docs::common = true, docs::required = false))]
     pub graphql: bool,
+
+    /// Configures the TLS options for the API server.
+    ///
+    /// When TLS is enabled, clients such as `vector top` and `vector tap` must connect using an
+    /// `https://` URL.
+    #[configurable(derived)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<TlsEnableableConfig>,
+
+    /// Configures the authentication required to access the API server.
+    ///
+    /// Authentication is required for the `/graphql` and `/playground` endpoints, but not for the
+    /// `/health` endpoint, so that it can still be used for health checks. The API should only be
+    /// exposed with authentication when TLS is enabled, as credentials are otherwise sent in
+    /// plain text.
+    #[cfg(feature = "api")]
+    #[configurable(derived)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<HttpServerAuthConfig>,
 }
 
 impl_generate_config_from_default!(Options);
@@ -47,6 +69,9 @@ impl Default for Options {
             playground: default_playground(),
             address: default_address(),
             graphql: default_graphql(),
+            tls: None,
+            #[cfg(feature = "api")]
+            auth: None,
         }
     }
 }
@@ -102,11 +127,18 @@ impl Options {
             }
         };
 
+        let tls = merge_optional("tls", self.tls.take(), other.tls)?;
+        #[cfg(feature = "api")]
+        let auth = merge_optional("auth", self.auth.take(), other.auth)?;
+
         let options = Options {
             address,
             enabled: self.enabled | other.enabled,
             playground: self.playground & other.playground,
             graphql: self.graphql & other.graphql,
+            tls,
+            #[cfg(feature = "api")]
+            auth,
         };
 
         *self = options;
@@ -114,6 +146,17 @@ impl Options {
     }
 }
 
+fn merge_optional<T: PartialEq>(
+    name: &str,
+    a: Option<T>,
+    b: Option<T>,
+) -> Result<Option<T>, String> {
+    match (a, b) {
+        (Some(a), Some(b)) if a != b => Err(format!("Conflicting `api` {name} options.")),
+        (a, b) => Ok(a.or(b)),
+    }
+}
+
 #[test]
 fn bool_merge() {
     let mut a = Options {
@@ -121,6 +164,7 @@ fn bool_merge() {
         address: None,
         playground: false,
         graphql: false,
+        ..Options::default()
     };
 
     a.merge(Options::default()).unwrap();
@@ -131,7 +175,8 @@ fn bool_merge() {
             enabled: true,
             address: default_address(),
             playground: false,
-            graphql: false
+            graphql: false,
+            ..Options::default()
         }
     );
 }
@@ -144,6 +189,7 @@ fn bind_merge() {
         address: Some(address),
         playground: true,
         graphql: true,
+        ..Options::default()
     };
 
     a.merge(Options::default()).unwrap();
@@ -155,6 +201,7 @@ fn bind_merge() {
             address: Some(address),
             playground: true,
             graphql: true,
+            ..Options::default()
         }
     );
 }
@@ -173,3 +220,26 @@ fn bind_conflict() {
 
     assert!(a.merge(b).is_err());
 }
+
+#[cfg(feature = "api")]
+#[test]
+fn auth_merge() {
+    let auth = HttpServerAuthConfig::Bearer {
+        token: "token".to_string().into(),
+    };
+    let mut a = Options {
+        auth: Some(auth.clone()),
+        ..Options::default()
+    };
+
+    a.merge(Options::default()).unwrap();
+    assert_eq!(a.auth, Some(auth));
+
+    let b = Options {
+        auth: Some(HttpServerAuthConfig::Bearer {
+            token: "other".to_string().into(),
+        }),
+        ..Options::default()
+    };
+    assert!(a.merge(b).is_err());
+}
@@ -3,7 +3,12 @@ use std::path::PathBuf;
 use clap::Parser;
 use serde_json::Value;
 
-use super::{ConfigBuilder, load_source_from_paths, loading::ConfigBuilderLoader, process_paths};
+use super::{
+    ConfigBuilder, load_source_from_paths,
+    loading::ConfigBuilderLoader,
+    process_paths,
+    redaction::{DEFAULT_SENSITIVE_ENVIRONMENT_VARIABLES, Redactor},
+};
 use crate::{cli::handle_config_errors, config};
 
 #[derive(Parser, Debug, Clone)]
@@ -17,6 +22,21 @@ pub struct Opts {
     #[arg(short, long)]
     include_defaults: bool,
 
+    /// Show the values of secrets instead of redacting them. Secrets are the values of sensitive
+    /// fields, and values interpolating secrets or environment variables whose names suggest they
+    /// hold secrets, such as `${API_TOKEN}`.
+    #[arg(long)]
+    show_secrets: bool,
+
+    /// Parts of environment variable names, regardless of case, that suggest the variables hold
+    /// secrets. Values interpolating these environment variables are redacted.
+    #[arg(
+        long,
+        value_delimiter(','),
+        default_values_t = DEFAULT_SENSITIVE_ENVIRONMENT_VARIABLES.map(String::from)
+    )]
+    sensitive_env_vars: Vec<String>,
+
     /// Read configuration from one or more files. Wildcard paths are supported.
     /// File format is detected from the file name.
     /// If zero files are specified, the deprecated default config path
@@ -134,6 +154,7 @@ fn serialize_to_json(
     source: toml::value::Table,
     source_builder: &ConfigBuilder,
     include_defaults: bool,
+    redactor: Option<&Redactor>,
     pretty_print: bool,
 ) -> serde_json::Result<String> {
     // Convert table to JSON
@@ -155,6 +176,10 @@ fn serialize_to_json(
         source_json = builder
     }
 
+    if let Some(redactor) = redactor {
+        redactor.redact(&mut source_json);
+    }
+
     sort_json_array_values(&mut source_json);
 
     // Get a JSON string. This will either be pretty printed or (default) minified.
@@ -170,6 +195,20 @@ fn serialize_to_json(
 /// Pipelines expansions, etc. The JSON result of this serialization can itself be used as a config,
 /// which also makes it useful for version control or treating as a singular unit of configuration.
 pub fn cmd(opts: &Opts) -> exitcode::ExitCode {
+    match normalized_config(opts) {
+        Ok(json) => {
+            #[allow(clippy::print_stdout)]
+            {
+                println!("{json}");
+            }
+
+            exitcode::OK
+        }
+        Err(code) => code,
+    }
+}
+
+fn normalized_config(opts: &Opts) -> Result<String, exitcode::ExitCode> {
     let paths = opts.paths_with_formats();
     // Start by serializing to a `ConfigBuilder`. This will leverage validation in config
     // builder fields which we'll use to error out if required.
@@ -180,32 +219,49 @@ pub fn cmd(opts: &Opts) -> exitcode::ExitCode {
                 .load_from_paths(&paths)
             {
                 Ok(builder) => (paths, builder),
-                Err(errs) => return handle_config_errors(errs),
+                Err(errs) => return Err(handle_config_errors(errs)),
             }
         }
-        None => return exitcode::CONFIG,
+        None => return Err(exitcode::CONFIG),
     };
 
     // Load source TOML.
     let source = match load_source_from_paths(&paths) {
         Ok(map) => map,
-        Err(errs) => return handle_config_errors(errs),
+        Err(errs) => return Err(handle_config_errors(errs)),
     };
 
-    let json = serialize_to_json(source, &builder, opts.include_defaults, opts.pretty);
+    let redactor = if opts.show_secrets {
+        None
+    } else {
+        match Redactor::new(&opts.sensitive_env_vars) {
+            Ok(redactor) => Some(redactor),
+            Err(error) => {
+                error!(
+                    message = "Failed to generate the config schema to redact secrets.",
+                    ?error
+                );
+                return Err(exitcode::SOFTWARE);
+            }
+        }
+    };
 
-    #[allow(clippy::print_stdout)]
-    {
-        println!("{}", json.expect("config should be serializable"));
-    }
+    let json = serialize_to_json(
+        source,
+        &builder,
+        opts.include_defaults,
+        redactor.as_ref(),
+        opts.pretty,
+    );
 
-    exitcode::OK
+    Ok(json.expect("config should be serializable"))
 }
 
 #[cfg(all(test, feature = "sources", feature = "transforms", feature = "sinks"))]
 mod tests {
     use std::collections::HashMap;
 
+    use clap::Parser;
     use proptest::{num, prelude::*, sample};
     use rand::{
         SeedableRng,
@@ -217,11 +273,12 @@ mod tests {
         SinkDescription, SourceDescription, TransformDescription,
     };
 
-    use super::merge_json;
+    use super::{Opts, merge_json, normalized_config};
     use crate::{
-        config::{ConfigBuilder, Format, cmd::serialize_to_json, vars},
+        config::{ConfigBuilder, Format, cmd::serialize_to_json, redaction::REDACTED, vars},
         generate,
         generate::{TransformInputsStrategy, generate_example},
+        test_util::temp_file,
     };
 
     #[test]
@@ -273,6 +330,7 @@ mod tests {
                 toml::from_str(config_source.as_ref()).unwrap(),
                 &ConfigBuilder::from_toml(interpolated_config_source.as_ref()),
                 true,
+                None,
                 false,
             )
             .unwrap()
@@ -290,6 +348,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn redacts_secrets_by_default() {
+        let path = temp_file().with_extension("toml");
+        std::fs::write(
+            &path,
+            r#"
+            [sources.in]
+            type = "demo_logs"
+            format = "syslog"
+
+            [sinks.out]
+            type = "http"
+            inputs = ["in"]
+            uri = "https://example.com"
+            encoding.codec = "json"
+            auth.strategy = "basic"
+            auth.user = "SECRET[vault.user]"
+            auth.password = "hunter2"
+        "#,
+        )
+        .unwrap();
+        let config = |args: &[&str]| -> serde_json::Value {
+            let opts = Opts::parse_from(
+                ["config", "--config", path.to_str().unwrap()]
+                    .iter()
+                    .chain(args),
+            );
+            serde_json::from_str(&normalized_config(&opts).unwrap()).unwrap()
+        };
+
+        let json = config(&[]);
+        assert_eq!(json["sinks"]["out"]["auth"]["strategy"], json!("basic"));
+        assert_eq!(json["sinks"]["out"]["auth"]["user"], json!(REDACTED));
+        assert_eq!(json["sinks"]["out"]["auth"]["password"], json!(REDACTED));
+
+        let json = config(&["--show-secrets"]);
+        assert_eq!(
+            json["sinks"]["out"]["auth"]["user"],
+            json!("SECRET[vault.user]")
+        );
+        assert_eq!(json["sinks"]["out"]["auth"]["password"], json!("hunter2"));
+    }
+
     /// Select any 2-4 sources
     fn arb_sources() -> impl Strategy<Value = Vec<&'static str>> {
         let mut types = SourceDescription::types();
@@ -370,6 +471,7 @@ mod tests {
                 toml::from_str(config_source.as_ref()).unwrap(),
                 &ConfigBuilder::from_toml(config_source.as_ref()),
                 false,
+                None,
                 false
             )
             .unwrap();
@@ -377,6 +479,7 @@ mod tests {
                 toml::from_str(shuffled_config_source.as_ref()).unwrap(),
                 &ConfigBuilder::from_toml(shuffled_config_source.as_ref()),
                 false,
+                None,
                 false
             )
             .unwrap();
@@ -394,6 +497,7 @@ mod tests {
                 toml::from_str(config_source.as_ref()).unwrap(),
                 &ConfigBuilder::from_toml(config_source.as_ref()),
                 false,
+                None,
                 false
             )
             .unwrap();
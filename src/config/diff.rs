@@ -25,7 +25,8 @@ impl ConfigDiff {
         ConfigDiff {
             sources: Difference::new(&old.sources, &new.sources, &components_to_reload),
             transforms: Difference::new(&old.transforms, &new.transforms, &components_to_reload),
-            sinks: Difference::new(&old.sinks, &new.sinks, &components_to_reload),
+            sinks: Difference::new(&old.sinks, &new.sinks, &components_to_reload)
+                .with_rerouting_changes(old, new),
             enrichment_tables: Difference::from_enrichment_tables(
                 &old.enrichment_tables,
                 &new.enrichment_tables,
@@ -113,6 +114,21 @@ impl Difference {
         }
     }
 
+    /// Marks the sinks which start or stop rerouting dropped events as changed, as they only
+    /// track the events they receive while components consume their `dropped` output.
+    fn with_rerouting_changes(mut self, old: &Config, new: &Config) -> Self {
+        self.to_change.extend(
+            old.sinks
+                .keys()
+                .filter(|key| {
+                    new.sinks.contains_key(*key)
+                        && old.reroutes_dropped(key) != new.reroutes_dropped(key)
+                })
+                .cloned(),
+        );
+        self
+    }
+
     fn from_enrichment_tables(
         old: &IndexMap<ComponentKey, EnrichmentTableOuter<OutputId>>,
         new: &IndexMap<ComponentKey, EnrichmentTableOuter<OutputId>>,
@@ -68,6 +68,7 @@ where
                     healthcheck: Default::default(),
                     buffer: Default::default(),
                     proxy: Default::default(),
+                    reroute_dropped: false,
                     inner: sink,
                 },
             )
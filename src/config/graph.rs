@@ -21,6 +21,7 @@ pub enum Node {
     },
     Sink {
         ty: DataType,
+        outputs: Vec<TransformOutput>,
     },
 }
 
@@ -44,8 +45,15 @@ impl fmt::Display for Node {
                 }
                 Ok(())
             }
-            Node::Sink { ty } => {
-                write!(f, "component_kind: sink\n  types: {ty}")
+            Node::Sink { ty, outputs } => {
+                write!(f, "component_kind: sink\n  types: {ty}")?;
+                if !outputs.is_empty() {
+                    write!(f, "\n  outputs:")?;
+                    for output in outputs {
+                        write!(f, "\n    {output}")?;
+                    }
+                }
+                Ok(())
             }
         }
     }
@@ -125,6 +133,7 @@ impl Graph {
                 id.clone(),
                 Node::Sink {
                     ty: config.inner.input().data_type(),
+                    outputs: config.outputs(),
                 },
             );
         }
@@ -213,7 +222,7 @@ impl Graph {
         match self.nodes[key] {
             Node::Source { .. } => panic!("no inputs on sources"),
             Node::Transform { in_ty, .. } => in_ty,
-            Node::Sink { ty } => ty,
+            Node::Sink { ty, .. } => ty,
         }
     }
 
@@ -221,8 +230,8 @@ impl Graph {
     ///
     /// # Panics
     ///
-    /// Will panic if the given id is not present in the graph or identifies a sink, which can't
-    /// have inputs.
+    /// Will panic if the given id is not present in the graph or identifies an output that doesn't
+    /// exist.
     fn get_output_type(&self, id: &OutputId) -> DataType {
         match &self.nodes[&id.component] {
             Node::Source { outputs } => outputs
@@ -230,12 +239,11 @@ impl Graph {
                 .find(|output| output.port == id.port)
                 .map(|output| output.ty)
                 .expect("output didn't exist"),
-            Node::Transform { outputs, .. } => outputs
+            Node::Transform { outputs, .. } | Node::Sink { outputs, .. } => outputs
                 .iter()
                 .find(|output| output.port == id.port)
                 .map(|output| output.ty)
                 .expect("output didn't exist"),
-            Node::Sink { .. } => panic!("no outputs on sinks"),
         }
     }
 
@@ -320,7 +328,6 @@ impl Graph {
         self.nodes
             .iter()
             .flat_map(|(key, node)| match node {
-                Node::Sink { .. } => vec![],
                 Node::Source { outputs } => outputs
                     .iter()
                     .map(|output| OutputId {
@@ -328,7 +335,7 @@ impl Graph {
                         port: output.port.clone(),
                     })
                     .collect(),
-                Node::Transform { outputs, .. } => outputs
+                Node::Transform { outputs, .. } | Node::Sink { outputs, .. } => outputs
                     .iter()
                     .map(|output| OutputId {
                         component: key.clone(),
@@ -378,9 +385,10 @@ impl Graph {
             .collect()
     }
 
-    /// From a given root node, get all paths from the root node to leaf nodes
-    /// where the leaf node must be a sink. This is useful for determining which
-    /// components are relevant in a Vector unit test.
+    /// From a given root node, get all paths from the root node to sinks. Paths
+    /// continue past sinks with a `dropped` output, so a path ends at every sink
+    /// along the way. This is useful for determining which components are
+    /// relevant in a Vector unit test.
     ///
     /// Caller must check for cycles before calling this function.
     pub fn paths_to_sink_from(&self, root: &ComponentKey) -> Vec<Vec<ComponentKey>> {
@@ -398,12 +406,11 @@ impl Graph {
                 .map(|e| e.to.clone())
                 .collect::<Vec<_>>();
 
-            if neighbors.is_empty() {
+            if neighbors.is_empty() || matches!(self.nodes.get(&n), Some(Node::Sink { .. })) {
                 paths.push(path.clone());
-            } else {
-                for neighbor in neighbors {
-                    traversal.push_back((neighbor, path.clone()));
-                }
+            }
+            for neighbor in neighbors {
+                traversal.push_back((neighbor, path.clone()));
             }
         }
 
@@ -412,7 +419,7 @@ impl Graph {
             .into_iter()
             .filter(|path| {
                 if let Some(key) = path.last() {
-                    matches!(self.nodes.get(key), Some(Node::Sink { ty: _ }))
+                    matches!(self.nodes.get(key), Some(Node::Sink { .. }))
                 } else {
                     false
                 }
@@ -486,7 +493,13 @@ mod test {
         fn add_sink(&mut self, id: &str, ty: DataType, inputs: Vec<&str>) {
             let id = ComponentKey::from(id);
             let inputs = clean_inputs(inputs);
-            self.nodes.insert(id.clone(), Node::Sink { ty });
+            self.nodes.insert(
+                id.clone(),
+                Node::Sink {
+                    ty,
+                    outputs: Vec::new(),
+                },
+            );
             for from in inputs {
                 self.edges.push(Edge {
                     from,
@@ -495,6 +508,15 @@ mod test {
             }
         }
 
+        fn add_sink_output(&mut self, id: &str, name: &str) {
+            let id = id.into();
+            match self.nodes.get_mut(&id) {
+                Some(Node::Sink { ty, outputs }) => outputs
+                    .push(TransformOutput::new(*ty, Default::default()).with_port(name)),
+                _ => panic!("invalid sink"),
+            }
+        }
+
         fn test_add_input(
             &mut self,
             node: &str,
@@ -867,6 +889,31 @@ mod test {
         assert_eq!(paths[1], vec!["source", "t4", "sink1"]);
     }
 
+    #[test]
+    fn paths_to_sink_through_dropped_output() {
+        let mut graph = Graph::default();
+        graph.add_source("source", DataType::Log);
+        graph.add_sink("primary", DataType::Log, vec!["source"]);
+        graph.add_sink_output("primary", "dropped");
+        graph.add_sink("dead_letter", DataType::Log, vec![]);
+        graph
+            .test_add_input("dead_letter", "primary.dropped", WildcardMatching::Strict)
+            .unwrap();
+
+        assert_eq!(graph.typecheck(), Ok(()));
+        assert_eq!(graph.check_for_cycles(), Ok(()));
+
+        let paths: Vec<Vec<_>> = graph
+            .paths_to_sink_from(&ComponentKey::from("source"))
+            .into_iter()
+            .map(|keys| keys.into_iter().map(|key| key.to_string()).collect())
+            .collect();
+
+        assert_eq!(paths.len(), 2);
+        assert_eq!(paths[0], vec!["source", "primary"]);
+        assert_eq!(paths[1], vec!["source", "primary", "dead_letter"]);
+    }
+
     #[test]
     fn paths_to_sink_multiple_inputs_into_sink() {
         let mut graph = Graph::default();
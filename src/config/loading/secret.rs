@@ -2,13 +2,16 @@ use std::{
     collections::{HashMap, HashSet},
     io::Read,
     sync::LazyLock,
+    time::{Duration, Instant},
 };
 
 use futures::TryFutureExt;
 use indexmap::IndexMap;
 use regex::{Captures, Regex};
 use serde::{Deserialize, Serialize};
-use toml::value::Table;
+use tokio::time::MissedTickBehavior;
+use tokio_util::sync::CancellationToken;
+use toml::{Value, value::Table};
 use vector_lib::config::ComponentKey;
 
 use crate::{
@@ -30,6 +33,10 @@ use crate::{
 pub static COLLECTOR: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"SECRET\[([[:word:]]+)\.([[:word:].-]+)\]").unwrap());
 
+/// The least time left until secrets expire for the configuration to still be reloaded to retrieve
+/// them again.
+const MIN_RELOAD_BEFORE_EXPIRY: Duration = Duration::from_secs(1);
+
 /// Helper type for specifically deserializing secrets backends.
 #[derive(Debug, Default, Deserialize, Serialize)]
 pub(crate) struct SecretBackendOuter {
@@ -37,11 +44,73 @@ pub(crate) struct SecretBackendOuter {
     pub(crate) secret: IndexMap<ComponentKey, SecretBackends>,
 }
 
+/// Where secrets are referenced in the config, by `<backend_name>.<secret_key>`.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+struct SecretReferences {
+    /// The components referencing each secret.
+    components: HashMap<String, HashSet<ComponentKey>>,
+    /// The secrets referenced outside of components, such as in global options.
+    outside_components: HashSet<String>,
+}
+
+impl SecretReferences {
+    fn collect(&mut self, table: &Table, hint: Option<ComponentHint>) {
+        match hint {
+            Some(ComponentHint::Test) => {}
+            Some(_) => self.collect_components(table),
+            None => {
+                for (field, value) in table {
+                    match (field.as_str(), value) {
+                        ("secret" | "tests", _) => {}
+                        (
+                            "sources" | "transforms" | "sinks" | "enrichment_tables",
+                            Value::Table(components),
+                        ) => self.collect_components(components),
+                        _ => collect_value_secrets(value, &mut |secret| {
+                            self.outside_components.insert(secret);
+                        }),
+                    }
+                }
+            }
+        }
+    }
+
+    fn collect_components(&mut self, components: &Table) {
+        for (key, value) in components {
+            collect_value_secrets(value, &mut |secret| {
+                self.components
+                    .entry(secret)
+                    .or_default()
+                    .insert(ComponentKey::from(key.as_str()));
+            });
+        }
+    }
+
+    /// Returns the components to reload for the given secrets to take effect, or `None` if the
+    /// whole config has to be reloaded.
+    fn components_to_reload<'a>(
+        &self,
+        secrets: impl IntoIterator<Item = &'a String>,
+    ) -> Option<HashSet<ComponentKey>> {
+        let mut components = HashSet::new();
+        for secret in secrets {
+            if self.outside_components.contains(secret) {
+                return None;
+            }
+            if let Some(keys) = self.components.get(secret) {
+                components.extend(keys.iter().cloned());
+            }
+        }
+        Some(components)
+    }
+}
+
 /// Loader for secrets backends.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct SecretBackendLoader {
     backends: IndexMap<ComponentKey, SecretBackends>,
     secret_keys: HashMap<String, HashSet<String>>,
+    references: SecretReferences,
     interpolate_env: bool,
 }
 
@@ -60,11 +129,14 @@ impl SecretBackendLoader {
     ) -> Result<HashMap<String, String>, String> {
         if self.secret_keys.is_empty() {
             debug!(message = "No secret placeholder found, skipping secret resolution.");
+            signal_handler.renew_secrets_refresh();
             return Ok(HashMap::new());
         }
 
         debug!(message = "Secret placeholders found, retrieving secrets from configured backends.");
         let mut secrets: HashMap<String, String> = HashMap::new();
+        let mut expires_at: Option<Instant> = None;
+        let mut refreshes = Vec::new();
         let mut signal_rx = signal_handler.subscribe();
 
         for (backend_name, keys) in &self.secret_keys {
@@ -85,16 +157,156 @@ impl SecretBackendLoader {
                 })
                 .await?;
 
+            if let Some(interval) = backend.refresh_interval() {
+                refreshes.push((
+                    backend_name.clone(),
+                    backend.clone(),
+                    backend_secrets.clone(),
+                    interval,
+                ));
+            }
+
             for (k, v) in backend_secrets {
                 trace!(message = "Successfully retrieved a secret.", backend = ?backend_name, key = ?k);
                 secrets.insert(format!("{backend_name}.{k}"), v);
             }
+
+            if let Some(backend_expires_at) = backend.expires_at() {
+                debug!(message = "Retrieved secrets expire.", backend = ?backend_name);
+                expires_at =
+                    Some(expires_at.map_or(backend_expires_at, |at| at.min(backend_expires_at)));
+            }
+        }
+
+        // Only once all the secrets are retrieved are the tasks refreshing the ones of the previous
+        // load superseded, so that they keep running if loading the configuration fails before.
+        let cancel = signal_handler.renew_secrets_refresh();
+        for (backend_name, backend, backend_secrets, interval) in refreshes {
+            refresh_periodically(
+                backend_name,
+                backend,
+                backend_secrets,
+                self.references.clone(),
+                interval,
+                signal_handler.clone_tx(),
+                cancel.clone(),
+            );
+        }
+        if let Some(expires_at) = expires_at {
+            // Timers are based on the clock of the runtime, which isn't the one of the backends.
+            let expires_at =
+                tokio::time::Instant::now() + expires_at.saturating_duration_since(Instant::now());
+            reload_before_expiry(expires_at, signal_handler.clone_tx(), cancel);
         }
 
         Ok(secrets)
     }
 }
 
+/// Reloads the configuration once two thirds of the time left until the secrets expire have
+/// elapsed, as Vault Agent does to renew leases, so that the secrets are retrieved again.
+///
+/// The reload is given up on once `cancel` is cancelled, when the secrets are retrieved again by
+/// a load of the configuration. Until then, such as when the reload fails, it's retried once two
+/// thirds of the time left have elapsed again, until the secrets expire.
+fn reload_before_expiry(
+    expires_at: tokio::time::Instant,
+    signal_tx: signal::SignalTx,
+    cancel: CancellationToken,
+) {
+    tokio::spawn(async move {
+        loop {
+            let left = expires_at.saturating_duration_since(tokio::time::Instant::now());
+            if left < MIN_RELOAD_BEFORE_EXPIRY {
+                warn!("Secrets weren't retrieved again before expiring.");
+                break;
+            }
+
+            tokio::select! {
+                _ = cancel.cancelled() => break,
+                _ = tokio::time::sleep(left.mul_f64(2.0 / 3.0)) => {
+                    info!("Secrets are about to expire, reloading configuration.");
+                    if let Err(error) = signal_tx.send(signal::SignalTo::ReloadFromDisk) {
+                        error!(message = "Unable to reload configuration, secrets will expire.", cause = %error);
+                        break;
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// Retrieves the secrets of a backend again every `interval`, and reloads the components
+/// referencing the secrets whose values changed, or the whole config if some of them are referenced
+/// outside of components. The values of the secrets are never logged.
+///
+/// The refresh is given up on once `cancel` is cancelled, when the secrets are retrieved again by a
+/// load of the configuration. Until then, such as when the reload fails, the refreshed secrets are
+/// compared to the ones last refreshed.
+fn refresh_periodically(
+    backend_name: String,
+    mut backend: SecretBackends,
+    mut secrets: HashMap<String, String>,
+    references: SecretReferences,
+    interval: Duration,
+    signal_tx: signal::SignalTx,
+    cancel: CancellationToken,
+) {
+    tokio::spawn(async move {
+        let mut refresh =
+            tokio::time::interval_at(tokio::time::Instant::now() + interval, interval);
+        refresh.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                biased;
+
+                _ = cancel.cancelled() => break,
+                _ = refresh.tick() => {
+                    let keys = secrets.keys().cloned().collect();
+                    let refreshed = match backend.retrieve(keys, &mut signal_tx.subscribe()).await {
+                        Ok(refreshed) => refreshed,
+                        Err(error) => {
+                            warn!(message = "Unable to refresh secrets.", backend = ?backend_name, %error);
+                            continue;
+                        }
+                    };
+
+                    let changed = refreshed
+                        .iter()
+                        .filter(|(key, value)| secrets.get(*key) != Some(*value))
+                        .map(|(key, _)| format!("{backend_name}.{key}"))
+                        .collect::<Vec<_>>();
+                    if changed.is_empty() {
+                        debug!(message = "Refreshed secrets are unchanged.", backend = ?backend_name);
+                        continue;
+                    }
+
+                    let signal = match references.components_to_reload(&changed) {
+                        Some(components) => {
+                            info!(
+                                message = "Secrets changed, reloading the components referencing them.",
+                                secrets = ?changed,
+                                components = ?components,
+                            );
+                            signal::SignalTo::ReloadComponents(components)
+                        }
+                        None => {
+                            info!(message = "Secrets changed, reloading configuration.", secrets = ?changed);
+                            signal::SignalTo::ReloadFromDisk
+                        }
+                    };
+                    if let Err(error) = signal_tx.send(signal) {
+                        error!(message = "Unable to reload configuration, secrets changed.", cause = %error);
+                        break;
+                    }
+                    secrets = refreshed;
+                }
+            }
+        }
+    });
+}
+
 impl Default for SecretBackendLoader {
     /// Creates a new SecretBackendLoader with default settings.
     /// By default, environment variable interpolation is enabled.
@@ -102,6 +314,7 @@ impl Default for SecretBackendLoader {
         Self {
             backends: IndexMap::new(),
             secret_keys: HashMap::new(),
+            references: SecretReferences::default(),
             interpolate_env: true,
         }
     }
@@ -115,7 +328,8 @@ impl Process for SecretBackendLoader {
         Ok(config_string)
     }
 
-    fn merge(&mut self, table: Table, _: Option<ComponentHint>) -> Result<(), Vec<String>> {
+    fn merge(&mut self, table: Table, hint: Option<ComponentHint>) -> Result<(), Vec<String>> {
+        self.references.collect(&table, hint);
         if table.contains_key("secret") {
             let additional = deserialize_table::<SecretBackendOuter>(table)?;
             self.backends.extend(additional.secret);
@@ -145,6 +359,23 @@ fn collect_secret_keys(input: &str, keys: &mut HashMap<String, HashSet<String>>)
     });
 }
 
+fn collect_value_secrets(value: &Value, collect: &mut impl FnMut(String)) {
+    match value {
+        Value::String(s) => COLLECTOR.captures_iter(s).for_each(|cap| {
+            if let (Some(backend), Some(key)) = (cap.get(1), cap.get(2)) {
+                collect(format!("{}.{}", backend.as_str(), key.as_str()));
+            }
+        }),
+        Value::Array(values) => values
+            .iter()
+            .for_each(|value| collect_value_secrets(value, collect)),
+        Value::Table(table) => table
+            .values()
+            .for_each(|value| collect_value_secrets(value, collect)),
+        _ => {}
+    }
+}
+
 pub fn interpolate(input: &str, secrets: &HashMap<String, String>) -> Result<String, Vec<String>> {
     let mut errors = Vec::<String>::new();
     let output = COLLECTOR
@@ -171,11 +402,176 @@ pub fn interpolate(input: &str, secrets: &HashMap<String, String>) -> Result<Str
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
+    use std::{
+        collections::{HashMap, HashSet},
+        time::Duration,
+    };
+
+    use indoc::{formatdoc, indoc};
+    use tokio::time::Instant;
+    use tokio_util::sync::CancellationToken;
+    use vector_lib::config::ComponentKey;
+
+    use super::{
+        Loader, SecretBackendLoader, collect_secret_keys, interpolate, reload_before_expiry,
+    };
+    use crate::{
+        config::Format,
+        signal::{SignalHandler, SignalTo},
+        test_util::temp_file,
+    };
+
+    /// Retrieves the secrets of a `file` backend refreshed every second, then changes the value
+    /// of the `password` secret and returns the signal sent.
+    async fn refresh_changed_password(config: &str) -> SignalTo {
+        let path = temp_file();
+        std::fs::write(&path, r#"{"password": "a", "token": "b"}"#).unwrap();
+        let config = formatdoc! {r#"
+            [secret.file]
+            type = "file"
+            path = "{}"
+            refresh_interval_secs = 1
+
+            {config}
+        "#, path.display()};
+
+        let mut loader = SecretBackendLoader::default();
+        loader
+            .load_from_str(config.as_bytes(), Format::Toml)
+            .unwrap();
+        let (mut signal_handler, mut signal_rx) = SignalHandler::new();
+        let secrets = loader
+            .take()
+            .retrieve_secrets(&mut signal_handler)
+            .await
+            .unwrap();
+        assert_eq!(secrets["file.password"], "a");
+
+        std::fs::write(&path, r#"{"password": "c", "token": "b"}"#).unwrap();
+        signal_rx.recv().await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn reloads_components_referencing_refreshed_secrets() {
+        let signal = refresh_changed_password(indoc! {r#"
+            [sources.in]
+            type = "demo_logs"
+            token = "SECRET[file.token]"
+
+            [sinks.out]
+            type = "http"
+            inputs = ["in"]
+            auth.password = "SECRET[file.password]"
+        "#})
+        .await;
+
+        assert_eq!(
+            signal,
+            SignalTo::ReloadComponents(HashSet::from([ComponentKey::from("out")]))
+        );
+    }
+
+    #[tokio::test]
+    async fn reloads_config_for_refreshed_secrets_outside_components() {
+        let signal = refresh_changed_password(indoc! {r#"
+            [api]
+            password = "SECRET[file.password]"
 
-    use indoc::indoc;
+            [sinks.out]
+            type = "http"
+            auth.password = "SECRET[file.password]"
+        "#})
+        .await;
 
-    use super::{collect_secret_keys, interpolate};
+        assert_eq!(signal, SignalTo::ReloadFromDisk);
+    }
+
+    #[tokio::test]
+    async fn refreshes_once_after_reload_through_api() {
+        let path = temp_file();
+        std::fs::write(&path, r#"{"password": "a"}"#).unwrap();
+        let config = formatdoc! {r#"
+            [secret.file]
+            type = "file"
+            path = "{}"
+            refresh_interval_secs = 1
+
+            [sinks.out]
+            type = "http"
+            auth.password = "SECRET[file.password]"
+        "#, path.display()};
+
+        let (mut signal_handler, mut signal_rx) = SignalHandler::new();
+        let load = async |signal_handler: &mut SignalHandler| {
+            let mut loader = SecretBackendLoader::default();
+            loader
+                .load_from_str(config.as_bytes(), Format::Toml)
+                .unwrap();
+            loader
+                .take()
+                .retrieve_secrets(signal_handler)
+                .await
+                .unwrap();
+        };
+        load(&mut signal_handler).await;
+
+        // Reloads requested through the API are sent as `ReloadFromDisk` signals, which load the
+        // configuration again.
+        signal_handler
+            .clone_tx()
+            .send(SignalTo::ReloadFromDisk)
+            .unwrap();
+        assert_eq!(signal_rx.recv().await.unwrap(), SignalTo::ReloadFromDisk);
+        load(&mut signal_handler).await;
+
+        // Only the refresh of the last load is still running, and it keeps running after
+        // requesting a reload, in case the reload fails.
+        std::fs::write(&path, r#"{"password": "b"}"#).unwrap();
+        let reload = SignalTo::ReloadComponents(HashSet::from([ComponentKey::from("out")]));
+        assert_eq!(signal_rx.recv().await.unwrap(), reload);
+        assert!(
+            tokio::time::timeout(Duration::from_secs(2), signal_rx.recv())
+                .await
+                .is_err()
+        );
+
+        std::fs::write(&path, r#"{"password": "c"}"#).unwrap();
+        assert_eq!(signal_rx.recv().await.unwrap(), reload);
+
+        signal_handler.renew_secrets_refresh();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn reloads_before_secrets_expire() {
+        let (signal_handler, mut signal_rx) = SignalHandler::new();
+        reload_before_expiry(
+            Instant::now() + Duration::from_secs(30),
+            signal_handler.clone_tx(),
+            CancellationToken::new(),
+        );
+
+        assert_eq!(signal_rx.recv().await.unwrap(), SignalTo::ReloadFromDisk);
+        // Until the secrets are retrieved again, the reload is retried.
+        assert_eq!(signal_rx.recv().await.unwrap(), SignalTo::ReloadFromDisk);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn reload_before_expiry_given_up_once_cancelled() {
+        let (signal_handler, mut signal_rx) = SignalHandler::new();
+        let cancel = CancellationToken::new();
+        reload_before_expiry(
+            Instant::now() + Duration::from_secs(30),
+            signal_handler.clone_tx(),
+            cancel.clone(),
+        );
+
+        cancel.cancel();
+        assert!(
+            tokio::time::timeout(Duration::from_secs(60), signal_rx.recv())
+                .await
+                .is_err()
+        );
+    }
 
     #[test]
     fn replacement() {
@@ -38,6 +38,7 @@ pub mod format;
 mod graph;
 pub mod loading;
 pub mod provider;
+pub mod redaction;
 pub mod schema;
 mod secret;
 mod sink;
@@ -60,7 +61,9 @@ pub use loading::{
 };
 pub use provider::ProviderConfig;
 pub use secret::SecretBackend;
-pub use sink::{BoxedSink, SinkConfig, SinkContext, SinkHealthcheckOptions, SinkOuter};
+pub use sink::{
+    BoxedSink, DROPPED_OUTPUT, SinkConfig, SinkContext, SinkHealthcheckOptions, SinkOuter,
+};
 pub use source::{BoxedSource, SourceConfig, SourceContext, SourceOuter};
 pub use transform::{
     BoxedTransform, TransformConfig, TransformContext, TransformOuter, get_transform_output_ids,
@@ -211,6 +214,23 @@ impl Config {
             .or_else(|| self.enrichment_tables.get(id).map(|s| &s.inputs[..]))
     }
 
+    /// Checks whether the sink reroutes the events it failed to deliver to components consuming
+    /// its `dropped` output.
+    pub fn reroutes_dropped(&self, sink: &ComponentKey) -> bool {
+        if !self.sinks.get(sink).is_some_and(|sink| sink.reroute_dropped) {
+            return false;
+        }
+        let output = OutputId::from((sink, DROPPED_OUTPUT.to_string()));
+        self.transforms
+            .values()
+            .any(|transform| transform.inputs.contains(&output))
+            || self.sinks.values().any(|sink| sink.inputs.contains(&output))
+            || self
+                .enrichment_tables
+                .values()
+                .any(|table| table.inputs.contains(&output))
+    }
+
     pub fn propagate_acknowledgements(&mut self) -> Result<(), Vec<String>> {
         let inputs: Vec<_> = self
             .sinks
@@ -251,6 +271,15 @@ impl Config {
                     .map(|input| (sink.clone(), input.clone()))
                     .collect();
                 self.propagate_acks_rec(inputs);
+            } else if let Some(upstream) = self.sinks.get(component) {
+                // Events rerouted from the `dropped` output of a sink are still owned by the
+                // sources feeding that sink.
+                let inputs = upstream
+                    .inputs
+                    .iter()
+                    .map(|input| (sink.clone(), input.clone()))
+                    .collect();
+                self.propagate_acks_rec(inputs);
             }
         }
     }
@@ -553,7 +582,7 @@ pub struct TestInput {
 
     /// The type of the input event.
     ///
-    /// Can be either `raw`, `vrl`, `log`, or `metric.
+    /// Can be either `raw`, `vrl`, `log`, `metric`, or `capture`.
     #[serde(default = "default_test_input_type", rename = "type")]
     pub type_str: String,
 
@@ -577,6 +606,12 @@ pub struct TestInput {
     ///
     /// Only relevant when `type` is `metric`.
     pub metric: Option<Metric>,
+
+    /// The path of a capture file, recorded with `vector tap --record`, whose events to use as
+    /// input events.
+    ///
+    /// Only relevant when `type` is `capture`.
+    pub path: Option<PathBuf>,
 }
 
 fn default_test_input_type() -> String {
@@ -0,0 +1,258 @@
+//! Redaction of secrets in configs output by Vector, such as by `vector config`.
+
+use std::collections::HashSet;
+
+use serde_json::{Map, Value};
+use vector_lib::configurable::{GenerateError, schema::generate_root_schema};
+
+use super::{ConfigBuilder, loading::COLLECTOR, vars::ENVIRONMENT_VARIABLE_INTERPOLATION_REGEX};
+
+/// The value redacted values are replaced with.
+pub const REDACTED: &str = "**REDACTED**";
+
+/// The parts of environment variable names considered to hold secrets by default.
+pub const DEFAULT_SENSITIVE_ENVIRONMENT_VARIABLES: [&str; 7] = [
+    "PASS",
+    "SECRET",
+    "TOKEN",
+    "KEY",
+    "CREDENTIAL",
+    "AUTH",
+    "PRIVATE",
+];
+
+/// Redacts the values of the config that are secrets, as determined by the config schema.
+///
+/// These are the values of fields marked as sensitive in the schema, such as `SensitiveString`
+/// fields, and the values interpolating secrets of secret backends, or environment variables
+/// whose names contain any of the given sensitive parts, regardless of case.
+pub struct Redactor {
+    root: Value,
+    definitions: Map<String, Value>,
+    sensitive_environment_variables: Vec<String>,
+}
+
+impl Redactor {
+    /// Creates a redactor for configs deserialized into a `ConfigBuilder`, redacting the values
+    /// interpolating environment variables whose names contain any of
+    /// `sensitive_environment_variables`.
+    pub fn new<S: AsRef<str>>(
+        sensitive_environment_variables: &[S],
+    ) -> Result<Self, GenerateError> {
+        let schema = generate_root_schema::<ConfigBuilder>()?;
+        let mut root =
+            serde_json::to_value(schema).expect("rendering root schema to JSON should not fail");
+        let definitions = match root
+            .as_object_mut()
+            .and_then(|root| root.remove("definitions"))
+        {
+            Some(Value::Object(definitions)) => definitions,
+            _ => Map::new(),
+        };
+        let sensitive_environment_variables = sensitive_environment_variables
+            .iter()
+            .map(|name| name.as_ref().to_uppercase())
+            .filter(|name| !name.is_empty())
+            .collect();
+        Ok(Self {
+            root,
+            definitions,
+            sensitive_environment_variables,
+        })
+    }
+
+    /// Redacts the secrets of the given config, in place.
+    pub fn redact(&self, config: &mut Value) {
+        self.redact_value(&[&self.root], config);
+    }
+
+    fn redact_value(&self, schemas: &[&Value], value: &mut Value) {
+        let mut applied = Vec::new();
+        for schema in schemas {
+            self.applied_schemas(schema, value, &mut HashSet::new(), &mut applied);
+        }
+
+        if applied.iter().any(|schema| is_sensitive(schema)) {
+            if !value.is_null() {
+                *value = Value::String(REDACTED.to_string());
+            }
+            return;
+        }
+
+        match value {
+            Value::String(s) => {
+                if self.interpolates_secret(s) {
+                    *s = REDACTED.to_string();
+                }
+            }
+            Value::Array(items) => {
+                let item_schemas = applied
+                    .iter()
+                    .filter_map(|schema| schema.get("items"))
+                    .collect::<Vec<_>>();
+                for item in items {
+                    self.redact_value(&item_schemas, item);
+                }
+            }
+            Value::Object(fields) => {
+                for (name, field) in fields {
+                    let mut field_schemas = applied
+                        .iter()
+                        .filter_map(|schema| schema.get("properties")?.get(name))
+                        .collect::<Vec<_>>();
+                    if field_schemas.is_empty() {
+                        field_schemas = applied
+                            .iter()
+                            .filter_map(|schema| schema.get("additionalProperties"))
+                            .collect();
+                    }
+                    self.redact_value(&field_schemas, field);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Collects the schemas applying to the given value, resolving references and subschemas.
+    ///
+    /// The `oneOf` and `anyOf` subschemas that apply are those whose constant fields, such as the
+    /// `type` of components, match the value.
+    fn applied_schemas<'a>(
+        &'a self,
+        schema: &'a Value,
+        value: &Value,
+        references: &mut HashSet<&'a str>,
+        applied: &mut Vec<&'a Value>,
+    ) {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            let definition = reference
+                .strip_prefix("#/definitions/")
+                .and_then(|name| self.definitions.get(name));
+            if let Some(definition) = definition
+                && references.insert(reference)
+            {
+                self.applied_schemas(definition, value, references, applied);
+            }
+        }
+
+        applied.push(schema);
+
+        for subschema in subschemas(schema, "allOf") {
+            self.applied_schemas(subschema, value, references, applied);
+        }
+        for subschema in subschemas(schema, "oneOf").chain(subschemas(schema, "anyOf")) {
+            let mut candidates = Vec::new();
+            self.applied_schemas(subschema, value, &mut references.clone(), &mut candidates);
+            if candidates
+                .iter()
+                .all(|candidate| matches_constants(candidate, value))
+            {
+                self.applied_schemas(subschema, value, references, applied);
+            }
+        }
+    }
+
+    /// Checks whether the value interpolates a secret of a secret backend, or a sensitive
+    /// environment variable.
+    fn interpolates_secret(&self, value: &str) -> bool {
+        COLLECTOR.is_match(value)
+            || ENVIRONMENT_VARIABLE_INTERPOLATION_REGEX
+                .captures_iter(value)
+                .filter_map(|caps| caps.get(1).or_else(|| caps.get(2)))
+                .any(|name| {
+                    let name = name.as_str().to_uppercase();
+                    self.sensitive_environment_variables
+                        .iter()
+                        .any(|sensitive| name.contains(sensitive.as_str()))
+                })
+    }
+}
+
+fn subschemas<'a>(schema: &'a Value, keyword: &str) -> impl Iterator<Item = &'a Value> {
+    schema
+        .get(keyword)
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+}
+
+fn is_sensitive(schema: &Value) -> bool {
+    schema
+        .get("_metadata")
+        .and_then(|metadata| metadata.get("sensitive"))
+        .and_then(Value::as_bool)
+        .unwrap_or(false)
+}
+
+/// Checks whether the fields of the value with a constant value in the schema have that value.
+fn matches_constants(schema: &Value, value: &Value) -> bool {
+    let Some(properties) = schema.get("properties").and_then(Value::as_object) else {
+        return true;
+    };
+    properties.iter().all(
+        |(name, property)| match (property.get("const"), value.get(name)) {
+            (Some(constant), Some(field)) => constant == field,
+            _ => true,
+        },
+    )
+}
+
+#[cfg(all(test, feature = "sources-demo_logs", feature = "sinks-http"))]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn redacts_secrets() {
+        let mut config = json!({
+            "sources": {
+                "in": {
+                    "type": "demo_logs",
+                    "format": "${LOG_FORMAT}",
+                },
+            },
+            "sinks": {
+                "out": {
+                    "type": "http",
+                    "inputs": ["in"],
+                    "uri": "https://${API_TOKEN}@example.com",
+                    "auth": {
+                        "strategy": "basic",
+                        "user": "SECRET[vault.user]",
+                        "password": "hunter2",
+                    },
+                },
+            },
+        });
+
+        Redactor::new(&DEFAULT_SENSITIVE_ENVIRONMENT_VARIABLES)
+            .unwrap()
+            .redact(&mut config);
+
+        assert_eq!(config["sources"]["in"]["format"], "${LOG_FORMAT}");
+        assert_eq!(config["sinks"]["out"]["inputs"], json!(["in"]));
+        assert_eq!(config["sinks"]["out"]["uri"], REDACTED);
+        assert_eq!(config["sinks"]["out"]["auth"]["strategy"], "basic");
+        assert_eq!(config["sinks"]["out"]["auth"]["user"], REDACTED);
+        assert_eq!(config["sinks"]["out"]["auth"]["password"], REDACTED);
+    }
+
+    #[test]
+    fn redacts_configured_environment_variables() {
+        let mut config = json!({
+            "sources": {
+                "in": {
+                    "type": "demo_logs",
+                    "format": "${LOG_FORMAT}",
+                    "lines": ["${API_TOKEN}"],
+                },
+            },
+        });
+
+        Redactor::new(&["format"]).unwrap().redact(&mut config);
+
+        assert_eq!(config["sources"]["in"]["format"], REDACTED);
+        assert_eq!(config["sources"]["in"]["lines"], json!(["${API_TOKEN}"]));
+    }
+}
@@ -1,4 +1,7 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    time::{Duration, Instant},
+};
 
 use enum_dispatch::enum_dispatch;
 use vector_lib::configurable::NamedComponent;
@@ -13,4 +16,16 @@ pub trait SecretBackend: NamedComponent + core::fmt::Debug + Send + Sync {
         secret_keys: HashSet<String>,
         signal_rx: &mut signal::SignalRx,
     ) -> crate::Result<HashMap<String, String>>;
+
+    /// Returns when the secrets retrieved last expire, if they do, such as secrets issued with a
+    /// lease. The configuration is reloaded beforehand, so that they are retrieved again.
+    fn expires_at(&self) -> Option<Instant> {
+        None
+    }
+
+    /// Returns how often to retrieve the secrets again, if they are to be refreshed. The
+    /// components referencing secrets whose values changed are then reloaded.
+    fn refresh_interval(&self) -> Option<Duration> {
+        None
+    }
 }
@@ -1,4 +1,4 @@
-use std::{cell::RefCell, path::PathBuf, time::Duration};
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, time::Duration};
 
 use async_trait::async_trait;
 use dyn_clone::DynClone;
@@ -6,7 +6,7 @@ use serde::Serialize;
 use serde_with::serde_as;
 use vector_lib::{
     buffers::{BufferConfig, BufferType},
-    config::{AcknowledgementsConfig, GlobalOptions, Input},
+    config::{AcknowledgementsConfig, GlobalOptions, Input, TransformOutput},
     configurable::{
         Configurable, GenerateError, Metadata, NamedComponent,
         attributes::CustomAttribute,
@@ -85,6 +85,25 @@ where
     #[serde(default, skip_serializing_if = "vector_lib::serde::is_default")]
     pub proxy: ProxyConfig,
 
+    /// Reroutes events that the sink failed to deliver to a named output instead of dropping them.
+    ///
+    /// Events are normally dropped once a request fails permanently, for example when the
+    /// service rejects it with a non-retriable error or all retries are exhausted. In some cases,
+    /// it may be desirable to keep these events around, to store them for later replay.
+    ///
+    /// In these cases, `reroute_dropped` can be set to `true` which forwards the original events
+    /// to a specially-named output, `dropped`. The events are annotated with additional fields
+    /// describing why they were dropped, including the HTTP status and the number of attempts
+    /// when they are known.
+    ///
+    /// Events the sink drops without delivering them, such as events that fail to be encoded, are
+    /// rerouted as well. Events are only tracked while a component consumes the `dropped` output.
+    ///
+    /// Only sinks that acknowledge the delivery of each request support this option.
+    #[serde(default, skip_serializing_if = "vector_lib::serde::is_default")]
+    #[configurable(metadata(docs::human_name = "Reroute Dropped Events"))]
+    pub reroute_dropped: bool,
+
     #[serde(flatten)]
     #[configurable(metadata(docs::hidden))]
     pub inner: BoxedSink,
@@ -107,6 +126,7 @@ where
             inner: inner.into(),
             proxy: Default::default(),
             graph: Default::default(),
+            reroute_dropped: false,
         }
     }
 
@@ -145,6 +165,19 @@ where
         &self.proxy
     }
 
+    /// Gets the outputs of this sink, which only has the `dropped` output when `reroute_dropped`
+    /// is enabled.
+    pub fn outputs(&self) -> Vec<TransformOutput> {
+        if self.reroute_dropped {
+            vec![
+                TransformOutput::new(self.inner.input().data_type(), HashMap::new())
+                    .with_port(DROPPED_OUTPUT),
+            ]
+        } else {
+            Vec::new()
+        }
+    }
+
     pub(super) fn map_inputs<U>(self, f: impl Fn(&T) -> U) -> SinkOuter<U>
     where
         U: Configurable + Serialize,
@@ -166,10 +199,14 @@ where
             healthcheck_uri: self.healthcheck_uri,
             proxy: self.proxy,
             graph: self.graph,
+            reroute_dropped: self.reroute_dropped,
         }
     }
 }
 
+/// The name of the output that events a sink failed to deliver are rerouted to.
+pub const DROPPED_OUTPUT: &str = "dropped";
+
 /// Healthcheck configuration.
 #[serde_as]
 #[configurable_component]
@@ -23,6 +23,7 @@ use tokio::sync::{
     oneshot::{self, Receiver},
 };
 use uuid::Uuid;
+use vector_lib::tap::capture;
 use vrl::{
     compiler::{Context, TargetValue, TimeZone, state::RuntimeState},
     diagnostic::Formatter,
@@ -541,14 +542,12 @@ fn build_and_validate_inputs(
 
     for (index, input) in test_inputs.iter().enumerate() {
         if available_insert_targets.contains(&input.insert_at) {
-            match build_input_event(input) {
-                Ok(input_event) => {
+            match build_input_events(input) {
+                Ok(input_events) => {
                     inputs
                         .entry(input.insert_at.clone())
-                        .and_modify(|events: &mut Vec<Event>| {
-                            events.push(input_event.clone());
-                        })
-                        .or_insert_with(|| vec![input_event]);
+                        .or_insert_with(Vec::new)
+                        .extend(input_events);
                 }
                 Err(error) => errors.push(error),
             }
@@ -603,6 +602,23 @@ fn build_outputs(
     }
 }
 
+fn build_input_events(input: &TestInput) -> Result<Vec<Event>, String> {
+    match input.type_str.as_ref() {
+        "capture" => match input.path.as_ref() {
+            Some(path) => {
+                let records = capture::read_capture(path).map_err(|error| error.to_string())?;
+                records
+                    .iter()
+                    .map(|record| record.decode().map_err(|error| error.to_string()))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(|events| events.into_iter().flatten().collect())
+            }
+            None => Err("input type 'capture' requires the field 'path'".to_string()),
+        },
+        _ => build_input_event(input).map(|event| vec![event]),
+    }
+}
+
 fn build_input_event(input: &TestInput) -> Result<Event, String> {
     match input.type_str.as_ref() {
         "raw" => match input.value.as_ref() {
@@ -886,6 +886,55 @@ async fn test_metric_input() {
     assert!(tests.remove(0).run().await.errors.is_empty());
 }
 
+#[tokio::test]
+async fn test_capture_input() {
+    crate::test_util::trace_init();
+
+    let path = crate::test_util::temp_file();
+    let record = capture::CaptureRecord {
+        version: capture::CAPTURE_VERSION,
+        offset_ms: 0,
+        component_id: "in".to_string(),
+        component_kind: "source".to_string(),
+        component_type: "demo_logs".to_string(),
+        output: None,
+        event: capture::encode_event(LogEvent::from("captured message").into()),
+    };
+    std::fs::write(&path, serde_json::to_string(&record).unwrap()).unwrap();
+
+    let config: ConfigBuilder = toml::from_str(&indoc::formatdoc! { r#"
+          [transforms.foo]
+            inputs = ["ignored"]
+            type = "remap"
+            source = '''
+            .new_field = "string value"
+            '''
+
+          [[tests]]
+            name = "successful test with captured events"
+
+            [tests.input]
+              insert_at = "foo"
+              type = "capture"
+              path = "{}"
+
+            [[tests.outputs]]
+              extract_from = "foo"
+              [[tests.outputs.conditions]]
+                type = "vrl"
+                source = """
+                    assert_eq!(.message, "captured message")
+                    assert_eq!(.new_field, "string value")
+                """
+      "#,
+        path.display()
+    })
+    .unwrap();
+
+    let mut tests = build_unit_tests(config).await.unwrap();
+    assert!(tests.remove(0).run().await.errors.is_empty());
+}
+
 #[tokio::test]
 async fn test_success_over_gap() {
     crate::test_util::trace_init();
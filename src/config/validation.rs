@@ -356,12 +356,20 @@ pub fn warnings(config: &Config) -> Vec<String> {
         .collect::<Vec<_>>()
     });
 
+    let sink_ids = config.sinks.iter().flat_map(|(key, sink)| {
+        sink.outputs()
+            .into_iter()
+            .filter_map(|output| output.port)
+            .map(|port| ("sink", OutputId::from((key, port))))
+            .collect::<Vec<_>>()
+    });
+
     let table_sinks = config
         .enrichment_tables
         .iter()
         .filter_map(|(key, table)| table.as_sink(key))
         .collect::<Vec<_>>();
-    for (input_type, id) in transform_ids.chain(source_ids) {
+    for (input_type, id) in transform_ids.chain(source_ids).chain(sink_ids) {
         if !config
             .transforms
             .iter()
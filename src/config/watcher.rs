@@ -2,14 +2,17 @@ use notify::{EventKind, RecursiveMode, recommended_watcher};
 use std::{
     collections::{HashMap, HashSet},
     path::{Path, PathBuf},
-    sync::mpsc::{Receiver, channel},
+    sync::{
+        Arc, Mutex,
+        mpsc::{Receiver, channel},
+    },
     thread,
     time::Duration,
 };
 
 use crate::{
     Error,
-    config::{ComponentConfig, ComponentType},
+    config::{ComponentConfig, ComponentType, Config},
 };
 
 /// Per notify own documentation, it's advised to have delay of more than 30 sec,
@@ -24,6 +27,7 @@ const CONFIG_WATCH_DELAY: std::time::Duration = std::time::Duration::from_secs(1
 const RETRY_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
 
 /// Refer to [`crate::cli::WatchConfigMethod`] for details.
+#[derive(Clone, Copy, Debug)]
 pub enum WatcherConfig {
     /// Recommended watcher for the current OS.
     RecommendedWatcher,
@@ -62,6 +66,19 @@ impl Watcher {
         }
         Ok(())
     }
+
+    fn unwatch(&mut self, path: &Path) -> Result<(), Error> {
+        use notify::Watcher as NotifyWatcher;
+        match self {
+            Watcher::RecommendedWatcher(watcher) => {
+                watcher.unwatch(path)?;
+            }
+            Watcher::PollWatcher(watcher) => {
+                watcher.unwatch(path)?;
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Sends a ReloadFromDisk or ReloadEnrichmentTables on config_path changes.
@@ -84,23 +101,204 @@ pub fn spawn_thread<'a>(
 
     config_paths.append(&mut component_config_paths);
 
+    let restart_signal_tx = signal_tx.clone();
+
+    spawn_watcher_thread(
+        watcher_conf,
+        config_paths,
+        delay,
+        move |changed_paths| {
+            let changed_components: HashMap<_, _> = component_configs
+                .clone()
+                .into_iter()
+                .flat_map(|p| p.contains(&changed_paths))
+                .collect();
+
+            info!("Configuration file changed.");
+            if !changed_components.is_empty() {
+                info!(
+                    "Component {:?} configuration changed.",
+                    changed_components.keys()
+                );
+                if changed_components
+                    .iter()
+                    .all(|(_, t)| *t == ComponentType::EnrichmentTable)
+                {
+                    info!("Only enrichment tables have changed.");
+                    _ = signal_tx
+                        .send(crate::signal::SignalTo::ReloadEnrichmentTables)
+                        .map_err(|error| {
+                            error!(
+                                message = "Unable to reload enrichment tables.",
+                                cause = %error,
+                                internal_log_rate_limit = false,
+                            )
+                        });
+                } else {
+                    _ = signal_tx
+                        .send(crate::signal::SignalTo::ReloadComponents(
+                            changed_components.into_keys().collect(),
+                        ))
+                        .map_err(|error| {
+                            error!(
+                                message = "Unable to reload component configuration. Restart Vector to reload it.",
+                                cause = %error,
+                                internal_log_rate_limit = false,
+                            )
+                        });
+                }
+            } else {
+                _ = signal_tx
+                    .send(crate::signal::SignalTo::ReloadFromDisk)
+                    .map_err(|error| {
+                        error!(
+                            message = "Unable to reload configuration file. Restart Vector to reload it.",
+                            cause = %error,
+                            internal_log_rate_limit = false,
+                        )
+                    });
+            }
+        },
+        move || {
+            // Config files could have changed while we weren't watching,
+            // so for a good measure raise SIGHUP and let reload logic
+            // determine if anything changed.
+            info!("Speculating that configuration files have changed.");
+            _ = restart_signal_tx.send(crate::signal::SignalTo::ReloadFromDisk).map_err(|error| {
+                error!(message = "Unable to reload configuration file. Restart Vector to reload it.", cause = %error)
+            });
+        },
+    )?;
+
+    info!("Watching configuration files.");
+
+    Ok(())
+}
+
+/// Sends a ReloadEnrichmentTables on changes to the files of enrichment tables, such as CSV files
+/// or GeoIP databases, so that they are reloaded without reloading the configuration.
+/// Accumulates file changes until no change for given duration has occurred.
+///
+/// Files replaced by renaming another file over them, as `geoipupdate` does, are watched again
+/// after each change. The watched files are replaced with [`EnrichmentTableWatcher::set_paths`].
+pub fn spawn_enrichment_tables_thread(
+    watcher_conf: WatcherConfig,
+    signal_tx: crate::signal::SignalTx,
+    table_paths: Vec<PathBuf>,
+    delay: impl Into<Option<Duration>>,
+) -> Result<EnrichmentTableWatcher, Error> {
+    let restart_signal_tx = signal_tx.clone();
+
+    let watched = spawn_watcher_thread(
+        watcher_conf,
+        table_paths,
+        delay,
+        move |_| {
+            info!("Enrichment table file changed.");
+            _ = signal_tx
+                .send(crate::signal::SignalTo::ReloadEnrichmentTables)
+                .map_err(|error| {
+                    error!(
+                        message = "Unable to reload enrichment tables.",
+                        cause = %error,
+                        internal_log_rate_limit = false,
+                    )
+                });
+        },
+        move || {
+            // Files could have changed while we weren't watching. Only the tables whose files
+            // have changed are reloaded, so it's cheap to reload them all.
+            info!("Speculating that enrichment table files have changed.");
+            _ = restart_signal_tx
+                .send(crate::signal::SignalTo::ReloadEnrichmentTables)
+                .map_err(
+                    |error| error!(message = "Unable to reload enrichment tables.", cause = %error),
+                );
+        },
+    )?;
+
+    info!("Watching enrichment table files.");
+
+    Ok(EnrichmentTableWatcher { watched })
+}
+
+/// Gets the files of the enrichment tables of the given config, which are to be watched.
+pub fn enrichment_table_paths(config: &Config) -> Vec<PathBuf> {
+    config
+        .enrichment_tables()
+        .flat_map(|(_, table)| table.inner.files_to_watch())
+        .cloned()
+        .collect()
+}
+
+/// Handle to the thread started by [`spawn_enrichment_tables_thread`].
+pub struct EnrichmentTableWatcher {
+    watched: Arc<Mutex<Watched>>,
+}
+
+impl EnrichmentTableWatcher {
+    /// Watches the given files instead of the ones watched so far, such as when the enrichment
+    /// tables change along with the configuration.
+    pub fn set_paths(&self, paths: Vec<PathBuf>) {
+        let mut watched = self.watched.lock().expect("watched paths lock poisoned");
+        let Watched {
+            paths: watched_paths,
+            watcher,
+        } = &mut *watched;
+
+        if let Some(watcher) = watcher {
+            for path in watched_paths.iter().filter(|path| !paths.contains(path)) {
+                if let Err(error) = watcher.unwatch(path) {
+                    debug!(message = "Failed to stop watching path.", path = ?path, %error);
+                }
+            }
+            if let Err(error) = watcher.add_paths(&paths) {
+                error!(message = "Failed to read enrichment table files to watch.", %error);
+            }
+        }
+
+        debug!(message = "Watching enrichment table files.", paths = ?paths);
+        *watched_paths = paths;
+    }
+}
+
+/// Paths watched by a watcher thread, along with the watcher while it is running.
+struct Watched {
+    paths: Vec<PathBuf>,
+    watcher: Option<Watcher>,
+}
+
+/// Calls `on_change` with the changed paths on changes to the given paths.
+/// Accumulates file changes until no change for given duration has occurred.
+/// The watcher is created again when it fails, after which `on_restart` is called.
+fn spawn_watcher_thread(
+    watcher_conf: WatcherConfig,
+    paths: Vec<PathBuf>,
+    delay: impl Into<Option<Duration>>,
+    mut on_change: impl FnMut(HashSet<PathBuf>) + Send + 'static,
+    mut on_restart: impl FnMut() + Send + 'static,
+) -> Result<Arc<Mutex<Watched>>, Error> {
     let delay = delay.into().unwrap_or(CONFIG_WATCH_DELAY);
 
     // Create watcher now so not to miss any changes happening between
     // returning from this function and the thread starting.
-    let mut watcher = Some(create_watcher(&watcher_conf, &config_paths)?);
-
-    info!("Watching configuration files.");
-
+    let (watcher, receiver) = create_watcher(&watcher_conf, &paths)?;
+    let watched = Arc::new(Mutex::new(Watched {
+        paths,
+        watcher: Some(watcher),
+    }));
+    let mut receiver = Some(receiver);
+
+    let thread_watched = Arc::clone(&watched);
     thread::spawn(move || {
         loop {
-            if let Some((mut watcher, receiver)) = watcher.take() {
+            if let Some(receiver) = receiver.take() {
                 while let Ok(Ok(event)) = receiver.recv() {
                     if matches!(
                         event.kind,
                         EventKind::Create(_) | EventKind::Remove(_) | EventKind::Modify(_)
                     ) {
-                        debug!(message = "Configuration file change detected.", event = ?event);
+                        debug!(message = "File change detected.", event = ?event);
 
                         // Collect paths from initial event
                         let mut changed_paths: HashSet<PathBuf> = event.paths.into_iter().collect();
@@ -121,90 +319,50 @@ pub fn spawn_thread<'a>(
                             delay = ?delay
                         );
 
-                        let changed_components: HashMap<_, _> = component_configs
-                            .clone()
-                            .into_iter()
-                            .flat_map(|p| p.contains(&changed_paths))
-                            .collect();
-
                         // We need to read paths to resolve any inode changes that may have happened.
                         // And we need to do it before raising sighup to avoid missing any change.
-                        if let Err(error) = watcher.add_paths(&config_paths) {
-                            error!(message = "Failed to read files to watch.", %error);
-                            break;
+                        {
+                            let mut watched =
+                                thread_watched.lock().expect("watched paths lock poisoned");
+                            let Watched { paths, watcher } = &mut *watched;
+                            if let Some(Err(error)) =
+                                watcher.as_mut().map(|watcher| watcher.add_paths(paths))
+                            {
+                                error!(message = "Failed to read files to watch.", %error);
+                                break;
+                            }
                         }
 
                         debug!(message = "Reloaded paths.");
 
-                        info!("Configuration file changed.");
-                        if !changed_components.is_empty() {
-                            info!(
-                                "Component {:?} configuration changed.",
-                                changed_components.keys()
-                            );
-                            if changed_components
-                                .iter()
-                                .all(|(_, t)| *t == ComponentType::EnrichmentTable)
-                            {
-                                info!("Only enrichment tables have changed.");
-                                _ = signal_tx
-                                    .send(crate::signal::SignalTo::ReloadEnrichmentTables)
-                                    .map_err(|error| {
-                                        error!(
-                                            message = "Unable to reload enrichment tables.",
-                                            cause = %error,
-                                            internal_log_rate_limit = false,
-                                        )
-                                    });
-                            } else {
-                                _ = signal_tx
-                                    .send(crate::signal::SignalTo::ReloadComponents(
-                                        changed_components.into_keys().collect(),
-                                    ))
-                                    .map_err(|error| {
-                                        error!(
-                                            message = "Unable to reload component configuration. Restart Vector to reload it.",
-                                            cause = %error,
-                                            internal_log_rate_limit = false,
-                                        )
-                                    });
-                            }
-                        } else {
-                            _ = signal_tx
-                                .send(crate::signal::SignalTo::ReloadFromDisk)
-                                .map_err(|error| {
-                                    error!(
-                                        message = "Unable to reload configuration file. Restart Vector to reload it.",
-                                        cause = %error,
-                                        internal_log_rate_limit = false,
-                                    )
-                                });
-                        }
+                        on_change(changed_paths);
                     } else {
                         debug!(message = "Ignoring event.", event = ?event)
                     }
                 }
             }
 
-            thread::sleep(RETRY_TIMEOUT);
+            thread_watched
+                .lock()
+                .expect("watched paths lock poisoned")
+                .watcher = None;
 
-            watcher = create_watcher(&watcher_conf, &config_paths)
-                .map_err(|error| error!(message = "Failed to create file watcher.", %error))
-                .ok();
+            thread::sleep(RETRY_TIMEOUT);
 
-            if watcher.is_some() {
-                // Config files could have changed while we weren't watching,
-                // so for a good measure raise SIGHUP and let reload logic
-                // determine if anything changed.
-                info!("Speculating that configuration files have changed.");
-                _ = signal_tx.send(crate::signal::SignalTo::ReloadFromDisk).map_err(|error| {
-                error!(message = "Unable to reload configuration file. Restart Vector to reload it.", cause = %error)
-            });
+            let mut watched = thread_watched.lock().expect("watched paths lock poisoned");
+            match create_watcher(&watcher_conf, &watched.paths) {
+                Ok((watcher, new_receiver)) => {
+                    watched.watcher = Some(watcher);
+                    receiver = Some(new_receiver);
+                    drop(watched);
+                    on_restart();
+                }
+                Err(error) => error!(message = "Failed to create file watcher.", %error),
             }
         }
     });
 
-    Ok(())
+    Ok(watched)
 }
 
 fn create_watcher(
@@ -425,4 +583,94 @@ mod tests {
             panic!("Test timed out");
         }
     }
+
+    #[tokio::test]
+    async fn enrichment_table_file_update() {
+        trace_init();
+
+        let delay = Duration::from_secs(3);
+        let file_path = temp_file();
+        let mut file = File::create(&file_path).unwrap();
+        let watcher_conf = WatcherConfig::RecommendedWatcher;
+
+        let (signal_tx, signal_rx) = broadcast::channel(128);
+        spawn_enrichment_tables_thread(watcher_conf, signal_tx, vec![file_path], delay).unwrap();
+
+        if !test_signal(
+            &mut file,
+            crate::signal::SignalTo::ReloadEnrichmentTables,
+            delay * 5,
+            signal_rx,
+        )
+        .await
+        {
+            panic!("Test timed out");
+        }
+    }
+
+    #[tokio::test]
+    async fn enrichment_table_paths_updated() {
+        trace_init();
+
+        let delay = Duration::from_secs(3);
+        let old_file_path = temp_file();
+        let new_file_path = temp_file();
+        let mut old_file = File::create(&old_file_path).unwrap();
+        let mut new_file = File::create(&new_file_path).unwrap();
+        let watcher_conf = WatcherConfig::RecommendedWatcher;
+
+        let (signal_tx, mut signal_rx) = broadcast::channel(128);
+        let watcher =
+            spawn_enrichment_tables_thread(watcher_conf, signal_tx, vec![old_file_path], delay)
+                .unwrap();
+        watcher.set_paths(vec![new_file_path]);
+
+        old_file.write_all(&[0]).unwrap();
+        old_file.sync_all().unwrap();
+        assert!(
+            tokio::time::timeout(delay * 2, signal_rx.recv())
+                .await
+                .is_err()
+        );
+
+        if !test_signal(
+            &mut new_file,
+            crate::signal::SignalTo::ReloadEnrichmentTables,
+            delay * 5,
+            signal_rx,
+        )
+        .await
+        {
+            panic!("Test timed out");
+        }
+    }
+
+    #[tokio::test]
+    async fn enrichment_table_file_replaced() {
+        trace_init();
+
+        let delay = Duration::from_secs(3);
+        let dir = temp_dir().to_path_buf();
+        let file_path = dir.join("GeoLite2-City.mmdb");
+        let watcher_conf = WatcherConfig::RecommendedWatcher;
+
+        std::fs::create_dir(&dir).unwrap();
+        File::create(&file_path).unwrap();
+
+        let (signal_tx, mut signal_rx) = broadcast::channel(128);
+        spawn_enrichment_tables_thread(watcher_conf, signal_tx, vec![file_path.clone()], delay)
+            .unwrap();
+
+        // Replaced twice, to check that the replacing file is watched in turn.
+        for _ in 0..2 {
+            let update_path = dir.join("GeoLite2-City.mmdb.update");
+            std::fs::write(&update_path, [0]).unwrap();
+            std::fs::rename(&update_path, &file_path).unwrap();
+
+            match tokio::time::timeout(delay * 5, signal_rx.recv()).await {
+                Ok(Ok(crate::signal::SignalTo::ReloadEnrichmentTables)) => {}
+                _ => panic!("Test timed out"),
+            }
+        }
+    }
 }
@@ -1,5 +1,5 @@
 //! Handles enrichment tables for `type = file`.
-use std::{collections::HashMap, fs, hash::Hasher, path::PathBuf, time::SystemTime};
+use std::{collections::HashMap, fs, hash::Hasher, net::IpAddr, path::PathBuf, time::SystemTime};
 
 use bytes::Bytes;
 use tracing::trace;
@@ -7,7 +7,7 @@ use vector_lib::{
     TimeZone,
     configurable::configurable_component,
     conversion::Conversion,
-    enrichment::{Case, Condition, IndexHandle, Table},
+    enrichment::{Case, Condition, ConditionKind, IndexHandle, Table},
 };
 use vrl::value::{ObjectMap, Value};
 
@@ -36,6 +36,22 @@ pub enum Encoding {
         #[serde(default = "default_delimiter")]
         delimiter: char,
     },
+
+    /// Decodes the file as [JSON][json], either an array of objects, or newline-delimited objects.
+    ///
+    /// Each object is a row, and its top-level fields are the columns. Nested values are kept as
+    /// is, and fields missing from an object are `null`.
+    ///
+    /// [json]: https://www.json.org/
+    Json,
+
+    /// Decodes the file as an [Apache Parquet][parquet] file.
+    ///
+    /// Columns are loaded with their types, and nested values are kept as is.
+    ///
+    /// [parquet]: https://parquet.apache.org/
+    #[cfg(feature = "enrichment-tables-file-parquet")]
+    Parquet,
 }
 
 impl Default for Encoding {
@@ -53,9 +69,7 @@ impl Default for Encoding {
 pub struct FileSettings {
     /// The path of the enrichment table file.
     ///
-    /// Currently, only [CSV][csv] files are supported.
-    ///
-    /// [csv]: https://en.wikipedia.org/wiki/Comma-separated_values
+    /// The file is decoded according to the configured `encoding`.
     pub path: PathBuf,
 
     /// File encoding configuration.
@@ -179,13 +193,54 @@ impl FileConfig {
         })
     }
 
+    /// Applies the schema to a value decoded from a typed format. Only strings are converted, as
+    /// other values already have their type.
+    fn convert_value(
+        &self,
+        timezone: TimeZone,
+        column: &str,
+        row: usize,
+        value: Value,
+    ) -> Result<Value, String> {
+        match value {
+            Value::Bytes(bytes) if self.schema.contains_key(column) => {
+                self.parse_column(timezone, column, row, &String::from_utf8_lossy(&bytes))
+            }
+            value => Ok(value),
+        }
+    }
+
     /// Load the configured file into memory. Required to create a new file enrichment table.
     pub fn load_file(&self, timezone: TimeZone) -> crate::Result<FileData> {
-        let Encoding::Csv {
-            include_headers,
-            delimiter,
-        } = self.file.encoding;
+        let (headers, data) = match self.file.encoding {
+            Encoding::Csv {
+                include_headers,
+                delimiter,
+            } => self.load_csv(timezone, include_headers, delimiter)?,
+            Encoding::Json => self.load_json(timezone)?,
+            #[cfg(feature = "enrichment-tables-file-parquet")]
+            Encoding::Parquet => self.load_parquet(timezone)?,
+        };
+
+        trace!(
+            "Loaded enrichment file {} with headers {:?}.",
+            self.file.path.to_str().unwrap_or("path with invalid utf"),
+            headers
+        );
+
+        Ok(FileData {
+            headers,
+            data,
+            modified: fs::metadata(&self.file.path)?.modified()?,
+        })
+    }
 
+    fn load_csv(
+        &self,
+        timezone: TimeZone,
+        include_headers: bool,
+        delimiter: char,
+    ) -> crate::Result<(Vec<String>, Vec<Vec<Value>>)> {
         let mut reader = csv::ReaderBuilder::new()
             .has_headers(include_headers)
             .delimiter(delimiter as u8)
@@ -219,19 +274,148 @@ impl FileConfig {
             })
             .collect::<crate::Result<Vec<_>>>()?;
 
-        trace!(
-            "Loaded enrichment file {} with headers {:?}.",
-            self.file.path.to_str().unwrap_or("path with invalid utf"),
-            headers
-        );
+        Ok((headers, data))
+    }
 
-        let file = reader.into_inner();
+    /// Loads a JSON file holding either an array of objects, or newline-delimited objects.
+    fn load_json(&self, timezone: TimeZone) -> crate::Result<(Vec<String>, Vec<Vec<Value>>)> {
+        type Object = serde_json::Map<String, serde_json::Value>;
 
-        Ok(FileData {
-            headers,
-            data,
-            modified: file.metadata()?.modified()?,
-        })
+        let contents = fs::read_to_string(&self.file.path)?;
+        let objects = if contents.trim_start().starts_with('[') {
+            serde_json::from_str::<Vec<Object>>(&contents)?
+        } else {
+            contents
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| !line.trim().is_empty())
+                .map(|(idx, line)| {
+                    serde_json::from_str::<Object>(line).map_err(|error| {
+                        format!("invalid JSON object on line {}: {error}", idx + 1)
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        };
+
+        let rows = objects.into_iter().map(|object| {
+            object
+                .into_iter()
+                .map(|(column, value)| (column, Value::from(value)))
+                .collect()
+        });
+
+        Ok(self.load_rows(timezone, rows)?)
+    }
+
+    /// Loads an Apache Parquet file, keeping the types of its columns.
+    #[cfg(feature = "enrichment-tables-file-parquet")]
+    fn load_parquet(&self, timezone: TimeZone) -> crate::Result<(Vec<String>, Vec<Vec<Value>>)> {
+        use parquet::file::reader::{FileReader, SerializedFileReader};
+
+        let reader = SerializedFileReader::new(fs::File::open(&self.file.path)?)?;
+        let rows = reader
+            .get_row_iter(None)?
+            .map(|row| {
+                Ok(row?
+                    .get_column_iter()
+                    .map(|(column, field)| (column.clone(), parquet_value(field)))
+                    .collect::<Vec<_>>())
+            })
+            .collect::<Result<Vec<_>, parquet::errors::ParquetError>>()?;
+
+        Ok(self.load_rows(timezone, rows)?)
+    }
+
+    /// Lays out rows of named values into columns, in the order the columns are first found in,
+    /// applying the schema to the values. Columns missing from a row are `null`.
+    fn load_rows(
+        &self,
+        timezone: TimeZone,
+        rows: impl IntoIterator<Item = Vec<(String, Value)>>,
+    ) -> Result<(Vec<String>, Vec<Vec<Value>>), String> {
+        let mut headers = Vec::<String>::new();
+        let mut data = Vec::new();
+
+        for (row, values) in rows.into_iter().enumerate() {
+            let mut columns = vec![Value::Null; headers.len()];
+
+            for (header, value) in values {
+                let idx = match headers.iter().position(|existing| *existing == header) {
+                    Some(idx) => idx,
+                    None => {
+                        headers.push(header);
+                        columns.push(Value::Null);
+                        headers.len() - 1
+                    }
+                };
+                columns[idx] = self.convert_value(timezone, &headers[idx], row, value)?;
+            }
+
+            data.push(columns);
+        }
+
+        // Rows loaded before a column was first found don't have it yet.
+        for columns in &mut data {
+            columns.resize(headers.len(), Value::Null);
+        }
+
+        Ok((headers, data))
+    }
+}
+
+/// Converts a Parquet field to the matching value, keeping nested values.
+#[cfg(feature = "enrichment-tables-file-parquet")]
+fn parquet_value(field: &parquet::record::Field) -> Value {
+    use parquet::record::Field;
+
+    match field {
+        Field::Null => Value::Null,
+        Field::Bool(value) => Value::from(*value),
+        Field::Byte(value) => Value::from(i64::from(*value)),
+        Field::Short(value) => Value::from(i64::from(*value)),
+        Field::Int(value) => Value::from(i64::from(*value)),
+        Field::Long(value) => Value::from(*value),
+        Field::UByte(value) => Value::from(i64::from(*value)),
+        Field::UShort(value) => Value::from(i64::from(*value)),
+        Field::UInt(value) => Value::from(i64::from(*value)),
+        Field::ULong(value) => {
+            i64::try_from(*value).map_or_else(|_| Value::from(*value as f64), Value::from)
+        }
+        Field::Float(value) => Value::from(f64::from(*value)),
+        Field::Double(value) => Value::from(*value),
+        Field::Str(value) => Value::from(value.as_str()),
+        Field::Bytes(value) => Value::from(Bytes::copy_from_slice(value.data())),
+        Field::Date(days) => chrono::DateTime::from_timestamp(i64::from(*days) * 86_400, 0)
+            .map_or(Value::Null, Value::Timestamp),
+        Field::TimestampMillis(millis) => {
+            chrono::DateTime::from_timestamp_millis(*millis).map_or(Value::Null, Value::Timestamp)
+        }
+        Field::TimestampMicros(micros) => {
+            chrono::DateTime::from_timestamp_micros(*micros).map_or(Value::Null, Value::Timestamp)
+        }
+        Field::Group(row) => Value::Object(
+            row.get_column_iter()
+                .map(|(column, field)| (column.as_str().into(), parquet_value(field)))
+                .collect(),
+        ),
+        Field::ListInternal(list) => {
+            Value::Array(list.elements().iter().map(parquet_value).collect())
+        }
+        Field::MapInternal(map) => Value::Object(
+            map.entries()
+                .iter()
+                .map(|(key, value)| {
+                    let key = match key {
+                        Field::Str(key) => key.clone(),
+                        key => key.to_string(),
+                    };
+                    (key.into(), parquet_value(value))
+                })
+                .collect(),
+        ),
+        // Decimals, half-precision floats and times of day are kept as their textual
+        // representation.
+        field => Value::from(field.to_string()),
     }
 }
 
@@ -271,6 +455,106 @@ pub struct File {
         Vec<usize>,
         HashMap<u64, Vec<usize>, hash_hasher::HashBuildHasher>,
     )>,
+    condition_indexes: Vec<(usize, ConditionKind, ConditionIndex)>,
+}
+
+/// An index of a column for conditions other than equality, pointing to the rows that may match.
+#[derive(Clone)]
+enum ConditionIndex {
+    /// Rows by the IP network in the column, along with the prefix lengths of those networks,
+    /// longest first.
+    Cidr {
+        networks: HashMap<(IpAddr, u8), Vec<usize>>,
+        lengths: Vec<u8>,
+    },
+    /// Rows by the lowercased prefix in the column, along with the lengths of those prefixes,
+    /// longest first.
+    Prefix {
+        prefixes: HashMap<String, Vec<usize>>,
+        lengths: Vec<usize>,
+    },
+    /// Rows sorted by the number in the column.
+    Range(Vec<(f64, usize)>),
+}
+
+impl ConditionIndex {
+    fn new<'a>(kind: ConditionKind, column: impl Iterator<Item = &'a Value>) -> Self {
+        match kind {
+            ConditionKind::Cidr => {
+                let mut networks = HashMap::<_, Vec<usize>>::new();
+                for (row, value) in column.enumerate() {
+                    if let Some(network) = parse_network(value) {
+                        networks.entry(network).or_default().push(row);
+                    }
+                }
+
+                let mut lengths = networks.keys().map(|(_, len)| *len).collect::<Vec<_>>();
+                lengths.sort_unstable_by(|a, b| b.cmp(a));
+                lengths.dedup();
+
+                Self::Cidr { networks, lengths }
+            }
+            ConditionKind::Prefix => {
+                let mut prefixes = HashMap::<_, Vec<usize>>::new();
+                for (row, value) in column.enumerate() {
+                    if let Some(prefix) = prefix_string(Case::Insensitive, value) {
+                        prefixes.entry(prefix).or_default().push(row);
+                    }
+                }
+
+                let mut lengths = prefixes.keys().map(String::len).collect::<Vec<_>>();
+                lengths.sort_unstable_by(|a, b| b.cmp(a));
+                lengths.dedup();
+
+                Self::Prefix { prefixes, lengths }
+            }
+            ConditionKind::Range => {
+                let mut rows = column
+                    .enumerate()
+                    .filter_map(|(row, value)| Some((number(value)?, row)))
+                    .collect::<Vec<_>>();
+                rows.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+                Self::Range(rows)
+            }
+        }
+    }
+
+    /// Looks up the rows that may match the condition. Rows matching networks and prefixes are
+    /// ordered with the most specific match first.
+    fn lookup(&self, condition: &Condition) -> Vec<usize> {
+        match (self, condition) {
+            (Self::Cidr { networks, lengths }, Condition::Cidr { ip, .. }) => lengths
+                .iter()
+                .filter(|len| **len <= address_len(*ip))
+                .filter_map(|len| networks.get(&(truncate_ip(*ip, *len), *len)))
+                .flatten()
+                .copied()
+                .collect(),
+            (Self::Prefix { prefixes, lengths }, Condition::Prefix { value, .. }) => {
+                let value = value.to_lowercase();
+                lengths
+                    .iter()
+                    .filter(|len| value.is_char_boundary(**len))
+                    .filter_map(|len| prefixes.get(&value[..*len]))
+                    .flatten()
+                    .copied()
+                    .collect()
+            }
+            (Self::Range(rows), Condition::Range { from, to, .. }) => {
+                let from = from.as_ref().and_then(number);
+                let to = to.as_ref().and_then(number);
+                let start = from.map_or(0, |from| rows.partition_point(|(value, _)| *value < from));
+
+                rows[start..]
+                    .iter()
+                    .take_while(|(value, _)| to.is_none_or(|to| *value <= to))
+                    .map(|(_, row)| *row)
+                    .collect()
+            }
+            _ => Vec::new(),
+        }
+    }
 }
 
 impl File {
@@ -282,6 +566,7 @@ impl File {
             data: data.data,
             headers: data.headers,
             indexes: Vec::new(),
+            condition_indexes: Vec::new(),
         }
     }
 
@@ -366,6 +651,28 @@ impl File {
                     _ => false,
                 },
             },
+            Condition::Cidr { field, ip } => match self.column_index(field) {
+                None => false,
+                Some(idx) => parse_network(&row[idx]).is_some_and(|(network, len)| {
+                    address_len(network) == address_len(*ip) && truncate_ip(*ip, len) == network
+                }),
+            },
+            Condition::Prefix { field, value } => match self.column_index(field) {
+                None => false,
+                Some(idx) => prefix_string(case, &row[idx]).is_some_and(|prefix| match case {
+                    Case::Sensitive => value.starts_with(&prefix),
+                    Case::Insensitive => value.to_lowercase().starts_with(&prefix),
+                }),
+            },
+            Condition::Range { field, from, to } => match self.column_index(field) {
+                None => false,
+                Some(idx) => number(&row[idx]).is_some_and(|value| {
+                    from.as_ref()
+                        .and_then(number)
+                        .is_none_or(|from| from <= value)
+                        && to.as_ref().and_then(number).is_none_or(|to| value <= to)
+                }),
+            },
         })
     }
 
@@ -521,6 +828,97 @@ impl File {
         let IndexHandle(handle) = handle;
         Ok(self.indexes[handle].2.get(&wildcard_key))
     }
+
+    /// Looks up the rows that may match the conditions in the condition indexes. Exact matches are
+    /// looked up in the hash indexes instead, so this is only used when there are none.
+    fn condition_indexed(&self, condition: &[Condition]) -> Option<Vec<usize>> {
+        if condition
+            .iter()
+            .any(|condition| matches!(condition, Condition::Equals { .. }))
+        {
+            return None;
+        }
+
+        condition.iter().find_map(|condition| {
+            let (field, kind) = match condition {
+                Condition::Cidr { field, .. } => (field, ConditionKind::Cidr),
+                Condition::Prefix { field, .. } => (field, ConditionKind::Prefix),
+                Condition::Range { field, .. } => (field, ConditionKind::Range),
+                _ => return None,
+            };
+            let column = self.column_index(field)?;
+
+            self.condition_indexes
+                .iter()
+                .find(|(idx, index_kind, _)| *idx == column && *index_kind == kind)
+                .map(|(_, _, index)| index.lookup(condition))
+        })
+    }
+}
+
+/// Returns the number of bits in addresses of the family of the IP address.
+const fn address_len(ip: IpAddr) -> u8 {
+    match ip {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
+/// Clears all but the first `len` bits of the IP address.
+fn truncate_ip(ip: IpAddr, len: u8) -> IpAddr {
+    match ip {
+        IpAddr::V4(ip) => {
+            let mask = u32::MAX.checked_shl(32 - u32::from(len)).unwrap_or(0);
+            IpAddr::V4((u32::from(ip) & mask).into())
+        }
+        IpAddr::V6(ip) => {
+            let mask = u128::MAX.checked_shl(128 - u32::from(len)).unwrap_or(0);
+            IpAddr::V6((u128::from(ip) & mask).into())
+        }
+    }
+}
+
+/// Parses an IP network in CIDR notation, or a single IP address, into its truncated address and
+/// prefix length.
+fn parse_network(value: &Value) -> Option<(IpAddr, u8)> {
+    let value = value.as_str()?;
+    let (ip, len) = match value.split_once('/') {
+        Some((ip, len)) => {
+            let ip: IpAddr = ip.trim().parse().ok()?;
+            (ip, len.trim().parse().ok()?)
+        }
+        None => {
+            let ip: IpAddr = value.trim().parse().ok()?;
+            (ip, address_len(ip))
+        }
+    };
+
+    (len <= address_len(ip)).then(|| (truncate_ip(ip, len), len))
+}
+
+/// Returns the value as the string it is compared to prefixes with, lowercased if the comparison
+/// is case insensitive.
+fn prefix_string(case: Case, value: &Value) -> Option<String> {
+    let value = match value {
+        Value::Bytes(bytes) => String::from_utf8_lossy(bytes).into_owned(),
+        Value::Integer(value) => value.to_string(),
+        _ => return None,
+    };
+
+    Some(match case {
+        Case::Sensitive => value,
+        Case::Insensitive => value.to_lowercase(),
+    })
+}
+
+/// Returns the value as a number, parsing strings as CSV columns are strings unless converted.
+fn number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Integer(value) => Some(*value as f64),
+        Value::Float(value) => Some(value.into_inner()),
+        Value::Bytes(bytes) => std::str::from_utf8(bytes).ok()?.trim().parse().ok(),
+        _ => None,
+    }
 }
 
 /// Adds the bytes from the given value to the hash.
@@ -571,6 +969,11 @@ impl Table for File {
         wildcard: Option<&Value>,
         index: Option<IndexHandle>,
     ) -> Result<ObjectMap, String> {
+        if let Some(rows) = self.condition_indexed(condition) {
+            let rows = rows.into_iter().map(move |idx| &self.data[idx]);
+            return single_or_err(self.sequential(rows, case, condition, select, wildcard));
+        }
+
         match index {
             None => {
                 // No index has been passed so we need to do a Sequential Scan.
@@ -600,6 +1003,13 @@ impl Table for File {
         wildcard: Option<&Value>,
         index: Option<IndexHandle>,
     ) -> Result<Vec<ObjectMap>, String> {
+        if let Some(rows) = self.condition_indexed(condition) {
+            let rows = rows.into_iter().map(move |idx| &self.data[idx]);
+            return Ok(self
+                .sequential(rows, case, condition, select, wildcard)
+                .collect());
+        }
+
         match index {
             None => {
                 // No index has been passed so we need to do a Sequential Scan.
@@ -667,6 +1077,30 @@ impl Table for File {
             .collect::<Vec<_>>()
     }
 
+    fn add_condition_index(&mut self, field: &str, kind: ConditionKind) -> Result<(), String> {
+        let column = self
+            .column_index(field)
+            .ok_or_else(|| format!("field(s) '{field}' missing from dataset"))?;
+
+        if !self
+            .condition_indexes
+            .iter()
+            .any(|(idx, index_kind, _)| *idx == column && *index_kind == kind)
+        {
+            let index = ConditionIndex::new(kind, self.data.iter().map(|row| &row[column]));
+            self.condition_indexes.push((column, kind, index));
+        }
+
+        Ok(())
+    }
+
+    fn condition_index_fields(&self) -> Vec<(String, ConditionKind)> {
+        self.condition_indexes
+            .iter()
+            .map(|(column, kind, _)| (self.headers[*column].clone(), *kind))
+            .collect()
+    }
+
     /// Checks the modified timestamp of the data file to see if data has changed.
     fn needs_reload(&self) -> bool {
         matches!(fs::metadata(&self.config.file.path)
@@ -1531,4 +1965,333 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn finds_rows_in_network() {
+        let mut file = File::new(
+            Default::default(),
+            FileData {
+                modified: SystemTime::now(),
+                data: vec![
+                    vec!["10.0.0.0/8".into(), "internal".into()],
+                    vec!["10.1.0.0/16".into(), "office".into()],
+                    vec!["192.168.0.1".into(), "router".into()],
+                    vec!["2001:db8::/32".into(), "documentation".into()],
+                ],
+                headers: vec!["network".to_string(), "name".to_string()],
+            },
+        );
+
+        let condition = |ip: &str| Condition::Cidr {
+            field: "network",
+            ip: ip.parse().unwrap(),
+        };
+        let names = |file: &File, ip: &str| {
+            file.find_table_rows(Case::Sensitive, &[condition(ip)], None, None, None)
+                .unwrap()
+                .into_iter()
+                .map(|row| row["name"].clone())
+                .collect::<Vec<_>>()
+        };
+
+        for indexed in [false, true] {
+            if indexed {
+                file.add_condition_index("network", ConditionKind::Cidr)
+                    .unwrap();
+            }
+
+            // Indexed lookups find the most specific network first, scans keep the order of the file.
+            let expected = if indexed {
+                ["office", "internal"]
+            } else {
+                ["internal", "office"]
+            };
+            assert_eq!(expected.map(Value::from).to_vec(), names(&file, "10.1.2.3"));
+            assert_eq!(vec![Value::from("internal")], names(&file, "10.2.0.1"));
+            assert_eq!(vec![Value::from("router")], names(&file, "192.168.0.1"));
+            assert_eq!(
+                vec![Value::from("documentation")],
+                names(&file, "2001:db8::1")
+            );
+            assert!(names(&file, "192.168.0.2").is_empty());
+        }
+
+        assert_eq!(
+            Ok(ObjectMap::from([
+                ("network".into(), Value::from("192.168.0.1")),
+                ("name".into(), Value::from("router")),
+            ])),
+            file.find_table_row(
+                Case::Sensitive,
+                &[condition("192.168.0.1")],
+                None,
+                None,
+                None
+            )
+        );
+        assert_eq!(
+            vec![("network".to_string(), ConditionKind::Cidr)],
+            file.condition_index_fields()
+        );
+    }
+
+    #[test]
+    fn finds_rows_with_prefix() {
+        let mut file = File::new(
+            Default::default(),
+            FileData {
+                modified: SystemTime::now(),
+                data: vec![
+                    vec!["+1".into(), "US".into()],
+                    vec!["+44".into(), "GB".into()],
+                    vec!["+4420".into(), "London".into()],
+                    vec!["Mozilla/".into(), "browser".into()],
+                ],
+                headers: vec!["prefix".to_string(), "name".to_string()],
+            },
+        );
+
+        let names = |file: &File, case: Case, value: &str| {
+            let condition = Condition::Prefix {
+                field: "prefix",
+                value: value.to_string(),
+            };
+            file.find_table_rows(case, &[condition], None, None, None)
+                .unwrap()
+                .into_iter()
+                .map(|row| row["name"].clone())
+                .collect::<Vec<_>>()
+        };
+
+        for indexed in [false, true] {
+            if indexed {
+                file.add_condition_index("prefix", ConditionKind::Prefix)
+                    .unwrap();
+            }
+
+            let expected = if indexed {
+                ["London", "GB"]
+            } else {
+                ["GB", "London"]
+            };
+            assert_eq!(
+                expected.map(Value::from).to_vec(),
+                names(&file, Case::Sensitive, "+442071234567")
+            );
+            assert_eq!(
+                vec![Value::from("US")],
+                names(&file, Case::Sensitive, "+15551234")
+            );
+            assert!(names(&file, Case::Sensitive, "mozilla/5.0").is_empty());
+            assert_eq!(
+                vec![Value::from("browser")],
+                names(&file, Case::Insensitive, "mozilla/5.0")
+            );
+        }
+    }
+
+    #[test]
+    fn finds_rows_in_range() {
+        let mut file = File::new(
+            Default::default(),
+            FileData {
+                modified: SystemTime::now(),
+                data: vec![
+                    vec![Value::from(100), "small".into()],
+                    vec![Value::from(500.5), "medium".into()],
+                    vec!["1000".into(), "large".into()],
+                ],
+                headers: vec!["size".to_string(), "name".to_string()],
+            },
+        );
+
+        let names = |file: &File, from: Option<Value>, to: Option<Value>| {
+            let condition = Condition::Range {
+                field: "size",
+                from,
+                to,
+            };
+            file.find_table_rows(Case::Sensitive, &[condition], None, None, None)
+                .unwrap()
+                .into_iter()
+                .map(|row| row["name"].clone())
+                .collect::<Vec<_>>()
+        };
+
+        for indexed in [false, true] {
+            if indexed {
+                file.add_condition_index("size", ConditionKind::Range)
+                    .unwrap();
+            }
+
+            assert_eq!(
+                vec![Value::from("small"), Value::from("medium")],
+                names(&file, Some(Value::from(100)), Some(Value::from(600)))
+            );
+            assert_eq!(
+                vec![Value::from("medium"), Value::from("large")],
+                names(&file, Some(Value::from(500.5)), None)
+            );
+            assert_eq!(
+                vec![Value::from("small")],
+                names(&file, None, Some(Value::from(499)))
+            );
+        }
+    }
+
+    #[test]
+    fn condition_index_missing_field() {
+        let mut file = File::new(
+            Default::default(),
+            FileData {
+                modified: SystemTime::now(),
+                data: Vec::new(),
+                headers: vec!["field1".to_string()],
+            },
+        );
+
+        assert_eq!(
+            Err("field(s) 'field2' missing from dataset".to_string()),
+            file.add_condition_index("field2", ConditionKind::Cidr)
+        );
+    }
+
+    fn load_file(
+        encoding: Encoding,
+        contents: &[u8],
+        schema: &[(&str, &str)],
+    ) -> crate::Result<FileData> {
+        let path = crate::test_util::temp_file();
+        fs::write(&path, contents).unwrap();
+
+        FileConfig {
+            file: FileSettings { path, encoding },
+            schema: schema
+                .iter()
+                .map(|(column, format)| (column.to_string(), format.to_string()))
+                .collect(),
+        }
+        .load_file(Default::default())
+    }
+
+    #[test]
+    fn loads_json_array() {
+        let data = load_file(
+            Encoding::Json,
+            br#"[
+                {"host": "a", "owner": {"team": "infra"}, "since": "2020-01-01"},
+                {"host": "b", "tags": ["db"]}
+            ]"#,
+            &[("since", "date")],
+        )
+        .unwrap();
+
+        assert_eq!(vec!["host", "owner", "since", "tags"], data.headers);
+        assert_eq!(
+            vec![
+                vec![
+                    Value::from("a"),
+                    vrl::value!({"team": "infra"}),
+                    Value::Timestamp(
+                        chrono::Utc
+                            .with_ymd_and_hms(2020, 1, 1, 0, 0, 0)
+                            .single()
+                            .expect("invalid timestamp")
+                    ),
+                    Value::Null,
+                ],
+                vec![
+                    Value::from("b"),
+                    Value::Null,
+                    Value::Null,
+                    vrl::value!(["db"]),
+                ],
+            ],
+            data.data
+        );
+    }
+
+    #[test]
+    fn loads_ndjson() {
+        let data = load_file(
+            Encoding::Json,
+            b"{\"host\": \"a\", \"count\": 1}\n\n{\"host\": \"b\", \"count\": 2.5}\n",
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(vec!["count", "host"], data.headers);
+        assert_eq!(
+            vec![
+                vec![Value::from(1), Value::from("a")],
+                vec![Value::from(2.5), Value::from("b")],
+            ],
+            data.data
+        );
+
+        let error = load_file(Encoding::Json, b"{\"host\": \"a\"}\nnot json\n", &[]).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .starts_with("invalid JSON object on line 2")
+        );
+    }
+
+    #[cfg(feature = "enrichment-tables-file-parquet")]
+    #[test]
+    fn loads_parquet() {
+        use std::sync::Arc;
+
+        use parquet::{
+            column::writer::ColumnWriter, data_type::ByteArray, file::writer::SerializedFileWriter,
+            schema::parser::parse_message_type,
+        };
+
+        let schema = parse_message_type(
+            "message schema { REQUIRED BINARY host (UTF8); REQUIRED INT64 count; REQUIRED BINARY since (UTF8); }",
+        )
+        .unwrap();
+        let mut contents = Vec::new();
+        let mut writer =
+            SerializedFileWriter::new(&mut contents, Arc::new(schema), Default::default()).unwrap();
+        let mut row_group = writer.next_row_group().unwrap();
+        while let Some(mut column) = row_group.next_column().unwrap() {
+            match column.untyped() {
+                ColumnWriter::ByteArrayColumnWriter(column) => {
+                    let values = if column.get_descriptor().name() == "host" {
+                        [ByteArray::from("a"), ByteArray::from("b")]
+                    } else {
+                        [ByteArray::from("2020-01-01"), ByteArray::from("2021-01-01")]
+                    };
+                    column.write_batch(&values, None, None).unwrap();
+                }
+                ColumnWriter::Int64ColumnWriter(column) => {
+                    column.write_batch(&[1, 2], None, None).unwrap();
+                }
+                _ => unreachable!(),
+            }
+            column.close().unwrap();
+        }
+        row_group.close().unwrap();
+        writer.close().unwrap();
+
+        let data = load_file(Encoding::Parquet, &contents, &[("since", "date")]).unwrap();
+
+        let date = |year| {
+            Value::Timestamp(
+                chrono::Utc
+                    .with_ymd_and_hms(year, 1, 1, 0, 0, 0)
+                    .single()
+                    .expect("invalid timestamp"),
+            )
+        };
+        assert_eq!(vec!["host", "count", "since"], data.headers);
+        assert_eq!(
+            vec![
+                vec![Value::from("a"), Value::from(1), date(2020)],
+                vec![Value::from("b"), Value::from(2), date(2021)],
+            ],
+            data.data
+        );
+    }
 }
@@ -326,9 +326,7 @@ impl Table for Geoip {
 
     /// Returns true if the underlying data has changed and the table needs reloading.
     fn needs_reload(&self) -> bool {
-        matches!(fs::metadata(&self.config.path)
-            .and_then(|metadata| metadata.modified()),
-            Ok(modified) if modified > self.last_modified)
+        super::maxmind_database_changed(&self.config.path, self.last_modified)
     }
 }
 
@@ -501,6 +499,28 @@ mod tests {
         assert!(values.is_none());
     }
 
+    #[test]
+    fn reloads_database_replaced_by_older_one() {
+        let path = crate::test_util::temp_file();
+        fs::copy("tests/data/GeoIP2-City-Test.mmdb", &path).unwrap();
+
+        let geoip = Geoip::new(GeoipConfig {
+            path: path.clone(),
+            locale: default_locale(),
+        })
+        .unwrap();
+        assert!(!geoip.needs_reload());
+
+        let older = geoip.last_modified - std::time::Duration::from_secs(3600);
+        fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(older)
+            .unwrap();
+        assert!(geoip.needs_reload());
+    }
+
     fn find(ip: &str, database: &str) -> Option<ObjectMap> {
         find_select(ip, database, None)
     }
@@ -0,0 +1,170 @@
+use std::{
+    num::{NonZeroU64, NonZeroUsize},
+    time::Duration,
+};
+
+use hyper::Body;
+use indexmap::IndexMap;
+use vector_lib::{
+    configurable::configurable_component, enrichment::Table, lookup::lookup_v2::OptionalValuePath,
+};
+
+use super::HttpTable;
+use crate::{
+    config::{EnrichmentTableConfig, GenerateConfig, ProxyConfig},
+    http::HttpClient,
+    template::Template,
+    tls::{TlsConfig, TlsSettings},
+};
+
+/// Configuration for the `http` enrichment table.
+#[configurable_component(enrichment_table("http"))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct HttpConfig {
+    /// The URL to request to look up rows.
+    ///
+    /// The URL is rendered with the values of the condition of the lookup, referenced by field
+    /// name, such as `{{ host }}`. Values are percent-encoded before being rendered.
+    #[configurable(metadata(
+        docs::examples = "https://cmdb.example.com/hosts/{{ host }}",
+        docs::examples = "https://api.example.com/users?id={{ user_id }}"
+    ))]
+    pub url: Template,
+
+    /// Headers to add to each request.
+    #[serde(default)]
+    #[configurable(metadata(
+        docs::additional_props_description = "An HTTP request header and its value."
+    ))]
+    #[configurable(metadata(docs::examples = "example_headers()"))]
+    pub headers: IndexMap<String, String>,
+
+    /// The field of the JSON response holding the rows.
+    ///
+    /// A JSON object is a single row, and an array of objects is a row for each object. By
+    /// default, the whole response is used.
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = "data", docs::examples = "result.items"))]
+    pub response_field: OptionalValuePath,
+
+    /// The time, in seconds, that the rows returned by a request are cached for.
+    ///
+    /// Once expired, the cached rows are still found by lookups while they are requested again.
+    #[serde(default = "default_ttl_secs")]
+    #[configurable(metadata(docs::type_unit = "seconds"))]
+    pub ttl_secs: u64,
+
+    /// The time, in seconds, that lookups finding no rows are cached for.
+    ///
+    /// This applies to requests that failed, or returned a `404 Not Found` response or no rows, so
+    /// that lookups of missing values don't result in a request each.
+    #[serde(default = "default_negative_ttl_secs")]
+    #[configurable(metadata(docs::type_unit = "seconds"))]
+    pub negative_ttl_secs: u64,
+
+    /// The maximum number of lookups to cache.
+    ///
+    /// Once reached, expired lookups are evicted to make room for new ones, or else the lookup
+    /// expiring the soonest.
+    #[serde(default = "default_max_entries")]
+    pub max_entries: usize,
+
+    /// The maximum number of requests in flight at once.
+    #[serde(default = "default_concurrency")]
+    pub concurrency: NonZeroUsize,
+
+    /// The maximum number of requests made per second.
+    #[serde(default = "default_rate_limit_num")]
+    pub rate_limit_num: NonZeroU64,
+
+    /// The timeout, in seconds, of each request.
+    #[serde(default = "default_timeout_secs")]
+    #[configurable(metadata(docs::type_unit = "seconds"))]
+    pub timeout_secs: u64,
+
+    #[configurable(derived)]
+    pub tls: Option<TlsConfig>,
+
+    #[configurable(derived)]
+    #[serde(default, skip_serializing_if = "crate::serde::is_default")]
+    pub proxy: ProxyConfig,
+}
+
+const fn default_ttl_secs() -> u64 {
+    300
+}
+
+const fn default_negative_ttl_secs() -> u64 {
+    60
+}
+
+const fn default_max_entries() -> usize {
+    100_000
+}
+
+const fn default_concurrency() -> NonZeroUsize {
+    NonZeroUsize::new(8).unwrap()
+}
+
+const fn default_rate_limit_num() -> NonZeroU64 {
+    NonZeroU64::new(100).unwrap()
+}
+
+const fn default_timeout_secs() -> u64 {
+    10
+}
+
+fn example_headers() -> IndexMap<String, String> {
+    IndexMap::from([(
+        "Authorization".to_string(),
+        "Bearer ${API_TOKEN}".to_string(),
+    )])
+}
+
+impl HttpConfig {
+    pub(super) const fn ttl(&self) -> Duration {
+        Duration::from_secs(self.ttl_secs)
+    }
+
+    pub(super) const fn negative_ttl(&self) -> Duration {
+        Duration::from_secs(self.negative_ttl_secs)
+    }
+
+    pub(super) const fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout_secs)
+    }
+}
+
+impl GenerateConfig for HttpConfig {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(Self {
+            url: Template::try_from("https://cmdb.example.com/hosts/{{ host }}")
+                .expect("valid template"),
+            headers: IndexMap::new(),
+            response_field: OptionalValuePath::none(),
+            ttl_secs: default_ttl_secs(),
+            negative_ttl_secs: default_negative_ttl_secs(),
+            max_entries: default_max_entries(),
+            concurrency: default_concurrency(),
+            rate_limit_num: default_rate_limit_num(),
+            timeout_secs: default_timeout_secs(),
+            tls: None,
+            proxy: ProxyConfig::default(),
+        })
+        .unwrap()
+    }
+}
+
+impl EnrichmentTableConfig for HttpConfig {
+    async fn build(
+        &self,
+        _globals: &crate::config::GlobalOptions,
+    ) -> crate::Result<Box<dyn Table + Send + Sync>> {
+        let tls_settings = TlsSettings::from_options(self.tls.as_ref())?;
+        let proxy = ProxyConfig::from_env().merge(&self.proxy);
+        let client = HttpClient::<Body>::new(tls_settings, &proxy)?;
+
+        Ok(Box::new(HttpTable::new(self.clone(), client)))
+    }
+}
@@ -0,0 +1,83 @@
+use std::borrow::Cow;
+
+use metrics::counter;
+use vector_lib::internal_event::{InternalEvent, error_stage, error_type};
+
+#[derive(Debug)]
+pub(crate) struct HttpEnrichmentTableCacheHit;
+
+impl InternalEvent for HttpEnrichmentTableCacheHit {
+    fn emit(self) {
+        counter!("http_enrichment_table_cache_hits_total").increment(1);
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("HttpEnrichmentTableCacheHit")
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct HttpEnrichmentTableCacheMiss;
+
+impl InternalEvent for HttpEnrichmentTableCacheMiss {
+    fn emit(self) {
+        counter!("http_enrichment_table_cache_misses_total").increment(1);
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("HttpEnrichmentTableCacheMiss")
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct HttpEnrichmentTableRequestError<'a> {
+    pub url: &'a str,
+    pub error: &'a str,
+}
+
+impl InternalEvent for HttpEnrichmentTableRequestError<'_> {
+    fn emit(self) {
+        error!(
+            message = "Enrichment table lookup request failed.",
+            url = %redact_query(self.url),
+            error = %self.error,
+            error_type = error_type::REQUEST_FAILED,
+            stage = error_stage::PROCESSING,
+        );
+        counter!(
+            "component_errors_total",
+            "error_type" => error_type::REQUEST_FAILED,
+            "stage" => error_stage::PROCESSING,
+        )
+        .increment(1);
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("HttpEnrichmentTableRequestError")
+    }
+}
+
+/// Redacts the query string of a URL, which can hold credentials such as API keys.
+fn redact_query(url: &str) -> Cow<'_, str> {
+    match url.split_once('?') {
+        Some((base, _)) => format!("{base}?[REDACTED]").into(),
+        None => url.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redacts_query_string() {
+        assert_eq!(
+            redact_query("https://api.example.com/users?id=1&api_key=secret"),
+            "https://api.example.com/users?[REDACTED]"
+        );
+        assert_eq!(
+            redact_query("https://cmdb.example.com/hosts/web-1"),
+            "https://cmdb.example.com/hosts/web-1"
+        );
+    }
+}
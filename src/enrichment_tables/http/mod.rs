@@ -0,0 +1,12 @@
+//! Handles enrichment tables for `type = http`.
+//!
+//! Rows are looked up by requesting a URL rendered from the condition of the lookup, and cached.
+//! As lookups can't wait on requests, a lookup missing from the cache finds no rows, and has its
+//! request made in the background, so that the rows are found by the lookups that follow.
+
+mod config;
+mod internal_events;
+mod table;
+
+pub use config::*;
+pub use table::*;
@@ -0,0 +1,576 @@
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use hyper::{Body, StatusCode};
+use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
+use tokio::{
+    sync::{Semaphore, mpsc},
+    time::MissedTickBehavior,
+};
+use vector_lib::enrichment::{Case, Condition, IndexHandle, Table};
+use vrl::value::{ObjectMap, Value};
+
+use super::{
+    HttpConfig,
+    internal_events::{
+        HttpEnrichmentTableCacheHit, HttpEnrichmentTableCacheMiss, HttpEnrichmentTableRequestError,
+    },
+};
+use crate::{event::LogEvent, http::HttpClient};
+
+/// A cached lookup. Lookups that found no rows are cached without rows.
+struct CacheEntry {
+    rows: Option<Vec<ObjectMap>>,
+    expires_at: Instant,
+}
+
+/// A lookup to request, cached under its key: the URL, lowercased for case insensitive lookups.
+struct Lookup {
+    key: String,
+    url: String,
+}
+
+#[derive(Default)]
+struct Cache {
+    entries: HashMap<String, CacheEntry>,
+    /// The keys of the entries, ordered by when they expire, to evict them in that order.
+    expirations: BTreeSet<(Instant, String)>,
+    /// The keys of the lookups that are being requested, so that each is only requested once at a
+    /// time.
+    pending: HashSet<String>,
+}
+
+impl Cache {
+    /// Stores the rows found by a lookup. When the cache is full, expired lookups are evicted, or
+    /// else the lookup expiring the soonest.
+    fn store(&mut self, key: String, rows: Option<Vec<ObjectMap>>, ttl: Duration, max: usize) {
+        self.pending.remove(&key);
+        if max == 0 {
+            return;
+        }
+
+        match self.entries.get(&key) {
+            Some(entry) => {
+                self.expirations.remove(&(entry.expires_at, key.clone()));
+            }
+            None if self.entries.len() >= max => {
+                let now = Instant::now();
+                while let Some((expires_at, _)) = self.expirations.first() {
+                    if *expires_at > now && self.entries.len() < max {
+                        break;
+                    }
+                    if let Some((_, evicted)) = self.expirations.pop_first() {
+                        self.entries.remove(&evicted);
+                    }
+                }
+            }
+            None => (),
+        }
+
+        let expires_at = Instant::now() + ttl;
+        self.expirations.insert((expires_at, key.clone()));
+        self.entries.insert(key, CacheEntry { rows, expires_at });
+    }
+}
+
+/// An enrichment table looking up rows by HTTP requests, whose results are cached.
+#[derive(Clone)]
+pub struct HttpTable {
+    config: Arc<HttpConfig>,
+    cache: Arc<Mutex<Cache>>,
+    requests: mpsc::Sender<Lookup>,
+}
+
+impl HttpTable {
+    /// Creates the table, along with the task making its requests, which runs until the table and
+    /// all of its clones are dropped.
+    pub(super) fn new(config: HttpConfig, client: HttpClient<Body>) -> Self {
+        let config = Arc::new(config);
+        let cache = Arc::new(Mutex::new(Cache::default()));
+        let (requests, receiver) = mpsc::channel(config.concurrency.get() * 16);
+
+        tokio::spawn(run_requests(
+            Arc::clone(&config),
+            Arc::clone(&cache),
+            client,
+            receiver,
+        ));
+
+        Self {
+            config,
+            cache,
+            requests,
+        }
+    }
+
+    fn lock_cache(&self) -> MutexGuard<'_, Cache> {
+        self.cache
+            .lock()
+            .expect("http enrichment table cache lock poisoned")
+    }
+
+    /// Renders the URL to request for the condition, along with the key the lookup is cached
+    /// under. The values are requested as they are, but case insensitive lookups of values
+    /// differing only by case share a key.
+    fn lookup(&self, case: Case, condition: &[Condition]) -> Result<Lookup, String> {
+        let mut values = ObjectMap::new();
+        let mut key_values = ObjectMap::new();
+        for condition in condition {
+            match condition {
+                Condition::Equals { field, value } => {
+                    let value = value.to_string_lossy();
+                    let encoded = utf8_percent_encode(&value, NON_ALPHANUMERIC).to_string();
+                    if case == Case::Insensitive {
+                        let lowercase = value.to_lowercase();
+                        let key_value = utf8_percent_encode(&lowercase, NON_ALPHANUMERIC);
+                        key_values.insert((*field).into(), key_value.to_string().into());
+                    }
+                    values.insert((*field).into(), encoded.into());
+                }
+                _ => return Err("Only equality conditions are allowed".to_string()),
+            }
+        }
+
+        let render = |values: ObjectMap| {
+            self.config
+                .url
+                .render_string(&LogEvent::from(values))
+                .map_err(|error| error.to_string())
+        };
+        let url = render(values)?;
+        let key = match case {
+            Case::Sensitive => url.clone(),
+            Case::Insensitive => render(key_values)?,
+        };
+        Ok(Lookup { key, url })
+    }
+
+    /// Queues a request for the lookup, unless it's already being requested. When too many
+    /// requests are queued, the lookup is requested by a later one instead.
+    fn request(&self, cache: &mut Cache, lookup: Lookup) {
+        if cache.pending.contains(&lookup.key) {
+            return;
+        }
+        let key = lookup.key.clone();
+        if self.requests.try_send(lookup).is_ok() {
+            cache.pending.insert(key);
+        }
+    }
+}
+
+impl Table for HttpTable {
+    fn find_table_row<'a>(
+        &self,
+        case: Case,
+        condition: &'a [Condition<'a>],
+        select: Option<&'a [String]>,
+        wildcard: Option<&Value>,
+        index: Option<IndexHandle>,
+    ) -> Result<ObjectMap, String> {
+        let mut rows = self.find_table_rows(case, condition, select, wildcard, index)?;
+
+        match rows.pop() {
+            Some(row) if rows.is_empty() => Ok(row),
+            Some(_) => Err("More than 1 row found".to_string()),
+            None => Err("Key not found".to_string()),
+        }
+    }
+
+    fn find_table_rows<'a>(
+        &self,
+        case: Case,
+        condition: &'a [Condition<'a>],
+        select: Option<&'a [String]>,
+        _wildcard: Option<&Value>,
+        _index: Option<IndexHandle>,
+    ) -> Result<Vec<ObjectMap>, String> {
+        if condition.is_empty() {
+            return Err("Key condition must be specified".to_string());
+        }
+        let lookup = self.lookup(case, condition)?;
+
+        let mut cache = self.lock_cache();
+        let rows = match cache.entries.get(&lookup.key) {
+            Some(entry) => {
+                emit!(HttpEnrichmentTableCacheHit);
+                let rows = entry.rows.clone().unwrap_or_default();
+                // Expired rows are still found until they are replaced.
+                if entry.expires_at <= Instant::now() {
+                    self.request(&mut cache, lookup);
+                }
+                rows
+            }
+            None => {
+                emit!(HttpEnrichmentTableCacheMiss);
+                self.request(&mut cache, lookup);
+                Vec::new()
+            }
+        };
+
+        Ok(match select {
+            Some(select) => rows
+                .into_iter()
+                .map(|row| {
+                    row.into_iter()
+                        .filter(|(field, _)| select.iter().any(|s| s.as_str() == field.as_str()))
+                        .collect()
+                })
+                .collect(),
+            None => rows,
+        })
+    }
+
+    fn add_index(&mut self, _case: Case, fields: &[&str]) -> Result<IndexHandle, String> {
+        if fields.is_empty() {
+            Err("Key field is required".to_string())
+        } else {
+            Ok(IndexHandle(0))
+        }
+    }
+
+    /// Returns a list of the field names that are in each index
+    fn index_fields(&self) -> Vec<(Case, Vec<String>)> {
+        Vec::new()
+    }
+
+    /// Doesn't need reload, lookups are requested as they are made
+    fn needs_reload(&self) -> bool {
+        false
+    }
+}
+
+impl std::fmt::Debug for HttpTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "HttpTable {{ url: {} }}", self.config.url)
+    }
+}
+
+/// Requests the queued lookups, within the concurrency and rate limits, until the table is dropped.
+async fn run_requests(
+    config: Arc<HttpConfig>,
+    cache: Arc<Mutex<Cache>>,
+    client: HttpClient<Body>,
+    mut receiver: mpsc::Receiver<Lookup>,
+) {
+    let semaphore = Arc::new(Semaphore::new(config.concurrency.get()));
+    let mut rate_limit = tokio::time::interval(Duration::from_secs_f64(
+        1.0 / config.rate_limit_num.get() as f64,
+    ));
+    rate_limit.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    while let Some(Lookup { key, url }) = receiver.recv().await {
+        rate_limit.tick().await;
+        let permit = Arc::clone(&semaphore)
+            .acquire_owned()
+            .await
+            .expect("semaphore is never closed");
+
+        let config = Arc::clone(&config);
+        let cache = Arc::clone(&cache);
+        let client = client.clone();
+        tokio::spawn(async move {
+            let result = tokio::time::timeout(config.timeout(), fetch(&config, &client, &url))
+                .await
+                .unwrap_or_else(|_| Err("request timed out".to_string()));
+            drop(permit);
+
+            let mut cache = cache
+                .lock()
+                .expect("http enrichment table cache lock poisoned");
+            let (rows, ttl) = match result {
+                Ok(rows) if !rows.is_empty() => (Some(rows), config.ttl()),
+                Ok(_) => (None, config.negative_ttl()),
+                Err(error) => {
+                    emit!(HttpEnrichmentTableRequestError {
+                        url: &url,
+                        error: &error
+                    });
+                    // Rows found earlier are kept while the request fails.
+                    let rows = cache.entries.get(&key).and_then(|entry| entry.rows.clone());
+                    (rows, config.negative_ttl())
+                }
+            };
+            cache.store(key, rows, ttl, config.max_entries);
+        });
+    }
+}
+
+/// Requests the URL, returning the rows of the response.
+async fn fetch(
+    config: &HttpConfig,
+    client: &HttpClient<Body>,
+    url: &str,
+) -> Result<Vec<ObjectMap>, String> {
+    let mut builder = http::request::Builder::new().uri(url);
+    for (header, value) in config.headers.iter() {
+        builder = builder.header(header.as_str(), value.as_str());
+    }
+    let request = builder
+        .body(Body::empty())
+        .map_err(|error| error.to_string())?;
+
+    let response = client
+        .send(request)
+        .await
+        .map_err(|error| error.to_string())?;
+    match response.status() {
+        StatusCode::NOT_FOUND => return Ok(Vec::new()),
+        status if !status.is_success() => return Err(format!("unexpected status {status}")),
+        _ => {}
+    }
+
+    let body = hyper::body::to_bytes(response.into_body())
+        .await
+        .map_err(|error| error.to_string())?;
+    let value = Value::from(
+        serde_json::from_slice::<serde_json::Value>(&body)
+            .map_err(|error| format!("invalid JSON response: {error}"))?,
+    );
+    let value = match &config.response_field.path {
+        Some(path) => value.get(path).cloned().unwrap_or(Value::Null),
+        None => value,
+    };
+
+    match value {
+        Value::Object(row) => Ok(vec![row]),
+        Value::Array(rows) => rows
+            .into_iter()
+            .map(|row| match row {
+                Value::Object(row) => Ok(row),
+                _ => Err("response rows must be JSON objects".to_string()),
+            })
+            .collect(),
+        Value::Null => Ok(Vec::new()),
+        _ => Err("response must be a JSON object or array of objects".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        convert::Infallible,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use hyper::{
+        Request, Response, Server,
+        service::{make_service_fn, service_fn},
+    };
+    use indexmap::IndexMap;
+    use vector_lib::lookup::lookup_v2::OptionalValuePath;
+
+    use super::*;
+    use crate::{
+        config::ProxyConfig,
+        enrichment_tables::http::HttpConfig,
+        template::Template,
+        test_util::{addr::next_addr, test_generate_config},
+    };
+
+    #[test]
+    fn generate_config() {
+        test_generate_config::<HttpConfig>();
+    }
+
+    /// Serves `{"data": {"owner": "<name>"}}` for `/hosts/<name>`, except for `/hosts/missing`,
+    /// counting the requests made.
+    async fn serve(addr: std::net::SocketAddr) -> Arc<AtomicUsize> {
+        let count = Arc::new(AtomicUsize::new(0));
+        let requests = Arc::clone(&count);
+        let service = make_service_fn(move |_| {
+            let requests = Arc::clone(&requests);
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request: Request<Body>| {
+                    requests.fetch_add(1, Ordering::SeqCst);
+                    let name = request
+                        .uri()
+                        .path()
+                        .trim_start_matches("/hosts/")
+                        .to_string();
+                    async move {
+                        Ok::<_, Infallible>(if name == "missing" {
+                            Response::builder()
+                                .status(StatusCode::NOT_FOUND)
+                                .body(Body::empty())
+                                .unwrap()
+                        } else {
+                            Response::new(Body::from(format!(
+                                r#"{{"data": {{"owner": "{name}"}}}}"#
+                            )))
+                        })
+                    }
+                }))
+            }
+        });
+        tokio::spawn(Server::bind(&addr).serve(service));
+        count
+    }
+
+    fn config(addr: std::net::SocketAddr) -> HttpConfig {
+        HttpConfig {
+            url: Template::try_from(format!("http://{addr}/hosts/{{{{ host }}}}")).unwrap(),
+            headers: IndexMap::new(),
+            response_field: OptionalValuePath::new("data"),
+            ttl_secs: 300,
+            negative_ttl_secs: 60,
+            max_entries: 100,
+            concurrency: 1.try_into().unwrap(),
+            rate_limit_num: 100.try_into().unwrap(),
+            timeout_secs: 5,
+            tls: None,
+            proxy: ProxyConfig::default(),
+        }
+    }
+
+    fn table(config: HttpConfig) -> HttpTable {
+        HttpTable::new(
+            config,
+            HttpClient::new(None, &ProxyConfig::default()).unwrap(),
+        )
+    }
+
+    fn condition(host: &str) -> [Condition<'static>; 1] {
+        [Condition::Equals {
+            field: "host",
+            value: host.into(),
+        }]
+    }
+
+    async fn wait_for_request(table: &HttpTable) {
+        for _ in 0..100 {
+            if table.lock_cache().pending.is_empty() {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("request wasn't made");
+    }
+
+    #[tokio::test]
+    async fn finds_row_once_requested() {
+        let (_guard, addr) = next_addr();
+        let requests = serve(addr).await;
+        let table = table(config(addr));
+
+        let condition = condition("web-1");
+        assert_eq!(
+            table.find_table_row(Case::Sensitive, &condition, None, None, None),
+            Err("Key not found".to_string())
+        );
+        wait_for_request(&table).await;
+
+        assert_eq!(
+            table.find_table_row(Case::Sensitive, &condition, None, None, None),
+            Ok(ObjectMap::from([("owner".into(), Value::from("web-1"))]))
+        );
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn caches_missing_rows() {
+        let (_guard, addr) = next_addr();
+        let requests = serve(addr).await;
+        let table = table(config(addr));
+
+        let condition = condition("missing");
+        for _ in 0..2 {
+            assert_eq!(
+                table.find_table_rows(Case::Sensitive, &condition, None, None, None),
+                Ok(Vec::new())
+            );
+            wait_for_request(&table).await;
+        }
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn requests_case_insensitive_values_as_they_are() {
+        let (_guard, addr) = next_addr();
+        let requests = serve(addr).await;
+        let table = table(config(addr));
+
+        assert!(
+            table
+                .find_table_rows(Case::Insensitive, &condition("WEB-1"), None, None, None)
+                .is_ok()
+        );
+        wait_for_request(&table).await;
+
+        // The lookup differing only by case is cached with the value that was requested.
+        assert_eq!(
+            table.find_table_row(Case::Insensitive, &condition("web-1"), None, None, None),
+            Ok(ObjectMap::from([("owner".into(), Value::from("WEB-1"))]))
+        );
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn evicts_lookups_when_full() {
+        let (_guard, addr) = next_addr();
+        let requests = serve(addr).await;
+        let table = table(HttpConfig {
+            max_entries: 1,
+            ..config(addr)
+        });
+
+        for host in ["web-1", "web-2"] {
+            assert!(
+                table
+                    .find_table_rows(Case::Sensitive, &condition(host), None, None, None)
+                    .is_ok()
+            );
+            wait_for_request(&table).await;
+        }
+
+        // The newer lookup is cached in place of the older one, rather than being requested again.
+        assert_eq!(
+            table.find_table_row(Case::Sensitive, &condition("web-2"), None, None, None),
+            Ok(ObjectMap::from([("owner".into(), Value::from("web-2"))]))
+        );
+        assert_eq!(table.lock_cache().entries.len(), 1);
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn evicts_expired_lookups_then_the_soonest_to_expire() {
+        let mut cache = Cache::default();
+        let ttl = Duration::from_secs(60);
+        cache.store("expired".to_string(), None, Duration::ZERO, 3);
+        cache.store("soonest".to_string(), None, ttl, 3);
+        cache.store("latest".to_string(), None, ttl * 2, 3);
+
+        // Replacing a lookup doesn't evict any other.
+        cache.store("soonest".to_string(), None, ttl, 3);
+        assert_eq!(cache.entries.len(), 3);
+        assert_eq!(cache.expirations.len(), 3);
+
+        cache.store("new".to_string(), None, ttl * 3, 3);
+        assert!(!cache.entries.contains_key("expired"));
+        assert!(cache.entries.contains_key("soonest"));
+
+        cache.store("newer".to_string(), None, ttl * 3, 3);
+        assert!(!cache.entries.contains_key("soonest"));
+        assert!(cache.entries.contains_key("latest"));
+        assert_eq!(cache.entries.len(), 3);
+        assert_eq!(cache.expirations.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn rejects_other_conditions() {
+        let (_guard, addr) = next_addr();
+        let table = table(config(addr));
+
+        let condition = [Condition::Prefix {
+            field: "host",
+            value: "web".to_string(),
+        }];
+        assert!(
+            table
+                .find_table_rows(Case::Sensitive, &condition, None, None, None)
+                .is_err()
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use std::{num::NonZeroU64, sync::Arc};
+use std::{num::NonZeroU64, path::PathBuf, sync::Arc};
 
 use async_trait::async_trait;
 use futures::{FutureExt, future};
@@ -17,7 +17,8 @@ use vrl::{path::OwnedTargetPath, value::Kind};
 use super::{Memory, internal_events::InternalMetricsConfig, source::EXPIRED_ROUTE};
 use crate::{
     config::{
-        EnrichmentTableConfig, SinkConfig, SinkContext, SourceConfig, SourceContext, SourceOutput,
+        EnrichmentTableConfig, GlobalOptions, SinkConfig, SinkContext, SourceConfig, SourceContext,
+        SourceOutput,
     },
     sinks::Healthcheck,
     sources::Source,
@@ -68,6 +69,16 @@ pub struct MemoryConfig {
     #[configurable(derived)]
     #[serde(default)]
     pub ttl_field: OptionalValuePath,
+    /// Configuration for persisting the table to snapshots in the data directory.
+    ///
+    /// By default, the table is not persisted, and its data is lost when Vector restarts.
+    #[configurable(derived)]
+    #[serde(skip_serializing_if = "vector_lib::serde::is_default")]
+    pub snapshot: Option<MemorySnapshotConfig>,
+
+    /// Key of this component, used to name the directory of its snapshots.
+    #[serde(skip)]
+    key: Option<ComponentKey>,
 
     #[serde(skip)]
     memory: Arc<Mutex<Option<Box<Memory>>>>,
@@ -102,11 +113,27 @@ pub struct MemorySourceConfig {
     pub source_key: String,
 }
 
+/// Configuration for persisting the memory enrichment table to snapshots.
+#[configurable_component]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct MemorySnapshotConfig {
+    /// The interval, in seconds, between snapshots of the table.
+    ///
+    /// A snapshot is also written when Vector shuts down. When the table is built, such as when
+    /// Vector starts, the entries of the last snapshot that have not expired yet are restored,
+    /// expiring when they would have.
+    #[serde(default = "default_snapshot_interval")]
+    #[configurable(metadata(docs::type_unit = "seconds"))]
+    pub interval_secs: NonZeroU64,
+}
+
 impl PartialEq for MemoryConfig {
     fn eq(&self, other: &Self) -> bool {
         self.ttl == other.ttl
             && self.scan_interval == other.scan_interval
             && self.flush_interval == other.flush_interval
+            && self.snapshot == other.snapshot
     }
 }
 impl Eq for MemoryConfig {}
@@ -123,6 +150,8 @@ impl Default for MemoryConfig {
             source_config: None,
             internal_metrics: InternalMetricsConfig::default(),
             ttl_field: OptionalValuePath::none(),
+            snapshot: None,
+            key: None,
         }
     }
 }
@@ -135,6 +164,10 @@ const fn default_scan_interval() -> NonZeroU64 {
     unsafe { NonZeroU64::new_unchecked(30) }
 }
 
+const fn default_snapshot_interval() -> NonZeroU64 {
+    unsafe { NonZeroU64::new_unchecked(60) }
+}
+
 impl MemoryConfig {
     pub(super) async fn get_or_build_memory(&self) -> Memory {
         let mut boxed_memory = self.memory.lock().await;
@@ -142,6 +175,19 @@ impl MemoryConfig {
             .get_or_insert_with(|| Box::new(Memory::new(self.clone())))
             .clone()
     }
+
+    /// Returns the path of the snapshots of the table, in the data directory, if they are enabled.
+    fn snapshot_path(&self, globals: &GlobalOptions) -> crate::Result<Option<PathBuf>> {
+        if self.snapshot.is_none() {
+            return Ok(None);
+        }
+        let key = self
+            .key
+            .as_ref()
+            .ok_or("the memory enrichment table key is unknown")?;
+        let directory = globals.resolve_and_make_data_subdir(None, key.id())?;
+        Ok(Some(directory.join("snapshot.json")))
+    }
 }
 
 impl EnrichmentTableConfig for MemoryConfig {
@@ -156,7 +202,9 @@ impl EnrichmentTableConfig for MemoryConfig {
         &self,
         default_key: &ComponentKey,
     ) -> Option<(ComponentKey, Box<dyn SinkConfig>)> {
-        Some((default_key.clone(), Box::new(self.clone())))
+        let mut config = self.clone();
+        config.key = Some(default_key.clone());
+        Some((default_key.clone(), Box::new(config)))
     }
 
     fn source_config(
@@ -176,8 +224,13 @@ impl EnrichmentTableConfig for MemoryConfig {
 #[async_trait]
 #[typetag::serde(name = "memory_enrichment_table")]
 impl SinkConfig for MemoryConfig {
-    async fn build(&self, _cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
-        let sink = VectorSink::from_event_streamsink(self.get_or_build_memory().await);
+    async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        let mut memory = self.get_or_build_memory().await;
+        if let Some(path) = self.snapshot_path(&cx.globals)? {
+            memory.restore_snapshot(&path).await;
+            memory.snapshot_path = Some(path);
+        }
+        let sink = VectorSink::from_event_streamsink(memory);
 
         Ok((sink, future::ok(()).boxed()))
     }
@@ -246,6 +299,7 @@ impl std::fmt::Debug for MemoryConfig {
             .field("scan_interval", &self.scan_interval)
             .field("flush_interval", &self.flush_interval)
             .field("max_byte_size", &self.max_byte_size)
+            .field("snapshot", &self.snapshot)
             .finish()
     }
 }
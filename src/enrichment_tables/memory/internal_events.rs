@@ -1,5 +1,10 @@
+use std::path::Path;
+
 use metrics::{counter, gauge};
-use vector_lib::{configurable::configurable_component, internal_event::InternalEvent};
+use vector_lib::{
+    configurable::configurable_component,
+    internal_event::{InternalEvent, error_stage, error_type},
+};
 
 /// Configuration of internal metrics for enrichment memory table.
 #[configurable_component]
@@ -151,3 +156,70 @@ impl InternalEvent for MemoryEnrichmentTableInsertFailed<'_> {
         Some("MemoryEnrichmentTableInsertFailed")
     }
 }
+
+#[derive(Debug)]
+pub(crate) struct MemoryEnrichmentTableSnapshotWritten {
+    pub written: usize,
+}
+
+impl InternalEvent for MemoryEnrichmentTableSnapshotWritten {
+    fn emit(self) {
+        debug!(
+            message = "Wrote memory enrichment table snapshot.",
+            entries = self.written,
+        );
+        counter!("memory_enrichment_table_snapshots_total").increment(1);
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("MemoryEnrichmentTableSnapshotWritten")
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct MemoryEnrichmentTableSnapshotRestored<'a> {
+    pub path: &'a Path,
+    pub restored: usize,
+}
+
+impl InternalEvent for MemoryEnrichmentTableSnapshotRestored<'_> {
+    fn emit(self) {
+        info!(
+            message = "Restored memory enrichment table snapshot.",
+            path = ?self.path,
+            entries = self.restored,
+        );
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("MemoryEnrichmentTableSnapshotRestored")
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct MemoryEnrichmentTableSnapshotFailed<'a> {
+    pub path: &'a Path,
+    pub error: std::io::Error,
+}
+
+impl InternalEvent for MemoryEnrichmentTableSnapshotFailed<'_> {
+    fn emit(self) {
+        error!(
+            message = "Memory enrichment table snapshot failed.",
+            path = ?self.path,
+            error = %self.error,
+            error_type = error_type::IO_FAILED,
+            stage = error_stage::PROCESSING,
+        );
+        counter!(
+            "component_errors_total",
+            "error_type" => error_type::IO_FAILED,
+            "stage" => error_stage::PROCESSING,
+        )
+        .increment(1);
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("MemoryEnrichmentTableSnapshotFailed")
+    }
+}
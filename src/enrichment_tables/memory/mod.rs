@@ -2,6 +2,7 @@
 
 mod config;
 mod internal_events;
+mod snapshot;
 mod source;
 mod table;
 
@@ -0,0 +1,35 @@
+//! Snapshots of the memory enrichment table, persisted to the data directory so that its entries
+//! survive restarts.
+
+use std::{io, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+/// Entry of a snapshot of the table.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub(super) struct SnapshotEntry {
+    pub(super) key: String,
+    /// The value, encoded as JSON.
+    pub(super) value: String,
+    /// When the entry expires, in seconds since the Unix epoch.
+    pub(super) expires_at: u64,
+}
+
+/// Reads the entries of the snapshot at the given path. No entries are read if there is no
+/// snapshot yet.
+pub(super) async fn read(path: &Path) -> io::Result<Vec<SnapshotEntry>> {
+    match tokio::fs::read(path).await {
+        Ok(contents) => Ok(serde_json::from_slice(&contents)?),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(error) => Err(error),
+    }
+}
+
+/// Writes the entries to the snapshot at the given path, replacing the previous snapshot
+/// atomically, so that it is never left partially written.
+pub(super) async fn write(path: &Path, entries: &[SnapshotEntry]) -> io::Result<()> {
+    let contents = serde_json::to_vec(entries)?;
+    let temp_path = path.with_extension("json.tmp");
+    tokio::fs::write(&temp_path, contents).await?;
+    tokio::fs::rename(&temp_path, path).await
+}
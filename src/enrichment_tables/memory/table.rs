@@ -1,8 +1,9 @@
 #![allow(unsafe_op_in_unsafe_fn)] // TODO review ShallowCopy usage code and fix properly.
 
 use std::{
+    path::{Path, PathBuf},
     sync::{Arc, Mutex, MutexGuard},
-    time::{Duration, Instant},
+    time::{Duration, Instant, SystemTime},
 };
 
 use async_trait::async_trait;
@@ -32,7 +33,10 @@ use vector_lib::{
 };
 use vrl::value::{KeyString, ObjectMap, Value};
 
-use super::source::MemorySource;
+use super::{
+    snapshot::{self, SnapshotEntry},
+    source::MemorySource,
+};
 use crate::{
     SourceSender,
     enrichment_tables::memory::{
@@ -40,7 +44,9 @@ use crate::{
         internal_events::{
             MemoryEnrichmentTableFlushed, MemoryEnrichmentTableInsertFailed,
             MemoryEnrichmentTableInserted, MemoryEnrichmentTableRead,
-            MemoryEnrichmentTableReadFailed, MemoryEnrichmentTableTtlExpired,
+            MemoryEnrichmentTableReadFailed, MemoryEnrichmentTableSnapshotFailed,
+            MemoryEnrichmentTableSnapshotRestored, MemoryEnrichmentTableSnapshotWritten,
+            MemoryEnrichmentTableTtlExpired,
         },
     },
 };
@@ -84,6 +90,16 @@ impl MemoryEntry {
     fn expired(&self, now: Instant) -> bool {
         now.duration_since(*self.update_time).as_secs() > self.ttl
     }
+
+    /// Converts this entry to an entry of a snapshot, expiring at the same time.
+    fn to_snapshot_entry(&self, key: &str, now: Instant, now_unix: u64) -> SnapshotEntry {
+        let elapsed = now.duration_since(*self.update_time).as_secs();
+        SnapshotEntry {
+            key: key.to_string(),
+            value: self.value.clone(),
+            expires_at: now_unix.saturating_add(self.ttl.saturating_sub(elapsed)),
+        }
+    }
 }
 
 #[derive(Default)]
@@ -112,6 +128,8 @@ pub struct Memory {
     read_handle: ThreadLocal<evmap::ReadHandle<String, MemoryEntry>>,
     pub(super) write_handle: Arc<Mutex<MemoryWriter>>,
     pub(super) config: MemoryConfig,
+    /// Path of the snapshots of the table, if they are enabled.
+    pub(super) snapshot_path: Option<PathBuf>,
     #[allow(dead_code)]
     expired_items_receiver: Receiver<Vec<MemoryEntryPair>>,
     expired_items_sender: Sender<Vec<MemoryEntryPair>>,
@@ -127,6 +145,7 @@ impl Memory {
         let (expired_tx, expired_rx) = tokio::sync::broadcast::channel(5);
         Self {
             config,
+            snapshot_path: None,
             read_handle_factory: read_handle.factory(),
             read_handle: ThreadLocal::new(),
             write_handle: Arc::new(Mutex::new(MemoryWriter {
@@ -285,6 +304,81 @@ impl Memory {
         }
     }
 
+    /// Restores the entries of the snapshot at the given path that have not expired yet. Entries
+    /// already in the table are kept.
+    pub(super) async fn restore_snapshot(&self, path: &Path) {
+        let entries = match snapshot::read(path).await {
+            Ok(entries) => entries,
+            Err(error) => {
+                emit!(MemoryEnrichmentTableSnapshotFailed { path, error });
+                return;
+            }
+        };
+
+        let mut writer = self.write_handle.lock().expect("mutex poisoned");
+        let now = Instant::now();
+        let now_unix = unix_timestamp();
+        let mut restored = 0;
+        for SnapshotEntry {
+            key,
+            value,
+            expires_at,
+        } in entries
+        {
+            if expires_at <= now_unix || writer.write_handle.contains_key(&key) {
+                continue;
+            }
+            let entry = MemoryEntry {
+                value,
+                update_time: now.into(),
+                ttl: expires_at - now_unix,
+            };
+            let entry_size = key.size_of() + entry.size_of();
+            if let Some(max_byte_size) = self.config.max_byte_size
+                && writer.metadata.byte_size.saturating_add(entry_size as u64) > max_byte_size
+            {
+                emit!(MemoryEnrichmentTableInsertFailed {
+                    key: &key,
+                    include_key_metric_tag: self.config.internal_metrics.include_key_tag
+                });
+                continue;
+            }
+            writer.metadata.byte_size = writer.metadata.byte_size.saturating_add(entry_size as u64);
+            writer.write_handle.update(key, entry);
+            restored += 1;
+        }
+        self.flush(writer);
+        emit!(MemoryEnrichmentTableSnapshotRestored { path, restored });
+    }
+
+    /// Writes the entries of the table that have not expired yet to its snapshot, if enabled.
+    async fn write_snapshot(&self) {
+        let Some(path) = &self.snapshot_path else {
+            return;
+        };
+
+        let now = Instant::now();
+        let now_unix = unix_timestamp();
+        let entries = self
+            .get_read_handle()
+            .read()
+            .map(|reader| {
+                reader
+                    .iter()
+                    .filter_map(|(k, v)| v.get_one().map(|entry| (k, entry)))
+                    .filter(|(_, entry)| !entry.expired(now))
+                    .map(|(k, entry)| entry.to_snapshot_entry(k, now, now_unix))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        let written = entries.len();
+        match snapshot::write(path, &entries).await {
+            Ok(()) => emit!(MemoryEnrichmentTableSnapshotWritten { written }),
+            Err(error) => emit!(MemoryEnrichmentTableSnapshotFailed { path, error }),
+        }
+    }
+
     pub(crate) fn as_source(
         &self,
         shutdown: ShutdownSignal,
@@ -300,6 +394,13 @@ impl Memory {
     }
 }
 
+fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default()
+}
+
 impl Clone for Memory {
     fn clone(&self) -> Self {
         Self {
@@ -307,6 +408,7 @@ impl Clone for Memory {
             read_handle: ThreadLocal::new(),
             write_handle: Arc::clone(&self.write_handle),
             config: self.config.clone(),
+            snapshot_path: self.snapshot_path.clone(),
             expired_items_sender: self.expired_items_sender.clone(),
             expired_items_receiver: self.expired_items_sender.subscribe(),
         }
@@ -404,6 +506,13 @@ impl StreamSink<Event> for Memory {
         let mut scan_interval = IntervalStream::new(interval(Duration::from_secs(
             self.config.scan_interval.into(),
         )));
+        let mut snapshot_interval = IntervalStream::new(interval(
+            self.config
+                .snapshot
+                .as_ref()
+                .map(|snapshot| Duration::from_secs(snapshot.interval_secs.get()))
+                .unwrap_or(Duration::MAX),
+        ));
 
         loop {
             tokio::select! {
@@ -438,7 +547,20 @@ impl StreamSink<Event> for Memory {
                     let writer = self.write_handle.lock().expect("mutex poisoned");
                     self.scan(writer);
                 }
+
+                Some(_) = snapshot_interval.next() => {
+                    self.write_snapshot().await;
+                }
+            }
+        }
+
+        if self.snapshot_path.is_some() {
+            // Make pending writes visible, so that they are part of the last snapshot.
+            {
+                let writer = self.write_handle.lock().expect("mutex poisoned");
+                self.flush(writer);
             }
+            self.write_snapshot().await;
         }
         Ok(())
     }
@@ -462,12 +584,16 @@ mod tests {
     use super::*;
     use crate::{
         enrichment_tables::memory::{
-            config::MemorySourceConfig, internal_events::InternalMetricsConfig,
+            config::{MemorySnapshotConfig, MemorySourceConfig},
+            internal_events::InternalMetricsConfig,
         },
         event::{Event, LogEvent},
-        test_util::components::{
-            SINK_TAGS, SOURCE_TAGS, run_and_assert_sink_compliance,
-            run_and_assert_source_compliance,
+        test_util::{
+            components::{
+                SINK_TAGS, SOURCE_TAGS, run_and_assert_sink_compliance,
+                run_and_assert_source_compliance,
+            },
+            temp_file,
         },
     };
 
@@ -785,6 +911,81 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn restores_snapshot_respecting_ttl() {
+        let path = temp_file();
+        let mut memory = Memory::new(Default::default());
+        {
+            let mut handle = memory.write_handle.lock().unwrap();
+            for (key, ttl, age) in [("fresh", 100, 10), ("expired", 100, 200)] {
+                handle.write_handle.update(
+                    key.to_string(),
+                    MemoryEntry {
+                        value: "5".to_string(),
+                        update_time: (Instant::now() - Duration::from_secs(age)).into(),
+                        ttl,
+                    },
+                );
+            }
+            handle.write_handle.refresh();
+        }
+        memory.snapshot_path = Some(path.clone());
+        memory.write_snapshot().await;
+
+        let restored = Memory::new(Default::default());
+        restored.restore_snapshot(&path).await;
+
+        let row = |key: &str| {
+            restored.find_table_row(
+                Case::Sensitive,
+                &[Condition::Equals {
+                    field: "key",
+                    value: Value::from(key),
+                }],
+                None,
+                None,
+                None,
+            )
+        };
+        let fresh = row("fresh").unwrap();
+        assert_eq!(fresh.get("value"), Some(&Value::from(5)));
+        assert!(matches!(fresh.get("ttl"), Some(Value::Integer(89..=90))));
+        assert!(row("expired").is_err());
+    }
+
+    #[tokio::test]
+    async fn writes_snapshot_on_shutdown() {
+        let path = temp_file();
+        let mut memory = Memory::new(build_memory_config(|c| {
+            c.snapshot = Some(MemorySnapshotConfig {
+                interval_secs: NonZeroU64::new(3600).unwrap(),
+            });
+        }));
+        memory.snapshot_path = Some(path.clone());
+        let event = Event::Log(LogEvent::from(ObjectMap::from([(
+            "test_key".into(),
+            Value::from(5),
+        )])));
+
+        // The first snapshot is written when the sink starts, so the entry is only part of the
+        // snapshot written once the input is closed.
+        run_and_assert_sink_compliance(
+            VectorSink::from_event_streamsink(memory),
+            stream::once(async move {
+                time::sleep(Duration::from_millis(100)).await;
+                event
+            }),
+            &SINK_TAGS,
+        )
+        .await;
+
+        let entries = snapshot::read(&path).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].key, "test_key");
+        assert_eq!(entries[0].value, "5");
+        assert!(entries[0].expires_at > unix_timestamp());
+    }
+
     #[tokio::test]
     async fn sink_spec_compliance() {
         let event = Event::Log(LogEvent::from(ObjectMap::from([(
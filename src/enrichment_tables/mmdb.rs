@@ -160,9 +160,7 @@ impl Table for Mmdb {
 
     /// Returns true if the underlying data has changed and the table needs reloading.
     fn needs_reload(&self) -> bool {
-        matches!(fs::metadata(&self.config.path)
-            .and_then(|metadata| metadata.modified()),
-            Ok(modified) if modified > self.last_modified)
+        super::maxmind_database_changed(&self.config.path, self.last_modified)
     }
 }
 
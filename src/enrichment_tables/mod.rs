@@ -1,5 +1,5 @@
 //! Functionality to handle enrichment tables.
-use std::path::PathBuf;
+use std::{path::PathBuf, time::Duration};
 
 use enum_dispatch::enum_dispatch;
 use vector_lib::configurable::configurable_component;
@@ -20,12 +20,20 @@ pub mod geoip;
 #[cfg(feature = "enrichment-tables-mmdb")]
 pub mod mmdb;
 
+#[cfg(feature = "enrichment-tables-sql")]
+pub mod sql;
+
+#[cfg(feature = "enrichment-tables-http")]
+pub mod http;
+
 /// Configuration options for an [enrichment table](https://vector.dev/docs/reference/glossary/#enrichment-tables) to be used in a
 /// [`remap`](https://vector.dev/docs/reference/configuration/transforms/remap/) transform. Currently supported are:
 ///
 /// * [CSV](https://en.wikipedia.org/wiki/Comma-separated_values) files
 /// * [MaxMind](https://www.maxmind.com/en/home) databases
 /// * In-memory storage
+/// * [PostgreSQL](https://www.postgresql.org/) and [SQLite](https://www.sqlite.org/) queries
+/// * HTTP lookups
 ///
 /// For the lookup in the enrichment tables to be as performant as possible, the data is indexed according
 /// to the fields that are used in the search. Note that indices can only be created for fields for which an
@@ -65,6 +73,16 @@ pub enum EnrichmentTables {
     /// [maxmind]: https://www.maxmind.com/
     #[cfg(feature = "enrichment-tables-mmdb")]
     Mmdb(mmdb::MmdbConfig),
+
+    /// Exposes the rows returned by a query to a SQL database as an enrichment table. The query is
+    /// run again periodically.
+    #[cfg(feature = "enrichment-tables-sql")]
+    Sql(sql::SqlConfig),
+
+    /// Exposes the rows returned by HTTP requests, made for each lookup, as an enrichment table.
+    /// The rows are cached, and requested in the background.
+    #[cfg(feature = "enrichment-tables-http")]
+    Http(http::HttpConfig),
 }
 
 impl GenerateConfig for EnrichmentTables {
@@ -91,6 +109,35 @@ impl EnrichmentTables {
             EnrichmentTables::Geoip(geoip_config) => vec![&geoip_config.path],
             #[cfg(feature = "enrichment-tables-mmdb")]
             EnrichmentTables::Mmdb(mmdb_config) => vec![&mmdb_config.path],
+            #[cfg(feature = "enrichment-tables-sql")]
+            EnrichmentTables::Sql(_) => vec![],
+            #[cfg(feature = "enrichment-tables-http")]
+            EnrichmentTables::Http(_) => vec![],
+        }
+    }
+
+    /// Gets the interval at which the table is to be reloaded, if it is periodically reloaded
+    pub fn refresh_interval(&self) -> Option<Duration> {
+        #[cfg(feature = "enrichment-tables-sql")]
+        if let EnrichmentTables::Sql(sql_config) = self {
+            return Some(sql_config.refresh_interval());
         }
+        None
     }
 }
+
+/// Checks whether the [MaxMind][maxmind] database at `path` changed since it was loaded, when it
+/// was last modified at `last_modified`.
+///
+/// Any change of the modified timestamp counts, as `geoipupdate` sets it to the build time of the
+/// database, which isn't necessarily later than the one of the database it replaces.
+///
+/// [maxmind]: https://maxmind.com
+#[cfg(any(
+    feature = "enrichment-tables-geoip",
+    feature = "enrichment-tables-mmdb"
+))]
+fn maxmind_database_changed(path: &std::path::Path, last_modified: std::time::SystemTime) -> bool {
+    matches!(std::fs::metadata(path).and_then(|metadata| metadata.modified()),
+        Ok(modified) if modified != last_modified)
+}
@@ -0,0 +1,50 @@
+use metrics::counter;
+use vector_lib::internal_event::{InternalEvent, error_stage, error_type};
+
+#[derive(Debug)]
+pub struct EnrichmentTableReloaded<'a> {
+    pub table: &'a str,
+}
+
+impl InternalEvent for EnrichmentTableReloaded<'_> {
+    fn emit(self) {
+        info!(
+            message = "Enrichment table reloaded.",
+            table = %self.table,
+            internal_log_rate_limit = false,
+        );
+        counter!(
+            "enrichment_table_reloads_total",
+            "table" => self.table.to_owned(),
+        )
+        .increment(1);
+    }
+}
+
+#[derive(Debug)]
+pub struct EnrichmentTableReloadError<'a> {
+    pub table: &'a str,
+    pub error: String,
+}
+
+impl InternalEvent for EnrichmentTableReloadError<'_> {
+    fn emit(self) {
+        error!(
+            message = "Enrichment table reload failed, the previously loaded data is still used.",
+            table = %self.table,
+            error = %self.error,
+            error_code = "enrichment_table_reload",
+            error_type = error_type::CONFIGURATION_FAILED,
+            stage = error_stage::PROCESSING,
+            internal_log_rate_limit = false,
+        );
+        counter!(
+            "component_errors_total",
+            "error_code" => "enrichment_table_reload",
+            "error_type" => error_type::CONFIGURATION_FAILED,
+            "stage" => error_stage::PROCESSING,
+            "table" => self.table.to_owned(),
+        )
+        .increment(1);
+    }
+}
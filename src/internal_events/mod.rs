@@ -46,6 +46,7 @@ mod dnstap;
 #[cfg(feature = "sources-docker_logs")]
 mod docker_logs;
 mod encoding_transcode;
+mod enrichment_tables;
 #[cfg(feature = "sources-eventstoredb_metrics")]
 mod eventstoredb_metrics;
 #[cfg(feature = "sources-exec")]
@@ -128,6 +129,10 @@ mod splunk_hec;
 mod statsd_sink;
 #[cfg(feature = "transforms-tag_cardinality_limit")]
 mod tag_cardinality_limit;
+#[cfg(feature = "transforms-tail_sample")]
+mod tail_sample;
+#[cfg(feature = "sources-tap_replay")]
+mod tap_replay;
 mod tcp;
 mod template;
 #[cfg(feature = "transforms-throttle")]
@@ -279,6 +284,10 @@ pub(crate) use self::splunk_hec::*;
 pub(crate) use self::statsd_sink::*;
 #[cfg(feature = "transforms-tag_cardinality_limit")]
 pub(crate) use self::tag_cardinality_limit::*;
+#[cfg(feature = "transforms-tail_sample")]
+pub(crate) use self::tail_sample::*;
+#[cfg(feature = "sources-tap_replay")]
+pub(crate) use self::tap_replay::*;
 #[cfg(feature = "transforms-throttle")]
 pub(crate) use self::throttle::*;
 #[cfg(unix)]
@@ -293,5 +302,6 @@ pub(crate) use self::window::*;
 pub(crate) use self::windows::*;
 pub use self::{
     adaptive_concurrency::*, batch::*, common::*, conditions::*, encoding_transcode::*,
-    heartbeat::*, http::*, open::*, process::*, socket::*, tcp::*, template::*, udp::*,
+    enrichment_tables::*, heartbeat::*, http::*, open::*, process::*, socket::*, tcp::*,
+    template::*, udp::*,
 };
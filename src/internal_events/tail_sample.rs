@@ -0,0 +1,26 @@
+use metrics::counter;
+use vector_lib::internal_event::{ComponentEventsDropped, INTENTIONAL, InternalEvent};
+
+#[derive(Debug)]
+pub struct TailSampleSpansDiscarded {
+    pub count: usize,
+}
+
+impl InternalEvent for TailSampleSpansDiscarded {
+    fn emit(self) {
+        emit!(ComponentEventsDropped::<INTENTIONAL> {
+            count: self.count,
+            reason: "Trace not sampled."
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct TailSampleTraceEvicted;
+
+impl InternalEvent for TailSampleTraceEvicted {
+    fn emit(self) {
+        debug!(message = "Trace buffer is full, deciding oldest trace early.");
+        counter!("tail_sample_evicted_traces_total").increment(1);
+    }
+}
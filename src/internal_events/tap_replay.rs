@@ -0,0 +1,31 @@
+use std::path::Path;
+
+use metrics::counter;
+use vector_lib::internal_event::{InternalEvent, error_stage, error_type};
+
+#[derive(Debug)]
+pub struct TapReplayCaptureError<'a, E> {
+    pub path: &'a Path,
+    pub error: E,
+}
+
+impl<E> InternalEvent for TapReplayCaptureError<'_, E>
+where
+    E: std::fmt::Display,
+{
+    fn emit(self) {
+        error!(
+            message = "Error reading capture file.",
+            path = %self.path.display(),
+            error = %self.error,
+            error_type = error_type::READER_FAILED,
+            stage = error_stage::RECEIVING,
+        );
+        counter!(
+            "component_errors_total",
+            "error_type" => error_type::READER_FAILED,
+            "stage" => error_stage::RECEIVING,
+        )
+        .increment(1);
+    }
+}
@@ -70,6 +70,7 @@ pub mod amqp;
 pub mod api;
 pub mod app;
 pub mod async_read;
+mod buffer;
 #[cfg(feature = "aws-config")]
 pub mod aws;
 #[allow(unreachable_pub)]
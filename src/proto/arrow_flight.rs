@@ -0,0 +1,7 @@
+#![allow(clippy::clone_on_ref_ptr)]
+#![allow(warnings, clippy::pedantic, clippy::nursery)]
+
+tonic::include_proto!("arrow.flight.protocol");
+
+pub use flight_service_client::FlightServiceClient as Client;
+pub use flight_service_server::{FlightService as Service, FlightServiceServer as Server};
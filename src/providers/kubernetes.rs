@@ -0,0 +1,853 @@
+use std::{
+    collections::BTreeMap,
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use async_stream::stream;
+use futures::{Stream, StreamExt, stream};
+use k8s_openapi::api::core::v1::{ConfigMap, Secret};
+use kube::{
+    Api, Client, Config as ClientConfig,
+    api::{ListParams, Patch, PatchParams},
+    config::{KubeConfigOptions, Kubeconfig},
+    runtime::{WatchStreamExt, watcher},
+};
+use tokio::sync::broadcast;
+use vector_lib::configurable::configurable_component;
+
+use super::BuildResult;
+use crate::{
+    config::{self, Config, Format, provider::ProviderConfig},
+    signal::{self, ConfigStatus},
+};
+
+/// Annotation the outcome of loading the configuration is reported in, on each resource.
+const STATUS_ANNOTATION: &str = "vector.dev/config-status";
+
+/// Configuration for the `kubernetes` provider.
+#[configurable_component(provider("kubernetes"))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields, default)]
+pub struct KubernetesConfig {
+    /// Label selector of the ConfigMaps, and Secrets if enabled, to load the configuration from.
+    ///
+    /// The entries of all the matching resources are merged into a single configuration, as if
+    /// each of them was a separate configuration file.
+    #[configurable(metadata(docs::examples = "app.kubernetes.io/part-of=vector-config"))]
+    label_selector: Option<String>,
+
+    /// Namespace of the ConfigMaps and Secrets.
+    ///
+    /// If not set, the namespace Vector is running in is used.
+    #[configurable(metadata(docs::examples = "vector"))]
+    namespace: Option<String>,
+
+    /// Optional path to a readable [kubeconfig][kubeconfig] file.
+    ///
+    /// If not set, a connection to Kubernetes is made using the in-cluster configuration.
+    ///
+    /// [kubeconfig]: https://kubernetes.io/docs/concepts/configuration/organize-cluster-access-kubeconfig/
+    #[configurable(metadata(docs::examples = "/path/to/.kube/config"))]
+    kube_config_file: Option<PathBuf>,
+
+    /// Format of the entries whose key doesn't end with a `.toml`, `.yaml`, `.yml` or `.json`
+    /// extension.
+    #[configurable(derived)]
+    config_format: Format,
+
+    /// Whether to also load the configuration from the Secrets matching the label selector.
+    ///
+    /// This is useful for parts of the configuration holding credentials. This requires the
+    /// permission to `list` and `watch` Secrets.
+    include_secrets: bool,
+
+    /// Whether to report the outcome of loading the configuration on the ConfigMaps and Secrets.
+    ///
+    /// The outcome is written to the `vector.dev/config-status` annotation of each resource, once
+    /// the configuration is running or failed to: `loaded`, `invalid (<count> errors)` or
+    /// `failed`. The errors themselves are logged. This requires the permission to `patch` them.
+    report_status: bool,
+}
+
+impl Default for KubernetesConfig {
+    fn default() -> Self {
+        Self {
+            label_selector: None,
+            namespace: None,
+            kube_config_file: None,
+            config_format: Format::default(),
+            include_secrets: false,
+            report_status: true,
+        }
+    }
+}
+
+/// Kind of the resources the configuration is loaded from.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+enum Kind {
+    ConfigMap,
+    Secret,
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ConfigMap => f.write_str("configmap"),
+            Self::Secret => f.write_str("secret"),
+        }
+    }
+}
+
+/// Entries of a single resource, by key.
+type Data = BTreeMap<String, String>;
+
+/// Entries of resources, by kind and name of the resource.
+type Entries = BTreeMap<(Kind, String), Data>;
+
+fn config_map_entry(config_map: ConfigMap) -> Option<((Kind, String), Data)> {
+    Some((
+        (Kind::ConfigMap, config_map.metadata.name?),
+        config_map.data.unwrap_or_default(),
+    ))
+}
+
+fn secret_entry(secret: Secret) -> Option<((Kind, String), Data)> {
+    // Secret values are arbitrary bytes, while configuration is text, so invalid UTF-8 sequences
+    // are replaced rather than making the whole Secret unusable.
+    let data = secret
+        .data
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(key, value)| (key, String::from_utf8_lossy(&value.0).into_owned()))
+        .chain(secret.string_data.unwrap_or_default())
+        .collect();
+    Some(((Kind::Secret, secret.metadata.name?), data))
+}
+
+/// Merges the entries of resources into a single configuration, and validates it.
+fn load_entries(entries: &Entries, config_format: Format) -> BuildResult {
+    let mut builder = Config::builder();
+    let mut errors = Vec::new();
+
+    for ((kind, name), data) in entries {
+        for (key, value) in data {
+            let format = Format::from_path(key).unwrap_or(config_format);
+            if let Err(errs) =
+                config::load(value.as_bytes(), format).and_then(|b| builder.append(b))
+            {
+                errors.extend(
+                    errs.into_iter()
+                        .map(|e| format!("{kind}/{name}/{key}: {e}")),
+                );
+            }
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    builder.clone().build()?;
+    Ok(builder)
+}
+
+/// APIs of the resources the configuration is loaded from.
+#[derive(Clone)]
+struct Apis {
+    config_maps: Api<ConfigMap>,
+    secrets: Option<Api<Secret>>,
+}
+
+impl Apis {
+    /// Lists the resources matching `label_selector`.
+    async fn list(&self, label_selector: &str) -> Result<Entries, kube::Error> {
+        let params = ListParams::default().labels(label_selector);
+        let mut entries = self
+            .config_maps
+            .list(&params)
+            .await?
+            .items
+            .into_iter()
+            .filter_map(config_map_entry)
+            .collect::<Entries>();
+        if let Some(secrets) = &self.secrets {
+            entries.extend(
+                secrets
+                    .list(&params)
+                    .await?
+                    .items
+                    .into_iter()
+                    .filter_map(secret_entry),
+            );
+        }
+        Ok(entries)
+    }
+
+    /// Annotates resources with the status of the configuration loaded from them.
+    async fn annotate_status(&self, entries: &Entries, status: ConfigStatus) {
+        let status = status_annotation(status);
+        let patch = Patch::Merge(serde_json::json!({
+            "metadata": { "annotations": { STATUS_ANNOTATION: status } }
+        }));
+
+        for (kind, name) in entries.keys() {
+            let result = match (kind, &self.secrets) {
+                (Kind::ConfigMap, _) => self
+                    .config_maps
+                    .patch(name, &PatchParams::default(), &patch)
+                    .await
+                    .map(|_| ()),
+                (Kind::Secret, Some(secrets)) => secrets
+                    .patch(name, &PatchParams::default(), &patch)
+                    .await
+                    .map(|_| ()),
+                (Kind::Secret, None) => continue,
+            };
+            if let Err(error) = result {
+                warn!(
+                    message = "Couldn't report configuration status.",
+                    resource = %format!("{kind}/{name}"),
+                    %error
+                );
+            }
+        }
+    }
+
+    /// Watches the resources matching `label_selector`.
+    fn watch(
+        &self,
+        label_selector: &str,
+    ) -> impl Stream<
+        Item = (
+            Kind,
+            watcher::Result<watcher::Event<((Kind, String), Data)>>,
+        ),
+    > + use<> {
+        let watcher_config = watcher::Config::default().labels(label_selector);
+
+        let config_maps = watcher(self.config_maps.clone(), watcher_config.clone())
+            .backoff(watcher::DefaultBackoff::default())
+            .map(|event| {
+                (
+                    Kind::ConfigMap,
+                    event.map(|event| map_event(event, config_map_entry)),
+                )
+            });
+        let secrets = self.secrets.clone().map(|secrets| {
+            watcher(secrets, watcher_config)
+                .backoff(watcher::DefaultBackoff::default())
+                .map(|event| {
+                    (
+                        Kind::Secret,
+                        event.map(|event| map_event(event, secret_entry)),
+                    )
+                })
+        });
+
+        stream::select(config_maps, stream::iter(secrets).flatten())
+    }
+}
+
+/// Returns the value of the status annotation for the given status.
+///
+/// The errors aren't included, as they're already logged, and may reveal parts of the
+/// configuration that are meant to be secret.
+fn status_annotation(status: ConfigStatus) -> String {
+    match status {
+        ConfigStatus::Loaded => "loaded".to_string(),
+        ConfigStatus::Invalid(1) => "invalid (1 error)".to_string(),
+        ConfigStatus::Invalid(errors) => format!("invalid ({errors} errors)"),
+        ConfigStatus::Failed => "failed".to_string(),
+    }
+}
+
+/// Maps the resource of a watcher event into its entries.
+///
+/// Resources without a name are ignored by mapping their events to `Init` events, which don't
+/// change anything when they're handled out of order.
+fn map_event<K>(
+    event: watcher::Event<K>,
+    entry: fn(K) -> Option<((Kind, String), Data)>,
+) -> watcher::Event<((Kind, String), Data)> {
+    match event {
+        watcher::Event::Apply(resource) => {
+            entry(resource).map_or(watcher::Event::Init, watcher::Event::Apply)
+        }
+        watcher::Event::Delete(resource) => {
+            entry(resource).map_or(watcher::Event::Init, watcher::Event::Delete)
+        }
+        watcher::Event::Init => watcher::Event::Init,
+        watcher::Event::InitApply(resource) => {
+            entry(resource).map_or(watcher::Event::Init, watcher::Event::InitApply)
+        }
+        watcher::Event::InitDone => watcher::Event::InitDone,
+    }
+}
+
+async fn create_client(kube_config_file: Option<&Path>) -> Result<Client, String> {
+    // If the user passed a custom Kubeconfig use it, otherwise we attempt to load the local
+    // kubeconfig, followed by the in-cluster environment variables
+    let client_config = match kube_config_file {
+        Some(path) => {
+            let kubeconfig = Kubeconfig::read_from(path).map_err(|error| error.to_string())?;
+            ClientConfig::from_custom_kubeconfig(kubeconfig, &KubeConfigOptions::default())
+                .await
+                .map_err(|error| error.to_string())?
+        }
+        None => ClientConfig::infer()
+            .await
+            .map_err(|error| error.to_string())?,
+    };
+
+    Client::try_from(client_config).map_err(|error| error.to_string())
+}
+
+/// Watches the resources matching `label_selector`, returning a stream of `ConfigBuilder` each
+/// time their entries change and still make up a valid configuration.
+///
+/// If `config_status` is given, the resources are annotated with the status of each configuration
+/// reported on it, as it's the one loaded from their latest entries.
+fn watch_resources(
+    apis: Apis,
+    label_selector: String,
+    mut loaded: Entries,
+    config_format: Format,
+    mut config_status: Option<broadcast::Receiver<ConfigStatus>>,
+) -> impl Stream<Item = signal::SignalTo> {
+    stream! {
+        let events = apis.watch(&label_selector);
+        tokio::pin!(events);
+
+        let mut current = loaded.clone();
+        // Resources listed while a watcher (re)initializes, until it's done listing them.
+        let mut initial = Entries::new();
+        loop {
+            let (kind, event) = tokio::select! {
+                Some(status) = next_status(&mut config_status) => {
+                    apis.annotate_status(&loaded, status).await;
+                    continue;
+                }
+                Some(next) = events.next() => next,
+                else => break,
+            };
+
+            match event {
+                Ok(watcher::Event::Apply((key, data))) => {
+                    current.insert(key, data);
+                }
+                Ok(watcher::Event::Delete((key, _))) => {
+                    current.remove(&key);
+                }
+                Ok(watcher::Event::Init) => {
+                    initial.retain(|(k, _), _| *k != kind);
+                    continue;
+                }
+                Ok(watcher::Event::InitApply((key, data))) => {
+                    initial.insert(key, data);
+                    continue;
+                }
+                Ok(watcher::Event::InitDone) => {
+                    current.retain(|(k, _), _| *k != kind);
+                    let (done, pending): (Entries, Entries) = std::mem::take(&mut initial)
+                        .into_iter()
+                        .partition(|((k, _), _)| *k == kind);
+                    current.extend(done);
+                    initial = pending;
+                }
+                Err(error) => {
+                    warn!(message = "Failed to watch resources.", %kind, %error);
+                    continue;
+                }
+            }
+
+            // Annotating the resources with the status triggers events too, which are skipped
+            // here since their entries are unchanged.
+            if current == loaded {
+                continue;
+            }
+            loaded = current.clone();
+
+            info!(
+                message = "Resources changed, reloading configuration.",
+                resources = ?loaded.keys().map(|(kind, name)| format!("{kind}/{name}")).collect::<Vec<_>>()
+            );
+            match load_entries(&loaded, config_format) {
+                // The status is reported once the configuration is reloaded.
+                Ok(config_builder) => {
+                    yield signal::SignalTo::ReloadFromConfigBuilder(config_builder)
+                }
+                Err(errors) => {
+                    if config_status.is_some() {
+                        apis.annotate_status(&loaded, ConfigStatus::Invalid(errors.len()))
+                            .await;
+                    }
+                    for error in errors {
+                        error!(
+                            message = "Invalid configuration in resources, keeping the current one.",
+                            %error
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Receives the next configuration status, if they're reported.
+async fn next_status(
+    config_status: &mut Option<broadcast::Receiver<ConfigStatus>>,
+) -> Option<ConfigStatus> {
+    let receiver = config_status.as_mut()?;
+    loop {
+        match receiver.recv().await {
+            Ok(status) => return Some(status),
+            // Only the latest status matters.
+            Err(broadcast::error::RecvError::Lagged(_)) => continue,
+            Err(broadcast::error::RecvError::Closed) => {
+                *config_status = None;
+                return None;
+            }
+        }
+    }
+}
+
+impl KubernetesConfig {
+    /// Loads the configuration from the resources matching `label_selector`, and watches them for
+    /// changes.
+    async fn load(
+        &self,
+        apis: Apis,
+        label_selector: String,
+        signal_handler: &mut signal::SignalHandler,
+    ) -> BuildResult {
+        let entries = apis
+            .list(&label_selector)
+            .await
+            .map_err(|error| vec![format!("Couldn't list resources: {error}")])?;
+
+        info!(
+            message = "Loading configuration from resources.",
+            resources = ?entries.keys().map(|(kind, name)| format!("{kind}/{name}")).collect::<Vec<_>>()
+        );
+        let config_builder = match load_entries(&entries, self.config_format) {
+            Ok(config_builder) => config_builder,
+            Err(errors) => {
+                if self.report_status {
+                    apis.annotate_status(&entries, ConfigStatus::Invalid(errors.len()))
+                        .await;
+                }
+                return Err(errors);
+            }
+        };
+
+        // Watch for changes to the resources, subscribing to the status of the configuration
+        // before it's reported once it's running.
+        let config_status = self
+            .report_status
+            .then(|| signal_handler.subscribe_config_status());
+        signal_handler.add(watch_resources(
+            apis,
+            label_selector,
+            entries,
+            self.config_format,
+            config_status,
+        ));
+
+        Ok(config_builder)
+    }
+
+    fn apis(&self, client: Client) -> Apis {
+        let secrets = self.include_secrets.then(|| match &self.namespace {
+            Some(namespace) => Api::namespaced(client.clone(), namespace),
+            None => Api::default_namespaced(client.clone()),
+        });
+        let config_maps = match &self.namespace {
+            Some(namespace) => Api::namespaced(client, namespace),
+            None => Api::default_namespaced(client),
+        };
+        Apis {
+            config_maps,
+            secrets,
+        }
+    }
+}
+
+impl ProviderConfig for KubernetesConfig {
+    async fn build(&mut self, signal_handler: &mut signal::SignalHandler) -> BuildResult {
+        let label_selector = self.label_selector.clone().ok_or_else(|| {
+            vec!["A label selector is required for the `kubernetes` provider.".to_owned()]
+        })?;
+
+        let client = create_client(self.kube_config_file.as_deref())
+            .await
+            .map_err(|error| vec![format!("Couldn't create Kubernetes client: {error}")])?;
+
+        self.load(self.apis(client), label_selector, signal_handler)
+            .await
+    }
+}
+
+impl_generate_config_from_default!(KubernetesConfig);
+
+#[cfg(test)]
+mod tests {
+    use std::{convert::Infallible, net::SocketAddr, time::Duration};
+
+    use bytes::Bytes;
+    use http::request::Parts;
+    use hyper::{
+        Body, Request, Response, Server,
+        service::{make_service_fn, service_fn},
+    };
+    use tokio::sync::mpsc;
+
+    use super::*;
+    use crate::test_util::next_addr;
+
+    const CONFIG_MAPS: &str = r#"{
+        "apiVersion": "v1",
+        "kind": "ConfigMapList",
+        "metadata": { "resourceVersion": "1" },
+        "items": [
+            {
+                "apiVersion": "v1",
+                "kind": "ConfigMap",
+                "metadata": { "name": "sinks", "namespace": "default", "resourceVersion": "1" },
+                "data": { "sinks.toml": "[sinks.out]\ntype = \"blackhole\"\ninputs = [\"in\"]\n" }
+            },
+            {
+                "apiVersion": "v1",
+                "kind": "ConfigMap",
+                "metadata": { "name": "sources", "namespace": "default", "resourceVersion": "1" },
+                "data": { "sources.yaml": "sources:\n  in:\n    type: demo_logs\n    format: json\n" }
+            }
+        ]
+    }"#;
+
+    const SECRETS: &str = r#"{
+        "apiVersion": "v1",
+        "kind": "SecretList",
+        "metadata": { "resourceVersion": "1" },
+        "items": [
+            {
+                "apiVersion": "v1",
+                "kind": "Secret",
+                "metadata": { "name": "credentials", "namespace": "default", "resourceVersion": "1" },
+                "data": { "sinks.toml": "W3NpbmtzLnNlY3JldF0KdHlwZSA9ICJibGFja2hvbGUiCmlucHV0cyA9IFsiaW4iXQo=" }
+            }
+        ]
+    }"#;
+
+    /// Update of the `sinks` ConfigMap, streamed to watch requests.
+    const CONFIG_MAP_MODIFIED: &str = concat!(
+        r#"{"type":"MODIFIED","object":{"apiVersion":"v1","kind":"ConfigMap","#,
+        r#""metadata":{"name":"sinks","namespace":"default","resourceVersion":"2"},"#,
+        r#""data":{"sinks.toml":"[sinks.changed]\ntype = \"blackhole\"\ninputs = [\"in\"]\n"}}}"#,
+        "\n"
+    );
+
+    /// Responds to a request to the Kubernetes API like the API server would.
+    fn respond(parts: &Parts) -> Response<Body> {
+        let path = parts.uri.path();
+        let watch = parts
+            .uri
+            .query()
+            .is_some_and(|query| query.contains("watch=true"));
+
+        let body = match (parts.method.as_str(), path.rsplit('/').next().unwrap()) {
+            ("GET", "configmaps") if watch => CONFIG_MAP_MODIFIED.to_string(),
+            ("GET", "configmaps") => CONFIG_MAPS.to_string(),
+            // No changes to the Secrets, end the watch without events.
+            ("GET", "secrets") if watch => String::new(),
+            ("GET", "secrets") => SECRETS.to_string(),
+            ("PATCH", name) => {
+                let kind = if path.contains("/secrets/") {
+                    "Secret"
+                } else {
+                    "ConfigMap"
+                };
+                serde_json::json!({
+                    "apiVersion": "v1",
+                    "kind": kind,
+                    "metadata": { "name": name, "namespace": "default" },
+                })
+                .to_string()
+            }
+            _ => {
+                return Response::builder().status(404).body(Body::empty()).unwrap();
+            }
+        };
+
+        Response::builder()
+            .header("Content-Type", "application/json")
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    /// Runs a mock Kubernetes API server, returning the requests it received in order.
+    fn mock_api_server(addr: SocketAddr) -> mpsc::UnboundedReceiver<(Parts, Bytes)> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let service = make_service_fn(move |_| {
+            let tx = tx.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request: Request<Body>| {
+                    let tx = tx.clone();
+                    async move {
+                        let (parts, body) = request.into_parts();
+                        let body = hyper::body::to_bytes(body).await.unwrap();
+                        let response = respond(&parts);
+                        _ = tx.send((parts, body));
+                        Ok::<_, Infallible>(response)
+                    }
+                }))
+            }
+        });
+        tokio::spawn(Server::bind(&addr).serve(service));
+        rx
+    }
+
+    fn apis(addr: SocketAddr, include_secrets: bool) -> Apis {
+        let client =
+            Client::try_from(ClientConfig::new(format!("http://{addr}").parse().unwrap())).unwrap();
+        KubernetesConfig {
+            include_secrets,
+            ..Default::default()
+        }
+        .apis(client)
+    }
+
+    /// Receives the next `count` requests, sorted by method and path.
+    async fn next_requests(
+        rx: &mut mpsc::UnboundedReceiver<(Parts, Bytes)>,
+        count: usize,
+    ) -> Vec<(Parts, Bytes)> {
+        let mut requests = Vec::new();
+        for _ in 0..count {
+            requests.push(rx.recv().await.unwrap());
+        }
+        requests.sort_by(|(a, _), (b, _)| {
+            (a.method.as_str(), a.uri.path()).cmp(&(b.method.as_str(), b.uri.path()))
+        });
+        requests
+    }
+
+    /// Receives the next `count` PATCH requests, skipping the others, sorted by path.
+    async fn next_patches(
+        rx: &mut mpsc::UnboundedReceiver<(Parts, Bytes)>,
+        count: usize,
+    ) -> Vec<(Parts, Bytes)> {
+        let mut patches = Vec::new();
+        while patches.len() < count {
+            let request = tokio::time::timeout(Duration::from_secs(10), rx.recv())
+                .await
+                .expect("resources weren't annotated")
+                .unwrap();
+            if request.0.method == "PATCH" {
+                patches.push(request);
+            }
+        }
+        patches.sort_by(|(a, _), (b, _)| a.uri.path().cmp(b.uri.path()));
+        patches
+    }
+
+    fn assert_status((parts, body): &(Parts, Bytes), path: &str, status: &str) {
+        assert_eq!(parts.method, "PATCH");
+        assert_eq!(parts.uri.path(), path);
+        let annotation = format!(r#""vector.dev/config-status":"{status}""#);
+        assert!(String::from_utf8_lossy(body).contains(&annotation));
+    }
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<KubernetesConfig>();
+    }
+
+    #[tokio::test]
+    async fn loads_config_maps() {
+        let addr = next_addr();
+        let mut rx = mock_api_server(addr);
+        let config = KubernetesConfig::default();
+        let (mut signal_handler, _signal_rx) = signal::SignalHandler::new();
+
+        let builder = config
+            .load(
+                apis(addr, false),
+                "app=vector".to_string(),
+                &mut signal_handler,
+            )
+            .await
+            .unwrap();
+        assert!(builder.sources.contains_key(&"in".into()));
+        assert!(builder.sinks.contains_key(&"out".into()));
+
+        // The ConfigMaps are listed before watching them, and are only annotated once the
+        // configuration is running.
+        let (parts, _) = &next_requests(&mut rx, 1).await[0];
+        assert_eq!(parts.method, "GET");
+        assert_eq!(parts.uri.path(), "/api/v1/namespaces/default/configmaps");
+        assert!(
+            parts
+                .uri
+                .query()
+                .unwrap()
+                .contains("labelSelector=app%3Dvector")
+        );
+
+        signal_handler.report_config_status(ConfigStatus::Loaded);
+        let patches = next_patches(&mut rx, 2).await;
+        assert_status(
+            &patches[0],
+            "/api/v1/namespaces/default/configmaps/sinks",
+            "loaded",
+        );
+        assert_status(
+            &patches[1],
+            "/api/v1/namespaces/default/configmaps/sources",
+            "loaded",
+        );
+
+        signal_handler.clear();
+    }
+
+    #[tokio::test]
+    async fn loads_secrets() {
+        let addr = next_addr();
+        let mut rx = mock_api_server(addr);
+        let config = KubernetesConfig {
+            include_secrets: true,
+            ..Default::default()
+        };
+        let (mut signal_handler, _signal_rx) = signal::SignalHandler::new();
+
+        let builder = config
+            .load(
+                apis(addr, true),
+                "app=vector".to_string(),
+                &mut signal_handler,
+            )
+            .await
+            .unwrap();
+        assert!(builder.sinks.contains_key(&"out".into()));
+        assert!(builder.sinks.contains_key(&"secret".into()));
+
+        let requests = next_requests(&mut rx, 2).await;
+        assert_eq!(
+            requests[1].0.uri.path(),
+            "/api/v1/namespaces/default/secrets"
+        );
+
+        signal_handler.report_config_status(ConfigStatus::Failed);
+        let patches = next_patches(&mut rx, 3).await;
+        assert_status(
+            &patches[2],
+            "/api/v1/namespaces/default/secrets/credentials",
+            "failed",
+        );
+
+        signal_handler.clear();
+    }
+
+    #[tokio::test]
+    async fn reloads_on_config_map_update() {
+        let addr = next_addr();
+        let _rx = mock_api_server(addr);
+        let apis = apis(addr, false);
+        let loaded = apis.list("app=vector").await.unwrap();
+
+        // Listing the unchanged ConfigMaps when the watch starts doesn't reload the configuration,
+        // only the update of the `sinks` ConfigMap does.
+        let signals = watch_resources(apis, "app=vector".to_string(), loaded, Format::Toml, None);
+        tokio::pin!(signals);
+        let signal = tokio::time::timeout(Duration::from_secs(10), signals.next())
+            .await
+            .expect("configuration wasn't reloaded")
+            .unwrap();
+
+        let signal::SignalTo::ReloadFromConfigBuilder(builder) = signal else {
+            panic!("unexpected signal");
+        };
+        assert!(builder.sources.contains_key(&"in".into()));
+        assert!(builder.sinks.contains_key(&"changed".into()));
+        assert!(!builder.sinks.contains_key(&"out".into()));
+    }
+
+    #[tokio::test]
+    async fn annotates_status_of_reloaded_config() {
+        let addr = next_addr();
+        let mut rx = mock_api_server(addr);
+        let apis = apis(addr, false);
+        let loaded = apis.list("app=vector").await.unwrap();
+        _ = rx.recv().await;
+
+        let (status_tx, status_rx) = broadcast::channel(1);
+        let signals = watch_resources(
+            apis,
+            "app=vector".to_string(),
+            loaded,
+            Format::Toml,
+            Some(status_rx),
+        );
+        tokio::pin!(signals);
+        assert!(matches!(
+            signals.next().await,
+            Some(signal::SignalTo::ReloadFromConfigBuilder(_))
+        ));
+
+        // The resources are only annotated once the reloaded configuration is reported on.
+        while let Ok((parts, _)) = rx.try_recv() {
+            assert_ne!(parts.method, "PATCH");
+        }
+        status_tx.send(ConfigStatus::Invalid(2)).unwrap();
+        let annotated = async {
+            let patches = next_patches(&mut rx, 2).await;
+            assert_status(
+                &patches[0],
+                "/api/v1/namespaces/default/configmaps/sinks",
+                "invalid (2 errors)",
+            );
+        };
+        tokio::select! {
+            _ = annotated => {},
+            _ = signals.by_ref().for_each(|_| async {}) => panic!("watch ended"),
+        }
+    }
+
+    #[test]
+    fn status_annotations() {
+        assert_eq!(status_annotation(ConfigStatus::Loaded), "loaded");
+        assert_eq!(
+            status_annotation(ConfigStatus::Invalid(1)),
+            "invalid (1 error)"
+        );
+        assert_eq!(
+            status_annotation(ConfigStatus::Invalid(3)),
+            "invalid (3 errors)"
+        );
+        assert_eq!(status_annotation(ConfigStatus::Failed), "failed");
+    }
+
+    #[test]
+    fn rejects_invalid_entries() {
+        let entries = Entries::from([(
+            (Kind::ConfigMap, "sources".to_string()),
+            BTreeMap::from([("sources.yaml".to_string(), "sources: [".to_string())]),
+        )]);
+
+        let errors = load_entries(&entries, Format::Toml).unwrap_err();
+        assert!(errors[0].starts_with("configmap/sources/sources.yaml: "));
+    }
+
+    #[test]
+    fn rejects_invalid_config() {
+        let entries = Entries::from([(
+            (Kind::Secret, "sinks".to_string()),
+            BTreeMap::from([(
+                "sinks".to_string(),
+                "[sinks.out]\ntype = \"blackhole\"\ninputs = [\"in\"]\n".to_string(),
+            )]),
+        )]);
+
+        assert!(load_entries(&entries, Format::Toml).is_err());
+    }
+}
@@ -8,6 +8,8 @@ use crate::{
 };
 
 pub mod http;
+#[cfg(feature = "kubernetes")]
+pub mod kubernetes;
 
 pub type BuildResult = std::result::Result<ConfigBuilder, Vec<String>>;
 
@@ -19,6 +21,10 @@ pub type BuildResult = std::result::Result<ConfigBuilder, Vec<String>>;
 pub enum Providers {
     /// HTTP.
     Http(http::HttpConfig),
+
+    /// Kubernetes ConfigMaps.
+    #[cfg(feature = "kubernetes")]
+    Kubernetes(kubernetes::KubernetesConfig),
 }
 
 // TODO: Use `enum_dispatch` here.
@@ -26,6 +32,8 @@ impl NamedComponent for Providers {
     fn get_component_name(&self) -> &'static str {
         match self {
             Self::Http(config) => config.get_component_name(),
+            #[cfg(feature = "kubernetes")]
+            Self::Kubernetes(config) => config.get_component_name(),
         }
     }
 }
@@ -1,4 +1,7 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
 
 use aws_sdk_secretsmanager::{Client, config};
 use vector_lib::configurable::{component::GenerateConfig, configurable_component};
@@ -6,6 +9,7 @@ use vector_lib::configurable::{component::GenerateConfig, configurable_component
 use crate::{
     aws::{AwsAuthentication, ClientBuilder, RegionOrEndpoint, create_client},
     config::{ProxyConfig, SecretBackend},
+    secrets::RefreshConfig,
     signal,
     tls::TlsConfig,
 };
@@ -38,6 +42,10 @@ pub struct AwsSecretsManagerBackend {
 
     #[configurable(derived)]
     pub tls: Option<TlsConfig>,
+
+    #[configurable(derived)]
+    #[serde(flatten)]
+    pub refresh: RefreshConfig,
 }
 
 impl GenerateConfig for AwsSecretsManagerBackend {
@@ -47,6 +55,7 @@ impl GenerateConfig for AwsSecretsManagerBackend {
             region: Default::default(),
             auth: Default::default(),
             tls: None,
+            refresh: Default::default(),
         })
         .unwrap()
     }
@@ -105,4 +114,8 @@ impl SecretBackend for AwsSecretsManagerBackend {
         }
         Ok(secrets)
     }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        self.refresh.interval()
+    }
 }
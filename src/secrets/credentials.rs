@@ -0,0 +1,221 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Component, Path, PathBuf},
+    time::Duration,
+};
+
+use tokio::process::Command;
+use vector_lib::configurable::configurable_component;
+
+use crate::{config::SecretBackend, secrets::RefreshConfig, signal};
+
+/// Configuration for the `credentials` secrets backend.
+///
+/// Reads the [credentials][credentials] systemd passes to Vector, such as with `LoadCredential=`,
+/// each secret key being the name of a credential.
+///
+/// [credentials]: https://systemd.io/CREDENTIALS/
+#[configurable_component(secrets("credentials"))]
+#[derive(Clone, Debug, Default)]
+pub struct CredentialsBackend {
+    /// The directory to read the credentials from.
+    ///
+    /// Defaults to the directory in the `CREDENTIALS_DIRECTORY` environment variable, set by
+    /// systemd.
+    #[configurable(metadata(docs::examples = "/run/credentials/vector.service"))]
+    pub directory: Option<PathBuf>,
+
+    /// Whether the credentials are encrypted with `systemd-creds encrypt`.
+    ///
+    /// Encrypted credentials are decrypted with `systemd-creds decrypt`. Credentials passed with
+    /// `LoadCredentialEncrypted=` are already decrypted by systemd.
+    #[serde(default)]
+    pub encrypted: bool,
+
+    /// Remove trailing whitespace from file contents.
+    #[serde(default)]
+    pub remove_trailing_whitespace: bool,
+
+    #[configurable(derived)]
+    #[serde(flatten)]
+    pub refresh: RefreshConfig,
+}
+
+impl_generate_config_from_default!(CredentialsBackend);
+
+impl CredentialsBackend {
+    fn directory(&self) -> crate::Result<PathBuf> {
+        match &self.directory {
+            Some(directory) => Ok(directory.clone()),
+            None => std::env::var_os("CREDENTIALS_DIRECTORY")
+                .map(PathBuf::from)
+                .ok_or_else(|| "neither `directory` nor CREDENTIALS_DIRECTORY is set".into()),
+        }
+    }
+
+    fn path(directory: &Path, key: &str) -> crate::Result<PathBuf> {
+        let mut components = Path::new(key).components();
+        match (components.next(), components.next()) {
+            (Some(Component::Normal(_)), None) if !key.contains(['/', '\\']) => {
+                Ok(directory.join(key))
+            }
+            _ => Err(format!("credential '{key}' is not a file name").into()),
+        }
+    }
+
+    async fn read(&self, path: PathBuf) -> crate::Result<String> {
+        if !self.encrypted {
+            return Ok(tokio::fs::read_to_string(&path).await?);
+        }
+
+        let output = Command::new("systemd-creds")
+            .arg("decrypt")
+            .arg(&path)
+            .arg("-")
+            .kill_on_drop(true)
+            .output()
+            .await
+            .map_err(|error| format!("failed to run systemd-creds: {error}"))?;
+        if !output.status.success() {
+            return Err(format!(
+                "systemd-creds failed to decrypt '{}' with {}: {}",
+                path.display(),
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim_end()
+            )
+            .into());
+        }
+        Ok(String::from_utf8(output.stdout)?)
+    }
+}
+
+impl SecretBackend for CredentialsBackend {
+    async fn retrieve(
+        &mut self,
+        secret_keys: HashSet<String>,
+        _: &mut signal::SignalRx,
+    ) -> crate::Result<HashMap<String, String>> {
+        let directory = self.directory()?;
+        let mut secrets = HashMap::new();
+        for k in secret_keys.into_iter() {
+            let contents = self.read(Self::path(&directory, &k)?).await?;
+            let secret = if self.remove_trailing_whitespace {
+                contents.trim_end()
+            } else {
+                &contents
+            };
+            if secret.is_empty() {
+                return Err(format!("credential '{k}' was empty").into());
+            }
+            secrets.insert(k, secret.to_string());
+        }
+        Ok(secrets)
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        self.refresh.interval()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::broadcast;
+
+    use super::*;
+    use crate::test_util::{temp_dir, test_generate_config};
+
+    #[test]
+    fn generate_config() {
+        test_generate_config::<CredentialsBackend>();
+    }
+
+    #[tokio::test]
+    async fn reads_credentials() {
+        let directory = temp_dir();
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("password"), "secret\n").unwrap();
+        let mut backend = CredentialsBackend {
+            directory: Some(directory),
+            remove_trailing_whitespace: true,
+            ..Default::default()
+        };
+        let (_tx, mut rx) = broadcast::channel(1);
+
+        let secrets = backend
+            .retrieve(HashSet::from([String::from("password")]), &mut rx)
+            .await
+            .unwrap();
+        assert_eq!(secrets["password"], "secret");
+
+        assert!(
+            backend
+                .retrieve(HashSet::from([String::from("token")]), &mut rx)
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_credentials_outside_directory() {
+        let directory = temp_dir();
+        std::fs::create_dir_all(directory.join("nested")).unwrap();
+        std::fs::write(directory.join("password"), "secret").unwrap();
+        let mut backend = CredentialsBackend {
+            directory: Some(directory.join("nested")),
+            ..Default::default()
+        };
+        let (_tx, mut rx) = broadcast::channel(1);
+
+        for key in [
+            "..",
+            ".",
+            "../password",
+            "nested/../../password",
+            "/etc/passwd",
+        ] {
+            let error = backend
+                .retrieve(HashSet::from([String::from(key)]), &mut rx)
+                .await
+                .unwrap_err();
+            assert_eq!(
+                error.to_string(),
+                format!("credential '{key}' is not a file name")
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn decrypts_credentials_with_systemd_creds() {
+        let directory = temp_dir();
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("password"), "not encrypted").unwrap();
+        let mut backend = CredentialsBackend {
+            directory: Some(directory),
+            encrypted: true,
+            ..Default::default()
+        };
+        let (_tx, mut rx) = broadcast::channel(1);
+
+        // Without systemd-creds the command fails to run, and with it the plain text credential
+        // fails to be decrypted.
+        let error = backend
+            .retrieve(HashSet::from([String::from("password")]), &mut rx)
+            .await
+            .unwrap_err();
+        let error = error.to_string();
+        let installed = std::env::var_os("PATH").is_some_and(|paths| {
+            std::env::split_paths(&paths).any(|path| path.join("systemd-creds").is_file())
+        });
+        if !installed {
+            assert!(
+                error.starts_with("failed to run systemd-creds: "),
+                "{error}"
+            );
+        } else {
+            assert!(
+                error.starts_with("systemd-creds failed to decrypt "),
+                "{error}"
+            );
+        }
+    }
+}
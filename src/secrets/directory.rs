@@ -1,11 +1,12 @@
 use std::{
     collections::{HashMap, HashSet},
     path::PathBuf,
+    time::Duration,
 };
 
 use vector_lib::configurable::{component::GenerateConfig, configurable_component};
 
-use crate::{config::SecretBackend, signal};
+use crate::{config::SecretBackend, secrets::RefreshConfig, signal};
 
 /// Configuration for the `directory` secrets backend.
 #[configurable_component(secrets("directory"))]
@@ -17,6 +18,10 @@ pub struct DirectoryBackend {
     /// Remove trailing whitespace from file contents.
     #[serde(default)]
     pub remove_trailing_whitespace: bool,
+
+    #[configurable(derived)]
+    #[serde(flatten)]
+    pub refresh: RefreshConfig,
 }
 
 impl GenerateConfig for DirectoryBackend {
@@ -24,6 +29,7 @@ impl GenerateConfig for DirectoryBackend {
         toml::Value::try_from(DirectoryBackend {
             path: PathBuf::from("/path/to/secrets"),
             remove_trailing_whitespace: false,
+            refresh: Default::default(),
         })
         .unwrap()
     }
@@ -51,4 +57,8 @@ impl SecretBackend for DirectoryBackend {
         }
         Ok(secrets)
     }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        self.refresh.interval()
+    }
 }
@@ -0,0 +1,105 @@
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
+
+use vector_lib::configurable::{component::GenerateConfig, configurable_component};
+
+use crate::{config::SecretBackend, secrets::RefreshConfig, signal};
+
+/// Configuration for the `env` secrets backend.
+///
+/// Unlike `${VARIABLE}` interpolation, the values of the environment variables are resolved as
+/// secrets, so they are not part of the interpolated config, and are redacted from the output of
+/// `vector config`.
+#[configurable_component(secrets("env"))]
+#[derive(Clone, Debug)]
+pub struct EnvBackend {
+    /// The names of the environment variables secrets can be read from.
+    ///
+    /// Secrets referencing other environment variables fail to be retrieved.
+    #[configurable(metadata(docs::examples = "DATABASE_PASSWORD"))]
+    pub variables: Vec<String>,
+
+    #[configurable(derived)]
+    #[serde(flatten)]
+    pub refresh: RefreshConfig,
+}
+
+impl GenerateConfig for EnvBackend {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(EnvBackend {
+            variables: vec![String::from("DATABASE_PASSWORD")],
+            refresh: Default::default(),
+        })
+        .unwrap()
+    }
+}
+
+impl SecretBackend for EnvBackend {
+    async fn retrieve(
+        &mut self,
+        secret_keys: HashSet<String>,
+        _: &mut signal::SignalRx,
+    ) -> crate::Result<HashMap<String, String>> {
+        let mut secrets = HashMap::new();
+        for k in secret_keys.into_iter() {
+            if !self.variables.contains(&k) {
+                return Err(format!("environment variable '{k}' is not in `variables`").into());
+            }
+            match std::env::var(&k) {
+                Ok(secret) if secret.is_empty() => {
+                    return Err(format!("environment variable '{k}' was empty").into());
+                }
+                Ok(secret) => {
+                    secrets.insert(k, secret);
+                }
+                Err(error) => {
+                    return Err(format!("environment variable '{k}' was not read: {error}").into());
+                }
+            }
+        }
+        Ok(secrets)
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        self.refresh.interval()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::broadcast;
+
+    use super::*;
+    use crate::test_util::test_generate_config;
+
+    #[test]
+    fn generate_config() {
+        test_generate_config::<EnvBackend>();
+    }
+
+    #[tokio::test]
+    async fn reads_allowed_variables_only() {
+        let mut backend = EnvBackend {
+            variables: vec![String::from("CARGO_PKG_NAME")],
+            refresh: Default::default(),
+        };
+        let (_tx, mut rx) = broadcast::channel(1);
+
+        let secrets = backend
+            .retrieve(HashSet::from([String::from("CARGO_PKG_NAME")]), &mut rx)
+            .await
+            .unwrap();
+        assert_eq!(secrets["CARGO_PKG_NAME"], env!("CARGO_PKG_NAME"));
+
+        let error = backend
+            .retrieve(HashSet::from([String::from("CARGO_PKG_VERSION")]), &mut rx)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "environment variable 'CARGO_PKG_VERSION' is not in `variables`"
+        );
+    }
+}
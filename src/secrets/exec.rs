@@ -1,4 +1,7 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
 
 use bytes::BytesMut;
 use futures_util::StreamExt;
@@ -8,7 +11,7 @@ use tokio_util::codec;
 use vector_lib::configurable::{component::GenerateConfig, configurable_component};
 use vrl::value::Value;
 
-use crate::{config::SecretBackend, signal};
+use crate::{config::SecretBackend, secrets::RefreshConfig, signal};
 
 /// Configuration for the command that will be `exec`ed
 #[configurable_component(secrets("exec"))]
@@ -75,6 +78,10 @@ pub struct ExecBackend {
     /// Settings for the protocol between Vector and the secrets executable.
     #[serde(default = "default_protocol_version")]
     pub protocol: ExecVersion,
+
+    #[configurable(derived)]
+    #[serde(flatten)]
+    pub refresh: RefreshConfig,
 }
 
 impl GenerateConfig for ExecBackend {
@@ -83,6 +90,7 @@ impl GenerateConfig for ExecBackend {
             command: vec![String::from("/path/to/script")],
             timeout: 5,
             protocol: ExecVersion::V1,
+            refresh: Default::default(),
         })
         .unwrap()
     }
@@ -147,6 +155,10 @@ impl SecretBackend for ExecBackend {
         }
         Ok(secrets)
     }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        self.refresh.interval()
+    }
 }
 
 async fn query_backend(
@@ -240,6 +252,7 @@ mod tests {
                 .to_vec(),
             timeout: 5,
             protocol,
+            refresh: Default::default(),
         }
     }
 
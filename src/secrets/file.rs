@@ -1,11 +1,12 @@
 use std::{
     collections::{HashMap, HashSet},
     path::PathBuf,
+    time::Duration,
 };
 
 use vector_lib::configurable::{component::GenerateConfig, configurable_component};
 
-use crate::{config::SecretBackend, signal};
+use crate::{config::SecretBackend, secrets::RefreshConfig, signal};
 
 /// Configuration for the `file` secrets backend.
 #[configurable_component(secrets("file"))]
@@ -13,12 +14,17 @@ use crate::{config::SecretBackend, signal};
 pub struct FileBackend {
     /// File path to read secrets from.
     pub path: PathBuf,
+
+    #[configurable(derived)]
+    #[serde(flatten)]
+    pub refresh: RefreshConfig,
 }
 
 impl GenerateConfig for FileBackend {
     fn generate_config() -> toml::Value {
         toml::Value::try_from(FileBackend {
             path: PathBuf::from("/path/to/secret"),
+            refresh: Default::default(),
         })
         .unwrap()
     }
@@ -45,4 +51,8 @@ impl SecretBackend for FileBackend {
         }
         Ok(secrets)
     }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        self.refresh.interval()
+    }
 }
@@ -1,5 +1,9 @@
 #![allow(missing_docs)]
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    num::NonZeroU64,
+    time::Duration,
+};
 
 use enum_dispatch::enum_dispatch;
 use vector_lib::configurable::configurable_component;
@@ -11,10 +15,14 @@ use crate::{
 
 #[cfg(feature = "secrets-aws-secrets-manager")]
 mod aws_secrets_manager;
+mod credentials;
 mod directory;
+mod env;
 mod exec;
 mod file;
 mod test;
+#[cfg(feature = "secrets-vault")]
+mod vault;
 
 ///	Configuration options to retrieve secrets from external backend in order to avoid storing secrets in plaintext
 /// in Vector config. Multiple backends can be configured. Use `SECRET[<backend_name>.<secret_key>]` to tell Vector to retrieve the secret. This placeholder is replaced by the secret
@@ -49,7 +57,11 @@ mod test;
 /// If an error occurred while reading the file or retrieving the secrets, Vector logs the error and exits.
 ///
 /// Secrets are loaded when Vector starts or if Vector receives a `SIGHUP` signal triggering its
-/// configuration reload process.
+/// configuration reload process. Secrets issued with a lease, such as dynamic secrets of the `vault`
+/// backend, are also loaded again before the lease expires, by reloading the configuration.
+///
+/// Secrets can also be retrieved again periodically with `refresh_interval_secs`, in which case the
+/// components referencing secrets whose values changed are reloaded.
 #[allow(clippy::large_enum_variant)]
 #[configurable_component(global_option("secret"))]
 #[derive(Clone, Debug)]
@@ -70,10 +82,20 @@ pub enum SecretBackends {
     /// Exec.
     Exec(exec::ExecBackend),
 
+    /// Environment variables.
+    Env(env::EnvBackend),
+
+    /// systemd credentials.
+    Credentials(credentials::CredentialsBackend),
+
     /// AWS Secrets Manager.
     #[cfg(feature = "secrets-aws-secrets-manager")]
     AwsSecretsManager(aws_secrets_manager::AwsSecretsManagerBackend),
 
+    /// HashiCorp Vault.
+    #[cfg(feature = "secrets-vault")]
+    Vault(vault::VaultBackend),
+
     /// Test.
     #[configurable(metadata(docs::hidden))]
     Test(test::TestBackend),
@@ -83,7 +105,29 @@ impl GenerateConfig for SecretBackends {
     fn generate_config() -> toml::Value {
         toml::Value::try_from(Self::File(file::FileBackend {
             path: "path/to/file".into(),
+            refresh: Default::default(),
         }))
         .unwrap()
     }
 }
+
+/// Options to retrieve the secrets of a backend again periodically.
+#[configurable_component]
+#[derive(Clone, Debug, Default)]
+pub struct RefreshConfig {
+    /// How often to retrieve the secrets again, in seconds.
+    ///
+    /// Components referencing secrets whose values changed are reloaded. By default, secrets are
+    /// only retrieved when the configuration is loaded.
+    #[serde(default)]
+    #[configurable(metadata(docs::type_unit = "seconds", docs::examples = 300))]
+    pub refresh_interval_secs: Option<NonZeroU64>,
+}
+
+impl RefreshConfig {
+    /// Gets the interval at which the secrets are retrieved again, if they are.
+    pub fn interval(&self) -> Option<Duration> {
+        self.refresh_interval_secs
+            .map(|secs| Duration::from_secs(secs.get()))
+    }
+}
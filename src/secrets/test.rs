@@ -1,8 +1,11 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
 
 use vector_lib::configurable::configurable_component;
 
-use crate::{config::SecretBackend, signal};
+use crate::{config::SecretBackend, secrets::RefreshConfig, signal};
 
 /// Configuration for the `test` secrets backend.
 #[configurable_component(secrets("test"))]
@@ -10,6 +13,10 @@ use crate::{config::SecretBackend, signal};
 pub struct TestBackend {
     /// Fixed value to replace all secrets with.
     pub replacement: String,
+
+    #[configurable(derived)]
+    #[serde(flatten)]
+    pub refresh: RefreshConfig,
 }
 
 impl_generate_config_from_default!(TestBackend);
@@ -25,4 +32,8 @@ impl SecretBackend for TestBackend {
             .map(|k| (k, self.replacement.clone()))
             .collect())
     }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        self.refresh.interval()
+    }
 }
@@ -0,0 +1,1221 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+    sync::{LazyLock, Mutex},
+    time::{Duration, Instant},
+};
+
+use http::{Method, Request};
+use hyper::Body;
+use serde::{Deserialize, de::DeserializeOwned};
+use vector_lib::{
+    configurable::{component::GenerateConfig, configurable_component},
+    sensitive_string::SensitiveString,
+};
+
+use crate::{
+    config::{ProxyConfig, SecretBackend},
+    http::HttpClient,
+    secrets::RefreshConfig,
+    signal,
+    tls::{TlsConfig, TlsSettings},
+};
+
+/// Configuration for the `vault` secrets backend.
+#[configurable_component(secrets("vault"))]
+#[derive(Clone, Debug)]
+pub struct VaultBackend {
+    /// The address of the Vault server.
+    #[configurable(metadata(docs::examples = "https://vault.example.com:8200"))]
+    pub address: String,
+
+    /// The [namespace][namespace] to authenticate and read the secret in.
+    ///
+    /// [namespace]: https://developer.hashicorp.com/vault/docs/enterprise/namespaces
+    #[configurable(metadata(docs::examples = "team-a"))]
+    pub namespace: Option<String>,
+
+    /// The path the secrets engine is mounted at.
+    #[serde(default = "default_mount")]
+    #[configurable(metadata(docs::examples = "secret", docs::examples = "database"))]
+    pub mount: String,
+
+    /// The path of the secret within the secrets engine.
+    ///
+    /// The secret keys of `SECRET[<backend_name>.<secret_key>]` placeholders are looked up in the
+    /// data of this secret.
+    #[configurable(metadata(docs::examples = "vector", docs::examples = "creds/vector"))]
+    pub secret_path: String,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub engine: VaultEngine,
+
+    #[configurable(derived)]
+    pub authentication: VaultAuth,
+
+    #[configurable(derived)]
+    pub tls: Option<TlsConfig>,
+
+    #[configurable(derived)]
+    #[serde(flatten)]
+    pub refresh: RefreshConfig,
+
+    /// When the secret read last expires, if it was issued with a lease.
+    #[serde(skip)]
+    expires_at: Option<Instant>,
+}
+
+/// The secrets engine the secret is read from.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum VaultEngine {
+    /// Version 1 of the key/value secrets engine. The secret is read at `<mount>/<secret_path>`.
+    KvV1,
+
+    /// Version 2 of the key/value secrets engine. The latest version of the secret is read at
+    /// `<mount>/data/<secret_path>`.
+    #[default]
+    KvV2,
+
+    /// A secrets engine issuing dynamic secrets, such as the database secrets engine. The secret is
+    /// read at `<mount>/<secret_path>`.
+    ///
+    /// Dynamic secrets are issued with a lease, and the configuration is reloaded before the lease
+    /// expires, so that it's renewed. A new secret is only issued once the lease can't be renewed
+    /// any further.
+    Dynamic,
+}
+
+/// The strategy to authenticate to Vault with.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(tag = "strategy", rename_all = "snake_case")]
+#[configurable(metadata(docs::enum_tag_description = "The authentication strategy to use."))]
+pub enum VaultAuth {
+    /// Authenticate with a token.
+    Token {
+        /// The token to authenticate with.
+        #[configurable(metadata(docs::examples = "${VAULT_TOKEN}"))]
+        token: SensitiveString,
+    },
+
+    /// Authenticate with the [AppRole][approle] auth method.
+    ///
+    /// [approle]: https://developer.hashicorp.com/vault/docs/auth/approle
+    #[serde(rename = "approle")]
+    AppRole {
+        /// The role ID to authenticate with.
+        #[configurable(metadata(docs::examples = "${VAULT_ROLE_ID}"))]
+        role_id: String,
+
+        /// The secret ID to authenticate with.
+        #[configurable(metadata(docs::examples = "${VAULT_SECRET_ID}"))]
+        secret_id: SensitiveString,
+
+        /// The path the AppRole auth method is mounted at.
+        #[serde(default = "default_approle_mount")]
+        mount: String,
+    },
+
+    /// Authenticate with the [Kubernetes][kubernetes] auth method, using the token of the service
+    /// account of the pod Vector runs in.
+    ///
+    /// [kubernetes]: https://developer.hashicorp.com/vault/docs/auth/kubernetes
+    Kubernetes {
+        /// The role to authenticate as.
+        #[configurable(metadata(docs::examples = "vector"))]
+        role: String,
+
+        /// The path of the service account token to authenticate with.
+        #[serde(default = "default_service_account_token_path")]
+        token_path: PathBuf,
+
+        /// The path the Kubernetes auth method is mounted at.
+        #[serde(default = "default_kubernetes_mount")]
+        mount: String,
+    },
+}
+
+fn default_mount() -> String {
+    "secret".to_string()
+}
+
+fn default_approle_mount() -> String {
+    "approle".to_string()
+}
+
+fn default_kubernetes_mount() -> String {
+    "kubernetes".to_string()
+}
+
+fn default_service_account_token_path() -> PathBuf {
+    PathBuf::from("/var/run/secrets/kubernetes.io/serviceaccount/token")
+}
+
+impl GenerateConfig for VaultBackend {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(VaultBackend {
+            address: String::from("https://vault.example.com:8200"),
+            namespace: None,
+            mount: default_mount(),
+            secret_path: String::from("vector"),
+            engine: VaultEngine::default(),
+            authentication: VaultAuth::Token {
+                token: String::from("${VAULT_TOKEN}").into(),
+            },
+            tls: None,
+            refresh: Default::default(),
+            expires_at: None,
+        })
+        .unwrap()
+    }
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    #[serde(default)]
+    errors: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct LoginResponse {
+    auth: LoginAuth,
+}
+
+#[derive(Deserialize)]
+struct LoginAuth {
+    client_token: String,
+    #[serde(default)]
+    lease_duration: u64,
+    #[serde(default)]
+    renewable: bool,
+}
+
+#[derive(Deserialize)]
+struct SecretResponse {
+    #[serde(default)]
+    lease_id: String,
+    #[serde(default)]
+    lease_duration: u64,
+    #[serde(default)]
+    renewable: bool,
+    data: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+#[derive(Deserialize)]
+struct RenewResponse {
+    lease_duration: u64,
+}
+
+/// The token a secret was read with.
+#[derive(Clone)]
+struct Token {
+    token: String,
+    /// The TTL of the token, if it expires.
+    ttl: Option<Duration>,
+    expires_at: Option<Instant>,
+    renewable: bool,
+}
+
+impl Token {
+    /// Whether less than a third of the TTL of the token remains, so that it's to be renewed before
+    /// being used again.
+    fn expires_soon(&self, now: Instant) -> bool {
+        self.ttl
+            .zip(self.expires_at)
+            .is_some_and(|(ttl, expires_at)| expires_at.saturating_duration_since(now) < ttl / 3)
+    }
+}
+
+/// A dynamic secret, along with its lease.
+#[derive(Clone)]
+struct Lease {
+    id: String,
+    renewable: bool,
+    ttl: Duration,
+    expires_at: Instant,
+    data: serde_json::Map<String, serde_json::Value>,
+    /// Leases are revoked along with the token they were issued to, so it's renewed along with
+    /// them.
+    token: Token,
+}
+
+impl Lease {
+    /// When the secret expires, either along with its lease or with its token.
+    fn expires_at(&self) -> Instant {
+        self.token
+            .expires_at
+            .map_or(self.expires_at, |at| at.min(self.expires_at))
+    }
+}
+
+/// The leases of the dynamic secrets read by the last load of the configuration, by Vault address,
+/// namespace and secret path, so that they are renewed by the next load rather than leaked.
+static LEASES: LazyLock<Mutex<HashMap<String, Lease>>> = LazyLock::new(Default::default);
+
+/// The tokens logged in with, by Vault address, namespace, auth method mount and role, so that
+/// they are reused by the next loads of the configuration until they're about to expire, rather
+/// than logging in on every load.
+static TOKENS: LazyLock<Mutex<HashMap<String, Token>>> = LazyLock::new(Default::default);
+
+impl VaultBackend {
+    /// Sends a request to the Vault API, decoding the JSON response.
+    async fn request<T: DeserializeOwned>(
+        &self,
+        client: &HttpClient<Body>,
+        method: Method,
+        path: &str,
+        token: Option<&str>,
+        body: Option<serde_json::Value>,
+    ) -> crate::Result<T> {
+        let mut builder = Request::builder().method(method).uri(format!(
+            "{}/v1/{}",
+            self.address.trim_end_matches('/'),
+            path
+        ));
+        if let Some(token) = token {
+            builder = builder.header("X-Vault-Token", token);
+        }
+        if let Some(namespace) = &self.namespace {
+            builder = builder.header("X-Vault-Namespace", namespace.as_str());
+        }
+        let request = match body {
+            Some(body) => builder
+                .header("Content-Type", "application/json")
+                .body(Body::from(serde_json::to_vec(&body)?))?,
+            None => builder.body(Body::empty())?,
+        };
+
+        let response = client.send(request).await?;
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await?;
+        if !status.is_success() {
+            let errors = serde_json::from_slice::<ErrorResponse>(&body)
+                .map(|response| response.errors.join(", "))
+                .unwrap_or_default();
+            return Err(
+                format!("request to '{path}' failed with status {status}: {errors}").into(),
+            );
+        }
+
+        Ok(serde_json::from_slice(&body)?)
+    }
+
+    /// The key of the tokens logged in with by this backend in [`TOKENS`].
+    fn token_key(&self) -> String {
+        let (mount, role) = match &self.authentication {
+            VaultAuth::Token { .. } => ("", ""),
+            VaultAuth::AppRole { role_id, mount, .. } => (mount.as_str(), role_id.as_str()),
+            VaultAuth::Kubernetes { role, mount, .. } => (mount.as_str(), role.as_str()),
+        };
+        format!(
+            "{}/{}/{}/{role}",
+            self.address.trim_end_matches('/'),
+            self.namespace.as_deref().unwrap_or_default(),
+            mount.trim_matches('/')
+        )
+    }
+
+    /// Returns the token to read the secret with, reusing the one logged in with by a previous
+    /// load of the configuration while it isn't about to expire, and renewing it when it is.
+    async fn token(&self, client: &HttpClient<Body>) -> crate::Result<Token> {
+        if matches!(self.authentication, VaultAuth::Token { .. }) {
+            return self.login(client).await;
+        }
+
+        let cached = TOKENS
+            .lock()
+            .expect("poisoned lock")
+            .get(&self.token_key())
+            .cloned();
+        if let Some(token) = cached {
+            if !token.expires_soon(Instant::now()) {
+                return Ok(token);
+            }
+            if token.renewable {
+                match self.renew_token(client, token).await {
+                    Ok(Some(token)) => return Ok(token),
+                    Ok(None) => {}
+                    Err(error) => warn!(
+                        message = "Unable to renew the Vault token, logging in again.",
+                        %error
+                    ),
+                }
+            }
+        }
+
+        let token = self.login(client).await?;
+        TOKENS
+            .lock()
+            .expect("poisoned lock")
+            .insert(self.token_key(), token.clone());
+        Ok(token)
+    }
+
+    /// Renews the token, returning `None` if it can't be renewed for as long as it was issued for
+    /// anymore, as it's about to reach its maximum TTL, in which case it isn't reused anymore.
+    async fn renew_token(
+        &self,
+        client: &HttpClient<Body>,
+        mut token: Token,
+    ) -> crate::Result<Option<Token>> {
+        let Some(ttl) = token.ttl else {
+            return Ok(Some(token));
+        };
+
+        let now = Instant::now();
+        let response: LoginResponse = self
+            .request(
+                client,
+                Method::POST,
+                "auth/token/renew-self",
+                Some(&token.token),
+                Some(serde_json::json!({})),
+            )
+            .await?;
+
+        let renewed = (response.auth.lease_duration >= ttl.as_secs()).then(|| {
+            token.expires_at = Some(now + Duration::from_secs(response.auth.lease_duration));
+            token.clone()
+        });
+
+        // The token may have been replaced by logging in again since, in which case the new one is
+        // kept.
+        let mut tokens = TOKENS.lock().expect("poisoned lock");
+        let key = self.token_key();
+        if tokens
+            .get(&key)
+            .is_none_or(|cached| cached.token == token.token)
+        {
+            match &renewed {
+                Some(token) => tokens.insert(key, token.clone()),
+                None => tokens.remove(&key),
+            };
+        }
+
+        Ok(renewed)
+    }
+
+    /// Logs in with the auth method, returning a new token.
+    async fn login(&self, client: &HttpClient<Body>) -> crate::Result<Token> {
+        let (mount, body) = match &self.authentication {
+            VaultAuth::Token { token } => {
+                return Ok(Token {
+                    token: token.inner().to_string(),
+                    ttl: None,
+                    expires_at: None,
+                    renewable: false,
+                });
+            }
+            VaultAuth::AppRole {
+                role_id,
+                secret_id,
+                mount,
+            } => (
+                mount,
+                serde_json::json!({ "role_id": role_id, "secret_id": secret_id.inner() }),
+            ),
+            VaultAuth::Kubernetes {
+                role,
+                token_path,
+                mount,
+            } => {
+                let jwt = tokio::fs::read_to_string(token_path)
+                    .await
+                    .map_err(|error| {
+                        format!(
+                            "unable to read service account token '{}': {error}",
+                            token_path.display()
+                        )
+                    })?;
+                (
+                    mount,
+                    serde_json::json!({ "role": role, "jwt": jwt.trim() }),
+                )
+            }
+        };
+
+        let now = Instant::now();
+        let response: LoginResponse = self
+            .request(
+                client,
+                Method::POST,
+                &format!("auth/{}/login", mount.trim_matches('/')),
+                None,
+                Some(body),
+            )
+            .await?;
+        let ttl = (response.auth.lease_duration > 0)
+            .then(|| Duration::from_secs(response.auth.lease_duration));
+
+        Ok(Token {
+            token: response.auth.client_token,
+            ttl,
+            expires_at: ttl.map(|ttl| now + ttl),
+            renewable: response.auth.renewable,
+        })
+    }
+
+    /// Reads the secret, returning its data and lease.
+    async fn read(
+        &self,
+        client: &HttpClient<Body>,
+        path: &str,
+    ) -> crate::Result<(serde_json::Map<String, serde_json::Value>, Option<Lease>)> {
+        let token = self.token(client).await?;
+
+        let now = Instant::now();
+        let response: SecretResponse = self
+            .request(client, Method::GET, path, Some(&token.token), None)
+            .await?;
+
+        let mut data = response.data.unwrap_or_default();
+        if self.engine == VaultEngine::KvV2 {
+            data = match data.remove("data") {
+                Some(serde_json::Value::Object(data)) => data,
+                _ => return Err(format!("secret '{path}' has no data").into()),
+            };
+        }
+
+        let lease = (!response.lease_id.is_empty() && response.lease_duration > 0).then(|| {
+            let ttl = Duration::from_secs(response.lease_duration);
+            Lease {
+                id: response.lease_id,
+                renewable: response.renewable,
+                ttl,
+                expires_at: now + ttl,
+                data: data.clone(),
+                token,
+            }
+        });
+        Ok((data, lease))
+    }
+
+    /// Renews the lease of a dynamic secret, along with the token it was issued to, returning
+    /// `None` if they can't be renewed for as long as they were issued for anymore, as they're
+    /// about to reach their maximum TTL.
+    async fn renew(
+        &self,
+        client: &HttpClient<Body>,
+        mut lease: Lease,
+    ) -> crate::Result<Option<Lease>> {
+        lease.token = match self.renew_token(client, lease.token).await? {
+            Some(token) => token,
+            None => return Ok(None),
+        };
+
+        let now = Instant::now();
+        let response: RenewResponse = self
+            .request(
+                client,
+                Method::PUT,
+                "sys/leases/renew",
+                Some(&lease.token.token),
+                Some(serde_json::json!({ "lease_id": lease.id, "increment": lease.ttl.as_secs() })),
+            )
+            .await?;
+        if response.lease_duration < lease.ttl.as_secs() {
+            return Ok(None);
+        }
+        lease.expires_at = now + Duration::from_secs(response.lease_duration);
+
+        Ok(Some(lease))
+    }
+
+    /// Reads a dynamic secret, renewing the lease of the one read by the previous load of the
+    /// configuration instead, if there's one, so that a lease isn't issued on every reload.
+    async fn read_dynamic(
+        &mut self,
+        client: &HttpClient<Body>,
+        path: &str,
+    ) -> crate::Result<serde_json::Map<String, serde_json::Value>> {
+        let lease_key = format!(
+            "{}/{}/{path}",
+            self.address.trim_end_matches('/'),
+            self.namespace.as_deref().unwrap_or_default()
+        );
+        let previous = LEASES
+            .lock()
+            .expect("poisoned lock")
+            .get(&lease_key)
+            .cloned();
+
+        let renewed = match previous {
+            Some(lease) => self.renew(client, lease).await.unwrap_or_else(|error| {
+                warn!(
+                    message = "Unable to renew the lease of the secret, reading it again.",
+                    secret = %path,
+                    %error
+                );
+                None
+            }),
+            None => None,
+        };
+        let (data, lease) = match renewed {
+            Some(lease) => (lease.data.clone(), Some(lease)),
+            None => self.read(client, path).await?,
+        };
+
+        // Dynamic secrets are revoked once their lease, or the one of the token they were issued
+        // to, expires.
+        self.expires_at = lease.as_ref().map(Lease::expires_at);
+        let mut leases = LEASES.lock().expect("poisoned lock");
+        match lease.filter(|lease| lease.renewable) {
+            Some(lease) => leases.insert(lease_key, lease),
+            None => leases.remove(&lease_key),
+        };
+
+        Ok(data)
+    }
+
+    fn secret_path(&self) -> String {
+        let mount = self.mount.trim_matches('/');
+        let path = self.secret_path.trim_matches('/');
+        match self.engine {
+            VaultEngine::KvV2 => format!("{mount}/data/{path}"),
+            VaultEngine::KvV1 | VaultEngine::Dynamic => format!("{mount}/{path}"),
+        }
+    }
+}
+
+impl SecretBackend for VaultBackend {
+    async fn retrieve(
+        &mut self,
+        secret_keys: HashSet<String>,
+        _: &mut signal::SignalRx,
+    ) -> crate::Result<HashMap<String, String>> {
+        let tls_settings = TlsSettings::from_options(self.tls.as_ref())?;
+        let client = HttpClient::<Body>::new(tls_settings, &ProxyConfig::from_env())?;
+
+        let path = self.secret_path();
+        let data = if self.engine == VaultEngine::Dynamic {
+            self.read_dynamic(&client, &path).await?
+        } else {
+            self.expires_at = None;
+            self.read(&client, &path).await?.0
+        };
+
+        let mut secrets = HashMap::new();
+        for k in secret_keys.into_iter() {
+            let secret = match data.get(&k) {
+                Some(serde_json::Value::String(secret)) => secret.clone(),
+                Some(serde_json::Value::Null) | None => {
+                    return Err(format!("key '{k}' in secret '{path}' does not exist").into());
+                }
+                Some(value) => value.to_string(),
+            };
+            if secret.is_empty() {
+                return Err(format!("value for key '{k}' in secret '{path}' was empty").into());
+            }
+            secrets.insert(k, secret);
+        }
+        Ok(secrets)
+    }
+
+    fn expires_at(&self) -> Option<Instant> {
+        self.expires_at
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        self.refresh.interval()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        convert::Infallible,
+        net::SocketAddr,
+        sync::{Arc, Mutex},
+    };
+
+    use hyper::{
+        Response, Server, StatusCode,
+        server::accept,
+        service::{make_service_fn, service_fn},
+    };
+    use tokio::sync::broadcast;
+
+    use super::*;
+    use crate::{
+        test_util::{addr::next_addr, temp_file, test_generate_config},
+        tls::{MaybeTlsSettings, TlsEnableableConfig},
+    };
+
+    #[test]
+    fn generate_config() {
+        test_generate_config::<VaultBackend>();
+    }
+
+    /// The requests made, as their path, token, namespace and body.
+    type Requests = Arc<Mutex<Vec<(String, Option<String>, Option<String>, String)>>>;
+
+    /// Serves the given responses by path, and a `404 Not Found` one for other paths.
+    async fn serve(
+        addr: SocketAddr,
+        responses: HashMap<&'static str, serde_json::Value>,
+    ) -> Requests {
+        serve_tls(addr, responses, None).await
+    }
+
+    async fn serve_tls(
+        addr: SocketAddr,
+        responses: HashMap<&'static str, serde_json::Value>,
+        tls: Option<TlsEnableableConfig>,
+    ) -> Requests {
+        let requests = Requests::default();
+        let recorded = Arc::clone(&requests);
+        let responses = Arc::new(responses);
+        let service = make_service_fn(move |_| {
+            let recorded = Arc::clone(&recorded);
+            let responses = Arc::clone(&responses);
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request: Request<Body>| {
+                    let recorded = Arc::clone(&recorded);
+                    let responses = Arc::clone(&responses);
+                    async move {
+                        let header = |name| {
+                            request
+                                .headers()
+                                .get(name)
+                                .map(|value: &http::HeaderValue| {
+                                    value.to_str().unwrap().to_string()
+                                })
+                        };
+                        let path = request.uri().path().to_string();
+                        let token = header("X-Vault-Token");
+                        let namespace = header("X-Vault-Namespace");
+                        let body = hyper::body::to_bytes(request.into_body()).await.unwrap();
+                        recorded.lock().unwrap().push((
+                            path.clone(),
+                            token,
+                            namespace,
+                            String::from_utf8(body.to_vec()).unwrap(),
+                        ));
+
+                        Ok::<_, Infallible>(match responses.get(path.as_str()) {
+                            Some(response) => Response::new(Body::from(response.to_string())),
+                            None => Response::builder()
+                                .status(StatusCode::NOT_FOUND)
+                                .body(Body::from(r#"{"errors": []}"#))
+                                .unwrap(),
+                        })
+                    }
+                }))
+            }
+        });
+        let listener = MaybeTlsSettings::from_config(tls.as_ref(), true)
+            .unwrap()
+            .bind(&addr)
+            .await
+            .unwrap();
+        tokio::spawn(Server::builder(accept::from_stream(listener.accept_stream())).serve(service));
+        requests
+    }
+
+    fn backend(addr: SocketAddr, engine: VaultEngine, authentication: VaultAuth) -> VaultBackend {
+        VaultBackend {
+            address: format!("http://{addr}/"),
+            namespace: Some("team-a".to_string()),
+            mount: default_mount(),
+            secret_path: "vector".to_string(),
+            engine,
+            authentication,
+            tls: None,
+            refresh: Default::default(),
+            expires_at: None,
+        }
+    }
+
+    fn keys(keys: &[&str]) -> HashSet<String> {
+        keys.iter().map(|key| key.to_string()).collect()
+    }
+
+    #[tokio::test]
+    async fn reads_kv_v2_secret() {
+        let (_guard, addr) = next_addr();
+        let requests = serve(
+            addr,
+            HashMap::from([(
+                "/v1/secret/data/vector",
+                serde_json::json!({"data": {"data": {"username": "vector", "port": 5432}}}),
+            )]),
+        )
+        .await;
+
+        let mut backend = backend(
+            addr,
+            VaultEngine::KvV2,
+            VaultAuth::Token {
+                token: "root".to_string().into(),
+            },
+        );
+        let (_tx, mut rx) = broadcast::channel(1);
+        let secrets = backend
+            .retrieve(keys(&["username", "port"]), &mut rx)
+            .await
+            .unwrap();
+
+        assert_eq!(secrets["username"], "vector");
+        assert_eq!(secrets["port"], "5432");
+        assert_eq!(backend.expires_at(), None);
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].1.as_deref(), Some("root"));
+        assert_eq!(requests[0].2.as_deref(), Some("team-a"));
+    }
+
+    #[tokio::test]
+    async fn reads_dynamic_secret_with_approle() {
+        let (_guard, addr) = next_addr();
+        let requests = serve(
+            addr,
+            HashMap::from([
+                (
+                    "/v1/auth/approle/login",
+                    serde_json::json!({"auth": {"client_token": "approle-token", "lease_duration": 7200}}),
+                ),
+                (
+                    "/v1/database/creds/vector",
+                    serde_json::json!({
+                        "lease_id": "database/creds/vector/abc",
+                        "lease_duration": 3600,
+                        "renewable": true,
+                        "data": {"username": "v-vector", "password": "generated"}
+                    }),
+                ),
+            ]),
+        )
+        .await;
+
+        let mut backend = backend(addr, VaultEngine::Dynamic, approle());
+        backend.mount = "database".to_string();
+        backend.secret_path = "creds/vector".to_string();
+
+        let before = Instant::now();
+        let (_tx, mut rx) = broadcast::channel(1);
+        let secrets = backend
+            .retrieve(keys(&["username", "password"]), &mut rx)
+            .await
+            .unwrap();
+
+        assert_eq!(secrets["username"], "v-vector");
+        assert_eq!(secrets["password"], "generated");
+
+        // The lease of the secret expires before the one of the token.
+        let expires_at = backend.expires_at().unwrap();
+        assert!(expires_at >= before + Duration::from_secs(3600));
+        assert!(expires_at <= Instant::now() + Duration::from_secs(3600));
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        let login: serde_json::Value = serde_json::from_str(&requests[0].3).unwrap();
+        assert_eq!(
+            login,
+            serde_json::json!({"role_id": "role", "secret_id": "secret"})
+        );
+        assert_eq!(requests[1].1.as_deref(), Some("approle-token"));
+    }
+
+    fn approle() -> VaultAuth {
+        VaultAuth::AppRole {
+            role_id: "role".to_string(),
+            secret_id: "secret".to_string().into(),
+            mount: default_approle_mount(),
+        }
+    }
+
+    fn dynamic_secret_responses(
+        renewed_lease_duration: u64,
+    ) -> HashMap<&'static str, serde_json::Value> {
+        HashMap::from([
+            (
+                "/v1/auth/approle/login",
+                serde_json::json!({"auth": {"client_token": "approle-token", "lease_duration": 7200}}),
+            ),
+            (
+                "/v1/database/creds/vector",
+                serde_json::json!({
+                    "lease_id": "database/creds/vector/abc",
+                    "lease_duration": 3600,
+                    "renewable": true,
+                    "data": {"username": "v-vector", "password": "generated"}
+                }),
+            ),
+            (
+                "/v1/auth/token/renew-self",
+                serde_json::json!({"auth": {"client_token": "approle-token", "lease_duration": 7200}}),
+            ),
+            (
+                "/v1/sys/leases/renew",
+                serde_json::json!({
+                    "lease_id": "database/creds/vector/abc",
+                    "lease_duration": renewed_lease_duration,
+                    "renewable": true
+                }),
+            ),
+        ])
+    }
+
+    /// Retrieves the dynamic secret twice, as loading the configuration and then reloading it does.
+    async fn retrieve_dynamic_secret_twice(addr: SocketAddr) {
+        for _ in 0..2 {
+            let mut backend = backend(addr, VaultEngine::Dynamic, approle());
+            backend.mount = "database".to_string();
+            backend.secret_path = "creds/vector".to_string();
+
+            let (_tx, mut rx) = broadcast::channel(1);
+            let secrets = backend
+                .retrieve(keys(&["username", "password"]), &mut rx)
+                .await
+                .unwrap();
+            assert_eq!(secrets["username"], "v-vector");
+            assert!(backend.expires_at().is_some());
+        }
+    }
+
+    fn paths(requests: &Requests) -> Vec<String> {
+        requests
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(path, ..)| path.clone())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn renews_dynamic_secret_lease_on_reload() {
+        let (_guard, addr) = next_addr();
+        let requests = serve(addr, dynamic_secret_responses(3600)).await;
+
+        retrieve_dynamic_secret_twice(addr).await;
+
+        assert_eq!(
+            paths(&requests),
+            [
+                "/v1/auth/approle/login",
+                "/v1/database/creds/vector",
+                "/v1/auth/token/renew-self",
+                "/v1/sys/leases/renew",
+            ]
+        );
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests[3].1.as_deref(), Some("approle-token"));
+        let renew: serde_json::Value = serde_json::from_str(&requests[3].3).unwrap();
+        assert_eq!(
+            renew,
+            serde_json::json!({"lease_id": "database/creds/vector/abc", "increment": 3600})
+        );
+    }
+
+    #[tokio::test]
+    async fn reads_dynamic_secret_again_once_lease_reaches_max_ttl() {
+        let (_guard, addr) = next_addr();
+        let requests = serve(addr, dynamic_secret_responses(60)).await;
+
+        retrieve_dynamic_secret_twice(addr).await;
+
+        assert_eq!(
+            paths(&requests),
+            [
+                "/v1/auth/approle/login",
+                "/v1/database/creds/vector",
+                "/v1/auth/token/renew-self",
+                "/v1/sys/leases/renew",
+                "/v1/database/creds/vector",
+            ]
+        );
+    }
+
+    fn kv_secret_responses(
+        renewed_token_duration: u64,
+    ) -> HashMap<&'static str, serde_json::Value> {
+        HashMap::from([
+            (
+                "/v1/auth/approle/login",
+                serde_json::json!({"auth": {"client_token": "approle-token", "lease_duration": 7200, "renewable": true}}),
+            ),
+            (
+                "/v1/secret/data/vector",
+                serde_json::json!({"data": {"data": {"username": "vector"}}}),
+            ),
+            (
+                "/v1/auth/token/renew-self",
+                serde_json::json!({"auth": {"client_token": "approle-token", "lease_duration": renewed_token_duration}}),
+            ),
+        ])
+    }
+
+    /// Retrieves the secret with a new backend, as loading the configuration does, after making
+    /// the token logged in with previously about to expire.
+    async fn retrieve_with_expiring_token(addr: SocketAddr) {
+        let mut backend = backend(addr, VaultEngine::KvV2, approle());
+        if let Some(token) = TOKENS.lock().unwrap().get_mut(&backend.token_key()) {
+            token.expires_at = Some(Instant::now() + Duration::from_secs(60));
+        }
+
+        let (_tx, mut rx) = broadcast::channel(1);
+        let secrets = backend
+            .retrieve(keys(&["username"]), &mut rx)
+            .await
+            .unwrap();
+        assert_eq!(secrets["username"], "vector");
+    }
+
+    #[tokio::test]
+    async fn reuses_token_until_it_expires_soon() {
+        let (_guard, addr) = next_addr();
+        let requests = serve(addr, kv_secret_responses(7200)).await;
+
+        for _ in 0..2 {
+            let mut backend = backend(addr, VaultEngine::KvV2, approle());
+            let (_tx, mut rx) = broadcast::channel(1);
+            backend
+                .retrieve(keys(&["username"]), &mut rx)
+                .await
+                .unwrap();
+        }
+        retrieve_with_expiring_token(addr).await;
+        retrieve_with_expiring_token(addr).await;
+
+        assert_eq!(
+            paths(&requests),
+            [
+                "/v1/auth/approle/login",
+                "/v1/secret/data/vector",
+                "/v1/secret/data/vector",
+                "/v1/auth/token/renew-self",
+                "/v1/secret/data/vector",
+                "/v1/auth/token/renew-self",
+                "/v1/secret/data/vector",
+            ]
+        );
+        assert!(
+            requests
+                .lock()
+                .unwrap()
+                .iter()
+                .skip(1)
+                .all(|(_, token, ..)| token.as_deref() == Some("approle-token"))
+        );
+    }
+
+    #[tokio::test]
+    async fn logs_in_again_once_token_reaches_max_ttl() {
+        let (_guard, addr) = next_addr();
+        let requests = serve(addr, kv_secret_responses(60)).await;
+
+        let mut backend = backend(addr, VaultEngine::KvV2, approle());
+        let (_tx, mut rx) = broadcast::channel(1);
+        backend
+            .retrieve(keys(&["username"]), &mut rx)
+            .await
+            .unwrap();
+        retrieve_with_expiring_token(addr).await;
+
+        assert_eq!(
+            paths(&requests),
+            [
+                "/v1/auth/approle/login",
+                "/v1/secret/data/vector",
+                "/v1/auth/token/renew-self",
+                "/v1/auth/approle/login",
+                "/v1/secret/data/vector",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn reads_secret_with_kubernetes_auth() {
+        let (_guard, addr) = next_addr();
+        let requests = serve(
+            addr,
+            HashMap::from([
+                (
+                    "/v1/auth/k8s/login",
+                    serde_json::json!({"auth": {"client_token": "kubernetes-token", "lease_duration": 3600}}),
+                ),
+                (
+                    "/v1/secret/data/vector",
+                    serde_json::json!({"data": {"data": {"username": "vector"}}}),
+                ),
+            ]),
+        )
+        .await;
+
+        let token_path = temp_file();
+        std::fs::write(&token_path, "service-account-token\n").unwrap();
+        let mut backend = backend(
+            addr,
+            VaultEngine::KvV2,
+            VaultAuth::Kubernetes {
+                role: "vector".to_string(),
+                token_path,
+                mount: "k8s".to_string(),
+            },
+        );
+        let (_tx, mut rx) = broadcast::channel(1);
+        let secrets = backend
+            .retrieve(keys(&["username"]), &mut rx)
+            .await
+            .unwrap();
+
+        assert_eq!(secrets["username"], "vector");
+        // Secrets of the key/value engines don't expire, even when the token does.
+        assert_eq!(backend.expires_at(), None);
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        let login: serde_json::Value = serde_json::from_str(&requests[0].3).unwrap();
+        assert_eq!(
+            login,
+            serde_json::json!({"role": "vector", "jwt": "service-account-token"})
+        );
+        assert_eq!(requests[1].1.as_deref(), Some("kubernetes-token"));
+    }
+
+    #[tokio::test]
+    async fn rejects_missing_kubernetes_token() {
+        let (_guard, addr) = next_addr();
+        let requests = serve(addr, HashMap::new()).await;
+
+        let mut backend = backend(
+            addr,
+            VaultEngine::KvV2,
+            VaultAuth::Kubernetes {
+                role: "vector".to_string(),
+                token_path: temp_file(),
+                mount: default_kubernetes_mount(),
+            },
+        );
+        let (_tx, mut rx) = broadcast::channel(1);
+        let error = backend
+            .retrieve(keys(&["username"]), &mut rx)
+            .await
+            .unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .starts_with("unable to read service account token")
+        );
+        assert!(requests.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn reads_secret_over_tls() {
+        let (_guard, addr) = next_addr();
+        let mut tls = TlsEnableableConfig::test_config();
+        tls.options.verify_hostname = Some(false);
+        let requests = serve_tls(
+            addr,
+            HashMap::from([(
+                "/v1/secret/data/vector",
+                serde_json::json!({"data": {"data": {"username": "vector"}}}),
+            )]),
+            Some(tls.clone()),
+        )
+        .await;
+
+        let mut backend = backend(
+            addr,
+            VaultEngine::KvV2,
+            VaultAuth::Token {
+                token: "root".to_string().into(),
+            },
+        );
+        backend.address = format!("https://{addr}");
+        backend.tls = Some(tls.options);
+        let (_tx, mut rx) = broadcast::channel(1);
+        let secrets = backend
+            .retrieve(keys(&["username"]), &mut rx)
+            .await
+            .unwrap();
+
+        assert_eq!(secrets["username"], "vector");
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn rejects_untrusted_certificate() {
+        let (_guard, addr) = next_addr();
+        serve_tls(
+            addr,
+            HashMap::new(),
+            Some(TlsEnableableConfig::test_config()),
+        )
+        .await;
+
+        let mut backend = backend(
+            addr,
+            VaultEngine::KvV2,
+            VaultAuth::Token {
+                token: "root".to_string().into(),
+            },
+        );
+        backend.address = format!("https://{addr}");
+        let (_tx, mut rx) = broadcast::channel(1);
+
+        assert!(
+            backend
+                .retrieve(keys(&["username"]), &mut rx)
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_missing_key() {
+        let (_guard, addr) = next_addr();
+        serve(
+            addr,
+            HashMap::from([(
+                "/v1/secret/vector",
+                serde_json::json!({"lease_duration": 2764800, "data": {"username": "vector"}}),
+            )]),
+        )
+        .await;
+
+        let mut backend = backend(
+            addr,
+            VaultEngine::KvV1,
+            VaultAuth::Token {
+                token: "root".to_string().into(),
+            },
+        );
+        let (_tx, mut rx) = broadcast::channel(1);
+        let error = backend
+            .retrieve(keys(&["password"]), &mut rx)
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "key 'password' in secret 'secret/vector' does not exist"
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_vault_errors() {
+        let (_guard, addr) = next_addr();
+        serve(addr, HashMap::new()).await;
+
+        let mut backend = backend(
+            addr,
+            VaultEngine::KvV2,
+            VaultAuth::Token {
+                token: "root".to_string().into(),
+            },
+        );
+        let (_tx, mut rx) = broadcast::channel(1);
+        let error = backend
+            .retrieve(keys(&["username"]), &mut rx)
+            .await
+            .unwrap_err();
+
+        assert!(error.to_string().contains("status 404"));
+    }
+}
@@ -5,6 +5,7 @@ use std::collections::HashSet;
 use snafu::Snafu;
 use tokio::{runtime::Runtime, sync::broadcast};
 use tokio_stream::{Stream, StreamExt};
+use tokio_util::sync::CancellationToken;
 
 use super::config::{ComponentKey, ConfigBuilder};
 
@@ -63,6 +64,17 @@ pub enum ShutdownError {
     SinkAborted { key: ComponentKey, error: String },
 }
 
+/// Status of a loaded configuration, reported to the providers it may have been loaded from.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ConfigStatus {
+    /// The configuration is running.
+    Loaded,
+    /// The configuration is invalid, with the given number of errors, so it isn't running.
+    Invalid(usize),
+    /// The configuration couldn't be started, so it isn't running.
+    Failed,
+}
+
 /// Convenience struct for app setup handling.
 pub struct SignalPair {
     pub handler: SignalHandler,
@@ -92,6 +104,8 @@ impl SignalPair {
 pub struct SignalHandler {
     tx: SignalTx,
     shutdown_txs: Vec<ShutdownTx>,
+    secrets_refresh: CancellationToken,
+    config_status: broadcast::Sender<ConfigStatus>,
 }
 
 impl SignalHandler {
@@ -102,6 +116,8 @@ impl SignalHandler {
         let handler = Self {
             tx,
             shutdown_txs: vec![],
+            secrets_refresh: CancellationToken::new(),
+            config_status: broadcast::channel(16).0,
         };
 
         (handler, rx)
@@ -177,6 +193,26 @@ impl SignalHandler {
         });
     }
 
+    /// Cancels the tasks refreshing the secrets retrieved by the previous load of the
+    /// configuration, returning the token to cancel the ones of the new load with.
+    pub fn renew_secrets_refresh(&mut self) -> CancellationToken {
+        self.secrets_refresh.cancel();
+        self.secrets_refresh = CancellationToken::new();
+        self.secrets_refresh.clone()
+    }
+
+    /// Subscribes to the status of the configurations loaded from then on.
+    pub fn subscribe_config_status(&self) -> broadcast::Receiver<ConfigStatus> {
+        self.config_status.subscribe()
+    }
+
+    /// Reports the status of the configuration that was just loaded, once it's running or
+    /// failed to.
+    pub fn report_config_status(&self, status: ConfigStatus) {
+        // An error just means no one is subscribed; safe to ignore.
+        _ = self.config_status.send(status);
+    }
+
     /// Shutdown active signal handlers.
     pub fn clear(&mut self) {
         for shutdown_tx in self.shutdown_txs.drain(..) {
@@ -0,0 +1,401 @@
+use std::{
+    num::NonZeroUsize,
+    task::{Context, Poll},
+};
+
+use futures::stream;
+use http::Uri;
+use hyper::client::HttpConnector;
+use hyper_openssl::HttpsConnector;
+use hyper_proxy::ProxyConnector;
+use snafu::Snafu;
+use tonic::{body::BoxBody, codec::CompressionEncoding};
+
+use crate::{
+    common::arrow_flight::{ArrowFlightError, LOGS_PATH, TRACES_PATH, encode_flight_data},
+    config::SinkHealthcheckOptions,
+    internal_events::EndpointBytesSent,
+    proto::arrow_flight::{self as proto, Empty, FlightData},
+    sinks::{
+        prelude::*,
+        util::{
+            RealtimeSizeBasedDefaultBatchSettings,
+            grpc::{HyperSvc, new_client, with_default_scheme},
+            uri,
+        },
+    },
+    tls::MaybeTlsSettings,
+};
+
+#[derive(Debug, Snafu)]
+pub enum ArrowFlightSinkError {
+    #[snafu(display("Request failed: {}", source))]
+    Request { source: tonic::Status },
+
+    #[snafu(display(
+        "Compression `{}` is not supported, use `none` or `gzip`.",
+        compression
+    ))]
+    UnsupportedCompression { compression: Compression },
+}
+
+/// Configuration for sending events over Arrow Flight.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ArrowFlightSinkConfig {
+    /// The address of the Arrow Flight server to which to connect.
+    ///
+    /// Both IP address and hostname are accepted formats.
+    ///
+    /// The address _must_ include a port.
+    #[configurable(validation(format = "uri"))]
+    #[configurable(metadata(docs::examples = "92.12.333.224:4319"))]
+    #[configurable(metadata(docs::examples = "https://somehost:4319"))]
+    pub address: String,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub compression: Compression,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub batch: BatchConfig<RealtimeSizeBasedDefaultBatchSettings>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub request: TowerRequestConfig,
+
+    #[configurable(derived)]
+    pub tls: Option<TlsConfig>,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        deserialize_with = "crate::serde::bool_or_struct",
+        skip_serializing_if = "crate::serde::is_default"
+    )]
+    pub acknowledgements: AcknowledgementsConfig,
+}
+
+impl ArrowFlightSinkConfig {
+    pub(super) async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        let compression = match self.compression {
+            Compression::None => false,
+            Compression::Gzip(_) => true,
+            compression => {
+                return Err(Box::new(ArrowFlightSinkError::UnsupportedCompression {
+                    compression,
+                }));
+            }
+        };
+
+        // Addresses without a scheme only use TLS when it's configured, while `https` addresses
+        // always do.
+        let tls = TlsSettings::from_options(self.tls.as_ref())?;
+        let uri = with_default_scheme(&self.address, self.tls.is_some())?;
+        let client = new_client(&MaybeTlsSettings::Tls(tls), cx.proxy())?;
+
+        let healthcheck_uri = cx
+            .healthcheck
+            .uri
+            .clone()
+            .map(|uri| uri.uri)
+            .unwrap_or_else(|| uri.clone());
+        let healthcheck_service = ArrowFlightService::new(client.clone(), healthcheck_uri, false);
+        let healthcheck = healthcheck(healthcheck_service, cx.healthcheck);
+
+        let service = ServiceBuilder::new()
+            .settings(self.request.into_settings(), ArrowFlightRetryLogic)
+            .service(ArrowFlightService::new(client, uri, compression));
+        let sink = ArrowFlightSink {
+            batch_settings: self.batch.into_batcher_settings()?,
+            service,
+        };
+
+        Ok((
+            VectorSink::from_event_streamsink(sink),
+            Box::pin(healthcheck),
+        ))
+    }
+
+    pub(super) fn input(&self) -> Input {
+        Input::new(DataType::Log | DataType::Trace)
+    }
+
+    pub(super) const fn acknowledgements(&self) -> &AcknowledgementsConfig {
+        &self.acknowledgements
+    }
+}
+
+/// Check that the remote server accepts Arrow Flight calls.
+async fn healthcheck(
+    mut service: ArrowFlightService,
+    options: SinkHealthcheckOptions,
+) -> crate::Result<()> {
+    if !options.enabled {
+        return Ok(());
+    }
+
+    match service.client.list_actions(Empty {}).await {
+        // A server that doesn't list its actions is still able to accept `DoPut` calls.
+        Ok(_) => Ok(()),
+        Err(source) if source.code() == tonic::Code::Unimplemented => Ok(()),
+        Err(source) => Err(Box::new(ArrowFlightSinkError::Request { source })),
+    }
+}
+
+#[derive(Clone, Default)]
+struct ArrowFlightRequest {
+    finalizers: EventFinalizers,
+    metadata: RequestMetadata,
+    /// The messages of a single `DoPut` stream, holding events of a single type.
+    ///
+    /// Each stream is sent with its own request, so that retrying a failed stream doesn't resend
+    /// the events of streams that were already delivered.
+    messages: Vec<FlightData>,
+}
+
+impl Finalizable for ArrowFlightRequest {
+    fn take_finalizers(&mut self) -> EventFinalizers {
+        self.finalizers.take_finalizers()
+    }
+}
+
+impl MetaDescriptive for ArrowFlightRequest {
+    fn get_metadata(&self) -> &RequestMetadata {
+        &self.metadata
+    }
+
+    fn metadata_mut(&mut self) -> &mut RequestMetadata {
+        &mut self.metadata
+    }
+}
+
+struct ArrowFlightResponse {
+    events_byte_size: GroupedCountByteSize,
+}
+
+impl DriverResponse for ArrowFlightResponse {
+    fn event_status(&self) -> EventStatus {
+        EventStatus::Delivered
+    }
+
+    fn events_sent(&self) -> &GroupedCountByteSize {
+        &self.events_byte_size
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ArrowFlightService {
+    client: proto::Client<HyperSvc>,
+    protocol: String,
+    endpoint: String,
+}
+
+impl ArrowFlightService {
+    fn new(
+        hyper_client: hyper::Client<ProxyConnector<HttpsConnector<HttpConnector>>, BoxBody>,
+        uri: Uri,
+        compression: bool,
+    ) -> Self {
+        let (protocol, endpoint) = uri::protocol_endpoint(uri.clone());
+        let mut client = proto::Client::new(HyperSvc::new(uri, hyper_client));
+
+        if compression {
+            client = client.send_compressed(CompressionEncoding::Gzip);
+        }
+        Self {
+            client,
+            protocol,
+            endpoint,
+        }
+    }
+}
+
+impl Service<ArrowFlightRequest> for ArrowFlightService {
+    type Response = ArrowFlightResponse;
+    type Error = crate::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    // Emission of an internal event in case of errors is handled upstream by the caller.
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    // Emission of internal events for errors and dropped events is handled upstream by the caller.
+    fn call(&mut self, mut request: ArrowFlightRequest) -> Self::Future {
+        let mut service = self.clone();
+        let metadata = std::mem::take(request.metadata_mut());
+        let byte_size = metadata.request_encoded_size();
+        let events_byte_size = metadata.into_events_estimated_json_encoded_byte_size();
+
+        Box::pin(async move {
+            let mut results = service
+                .client
+                .do_put(stream::iter(request.messages))
+                .await
+                .map_err(|source| ArrowFlightSinkError::Request { source })?
+                .into_inner();
+            while results
+                .message()
+                .await
+                .map_err(|source| ArrowFlightSinkError::Request { source })?
+                .is_some()
+            {}
+
+            emit!(EndpointBytesSent {
+                byte_size,
+                protocol: &service.protocol,
+                endpoint: &service.endpoint,
+            });
+
+            Ok(ArrowFlightResponse { events_byte_size })
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ArrowFlightRetryLogic;
+
+impl RetryLogic for ArrowFlightRetryLogic {
+    type Error = ArrowFlightSinkError;
+    type Request = ArrowFlightRequest;
+    type Response = ArrowFlightResponse;
+
+    fn is_retriable_error(&self, err: &Self::Error) -> bool {
+        use tonic::Code::*;
+
+        match err {
+            ArrowFlightSinkError::Request { source } => !matches!(
+                source.code(),
+                NotFound
+                    | InvalidArgument
+                    | AlreadyExists
+                    | PermissionDenied
+                    | OutOfRange
+                    | Unimplemented
+                    | Unauthenticated
+                    | DataLoss
+            ),
+            ArrowFlightSinkError::UnsupportedCompression { .. } => false,
+        }
+    }
+}
+
+struct ArrowFlightSink<S> {
+    batch_settings: BatcherSettings,
+    service: S,
+}
+
+impl<S> ArrowFlightSink<S>
+where
+    S: Service<ArrowFlightRequest> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Response: DriverResponse + Send + 'static,
+    S::Error: std::fmt::Debug + Into<crate::Error> + Send,
+{
+    async fn run_inner(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        input
+            .batched(self.batch_settings.as_byte_size_config())
+            .flat_map(|events| stream::iter(build_requests(events)))
+            .filter_map(|request| async move {
+                match request {
+                    Ok(request) => Some(request),
+                    Err(error) => {
+                        emit!(SinkRequestBuildError { error });
+                        None
+                    }
+                }
+            })
+            .into_driver(self.service)
+            .run()
+            .await
+    }
+}
+
+/// Encodes a batch of events into one request with a `DoPut` stream for logs, and another one with
+/// a `DoPut` stream for traces.
+fn build_requests(events: Vec<Event>) -> Vec<Result<ArrowFlightRequest, ArrowFlightError>> {
+    let (traces, logs): (Vec<_>, Vec<_>) = events
+        .into_iter()
+        .partition(|event| matches!(event, Event::Trace(_)));
+
+    [(logs, LOGS_PATH), (traces, TRACES_PATH)]
+        .into_iter()
+        .filter(|(events, _)| !events.is_empty())
+        .map(|(events, path)| build_request(events, path))
+        .collect()
+}
+
+fn build_request(
+    mut events: Vec<Event>,
+    path: &str,
+) -> Result<ArrowFlightRequest, ArrowFlightError> {
+    let finalizers = events.take_finalizers();
+    let builder = RequestMetadataBuilder::from_events(&events);
+    let messages = encode_flight_data(events, path)?;
+
+    let byte_size = messages
+        .iter()
+        .map(|data| data.data_header.len() + data.data_body.len())
+        .sum::<usize>();
+    let byte_size = NonZeroUsize::new(byte_size).expect("payload should never be zero length");
+
+    Ok(ArrowFlightRequest {
+        finalizers,
+        metadata: builder.with_request_size(byte_size),
+        messages,
+    })
+}
+
+#[async_trait]
+impl<S> StreamSink<Event> for ArrowFlightSink<S>
+where
+    S: Service<ArrowFlightRequest> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Response: DriverResponse + Send + 'static,
+    S::Error: std::fmt::Debug + Into<crate::Error> + Send,
+{
+    async fn run(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        self.run_inner(input).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_lib::event::{LogEvent, TraceEvent};
+    use vrl::btreemap;
+
+    use super::*;
+    use crate::common::arrow_flight::descriptor_path;
+
+    #[test]
+    fn builds_one_request_per_event_type() {
+        let trace = TraceEvent::from(btreemap! {
+            "trace_id" => "abc",
+            "name" => "GET /users",
+        });
+        let events = vec![
+            Event::Log(LogEvent::from("hello")),
+            Event::Trace(trace),
+            Event::Log(LogEvent::from("world")),
+        ];
+
+        let requests = build_requests(events)
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        let streams = requests
+            .iter()
+            .map(|request| {
+                (
+                    descriptor_path(request.messages[0].flight_descriptor.as_ref()).unwrap(),
+                    request.metadata.event_count(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(streams, vec![(LOGS_PATH, 2), (TRACES_PATH, 1)]);
+    }
+}
@@ -1,3 +1,5 @@
+mod arrow_flight;
+
 use indoc::indoc;
 use vector_config::component::GenerateConfig;
 use vector_lib::{
@@ -8,6 +10,7 @@ use vector_lib::{
     configurable::configurable_component,
 };
 
+use self::arrow_flight::ArrowFlightSinkConfig;
 use crate::{
     codecs::{EncodingConfigWithFraming, Transformer},
     config::{AcknowledgementsConfig, Input, SinkConfig, SinkContext},
@@ -18,7 +21,10 @@ use crate::{
 };
 
 /// Configuration for the `OpenTelemetry` sink.
-#[configurable_component(sink("opentelemetry", "Deliver OTLP data over HTTP."))]
+#[configurable_component(sink(
+    "opentelemetry",
+    "Deliver OTLP data over HTTP, or events over Arrow Flight."
+))]
 #[derive(Clone, Debug, Default)]
 pub struct OpenTelemetryConfig {
     /// Protocol configuration
@@ -27,7 +33,7 @@ pub struct OpenTelemetryConfig {
 }
 
 /// The protocol used to send data to OpenTelemetry.
-/// Currently only HTTP is supported, but we plan to support gRPC.
+/// HTTP and Arrow Flight are supported, and we plan to support gRPC.
 /// The proto definitions are defined [here](https://github.com/vectordotdev/vector/blob/master/lib/opentelemetry-proto/src/proto/opentelemetry-proto/opentelemetry/proto/README.md).
 #[configurable_component]
 #[derive(Clone, Debug)]
@@ -36,6 +42,12 @@ pub struct OpenTelemetryConfig {
 pub enum Protocol {
     /// Send data over HTTP.
     Http(HttpSinkConfig),
+
+    /// Send log and trace events as Arrow record batches over Arrow Flight.
+    ///
+    /// The receiving end must be an `opentelemetry` source with `arrow_flight` enabled, or another
+    /// Arrow Flight server accepting `DoPut` streams with a `logs` or `traces` descriptor path.
+    ArrowFlight(ArrowFlightSinkConfig),
 }
 
 impl Default for Protocol {
@@ -79,18 +91,21 @@ impl SinkConfig for OpenTelemetryConfig {
     async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
         match &self.protocol {
             Protocol::Http(config) => config.build(cx).await,
+            Protocol::ArrowFlight(config) => config.build(cx).await,
         }
     }
 
     fn input(&self) -> Input {
         match &self.protocol {
             Protocol::Http(config) => config.input(),
+            Protocol::ArrowFlight(config) => config.input(),
         }
     }
 
     fn acknowledgements(&self) -> &AcknowledgementsConfig {
         match self.protocol {
             Protocol::Http(ref config) => config.acknowledgements(),
+            Protocol::ArrowFlight(ref config) => config.acknowledgements(),
         }
     }
 }
@@ -101,4 +116,93 @@ mod test {
     fn generate_config() {
         crate::test_util::test_generate_config::<super::OpenTelemetryConfig>();
     }
+
+    #[cfg(feature = "sources-opentelemetry")]
+    #[tokio::test]
+    async fn arrow_flight_to_source() {
+        use futures::{StreamExt, stream};
+        use vector_lib::event::{EventStatus, TraceEvent};
+        use vrl::btreemap;
+
+        use super::*;
+        use crate::{
+            SourceSender,
+            config::{SourceConfig, SourceContext},
+            event::{Event, LogEvent, Value, into_event_stream},
+            sources::opentelemetry::config::{
+                ArrowFlightConfig, GrpcConfig, HttpConfig, LOGS, OpentelemetryConfig, TRACES,
+            },
+            test_util::{
+                addr::next_addr,
+                collect_ready,
+                components::{HTTP_SINK_TAGS, run_and_assert_sink_compliance},
+                wait_for_tcp,
+            },
+        };
+
+        let (_guard_0, grpc_addr) = next_addr();
+        let (_guard_1, http_addr) = next_addr();
+        let (_guard_2, flight_addr) = next_addr();
+
+        let source = OpentelemetryConfig {
+            grpc: GrpcConfig {
+                address: grpc_addr,
+                tls: Default::default(),
+            },
+            http: HttpConfig {
+                address: http_addr,
+                tls: Default::default(),
+                keepalive: Default::default(),
+                headers: Default::default(),
+            },
+            arrow_flight: Some(ArrowFlightConfig {
+                address: flight_addr,
+                tls: Default::default(),
+            }),
+            acknowledgements: Default::default(),
+            log_namespace: Default::default(),
+            use_otlp_decoding: false,
+        };
+        let (mut sender, _) = SourceSender::new_test_finalize(EventStatus::Delivered);
+        let logs = sender
+            .add_outputs(EventStatus::Delivered, LOGS.to_string())
+            .flat_map(into_event_stream);
+        let traces = sender
+            .add_outputs(EventStatus::Delivered, TRACES.to_string())
+            .flat_map(into_event_stream);
+        let server = source
+            .build(SourceContext::new_test(sender, None))
+            .await
+            .unwrap();
+        tokio::spawn(server);
+        wait_for_tcp(flight_addr).await;
+
+        let config: OpenTelemetryConfig = toml::from_str(&format!(
+            r#"
+            [protocol]
+            type = "arrow_flight"
+            address = "{flight_addr}"
+            "#
+        ))
+        .unwrap();
+        let (sink, _) = config.build(SinkContext::default()).await.unwrap();
+
+        let trace = TraceEvent::from(btreemap! {
+            "trace_id" => "abc",
+            "name" => "GET /users",
+        });
+        let events = vec![
+            Event::Log(LogEvent::from("hello")),
+            Event::Trace(trace.clone()),
+        ];
+        run_and_assert_sink_compliance(sink, stream::iter(events), &HTTP_SINK_TAGS).await;
+
+        let logs = collect_ready(logs).await;
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].as_log().get("message"), Some(&Value::from("hello")));
+
+        let traces = collect_ready(traces).await;
+        assert_eq!(traces.len(), 1);
+        assert_eq!(traces[0].as_trace().as_map(), trace.as_map());
+    }
 }
@@ -823,6 +823,10 @@ impl DriverResponse for HttpResponse {
     fn bytes_sent(&self) -> Option<usize> {
         Some(self.raw_byte_size)
     }
+
+    fn http_status(&self) -> Option<u16> {
+        Some(self.http_response.status().as_u16())
+    }
 }
 
 /// Creates a `RetryLogic` for use with `HttpResponse`.
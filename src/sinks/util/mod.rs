@@ -8,6 +8,8 @@ pub mod builder;
 pub mod compressor;
 pub mod datagram;
 pub mod encoding;
+#[cfg(any(feature = "sinks-vector", feature = "sinks-opentelemetry"))]
+pub mod grpc;
 pub mod http;
 pub mod metadata;
 pub mod normalizer;
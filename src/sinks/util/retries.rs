@@ -128,6 +128,7 @@ impl<L: RetryLogic> FibonacciRetryPolicy<L> {
 
     fn build_retry(&mut self) -> RetryPolicyFuture {
         self.advance();
+        vector_lib::stream::record_retry();
         let delay = Box::pin(sleep(self.backoff()));
 
         debug!(message = "Retrying request.", delay_ms = %self.backoff().as_millis());
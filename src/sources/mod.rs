@@ -92,6 +92,8 @@ pub mod static_metrics;
 pub mod statsd;
 #[cfg(feature = "sources-syslog")]
 pub mod syslog;
+#[cfg(feature = "sources-tap_replay")]
+pub mod tap_replay;
 #[cfg(feature = "sources-vector")]
 pub mod vector;
 #[cfg(feature = "sources-websocket")]
@@ -0,0 +1,126 @@
+use std::pin::Pin;
+
+use futures::{Stream, stream};
+use tonic::{Request, Response, Status, Streaming, codec::CompressionEncoding};
+
+use super::{
+    config::{LOGS, TRACES},
+    grpc::Service,
+};
+use crate::{
+    common::arrow_flight::{FlightDataDecoder, TRACES_PATH, descriptor_path},
+    proto::arrow_flight::{
+        self as proto, Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor,
+        FlightInfo, HandshakeRequest, HandshakeResponse, PutResult, SchemaResult, Ticket,
+    },
+};
+
+type ResponseStream<T> = Pin<Box<dyn Stream<Item = Result<T, Status>> + Send + 'static>>;
+
+pub(super) fn build_arrow_flight_service(service: Service) -> proto::Server<Service> {
+    proto::Server::new(service)
+        .accept_compressed(CompressionEncoding::Gzip)
+        .max_decoding_message_size(usize::MAX)
+}
+
+/// Only `DoPut` is supported, as the source receives events but doesn't serve any.
+#[tonic::async_trait]
+impl proto::Service for Service {
+    type HandshakeStream = ResponseStream<HandshakeResponse>;
+    type ListFlightsStream = ResponseStream<FlightInfo>;
+    type DoGetStream = ResponseStream<FlightData>;
+    type DoPutStream = ResponseStream<PutResult>;
+    type DoExchangeStream = ResponseStream<FlightData>;
+    type DoActionStream = ResponseStream<proto::Result>;
+    type ListActionsStream = ResponseStream<ActionType>;
+
+    async fn handshake(
+        &self,
+        _request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<Response<Self::HandshakeStream>, Status> {
+        Err(Status::unimplemented("Handshake is not supported"))
+    }
+
+    async fn list_flights(
+        &self,
+        _request: Request<Criteria>,
+    ) -> Result<Response<Self::ListFlightsStream>, Status> {
+        Err(Status::unimplemented("ListFlights is not supported"))
+    }
+
+    async fn get_flight_info(
+        &self,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(Status::unimplemented("GetFlightInfo is not supported"))
+    }
+
+    async fn get_schema(
+        &self,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<SchemaResult>, Status> {
+        Err(Status::unimplemented("GetSchema is not supported"))
+    }
+
+    async fn do_get(
+        &self,
+        _request: Request<Ticket>,
+    ) -> Result<Response<Self::DoGetStream>, Status> {
+        Err(Status::unimplemented("DoGet is not supported"))
+    }
+
+    async fn do_put(
+        &self,
+        request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoPutStream>, Status> {
+        let mut messages = request.into_inner();
+        let Some(first) = messages.message().await? else {
+            return Ok(Response::new(Box::pin(stream::empty())));
+        };
+
+        // The descriptor of the first message determines the type of all events of the stream.
+        let path = descriptor_path(first.flight_descriptor.as_ref())
+            .map_err(|error| Status::invalid_argument(error.to_string()))?;
+        let output = if path == TRACES_PATH { TRACES } else { LOGS };
+        let mut decoder = FlightDataDecoder::new(path);
+
+        let mut results = Vec::new();
+        let mut next = Some(first);
+        while let Some(data) = next {
+            if let Some(events) = decoder
+                .push(data)
+                .map_err(|error| Status::invalid_argument(error.to_string()))?
+            {
+                self.handle_events(events, output).await?;
+                results.push(Ok(PutResult {
+                    app_metadata: Vec::new(),
+                }));
+            }
+            next = messages.message().await?;
+        }
+
+        Ok(Response::new(Box::pin(stream::iter(results))))
+    }
+
+    async fn do_exchange(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoExchangeStream>, Status> {
+        Err(Status::unimplemented("DoExchange is not supported"))
+    }
+
+    async fn do_action(
+        &self,
+        _request: Request<Action>,
+    ) -> Result<Response<Self::DoActionStream>, Status> {
+        Err(Status::unimplemented("DoAction is not supported"))
+    }
+
+    async fn list_actions(
+        &self,
+        _request: Request<Empty>,
+    ) -> Result<Response<Self::ListActionsStream>, Status> {
+        // Used by the `opentelemetry` sink as a health check.
+        Ok(Response::new(Box::pin(stream::empty())))
+    }
+}
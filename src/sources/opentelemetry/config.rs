@@ -11,6 +11,7 @@ use crate::{
         Source,
         http_server::{build_param_matcher, remove_duplicates},
         opentelemetry::{
+            arrow_flight::build_arrow_flight_service,
             grpc::Service,
             http::{build_warp_filter, run_http_server},
         },
@@ -18,7 +19,7 @@ use crate::{
     },
 };
 use futures::FutureExt;
-use futures_util::{TryFutureExt, future::join};
+use futures_util::{TryFutureExt, future::join3};
 use tonic::{codec::CompressionEncoding, transport::server::RoutesBuilder};
 use vector_config::indexmap::IndexSet;
 use vector_lib::{
@@ -35,6 +36,7 @@ use vector_lib::{
         proto::collector::{
             logs::v1::logs_service_server::LogsServiceServer,
             metrics::v1::metrics_service_server::MetricsServiceServer,
+            profiles::v1development::profiles_service_server::ProfilesServiceServer,
             trace::v1::trace_service_server::TraceServiceServer,
         },
     },
@@ -46,6 +48,7 @@ use vrl::value::{Kind, kind::Collection};
 pub const LOGS: &str = "logs";
 pub const METRICS: &str = "metrics";
 pub const TRACES: &str = "traces";
+pub const PROFILES: &str = "profiles";
 
 /// Configuration for the `opentelemetry` source.
 #[configurable_component(source("opentelemetry", "Receive OTLP data through gRPC or HTTP."))]
@@ -58,6 +61,10 @@ pub struct OpentelemetryConfig {
     #[configurable(derived)]
     pub http: HttpConfig,
 
+    #[configurable(derived)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub arrow_flight: Option<ArrowFlightConfig>,
+
     #[configurable(derived)]
     #[serde(default, deserialize_with = "bool_or_struct")]
     pub acknowledgements: SourceAcknowledgementsConfig,
@@ -72,6 +79,9 @@ pub struct OpentelemetryConfig {
     /// One major caveat here is that the incoming metrics will be parsed as logs but they will preserve the OTLP format.
     /// This means that components that work on metrics, will not be compatible with this output.
     /// However, these events can be forwarded directly to a downstream OTEL collector.
+    ///
+    /// Profiles are always decoded this way, regardless of this setting, as they have no
+    /// equivalent Vector event type.
     #[configurable(derived)]
     #[serde(default)]
     pub use_otlp_decoding: bool,
@@ -136,6 +146,35 @@ pub struct HttpConfig {
     pub headers: Vec<String>,
 }
 
+/// Configuration for the `opentelemetry` Arrow Flight server.
+///
+/// When set, the source also accepts log and trace events sent in the Arrow columnar format through
+/// Arrow Flight `DoPut` streams, such as those sent by the `opentelemetry` sink with the
+/// `arrow_flight` protocol. The descriptor path of each stream, `logs` or `traces`, selects the
+/// output the events are sent to.
+#[configurable_component]
+#[configurable(metadata(docs::examples = "example_arrow_flight_config()"))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ArrowFlightConfig {
+    /// The socket address to listen for connections on.
+    ///
+    /// It _must_ include a port.
+    #[configurable(metadata(docs::examples = "0.0.0.0:4319", docs::examples = "localhost:4319"))]
+    pub address: SocketAddr,
+
+    #[configurable(derived)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<TlsEnableableConfig>,
+}
+
+fn example_arrow_flight_config() -> ArrowFlightConfig {
+    ArrowFlightConfig {
+        address: "0.0.0.0:4319".parse().unwrap(),
+        tls: None,
+    }
+}
+
 fn example_http_config() -> HttpConfig {
     HttpConfig {
         address: "0.0.0.0:4318".parse().unwrap(),
@@ -150,6 +189,7 @@ impl GenerateConfig for OpentelemetryConfig {
         toml::Value::try_from(Self {
             grpc: example_grpc_config(),
             http: example_http_config(),
+            arrow_flight: None,
             acknowledgements: Default::default(),
             log_namespace: None,
             use_otlp_decoding: false,
@@ -186,6 +226,8 @@ impl SourceConfig for OpentelemetryConfig {
         let logs_deserializer = self.get_signal_deserializer(OtlpSignalType::Logs)?;
         let metrics_deserializer = self.get_signal_deserializer(OtlpSignalType::Metrics)?;
         let traces_deserializer = self.get_signal_deserializer(OtlpSignalType::Traces)?;
+        let profiles_deserializer =
+            OtlpDeserializer::new_with_signals(IndexSet::from([OtlpSignalType::Profiles]));
 
         let log_service = LogsServiceServer::new(Service {
             pipeline: cx.out.clone(),
@@ -217,11 +259,22 @@ impl SourceConfig for OpentelemetryConfig {
         .accept_compressed(CompressionEncoding::Gzip)
         .max_decoding_message_size(usize::MAX);
 
+        let profiles_service = ProfilesServiceServer::new(Service {
+            pipeline: cx.out.clone(),
+            acknowledgements,
+            log_namespace,
+            events_received: events_received.clone(),
+            deserializer: Some(profiles_deserializer.clone()),
+        })
+        .accept_compressed(CompressionEncoding::Gzip)
+        .max_decoding_message_size(usize::MAX);
+
         let mut builder = RoutesBuilder::default();
         builder
             .add_service(log_service)
             .add_service(metrics_service)
-            .add_service(trace_service);
+            .add_service(trace_service)
+            .add_service(profiles_service);
 
         let grpc_source = run_grpc_server_with_routes(
             self.grpc.address,
@@ -239,6 +292,32 @@ impl SourceConfig for OpentelemetryConfig {
         let headers =
             build_param_matcher(&remove_duplicates(self.http.headers.clone(), "headers"))?;
 
+        let arrow_flight_source = match self.arrow_flight.as_ref() {
+            Some(config) => {
+                let tls_settings = MaybeTlsSettings::from_config(config.tls.as_ref(), true)?;
+                let mut builder = RoutesBuilder::default();
+                builder.add_service(build_arrow_flight_service(Service {
+                    pipeline: cx.out.clone(),
+                    acknowledgements,
+                    log_namespace,
+                    events_received: events_received.clone(),
+                    deserializer: None,
+                }));
+
+                run_grpc_server_with_routes(
+                    config.address,
+                    tls_settings,
+                    builder.routes(),
+                    cx.shutdown.clone(),
+                )
+                .map_err(|error| {
+                    error!(message = "Source future failed.", %error);
+                })
+                .boxed()
+            }
+            None => futures::future::ok(()).boxed(),
+        };
+
         let filters = build_warp_filter(
             acknowledgements,
             log_namespace,
@@ -249,6 +328,7 @@ impl SourceConfig for OpentelemetryConfig {
             logs_deserializer,
             metrics_deserializer,
             traces_deserializer,
+            profiles_deserializer,
         );
 
         let http_source = run_http_server(
@@ -259,7 +339,9 @@ impl SourceConfig for OpentelemetryConfig {
             self.http.keepalive.clone(),
         );
 
-        Ok(join(grpc_source, http_source).map(|_| Ok(())).boxed())
+        Ok(join3(grpc_source, http_source, arrow_flight_source)
+            .map(|_| Ok(()))
+            .boxed())
     }
 
     // TODO: appropriately handle "severity" meaning across both "severity_text" and "severity_number",
@@ -361,14 +443,19 @@ impl SourceConfig for OpentelemetryConfig {
             SourceOutput::new_maybe_logs(DataType::Log, schema_definition).with_port(LOGS),
             metrics_output,
             SourceOutput::new_traces().with_port(TRACES),
+            SourceOutput::new_maybe_logs(DataType::Log, Definition::any()).with_port(PROFILES),
         ]
     }
 
     fn resources(&self) -> Vec<Resource> {
-        vec![
+        let mut resources = vec![
             Resource::tcp(self.grpc.address),
             Resource::tcp(self.http.address),
-        ]
+        ];
+        if let Some(config) = self.arrow_flight.as_ref() {
+            resources.push(Resource::tcp(config.address));
+        }
+        resources
     }
 
     fn can_acknowledge(&self) -> bool {
@@ -15,6 +15,10 @@ use vector_lib::{
             ExportMetricsServiceRequest, ExportMetricsServiceResponse,
             metrics_service_server::MetricsService,
         },
+        profiles::v1development::{
+            ExportProfilesServiceRequest, ExportProfilesServiceResponse,
+            profiles_service_server::ProfilesService,
+        },
         trace::v1::{
             ExportTraceServiceRequest, ExportTraceServiceResponse,
             trace_service_server::TraceService,
@@ -25,7 +29,7 @@ use vector_lib::{
 use crate::{
     SourceSender,
     internal_events::{EventsReceived, StreamClosedError},
-    sources::opentelemetry::config::{LOGS, METRICS, TRACES},
+    sources::opentelemetry::config::{LOGS, METRICS, PROFILES, TRACES},
 };
 
 #[derive(Clone)]
@@ -126,8 +130,35 @@ impl MetricsService for Service {
     }
 }
 
+#[tonic::async_trait]
+impl ProfilesService for Service {
+    async fn export(
+        &self,
+        request: Request<ExportProfilesServiceRequest>,
+    ) -> Result<Response<ExportProfilesServiceResponse>, Status> {
+        // Profiles have no native Vector representation, so they are always passed through as
+        // OTLP-structured log events.
+        let deserializer = self
+            .deserializer
+            .as_ref()
+            .ok_or_else(|| Status::unimplemented("Profiles decoding is not configured"))?;
+        let raw_bytes = request.get_ref().encode_to_vec();
+        let bytes = bytes::Bytes::from(raw_bytes);
+        let events = deserializer
+            .parse(bytes, self.log_namespace)
+            .map_err(|e| Status::invalid_argument(e.to_string()))
+            .map(|buf| buf.into_vec())?;
+
+        self.handle_events(events, PROFILES).await?;
+
+        Ok(Response::new(ExportProfilesServiceResponse {
+            partial_success: None,
+        }))
+    }
+}
+
 impl Service {
-    async fn handle_events(
+    pub(super) async fn handle_events(
         &self,
         mut events: Vec<Event>,
         log_name: &'static str,
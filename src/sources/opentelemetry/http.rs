@@ -20,6 +20,7 @@ use vector_lib::{
     opentelemetry::proto::collector::{
         logs::v1::{ExportLogsServiceRequest, ExportLogsServiceResponse},
         metrics::v1::{ExportMetricsServiceRequest, ExportMetricsServiceResponse},
+        profiles::v1development::ExportProfilesServiceResponse,
         trace::v1::{ExportTraceServiceRequest, ExportTraceServiceResponse},
     },
     tls::MaybeTlsIncomingStream,
@@ -38,7 +39,7 @@ use crate::{
     shutdown::ShutdownSignal,
     sources::{
         http_server::HttpConfigParamKind,
-        opentelemetry::config::{LOGS, METRICS, OpentelemetryConfig, TRACES},
+        opentelemetry::config::{LOGS, METRICS, OpentelemetryConfig, PROFILES, TRACES},
         util::{add_headers, decompress_body},
     },
     tls::MaybeTlsSettings,
@@ -97,6 +98,7 @@ pub(crate) fn build_warp_filter(
     logs_deserializer: Option<OtlpDeserializer>,
     metrics_deserializer: Option<OtlpDeserializer>,
     traces_deserializer: Option<OtlpDeserializer>,
+    profiles_deserializer: OtlpDeserializer,
 ) -> BoxedFilter<(Response,)> {
     let log_filters = build_warp_log_filter(
         acknowledgements,
@@ -117,15 +119,25 @@ pub(crate) fn build_warp_filter(
     let trace_filters = build_warp_trace_filter(
         acknowledgements,
         out.clone(),
+        bytes_received.clone(),
+        events_received.clone(),
+        traces_deserializer,
+    );
+    let profiles_filters = build_warp_profiles_filter(
+        acknowledgements,
+        log_namespace,
+        out,
         bytes_received,
         events_received,
-        traces_deserializer,
+        profiles_deserializer,
     );
     log_filters
         .or(trace_filters)
         .unify()
         .or(metrics_filters)
         .unify()
+        .or(profiles_filters)
+        .unify()
         .boxed()
 }
 
@@ -165,6 +177,7 @@ fn parse_with_deserializer(
 }
 
 fn build_ingest_filter<Resp, F>(
+    api_version: &'static str,
     telemetry_type: &'static str,
     acknowledgements: bool,
     out: SourceSender,
@@ -179,7 +192,7 @@ where
         + Fn(Option<String>, HeaderMap, Bytes) -> Result<Vec<Event>, ErrorMessage>,
 {
     warp::post()
-        .and(warp::path("v1"))
+        .and(warp::path(api_version))
         .and(warp::path(telemetry_type))
         .and(warp::path::end())
         .and(warp::header::exact_ignore_case(
@@ -240,6 +253,7 @@ fn build_warp_log_filter(
     };
 
     build_ingest_filter::<ExportLogsServiceResponse, _>(
+        "v1",
         LOGS,
         acknowledgements,
         source_sender,
@@ -275,6 +289,7 @@ fn build_warp_metrics_filter(
     };
 
     build_ingest_filter::<ExportMetricsServiceResponse, _>(
+        "v1",
         METRICS,
         acknowledgements,
         source_sender,
@@ -311,6 +326,7 @@ fn build_warp_trace_filter(
     };
 
     build_ingest_filter::<ExportTraceServiceResponse, _>(
+        "v1",
         TRACES,
         acknowledgements,
         source_sender,
@@ -318,6 +334,41 @@ fn build_warp_trace_filter(
     )
 }
 
+fn build_warp_profiles_filter(
+    acknowledgements: bool,
+    log_namespace: LogNamespace,
+    source_sender: SourceSender,
+    bytes_received: Registered<BytesReceived>,
+    events_received: Registered<EventsReceived>,
+    deserializer: OtlpDeserializer,
+) -> BoxedFilter<(Response,)> {
+    let make_events = move |encoding_header: Option<String>, _headers: HeaderMap, body: Bytes| {
+        decompress_body(encoding_header.as_deref(), body)
+            .inspect_err(|err| {
+                // Other status codes are already handled by `sources::util::decompress_body` (tech debt).
+                if err.status_code() == StatusCode::UNSUPPORTED_MEDIA_TYPE {
+                    emit!(HttpBadRequest::new(
+                        err.status_code().as_u16(),
+                        err.message()
+                    ));
+                }
+            })
+            .and_then(|decoded_body| {
+                bytes_received.emit(ByteSize(decoded_body.len()));
+                decode_profiles_body(&deserializer, decoded_body, log_namespace, &events_received)
+            })
+    };
+
+    // The profiles signal is still in development, so its path carries the development version.
+    build_ingest_filter::<ExportProfilesServiceResponse, _>(
+        "v1development",
+        PROFILES,
+        acknowledgements,
+        source_sender,
+        make_events,
+    )
+}
+
 fn decode_trace_body(
     body: Bytes,
     events_received: &Registered<EventsReceived>,
@@ -379,6 +430,22 @@ fn decode_metrics_body(
     Ok(events)
 }
 
+fn decode_profiles_body(
+    deserializer: &OtlpDeserializer,
+    body: Bytes,
+    log_namespace: LogNamespace,
+    events_received: &Registered<EventsReceived>,
+) -> Result<Vec<Event>, ErrorMessage> {
+    let events = parse_with_deserializer(deserializer, body, log_namespace)?;
+
+    events_received.emit(CountByteSize(
+        events.len(),
+        events.estimated_json_encoded_size_of(),
+    ));
+
+    Ok(events)
+}
+
 async fn handle_request(
     events: Result<Vec<Event>, ErrorMessage>,
     acknowledgements: bool,
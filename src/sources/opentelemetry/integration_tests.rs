@@ -60,6 +60,7 @@ async fn receive_logs_legacy_namespace() {
                 keepalive: Default::default(),
                 headers: vec![],
             },
+            arrow_flight: None,
             acknowledgements: Default::default(),
             log_namespace: Default::default(),
             use_otlp_decoding: false,
@@ -159,6 +160,7 @@ async fn receive_trace() {
                 keepalive: Default::default(),
                 headers: vec![],
             },
+            arrow_flight: None,
             acknowledgements: Default::default(),
             log_namespace: Default::default(),
             use_otlp_decoding: false,
@@ -264,6 +266,7 @@ async fn receive_metric() {
                 keepalive: Default::default(),
                 headers: vec![],
             },
+            arrow_flight: None,
             acknowledgements: Default::default(),
             log_namespace: Default::default(),
             use_otlp_decoding: false,
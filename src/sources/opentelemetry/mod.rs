@@ -4,6 +4,7 @@ mod integration_tests;
 mod tests;
 
 pub mod config;
+mod arrow_flight;
 mod grpc;
 mod http;
 mod reply;
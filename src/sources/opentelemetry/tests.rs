@@ -18,6 +18,9 @@ use vector_lib::{
             metrics::v1::{
                 ExportMetricsServiceRequest, metrics_service_client::MetricsServiceClient,
             },
+            profiles::v1development::{
+                ExportProfilesServiceRequest, profiles_service_client::ProfilesServiceClient,
+            },
         },
         common::v1::{
             AnyValue, InstrumentationScope, KeyValue, any_value, any_value::Value::StringValue,
@@ -29,6 +32,7 @@ use vector_lib::{
             Sum, Summary, SummaryDataPoint, exponential_histogram_data_point::Buckets,
             metric::Data, summary_data_point::ValueAtQuantile,
         },
+        profiles::v1development::{Profile, ResourceProfiles, ScopeProfiles},
         resource::v1::{Resource, Resource as OtelResource},
     },
 };
@@ -37,13 +41,17 @@ use warp::http::HeaderMap;
 
 use crate::{
     SourceSender,
+    common::arrow_flight::{LOGS_PATH, encode_flight_data},
     config::{OutputId, SourceConfig, SourceContext},
     event::{
         Event, EventStatus, LogEvent, Metric as MetricEvent, MetricKind, MetricTags, MetricValue,
         ObjectMap, Value, into_event_stream,
         metric::{Bucket, Quantile},
     },
-    sources::opentelemetry::config::{GrpcConfig, HttpConfig, LOGS, METRICS, OpentelemetryConfig},
+    proto::arrow_flight,
+    sources::opentelemetry::config::{
+        ArrowFlightConfig, GrpcConfig, HttpConfig, LOGS, METRICS, OpentelemetryConfig, PROFILES,
+    },
     test_util::{
         self,
         addr::next_addr,
@@ -1093,6 +1101,7 @@ async fn http_headers() {
                     "AbsentHeader".to_string(),
                 ],
             },
+            arrow_flight: None,
             acknowledgements: Default::default(),
             log_namespace: Default::default(),
             use_otlp_decoding: false,
@@ -1175,6 +1184,149 @@ async fn http_headers() {
     .await;
 }
 
+fn create_test_profiles_request() -> ExportProfilesServiceRequest {
+    ExportProfilesServiceRequest {
+        resource_profiles: vec![ResourceProfiles {
+            resource: Some(OtelResource {
+                attributes: vec![KeyValue {
+                    key: "service.name".into(),
+                    value: Some(AnyValue {
+                        value: Some(StringValue("checkout".into())),
+                    }),
+                }],
+                dropped_attributes_count: 0,
+            }),
+            scope_profiles: vec![ScopeProfiles {
+                scope: None,
+                profiles: vec![Profile {
+                    string_table: vec!["".into(), "cpu".into(), "nanoseconds".into()],
+                    time_nanos: 1,
+                    duration_nanos: 10_000_000_000,
+                    profile_id: str_into_hex_bytes("4ac52aadf321c2e531db005df08792f5"),
+                    ..Default::default()
+                }],
+                schema_url: "".into(),
+            }],
+            schema_url: "".into(),
+        }],
+    }
+}
+
+fn assert_profiles_event(event: &Event) {
+    let log = event.as_log();
+    assert_eq!(
+        log.get("resourceProfiles[0].resource.attributes[0].key"),
+        Some(&Value::from("service.name"))
+    );
+    assert_eq!(
+        log.get("resourceProfiles[0].scopeProfiles[0].profiles[0].durationNanos"),
+        Some(&Value::from(10_000_000_000_i64))
+    );
+}
+
+#[tokio::test]
+async fn receive_grpc_profiles() {
+    assert_source_compliance(&SOURCE_TAGS, async {
+        let env = build_otlp_test_env(PROFILES, None).await;
+
+        let mut client = ProfilesServiceClient::connect(format!("http://{}", env.grpc_addr))
+            .await
+            .unwrap();
+        client
+            .export(Request::new(create_test_profiles_request()))
+            .await
+            .unwrap();
+
+        let output = test_util::collect_ready(env.output).await;
+        assert_eq!(output.len(), 1);
+        assert_profiles_event(&output[0]);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn receive_http_profiles() {
+    assert_source_compliance(&SOURCE_TAGS, async {
+        let env = build_otlp_test_env(PROFILES, None).await;
+        test_util::wait_for_tcp(env.config.http.address).await;
+
+        let response = reqwest::Client::new()
+            .post(format!(
+                "http://{}/v1development/profiles",
+                env.config.http.address
+            ))
+            .header("Content-Type", "application/x-protobuf")
+            .body(create_test_profiles_request().encode_to_vec())
+            .send()
+            .await
+            .expect("Failed to send profiles to Opentelemetry Collector.");
+        assert!(response.status().is_success());
+
+        let output = test_util::collect_ready(env.output).await;
+        assert_eq!(output.len(), 1);
+        assert_profiles_event(&output[0]);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn receive_arrow_flight_logs() {
+    assert_source_compliance(&SOURCE_TAGS, async {
+        let (_guard_0, grpc_addr) = next_addr();
+        let (_guard_1, http_addr) = next_addr();
+        let (_guard_2, flight_addr) = next_addr();
+
+        let config = OpentelemetryConfig {
+            grpc: GrpcConfig {
+                address: grpc_addr,
+                tls: Default::default(),
+            },
+            http: HttpConfig {
+                address: http_addr,
+                tls: Default::default(),
+                keepalive: Default::default(),
+                headers: Default::default(),
+            },
+            arrow_flight: Some(ArrowFlightConfig {
+                address: flight_addr,
+                tls: Default::default(),
+            }),
+            acknowledgements: Default::default(),
+            log_namespace: Default::default(),
+            use_otlp_decoding: false,
+        };
+
+        let (sender, output, _) = new_source(EventStatus::Delivered, LOGS.to_string());
+        let server = config
+            .build(SourceContext::new_test(sender, None))
+            .await
+            .expect("Failed to build source");
+        tokio::spawn(server);
+        test_util::wait_for_tcp(flight_addr).await;
+
+        let mut log = LogEvent::from("hello");
+        log.insert("severity_text", "INFO");
+        let messages = encode_flight_data(vec![Event::Log(log)], LOGS_PATH).unwrap();
+
+        let mut client = arrow_flight::Client::connect(format!("http://{flight_addr}"))
+            .await
+            .unwrap();
+        let mut results = client
+            .do_put(futures::stream::iter(messages))
+            .await
+            .unwrap()
+            .into_inner();
+        while results.message().await.unwrap().is_some() {}
+
+        let output = test_util::collect_ready(output).await;
+        assert_eq!(output.len(), 1);
+        let log = output[0].as_log();
+        assert_eq!(log.get("message"), Some(&Value::from("hello")));
+        assert_eq!(log.get("severity_text"), Some(&Value::from("INFO")));
+    })
+    .await;
+}
+
 pub struct OTelTestEnv {
     pub grpc_addr: String,
     pub config: OpentelemetryConfig,
@@ -1199,6 +1351,7 @@ pub async fn build_otlp_test_env(
             keepalive: Default::default(),
             headers: Default::default(),
         },
+        arrow_flight: None,
         acknowledgements: Default::default(),
         log_namespace,
         use_otlp_decoding: false,
@@ -0,0 +1,326 @@
+use std::{collections::HashSet, path::PathBuf, time::Duration};
+
+use snafu::{ResultExt, Snafu};
+use tokio::{
+    io::AsyncBufRead,
+    time::{Instant, sleep_until},
+};
+use vector_lib::{
+    EstimatedJsonEncodedSizeOf,
+    codecs::NativeDeserializerConfig,
+    config::{DataType, LogNamespace},
+    configurable::configurable_component,
+    internal_event::{ByteSize, BytesReceived, CountByteSize, InternalEventHandle as _, Protocol},
+    tap::capture::{CaptureError, CaptureReader},
+};
+
+use crate::{
+    SourceSender,
+    config::{GenerateConfig, SourceConfig, SourceContext, SourceOutput},
+    event::Event,
+    internal_events::{EventsReceived, StreamClosedError, TapReplayCaptureError},
+    shutdown::ShutdownSignal,
+};
+
+/// Configuration for the `tap_replay` source.
+#[configurable_component(source(
+    "tap_replay",
+    "Replay events recorded with `vector tap --record`."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct TapReplayConfig {
+    /// The path of the capture file to replay.
+    #[configurable(metadata(docs::examples = "/var/lib/vector/capture.ndjson"))]
+    pub path: PathBuf,
+
+    /// How fast to replay events, relative to the timing they were recorded with.
+    ///
+    /// The default replays events at their original timing, `2.0` replays them twice as fast. To
+    /// replay all events as quickly as possible, set `speed` to `0.0`.
+    #[serde(default = "default_speed")]
+    #[configurable(metadata(docs::examples = 1.0, docs::examples = 10.0, docs::examples = 0.0))]
+    pub speed: f64,
+
+    /// Named outputs to replay events on.
+    ///
+    /// Events captured from a component output listed here are replayed on the output of this
+    /// source with the same name, so that events captured from different outputs, such as the
+    /// routes of a `route` transform, can be told apart. Events captured from any other output are
+    /// replayed on the default output.
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = "errors"))]
+    pub outputs: Vec<String>,
+}
+
+const fn default_speed() -> f64 {
+    1.0
+}
+
+#[derive(Debug, Snafu)]
+enum TapReplayError {
+    #[snafu(display("`speed` must not be negative, got {}", speed))]
+    NegativeSpeed { speed: f64 },
+    #[snafu(display("Couldn't replay capture file {:?}: {}", path, source))]
+    InvalidCapture { path: PathBuf, source: CaptureError },
+}
+
+impl GenerateConfig for TapReplayConfig {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(Self {
+            path: PathBuf::from("/var/lib/vector/capture.ndjson"),
+            speed: default_speed(),
+            outputs: Vec::new(),
+        })
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "tap_replay")]
+impl SourceConfig for TapReplayConfig {
+    async fn build(&self, cx: SourceContext) -> crate::Result<super::Source> {
+        if self.speed < 0.0 {
+            return Err(TapReplayError::NegativeSpeed { speed: self.speed }.into());
+        }
+
+        // The capture is read as it's replayed, but it's opened upfront, so that missing captures
+        // are rejected on startup.
+        let reader = CaptureReader::open(&self.path)
+            .await
+            .context(InvalidCaptureSnafu {
+                path: self.path.clone(),
+            })?;
+
+        Ok(Box::pin(tap_replay_source(
+            reader,
+            self.path.clone(),
+            self.speed,
+            self.outputs.iter().cloned().collect(),
+            cx.out,
+            cx.shutdown,
+        )))
+    }
+
+    fn outputs(&self, global_log_namespace: LogNamespace) -> Vec<SourceOutput> {
+        // Captured events are replayed as is, along with the metadata they were recorded with.
+        let output = SourceOutput::new_maybe_logs(
+            DataType::all_bits(),
+            NativeDeserializerConfig.schema_definition(global_log_namespace),
+        );
+
+        std::iter::once(output.clone())
+            .chain(
+                self.outputs
+                    .iter()
+                    .map(|name| output.clone().with_port(name)),
+            )
+            .collect()
+    }
+
+    fn can_acknowledge(&self) -> bool {
+        false
+    }
+}
+
+async fn tap_replay_source<R: AsyncBufRead + Unpin>(
+    mut reader: CaptureReader<R>,
+    path: PathBuf,
+    speed: f64,
+    outputs: HashSet<String>,
+    mut out: SourceSender,
+    mut shutdown: ShutdownSignal,
+) -> Result<(), ()> {
+    let events_received = register!(EventsReceived);
+    let bytes_received = register!(BytesReceived::from(Protocol::from("file")));
+    let start = Instant::now();
+
+    loop {
+        let record = tokio::select! {
+            record = reader.next_record() => record,
+            _ = &mut shutdown => return Ok(()),
+        };
+        let replay = record.and_then(|record| {
+            record
+                .map(|record| record.decode().map(|events| (record, events)))
+                .transpose()
+        });
+        let (record, events) = match replay {
+            Ok(Some(replay)) => replay,
+            Ok(None) => return Ok(()),
+            Err(error) => {
+                emit!(TapReplayCaptureError { path: &path, error });
+                return Err(());
+            }
+        };
+
+        if speed > 0.0 {
+            let offset = Duration::from_millis(record.offset_ms);
+            tokio::select! {
+                _ = sleep_until(start + offset.div_f64(speed)) => {},
+                _ = &mut shutdown => return Ok(()),
+            }
+        }
+
+        bytes_received.emit(ByteSize(record.event.len()));
+        let count = events.len();
+        events_received.emit(CountByteSize(
+            count,
+            events.estimated_json_encoded_size_of(),
+        ));
+
+        let sent = match record.output.filter(|output| outputs.contains(output)) {
+            Some(output) => out.send_batch_named(&output, events).await,
+            None => out.send_batch(events).await,
+        };
+        if sent.is_err() {
+            emit!(StreamClosedError { count });
+            return Err(());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use futures::StreamExt;
+    use vector_lib::{
+        event::EventStatus,
+        tap::capture::{self, CaptureRecord},
+    };
+
+    use super::*;
+    use crate::{
+        event::{LogEvent, Metric, MetricKind, MetricValue},
+        test_util::{
+            self,
+            components::{SOURCE_TAGS, run_and_assert_source_compliance},
+        },
+    };
+
+    #[test]
+    fn generate_config() {
+        test_util::test_generate_config::<TapReplayConfig>();
+    }
+
+    fn write_capture(events: &[(u64, Option<&str>, Event)]) -> PathBuf {
+        let path = test_util::temp_file();
+        let mut file = std::fs::File::create(&path).unwrap();
+        for (offset_ms, output, event) in events {
+            let record = CaptureRecord {
+                version: capture::CAPTURE_VERSION,
+                offset_ms: *offset_ms,
+                component_id: "in".to_string(),
+                component_kind: "source".to_string(),
+                component_type: "demo_logs".to_string(),
+                output: output.map(ToString::to_string),
+                event: capture::encode_event(event.clone()),
+            };
+            writeln!(file, "{}", serde_json::to_string(&record).unwrap()).unwrap();
+        }
+        path
+    }
+
+    #[tokio::test]
+    async fn replays_capture() {
+        let captured = [
+            Event::from(LogEvent::from("first")),
+            Event::from(Metric::new(
+                "requests",
+                MetricKind::Incremental,
+                MetricValue::Counter { value: 1.0 },
+            )),
+        ];
+        let path = write_capture(&[
+            (0, None, captured[0].clone()),
+            (50, None, captured[1].clone()),
+        ]);
+
+        let events = run_and_assert_source_compliance(
+            TapReplayConfig {
+                path,
+                speed: 0.0,
+                outputs: Vec::new(),
+            },
+            Duration::from_millis(100),
+            &SOURCE_TAGS,
+        )
+        .await;
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(
+            events[0].as_log().get("message"),
+            captured[0].as_log().get("message")
+        );
+        assert_eq!(
+            events[1].as_metric().value(),
+            captured[1].as_metric().value()
+        );
+    }
+
+    #[tokio::test]
+    async fn replays_named_outputs() {
+        let path = write_capture(&[
+            (0, Some("errors"), Event::from(LogEvent::from("error"))),
+            (0, Some("other"), Event::from(LogEvent::from("other"))),
+            (0, None, Event::from(LogEvent::from("default"))),
+        ]);
+
+        let (mut tx, rx) = SourceSender::new_test();
+        let mut errors = tx.add_outputs(EventStatus::Delivered, "errors".to_string());
+        let config = TapReplayConfig {
+            path,
+            speed: 0.0,
+            outputs: vec!["errors".to_string()],
+        };
+        config
+            .build(SourceContext::new_test(tx, None))
+            .await
+            .unwrap()
+            .await
+            .unwrap();
+
+        let errors = errors.next().await.unwrap().events;
+        assert_eq!(errors.len(), 1);
+
+        // Events captured from outputs that aren't replayed separately go to the default output.
+        let messages = rx
+            .map(|event| event.as_log()["message"].to_string_lossy().into_owned())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(messages, vec!["other", "default"]);
+    }
+
+    #[tokio::test]
+    async fn rejects_missing_capture() {
+        let config = TapReplayConfig {
+            path: test_util::temp_file(),
+            speed: 1.0,
+            outputs: Vec::new(),
+        };
+        assert!(
+            config
+                .build(SourceContext::new_test(SourceSender::new_test().0, None))
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn stops_on_invalid_capture() {
+        let path = test_util::temp_file();
+        std::fs::write(&path, "not a capture record\n").unwrap();
+
+        let config = TapReplayConfig {
+            path,
+            speed: 1.0,
+            outputs: Vec::new(),
+        };
+        let source = config
+            .build(SourceContext::new_test(SourceSender::new_test().0, None))
+            .await
+            .unwrap();
+        assert!(source.await.is_err());
+    }
+}
@@ -2,7 +2,7 @@ use std::time::Duration;
 
 use vector_lib::{
     api_client::Client,
-    tap::{EventFormatter, OutputChannel, TapRunner},
+    tap::{EventFormatter, OutputChannel, TapRunner, capture::CaptureWriter},
 };
 
 use crate::signal::{SignalRx, SignalTo};
@@ -16,7 +16,16 @@ pub(crate) async fn cmd(opts: &super::Opts, signal_rx: SignalRx) -> exitcode::Ex
     let url = opts.url();
     // Return early with instructions for enabling the API if the endpoint isn't reachable
     // via a healthcheck.
-    let client = Client::new(url.clone());
+    let client = match Client::new_with_options(url.clone(), &opts.client_options()) {
+        Ok(client) => client,
+        Err(error) => {
+            #[allow(clippy::print_stderr)]
+            {
+                eprintln!("Couldn't create the Vector API client: {error:#}");
+            }
+            return exitcode::CONFIG;
+        }
+    };
     #[allow(clippy::print_stderr)]
     if client.healthcheck().await.is_err() {
         eprintln!(
@@ -40,14 +49,29 @@ pub(crate) async fn cmd(opts: &super::Opts, signal_rx: SignalRx) -> exitcode::Ex
 /// Observe event flow from specified components
 pub async fn tap(opts: &super::Opts, mut signal_rx: SignalRx) -> exitcode::ExitCode {
     let subscription_url = opts.web_socket_url();
-    let output_channel = OutputChannel::Stdout(EventFormatter::new(opts.meta, opts.format));
+    let output_channel = match &opts.record {
+        Some(path) => match CaptureWriter::create(path).await {
+            Ok(writer) => OutputChannel::Record(writer),
+            Err(error) => {
+                #[allow(clippy::print_stderr)]
+                {
+                    eprintln!("Couldn't create capture file {path:?}: {error}");
+                }
+                return exitcode::CANTCREAT;
+            }
+        },
+        None => OutputChannel::Stdout(EventFormatter::new(opts.meta, opts.format)),
+    };
+    let client_options = opts.client_options();
     let tap_runner = TapRunner::new(
         &subscription_url,
         opts.inputs_of.clone(),
         opts.outputs_patterns().clone(),
         &output_channel,
-        opts.format,
-    );
+        opts.format(),
+        &client_options,
+    )
+    .with_programs(opts.filter.clone(), opts.transform.clone());
 
     loop {
         tokio::select! {
@@ -1,11 +1,13 @@
 //! Tap subcommand
 mod cmd;
 
+use std::path::PathBuf;
+
 use clap::Parser;
 pub(crate) use cmd::cmd;
 pub use cmd::tap;
 use url::Url;
-use vector_lib::api_client::gql::TapEncodingFormat;
+use vector_lib::api_client::{ClientArgs, ClientOptions, gql::TapEncodingFormat};
 
 use crate::config::api::default_graphql_url;
 
@@ -21,6 +23,9 @@ pub struct Opts {
     #[arg(short, long)]
     url: Option<Url>,
 
+    #[command(flatten)]
+    client: ClientArgs,
+
     /// Maximum number of events to sample each interval
     #[arg(default_value = "100", short = 'l', long)]
     limit: u32,
@@ -56,6 +61,23 @@ pub struct Opts {
     /// Specifies a duration (in milliseconds) to sample logs (e.g. specifying 10000 will sample logs for 10 seconds then exit)
     #[arg(short = 'd', long)]
     duration_ms: Option<u64>,
+
+    /// VRL boolean expression to filter events with. Filtering happens in the Vector instance
+    /// being tapped, so only matching events are sent (e.g. '.status >= 500'). Requires the API of
+    /// that instance to be configured with authentication
+    #[arg(long)]
+    filter: Option<String>,
+
+    /// VRL program to transform events with before they're sent, such as to only keep a subset of
+    /// their fields (e.g. '. = {"message": .message}'). Applied after the filter. Requires the API
+    /// of the Vector instance being tapped to be configured with authentication.
+    #[arg(long)]
+    transform: Option<String>,
+
+    /// Record events, losslessly, to a capture file at the given path instead of printing them.
+    /// Capture files can be replayed with the `tap_replay` source, or used as unit test inputs.
+    #[arg(long)]
+    record: Option<PathBuf>,
 }
 
 impl Opts {
@@ -77,12 +99,26 @@ impl Opts {
         }
     }
 
+    /// Encoding format to tap events with. Recorded events are always native encoded.
+    pub fn format(&self) -> TapEncodingFormat {
+        if self.record.is_some() {
+            TapEncodingFormat::Native
+        } else {
+            self.format
+        }
+    }
+
     /// Use the provided URL as the Vector GraphQL API server, or default to the local port
     /// provided by the API config.
     pub fn url(&self) -> Url {
         self.url.clone().unwrap_or_else(default_graphql_url)
     }
 
+    /// Options for connecting to the Vector GraphQL API server.
+    pub fn client_options(&self) -> ClientOptions {
+        self.client.options()
+    }
+
     /// URL with scheme set to WebSockets
     pub fn web_socket_url(&self) -> Url {
         let mut url = self.url();
@@ -3,7 +3,7 @@ use std::time::Duration;
 use chrono::Local;
 use futures_util::future::join_all;
 use tokio::sync::{mpsc, oneshot};
-use vector_lib::api_client::{Client, connect_subscription_client};
+use vector_lib::api_client::{Client, connect_subscription_client_with_options};
 
 use vector_lib::top::{
     dashboard::{init_dashboard, is_tty},
@@ -28,7 +28,16 @@ pub async fn cmd(opts: &super::Opts) -> exitcode::ExitCode {
 
     let url = opts.url();
     // Create a new API client for connecting to the local/remote Vector instance.
-    let client = Client::new(url.clone());
+    let client = match Client::new_with_options(url.clone(), &opts.client_options()) {
+        Ok(client) => client,
+        Err(error) => {
+            #[allow(clippy::print_stderr)]
+            {
+                eprintln!("Couldn't create the Vector API client: {error:#}");
+            }
+            return exitcode::CONFIG;
+        }
+    };
     #[allow(clippy::print_stderr)]
     if client.healthcheck().await.is_err() {
         eprintln!(
@@ -53,7 +62,7 @@ pub async fn cmd(opts: &super::Opts) -> exitcode::ExitCode {
 pub async fn top(opts: &super::Opts, client: Client, dashboard_title: &str) -> exitcode::ExitCode {
     // Channel for updating state via event messages
     let (tx, rx) = tokio::sync::mpsc::channel(20);
-    let state_rx = state::updater(rx).await;
+    let state_rx = state::updater(rx, opts.history_len()).await;
     // Channel for shutdown signal
     let (shutdown_tx, shutdown_rx) = oneshot::channel::<()>();
 
@@ -94,6 +103,7 @@ async fn subscription(
     shutdown_tx: oneshot::Sender<()>,
 ) {
     let ws_url = opts.web_socket_url();
+    let client_options = opts.client_options();
 
     loop {
         // Initialize state. On future reconnects, we re-initialize state in
@@ -108,13 +118,14 @@ async fn subscription(
         };
         _ = tx.send(EventType::InitializeState(state)).await;
 
-        let subscription_client = match connect_subscription_client(ws_url.clone()).await {
-            Ok(c) => c,
-            Err(_) => {
-                tokio::time::sleep(Duration::from_millis(RECONNECT_DELAY)).await;
-                continue;
-            }
-        };
+        let subscription_client =
+            match connect_subscription_client_with_options(ws_url.clone(), &client_options).await {
+                Ok(c) => c,
+                Err(_) => {
+                    tokio::time::sleep(Duration::from_millis(RECONNECT_DELAY)).await;
+                    continue;
+                }
+            };
 
         // Subscribe to updated metrics
         let finished = metrics::subscribe(
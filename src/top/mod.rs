@@ -6,6 +6,7 @@ use glob::Pattern;
 
 pub use cmd::{cmd, top};
 use url::Url;
+use vector_lib::api_client::{ClientArgs, ClientOptions};
 
 use crate::config::api::default_graphql_url;
 
@@ -21,6 +22,9 @@ pub struct Opts {
     #[arg(short, long)]
     url: Option<Url>,
 
+    #[command(flatten)]
+    client: ClientArgs,
+
     /// Humanize metrics, using numeric suffixes - e.g. 1,100 = 1.10 k, 1,000,000 = 1.00 M
     #[arg(short = 'H', long, default_value_t = true)]
     human_metrics: bool,
@@ -31,6 +35,10 @@ pub struct Opts {
     #[arg(short, long)]
     no_reconnect: bool,
 
+    /// How far back the throughput history shown in sparklines goes, in seconds
+    #[arg(default_value = "300", long)]
+    history: u32,
+
     /// Components IDs to observe (comma-separated; accepts glob patterns)
     #[arg(default_value = "*", value_delimiter(','), short = 'c', long)]
     components: Vec<Pattern>,
@@ -43,6 +51,16 @@ impl Opts {
         self.url.clone().unwrap_or_else(default_graphql_url)
     }
 
+    /// Number of throughput samples to keep for each component, to cover the history.
+    pub fn history_len(&self) -> usize {
+        (u64::from(self.history) * 1000 / u64::from(self.interval.max(1))).max(1) as usize
+    }
+
+    /// Options for connecting to the Vector GraphQL API server.
+    pub fn client_options(&self) -> ClientOptions {
+        self.client.options()
+    }
+
     /// URL with scheme set to WebSockets
     pub fn web_socket_url(&self) -> Url {
         let mut url = self.url();
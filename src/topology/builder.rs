@@ -1,18 +1,24 @@
 use std::{
     collections::HashMap,
+    fmt,
     future::ready,
     num::NonZeroUsize,
     sync::{Arc, LazyLock, Mutex},
-    time::Instant,
+    time::{Duration, Instant},
 };
 
-use futures::{FutureExt, StreamExt, TryStreamExt, stream::FuturesOrdered};
+use futures::{
+    FutureExt, StreamExt, TryStreamExt,
+    future::{BoxFuture, Shared},
+    stream::FuturesOrdered,
+};
 use futures_util::stream::FuturesUnordered;
+use indexmap::IndexMap;
 use metrics::gauge;
 use stream_cancel::{StreamExt as StreamCancelExt, Trigger, Tripwire};
 use tokio::{
     select,
-    sync::{mpsc::UnboundedSender, oneshot},
+    sync::{mpsc::UnboundedSender, oneshot, watch},
     time::timeout,
 };
 use tracing::Instrument;
@@ -34,6 +40,7 @@ use vector_lib::{
 
 use super::{
     BuiltBuffer, ConfigDiff,
+    dead_letter::DeadLetter,
     fanout::{self, Fanout},
     schema,
     task::{Task, TaskOutput, TaskResult},
@@ -41,13 +48,15 @@ use super::{
 use crate::{
     SourceSender,
     config::{
-        ComponentKey, Config, DataType, EnrichmentTableConfig, Input, Inputs, OutputId,
-        ProxyConfig, SinkContext, SourceContext, TransformContext, TransformOuter, TransformOutput,
+        ComponentKey, Config, DataType, EnrichmentTableConfig, EnrichmentTableOuter, GlobalOptions,
+        Input, Inputs, OutputId, ProxyConfig, SinkContext, SourceContext, TransformContext,
+        TransformOuter, TransformOutput,
     },
     event::{EventArray, EventContainer},
     extra_context::ExtraContext,
-    internal_events::EventsReceived,
+    internal_events::{EnrichmentTableReloadError, EnrichmentTableReloaded, EventsReceived},
     shutdown::SourceShutdownCoordinator,
+    sinks::Healthcheck,
     spawn_named,
     topology::task::TaskError,
     transforms::{SyncTransform, TaskTransform, Transform, TransformOutputs, TransformOutputsBuf},
@@ -82,6 +91,8 @@ struct Builder<'a> {
     inputs: HashMap<ComponentKey, (BufferSender<EventArray>, Inputs<OutputId>)>,
     healthchecks: HashMap<ComponentKey, Task>,
     detach_triggers: HashMap<ComponentKey, Trigger>,
+    source_pauses: HashMap<ComponentKey, watch::Sender<bool>>,
+    sink_healthchecks: HashMap<ComponentKey, SharedHealthcheck>,
     extra_context: ExtraContext,
     utilization_emitter: Option<UtilizationEmitter>,
     utilization_registry: UtilizationRegistry,
@@ -114,6 +125,8 @@ impl<'a> Builder<'a> {
             inputs: HashMap::new(),
             healthchecks: HashMap::new(),
             detach_triggers: HashMap::new(),
+            source_pauses: HashMap::new(),
+            sink_healthchecks: HashMap::new(),
             extra_context,
             utilization_emitter: emitter,
             utilization_registry: registry,
@@ -140,6 +153,8 @@ impl<'a> Builder<'a> {
                 healthchecks: self.healthchecks,
                 shutdown_coordinator: self.shutdown_coordinator,
                 detach_triggers: self.detach_triggers,
+                source_pauses: self.source_pauses,
+                sink_healthchecks: self.sink_healthchecks,
                 utilization: self
                     .utilization_emitter
                     .map(|e| (e, self.utilization_registry)),
@@ -176,7 +191,10 @@ impl<'a> Builder<'a> {
                 let indexes = if !self.diff.enrichment_tables.is_added(name) {
                     // If this is an existing enrichment table, we need to store the indexes to reapply
                     // them again post load.
-                    Some(ENRICHMENT_TABLES.index_fields(&table_name))
+                    Some((
+                        ENRICHMENT_TABLES.index_fields(&table_name),
+                        ENRICHMENT_TABLES.condition_index_fields(&table_name),
+                    ))
                 } else {
                     None
                 };
@@ -190,7 +208,7 @@ impl<'a> Builder<'a> {
                     }
                 };
 
-                if let Some(indexes) = indexes {
+                if let Some((indexes, condition_indexes)) = indexes {
                     for (case, index) in indexes {
                         match table
                             .add_index(case, &index.iter().map(|s| s.as_ref()).collect::<Vec<_>>())
@@ -207,6 +225,15 @@ impl<'a> Builder<'a> {
                             }
                         }
                     }
+
+                    for (field, kind) in condition_indexes {
+                        if let Err(error) = table.add_condition_index(&field, kind) {
+                            error!(message = "Unable to add index to reloaded enrichment table.",
+                                table = ?name.to_string(),
+                                %error);
+                            continue 'tables;
+                        }
+                    }
                 }
 
                 enrichment_tables.insert(table_name, table);
@@ -265,6 +292,7 @@ impl<'a> Builder<'a> {
                 .with_timeout(source.inner.send_timeout());
             let mut pumps = Vec::new();
             let mut controls = HashMap::new();
+            let (pause_tx, pause_rx) = watch::channel(false);
             let mut schema_definitions = HashMap::with_capacity(source_outputs.len());
 
             for output in source_outputs.into_iter() {
@@ -273,6 +301,7 @@ impl<'a> Builder<'a> {
                 let (mut fanout, control) = Fanout::new();
                 let source_type = source.inner.get_component_name();
                 let source = Arc::new(key.clone());
+                let mut paused = pause_rx.clone();
 
                 let pump = async move {
                     debug!("Source pump starting.");
@@ -282,6 +311,11 @@ impl<'a> Builder<'a> {
                         send_reference,
                     }) = rx.next().await
                     {
+                        // While the source is paused, events are held back, applying backpressure
+                        // to the source once its sender is full. An error means the pause handle
+                        // was dropped along with the source, which can't be paused anymore.
+                        _ = paused.wait_for(|paused| !paused).await;
+
                         array.set_output_id(&source);
                         array.set_source_type(source_type);
                         fanout
@@ -423,6 +457,7 @@ impl<'a> Builder<'a> {
 
             self.outputs.extend(controls);
             self.tasks.insert(key.clone(), pump);
+            self.source_pauses.insert(key.clone(), pause_tx);
             source_tasks.insert(key.clone(), server);
         }
 
@@ -561,6 +596,7 @@ impl<'a> Builder<'a> {
 
             let typetag = sink.inner.get_component_name();
             let input_type = sink.inner.input().data_type();
+            let sink_outputs = sink.outputs();
 
             let span = error_span!(
                 "sink",
@@ -630,6 +666,16 @@ impl<'a> Builder<'a> {
 
             let (trigger, tripwire) = Tripwire::new();
 
+            // Sinks rerouting the events they failed to deliver track each event until it's
+            // finalized, as long as some component consumes their `dropped` output.
+            let (tracker, dead_letter) = if !self.config.reroutes_dropped(key) {
+                (None, None)
+            } else {
+                let (tracker, dead_letter, controls) = DeadLetter::new(key, typetag, sink_outputs);
+                self.outputs.extend(controls);
+                (Some(tracker), Some(dead_letter))
+            };
+
             let utilization_sender = self
                 .utilization_registry
                 .add_component(key.clone(), gauge!("utilization"));
@@ -652,7 +698,7 @@ impl<'a> Builder<'a> {
                 let mut rx = wrap(utilization_sender, component_key.clone(), rx);
 
                 let events_received = register!(EventsReceived);
-                sink.run(
+                let sink = sink.run(
                     rx.by_ref()
                         .filter(|events: &EventArray| ready(filter_events_type(events, input_type)))
                         .inspect(|events| {
@@ -661,84 +707,186 @@ impl<'a> Builder<'a> {
                                 events.estimated_json_encoded_size_of(),
                             ))
                         })
-                        .take_until_if(tripwire),
-                )
-                .await
-                .map(|_| {
-                    debug!("Sink finished normally.");
-                    TaskOutput::Sink(rx)
-                })
-                .map_err(|_| {
-                    debug!("Sink finished with an error.");
-                    TaskError::Opaque
-                })
-            };
-
-            let task = Task::new(key.clone(), typetag, sink);
-
-            let component_key = key.clone();
-            let healthcheck_task = async move {
-                if enable_healthcheck {
-                    timeout(healthcheck_timeout, healthcheck)
-                        .map(|result| match result {
-                            Ok(Ok(_)) => {
-                                info!("Healthcheck passed.");
-                                Ok(TaskOutput::Healthcheck)
-                            }
-                            Ok(Err(error)) => {
-                                error!(
-                                    msg = "Healthcheck failed.",
-                                    %error,
-                                    component_kind = "sink",
-                                    component_type = typetag,
-                                    component_id = %component_key.id(),
-                                );
-                                Err(TaskError::wrapped(error))
-                            }
-                            Err(e) => {
-                                error!(
-                                    msg = "Healthcheck timed out.",
-                                    component_kind = "sink",
-                                    component_type = typetag,
-                                    component_id = %component_key.id(),
-                                );
-                                Err(TaskError::wrapped(Box::new(e)))
+                        .then(move |mut events| {
+                            let tracker = tracker.clone();
+                            async move {
+                                if let Some(tracker) = tracker {
+                                    tracker.track(&mut events).await;
+                                }
+                                events
                             }
                         })
-                        .await
-                } else {
-                    info!("Healthcheck disabled.");
-                    Ok(TaskOutput::Healthcheck)
+                        .take_until_if(tripwire),
+                );
+                let dead_letter = async move {
+                    match dead_letter {
+                        Some(dead_letter) => dead_letter.run().await,
+                        None => Ok(()),
+                    }
+                };
+
+                let (result, dead_letter_result) = futures::join!(sink, dead_letter);
+                if let Err(error) = dead_letter_result {
+                    debug!("Sink dead letter output finished with an error.");
+                    return Err(TaskError::wrapped(error));
                 }
+
+                result
+                    .map(|_| {
+                        debug!("Sink finished normally.");
+                        TaskOutput::Sink(rx)
+                    })
+                    .map_err(|_| {
+                        debug!("Sink finished with an error.");
+                        TaskError::Opaque
+                    })
             };
 
-            let healthcheck_task = Task::new(key.clone(), typetag, healthcheck_task);
+            let task = Task::new(key.clone(), typetag, sink);
+
+            let healthcheck = shared_healthcheck(healthcheck, healthcheck_timeout);
+            let healthcheck_task =
+                healthcheck_task(key, typetag, healthcheck.clone(), enable_healthcheck);
 
             self.inputs.insert(key.clone(), (tx, sink_inputs.clone()));
             self.healthchecks.insert(key.clone(), healthcheck_task);
+            self.sink_healthchecks.insert(key.clone(), healthcheck);
             self.tasks.insert(key.clone(), task);
             self.detach_triggers.insert(key.clone(), trigger);
         }
     }
 }
 
+/// Healthcheck of a sink that can be awaited more than once.
+///
+/// The healthcheck only runs the first time it's awaited, and its outcome is shared with everyone
+/// awaiting it, which lets the healthcheck of a running sink be requested on demand.
+pub(crate) type SharedHealthcheck = Shared<BoxFuture<'static, Result<(), HealthcheckError>>>;
+
+/// Error returned by a failed [`SharedHealthcheck`].
+#[derive(Clone, Debug)]
+pub(crate) enum HealthcheckError {
+    Failed(String),
+    TimedOut,
+}
+
+impl fmt::Display for HealthcheckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Failed(error) => error.fmt(f),
+            Self::TimedOut => f.write_str("healthcheck timed out"),
+        }
+    }
+}
+
+impl std::error::Error for HealthcheckError {}
+
+fn shared_healthcheck(
+    healthcheck: Healthcheck,
+    healthcheck_timeout: Duration,
+) -> SharedHealthcheck {
+    timeout(healthcheck_timeout, healthcheck)
+        .map(|result| match result {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(error)) => Err(HealthcheckError::Failed(error.to_string())),
+            Err(_) => Err(HealthcheckError::TimedOut),
+        })
+        .boxed()
+        .shared()
+}
+
+fn healthcheck_task(
+    key: &ComponentKey,
+    typetag: &'static str,
+    healthcheck: SharedHealthcheck,
+    enabled: bool,
+) -> Task {
+    let component_key = key.clone();
+    let healthcheck_task = async move {
+        if enabled {
+            healthcheck
+                .map(|result| match result {
+                    Ok(()) => {
+                        info!("Healthcheck passed.");
+                        Ok(TaskOutput::Healthcheck)
+                    }
+                    Err(HealthcheckError::Failed(error)) => {
+                        error!(
+                            msg = "Healthcheck failed.",
+                            %error,
+                            component_kind = "sink",
+                            component_type = typetag,
+                            component_id = %component_key.id(),
+                        );
+                        Err(TaskError::wrapped(error.into()))
+                    }
+                    Err(error @ HealthcheckError::TimedOut) => {
+                        error!(
+                            msg = "Healthcheck timed out.",
+                            component_kind = "sink",
+                            component_type = typetag,
+                            component_id = %component_key.id(),
+                        );
+                        Err(TaskError::wrapped(Box::new(error)))
+                    }
+                })
+                .await
+        } else {
+            info!("Healthcheck disabled.");
+            Ok(TaskOutput::Healthcheck)
+        }
+    };
+
+    Task::new(key.clone(), typetag, healthcheck_task)
+}
+
 pub async fn reload_enrichment_tables(config: &Config) {
+    let enrichment_tables =
+        build_reloaded_enrichment_tables(&config.enrichment_tables, &config.global).await;
+    load_reloaded_enrichment_tables(enrichment_tables);
+}
+
+/// Returns the keys of the given enrichment tables that need to be reloaded.
+pub fn enrichment_tables_needing_reload<'a>(
+    keys: impl IntoIterator<Item = &'a ComponentKey>,
+) -> Vec<ComponentKey> {
+    keys.into_iter()
+        .filter(|key| ENRICHMENT_TABLES.needs_reload(&key.to_string()))
+        .cloned()
+        .collect()
+}
+
+/// Builds the enrichment tables that need to be reloaded, with the indexes of the tables they
+/// replace.
+///
+/// Tables failing to build or to be indexed are left out, so that their previously loaded data
+/// keeps being used.
+pub async fn build_reloaded_enrichment_tables(
+    tables: &IndexMap<ComponentKey, EnrichmentTableOuter<OutputId>>,
+    global: &GlobalOptions,
+) -> HashMap<String, Box<dyn vector_lib::enrichment::Table + Send + Sync>> {
     let mut enrichment_tables = HashMap::new();
     // Build enrichment tables
-    'tables: for (name, table_outer) in config.enrichment_tables.iter() {
+    'tables: for (name, table_outer) in tables.iter() {
         let table_name = name.to_string();
         if ENRICHMENT_TABLES.needs_reload(&table_name) {
-            let indexes = Some(ENRICHMENT_TABLES.index_fields(&table_name));
+            let indexes = Some((
+                ENRICHMENT_TABLES.index_fields(&table_name),
+                ENRICHMENT_TABLES.condition_index_fields(&table_name),
+            ));
 
-            let mut table = match table_outer.inner.build(&config.global).await {
+            let mut table = match table_outer.inner.build(global).await {
                 Ok(table) => table,
                 Err(error) => {
-                    error!("Enrichment table \"{name}\" reload failed: {error}");
+                    emit!(EnrichmentTableReloadError {
+                        table: &table_name,
+                        error: error.to_string(),
+                    });
                     continue;
                 }
             };
 
-            if let Some(indexes) = indexes {
+            if let Some((indexes, condition_indexes)) = indexes {
                 for (case, index) in indexes {
                     match table
                         .add_index(case, &index.iter().map(|s| s.as_ref()).collect::<Vec<_>>())
@@ -748,23 +896,73 @@ pub async fn reload_enrichment_tables(config: &Config) {
                             // If there is an error adding an index we do not want to use the reloaded
                             // data, the previously loaded data will still need to be used.
                             // Just report the error and continue.
-                            error!(
-                                message = "Unable to add index to reloaded enrichment table.",
-                                table = ?name.to_string(),
-                                %error
-                            );
+                            emit!(EnrichmentTableReloadError {
+                                table: &table_name,
+                                error: format!("Unable to add index: {error}"),
+                            });
                             continue 'tables;
                         }
                     }
                 }
+
+                for (field, kind) in condition_indexes {
+                    if let Err(error) = table.add_condition_index(&field, kind) {
+                        emit!(EnrichmentTableReloadError {
+                            table: &table_name,
+                            error: format!("Unable to add index: {error}"),
+                        });
+                        continue 'tables;
+                    }
+                }
             }
 
             enrichment_tables.insert(table_name, table);
         }
     }
 
+    enrichment_tables
+}
+
+/// Drops the enrichment tables built by [`build_reloaded_enrichment_tables`] from `built_from`
+/// that are stale compared to the `current` configuration, as it was reloaded while they were
+/// being built.
+///
+/// A table is stale if it was removed or changed in the configuration, or if indexes were added
+/// to the loaded table, since the handles of these indexes don't exist in the built table.
+pub fn retain_current_enrichment_tables(
+    tables: &mut HashMap<String, Box<dyn vector_lib::enrichment::Table + Send + Sync>>,
+    built_from: &IndexMap<ComponentKey, EnrichmentTableOuter<OutputId>>,
+    current: &IndexMap<ComponentKey, EnrichmentTableOuter<OutputId>>,
+) {
+    tables.retain(|name, table| {
+        let key = ComponentKey::from(name.as_str());
+        let unchanged = match (built_from.get(&key), current.get(&key)) {
+            (Some(old), Some(new)) => {
+                serde_json::to_value(old).unwrap() == serde_json::to_value(new).unwrap()
+            }
+            _ => false,
+        };
+        let current = unchanged
+            && table.index_fields() == ENRICHMENT_TABLES.index_fields(name)
+            && table.condition_index_fields() == ENRICHMENT_TABLES.condition_index_fields(name);
+        if !current {
+            debug!(message = "Dropping stale reload of enrichment table.", table = %name);
+        }
+        current
+    });
+}
+
+/// Swaps in enrichment tables built by [`build_reloaded_enrichment_tables`].
+pub fn load_reloaded_enrichment_tables(
+    enrichment_tables: HashMap<String, Box<dyn vector_lib::enrichment::Table + Send + Sync>>,
+) {
+    let reloaded = enrichment_tables.keys().cloned().collect::<Vec<_>>();
     ENRICHMENT_TABLES.load(enrichment_tables);
     ENRICHMENT_TABLES.finish_load();
+
+    for table in reloaded {
+        emit!(EnrichmentTableReloaded { table: &table });
+    }
 }
 
 pub struct TopologyPieces {
@@ -775,6 +973,8 @@ pub struct TopologyPieces {
     pub(super) healthchecks: HashMap<ComponentKey, Task>,
     pub(crate) shutdown_coordinator: SourceShutdownCoordinator,
     pub(crate) detach_triggers: HashMap<ComponentKey, Trigger>,
+    pub(crate) source_pauses: HashMap<ComponentKey, watch::Sender<bool>>,
+    pub(crate) sink_healthchecks: HashMap<ComponentKey, SharedHealthcheck>,
     pub(crate) utilization: Option<(UtilizationEmitter, UtilizationRegistry)>,
 }
 
@@ -0,0 +1,49 @@
+use tokio::sync::{mpsc, oneshot};
+
+use crate::config::ComponentKey;
+
+/// Maximum number of pending control requests.
+const CONTROL_CHANNEL_SIZE: usize = 16;
+
+pub type ControlTx = mpsc::Sender<ControlRequest>;
+pub type ControlRx = mpsc::Receiver<ControlRequest>;
+
+/// Creates the channel used to control the running topology at runtime.
+pub fn control_channel() -> (ControlTx, ControlRx) {
+    mpsc::channel(CONTROL_CHANNEL_SIZE)
+}
+
+/// An action to take on the running topology, requested at runtime.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ControlCommand {
+    /// Reload the configuration from disk, as on `SIGHUP`.
+    ReloadConfig,
+    /// Reload all enrichment tables.
+    ReloadEnrichmentTables,
+    /// Pause the given source.
+    PauseSource(ComponentKey),
+    /// Resume the given, previously paused, source.
+    ResumeSource(ComponentKey),
+    /// Run the healthcheck of the given sink.
+    Healthcheck(ComponentKey),
+}
+
+/// A control command, along with the channel its outcome is reported on.
+#[derive(Debug)]
+pub struct ControlRequest {
+    pub command: ControlCommand,
+    pub response: oneshot::Sender<Result<(), String>>,
+}
+
+impl ControlRequest {
+    /// Sends the given command over the control channel, and waits for its outcome.
+    pub async fn send(tx: &ControlTx, command: ControlCommand) -> Result<(), String> {
+        let (response, response_rx) = oneshot::channel();
+        tx.send(Self { command, response })
+            .await
+            .map_err(|_| "Vector is shutting down.".to_string())?;
+        response_rx
+            .await
+            .map_err(|_| "Vector is shutting down.".to_string())?
+    }
+}
@@ -10,7 +10,7 @@ use crate::{
     extra_context::ExtraContext,
     internal_events::{VectorRecoveryError, VectorReloadError, VectorReloaded},
     signal::ShutdownError,
-    topology::{ReloadError, RunningTopology},
+    topology::{ControlTx, ReloadError, RunningTopology},
 };
 
 #[derive(Clone, Debug)]
@@ -40,7 +40,12 @@ pub struct TopologyController {
     pub require_healthy: Option<bool>,
     #[cfg(feature = "api")]
     pub api_server: Option<api::Server>,
+    /// Sender for commands controlling the topology at runtime, handed to the API server.
+    pub control_tx: ControlTx,
     pub extra_context: ExtraContext,
+    /// Watcher of the files of the enrichment tables, which watches the files of the tables of
+    /// each reloaded config.
+    pub enrichment_table_watcher: Option<config::watcher::EnrichmentTableWatcher>,
 }
 
 impl std::fmt::Debug for TopologyController {
@@ -86,6 +91,7 @@ impl TopologyController {
                 self.topology.config(),
                 self.topology.watch(),
                 Arc::<AtomicBool>::clone(&self.topology.running),
+                self.control_tx.clone(),
                 &Handle::current(),
             ) {
                 Ok(api_server) => {
@@ -117,6 +123,12 @@ impl TopologyController {
                     api_server.update_config(self.topology.config());
                 }
 
+                if let Some(ref watcher) = self.enrichment_table_watcher {
+                    watcher.set_paths(config::watcher::enrichment_table_paths(
+                        self.topology.config(),
+                    ));
+                }
+
                 emit!(VectorReloaded {
                     config_paths: &self.config_paths
                 });
@@ -0,0 +1,255 @@
+//! Rerouting of the events a sink failed to deliver to its `dropped` output.
+//!
+//! While components consume the `dropped` output of a sink, each event sent to the sink is given a
+//! fresh finalizer, while a copy of the event keeps the original finalizers. Once the sink
+//! finalizes the event, the copy is either released, acknowledging the original finalizers, or
+//! annotated with the details of the failure and sent to the `dropped` output, which then takes
+//! over the original finalizers. Events the sink drops without finalizing them, such as events
+//! failing to be encoded, count as rejected.
+
+use std::{collections::HashMap, future::Future};
+
+use futures::{StreamExt, stream::FuturesUnordered};
+use tokio::{select, sync::mpsc};
+use vector_lib::{
+    config::{LogNamespace, log_schema},
+    lookup::{PathPrefix, metadata_path},
+};
+use vrl::path;
+
+use super::fanout;
+use crate::{
+    config::{ComponentKey, DROPPED_OUTPUT, OutputId, TransformOutput},
+    event::{
+        BatchNotifier, BatchStatus, BatchStatusReceiver, DeliveryFailure, Event, EventArray,
+        EventFinalizer, EventFinalizers, EventMutRef, EventStatus,
+    },
+    transforms::TransformOutputs,
+};
+
+/// The number of tracked events waiting to be picked up by the dead letter task. Once reached, the
+/// sink stops receiving events until the dead letter task catches up, for example when the
+/// components consuming the `dropped` output are slow.
+const PENDING_EVENTS_LIMIT: usize = 1024;
+
+struct PendingEvent {
+    event: Event,
+    finalizers: EventFinalizers,
+    receiver: BatchStatusReceiver,
+}
+
+/// Tracks the events sent to a sink, until the sink finalizes them.
+#[derive(Clone)]
+pub(super) struct DeadLetterTracker {
+    pending: mpsc::Sender<PendingEvent>,
+}
+
+impl DeadLetterTracker {
+    /// Attaches a new finalizer to each event of the array, keeping a copy of the event and its
+    /// original finalizers until the sink finalizes it.
+    pub(super) async fn track(&self, events: &mut EventArray) {
+        let pending = events
+            .iter_events_mut()
+            .map(|mut event| {
+                let finalizers = event.metadata_mut().take_finalizers();
+                let copy = match &event {
+                    EventMutRef::Log(log) => Event::Log((**log).clone()),
+                    EventMutRef::Metric(metric) => Event::Metric((**metric).clone()),
+                    EventMutRef::Trace(trace) => Event::Trace((**trace).clone()),
+                };
+                let (batch, receiver) = BatchNotifier::new_rejecting_dropped_with_receiver();
+                event
+                    .metadata_mut()
+                    .add_finalizer(EventFinalizer::new(batch));
+                PendingEvent {
+                    event: copy,
+                    finalizers,
+                    receiver,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        for pending in pending {
+            // If the dead letter task is gone, the original finalizers are dropped along with the
+            // copy, which is what would have happened without tracking.
+            _ = self.pending.send(pending).await;
+        }
+    }
+}
+
+/// Forwards the events a sink failed to deliver to its `dropped` output.
+pub(super) struct DeadLetter {
+    pending: mpsc::Receiver<PendingEvent>,
+    outputs: TransformOutputs,
+    component_key: ComponentKey,
+    component_type: &'static str,
+}
+
+impl DeadLetter {
+    /// Creates the tracker and dead letter task of a sink, along with the controls of its outputs.
+    pub(super) fn new(
+        component_key: &ComponentKey,
+        component_type: &'static str,
+        outputs: Vec<TransformOutput>,
+    ) -> (
+        DeadLetterTracker,
+        Self,
+        HashMap<OutputId, fanout::ControlChannel>,
+    ) {
+        let (tx, rx) = mpsc::channel(PENDING_EVENTS_LIMIT);
+        let (outputs, controls) = TransformOutputs::new(outputs, component_key);
+        let controls = controls
+            .into_iter()
+            .map(|(port, control)| {
+                let id = port
+                    .map(|port| OutputId::from((component_key, port)))
+                    .unwrap_or_else(|| OutputId::from(component_key));
+                (id, control)
+            })
+            .collect();
+
+        let dead_letter = Self {
+            pending: rx,
+            outputs,
+            component_key: component_key.clone(),
+            component_type,
+        };
+        (DeadLetterTracker { pending: tx }, dead_letter, controls)
+    }
+
+    /// Runs until the tracker is dropped and all of the tracked events are finalized.
+    pub(super) async fn run(mut self) -> crate::Result<()> {
+        let mut in_flight = FuturesUnordered::new();
+        let mut tracking = true;
+
+        loop {
+            select! {
+                pending = self.pending.recv(), if tracking => match pending {
+                    Some(pending) => in_flight.push(wait_for_status(pending)),
+                    None => tracking = false,
+                },
+
+                Some((status, failure, event, finalizers)) = in_flight.next(), if !in_flight.is_empty() => {
+                    self.handle_status(status, failure, event, finalizers).await?;
+                }
+
+                else => break,
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn handle_status(
+        &mut self,
+        status: BatchStatus,
+        failure: Option<DeliveryFailure>,
+        mut event: Event,
+        finalizers: EventFinalizers,
+    ) -> crate::Result<()> {
+        let reason = match status {
+            BatchStatus::Delivered => {
+                finalizers.update_status(EventStatus::Delivered);
+                return Ok(());
+            }
+            BatchStatus::Errored => "errored",
+            BatchStatus::Rejected => "rejected",
+        };
+
+        self.annotate_dropped(&mut event, reason, failure.unwrap_or_default());
+        event.metadata_mut().merge_finalizers(finalizers);
+
+        let mut buf = self.outputs.new_buf_with_capacity(1);
+        buf.push(Some(DROPPED_OUTPUT), event);
+        self.outputs.send(&mut buf).await
+    }
+
+    fn dropped_data(&self, reason: &str, failure: DeliveryFailure) -> serde_json::Value {
+        let message = if failure.reason.is_empty() {
+            "Sink failed to deliver the event.".to_string()
+        } else {
+            failure.reason
+        };
+        serde_json::json!({
+                "reason": reason,
+                "message": message,
+                "http_status": failure.http_status,
+                "attempts": failure.attempts,
+                "component_id": self.component_key,
+                "component_type": self.component_type,
+                "component_kind": "sink",
+        })
+    }
+
+    fn annotate_dropped(&self, event: &mut Event, reason: &str, failure: DeliveryFailure) {
+        match event {
+            Event::Log(log) => match log.namespace() {
+                LogNamespace::Legacy => {
+                    if let Some(metadata_key) = log_schema().metadata_key() {
+                        log.insert(
+                            (PathPrefix::Event, metadata_key.concat(path!("dropped"))),
+                            self.dropped_data(reason, failure),
+                        );
+                    }
+                }
+                LogNamespace::Vector => {
+                    log.insert(
+                        metadata_path!("vector", "dropped"),
+                        self.dropped_data(reason, failure),
+                    );
+                }
+            },
+            Event::Metric(metric) => {
+                if let Some(metadata_key) = log_schema().metadata_key() {
+                    metric.replace_tag(format!("{metadata_key}.dropped.reason"), reason.into());
+                    if let Some(http_status) = failure.http_status {
+                        metric.replace_tag(
+                            format!("{metadata_key}.dropped.http_status"),
+                            http_status.to_string(),
+                        );
+                    }
+                    if let Some(attempts) = failure.attempts {
+                        metric.replace_tag(
+                            format!("{metadata_key}.dropped.attempts"),
+                            attempts.to_string(),
+                        );
+                    }
+                    metric.replace_tag(
+                        format!("{metadata_key}.dropped.component_id"),
+                        self.component_key.to_string(),
+                    );
+                    metric.replace_tag(
+                        format!("{metadata_key}.dropped.component_type"),
+                        self.component_type.into(),
+                    );
+                    metric.replace_tag(
+                        format!("{metadata_key}.dropped.component_kind"),
+                        "sink".into(),
+                    );
+                }
+            }
+            Event::Trace(trace) => {
+                if let Some(metadata_key) = log_schema().metadata_key() {
+                    trace.insert(
+                        (PathPrefix::Event, metadata_key.concat(path!("dropped"))),
+                        self.dropped_data(reason, failure),
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn wait_for_status(
+    pending: PendingEvent,
+) -> impl Future<Output = (BatchStatus, Option<DeliveryFailure>, Event, EventFinalizers)> {
+    let PendingEvent {
+        event,
+        finalizers,
+        receiver,
+    } = pending;
+    async move {
+        let (status, failure) = receiver.recv_with_failure().await;
+        (status, failure, event, finalizers)
+    }
+}
@@ -11,7 +11,9 @@ pub(super) use vector_lib::fanout;
 pub mod schema;
 
 pub mod builder;
+mod control;
 mod controller;
+mod dead_letter;
 mod ready_arrays;
 mod running;
 mod task;
@@ -31,6 +33,7 @@ use vector_lib::buffers::topology::channel::{BufferReceiverStream, BufferSender}
 use self::task::{Task, TaskError, TaskResult};
 pub use self::{
     builder::TopologyPieces,
+    control::{ControlCommand, ControlRequest, ControlRx, ControlTx, control_channel},
     controller::{ReloadOutcome, SharedTopologyController, TopologyController},
     running::{ReloadError, RunningTopology, ShutdownErrorReceiver},
 };
@@ -62,6 +62,8 @@ pub struct RunningTopology {
     tasks: HashMap<ComponentKey, TaskHandle>,
     shutdown_coordinator: SourceShutdownCoordinator,
     detach_triggers: HashMap<ComponentKey, DisabledTrigger>,
+    source_pauses: HashMap<ComponentKey, watch::Sender<bool>>,
+    sink_healthchecks: HashMap<ComponentKey, builder::SharedHealthcheck>,
     pub(crate) config: Config,
     pub(crate) abort_tx: mpsc::UnboundedSender<ShutdownError>,
     watch: (WatchTx, WatchRx),
@@ -82,6 +84,8 @@ impl RunningTopology {
             outputs_tap_metadata: HashMap::new(),
             shutdown_coordinator: SourceShutdownCoordinator::default(),
             detach_triggers: HashMap::new(),
+            source_pauses: HashMap::new(),
+            sink_healthchecks: HashMap::new(),
             source_tasks: HashMap::new(),
             tasks: HashMap::new(),
             abort_tx,
@@ -370,6 +374,44 @@ impl RunningTopology {
         reload_enrichment_tables(&self.config).await;
     }
 
+    /// Pauses or resumes the given source.
+    ///
+    /// While a source is paused, the events it produces are held back, applying backpressure to
+    /// it. Sources are resumed whenever they're reloaded.
+    pub(crate) fn set_source_paused(&self, key: &ComponentKey, paused: bool) -> Result<(), String> {
+        let pause = self
+            .source_pauses
+            .get(key)
+            .ok_or_else(|| format!("Source \"{key}\" doesn't exist."))?;
+        pause.send_replace(paused);
+        Ok(())
+    }
+
+    /// Gets the healthcheck of the given running sink, to be awaited on demand.
+    ///
+    /// The healthcheck is shared with the one run when the sink was started, so it only runs if it
+    /// hasn't already, such as when healthchecks are disabled, and its outcome is reported
+    /// otherwise. The returned future doesn't borrow the topology, so that the healthcheck can run
+    /// without holding up changes to the topology.
+    pub(crate) fn sink_healthcheck(
+        &self,
+        key: &ComponentKey,
+    ) -> Result<impl Future<Output = Result<(), String>> + use<>, String> {
+        let healthcheck = self
+            .sink_healthchecks
+            .get(key)
+            .cloned()
+            .ok_or_else(|| format!("Sink \"{key}\" doesn't exist."))?;
+        let key = key.clone();
+
+        Ok(async move {
+            info!(component_id = %key, "Running healthcheck.");
+            healthcheck
+                .await
+                .map_err(|error| format!("Healthcheck for sink \"{key}\" failed: {error}"))
+        })
+    }
+
     pub(crate) async fn run_healthchecks(
         &mut self,
         diff: &ConfigDiff,
@@ -419,6 +461,12 @@ impl RunningTopology {
             let timeout = Duration::from_secs(30);
             let mut source_shutdown_handles = Vec::new();
 
+            // Paused sources are resumed, so that the events they hold back don't prevent them
+            // from shutting down.
+            for key in diff.sources.removed_and_changed() {
+                self.source_pauses.remove(key);
+            }
+
             let deadline = Instant::now() + timeout;
             for key in &diff.sources.to_remove {
                 debug!(component_id = %key, "Removing source.");
@@ -598,6 +646,7 @@ impl RunningTopology {
         for key in &removed_sinks {
             debug!(component_id = %key, "Removing sink.");
             self.remove_inputs(key, diff, new_config).await;
+            self.remove_outputs(key);
 
             if let Some(registry) = self.utilization_registry.as_ref() {
                 registry.remove_component(key);
@@ -642,6 +691,7 @@ impl RunningTopology {
                 buffer_tx.insert((*key).clone(), self.inputs.get(key).unwrap().clone());
             }
             self.remove_inputs(key, diff, new_config).await;
+            self.remove_outputs(key);
         }
 
         // Now that we've disconnected or temporarily detached the inputs to all changed/removed
@@ -711,7 +761,8 @@ impl RunningTopology {
             }
 
             for key in &diff.sinks.to_remove {
-                // Sinks only have inputs
+                // Sinks only have outputs when rerouting dropped events
+                self.outputs_tap_metadata.remove(key);
                 self.inputs_tap_metadata.remove(key);
             }
 
@@ -765,6 +816,15 @@ impl RunningTopology {
                 }
             }
 
+            for key in diff.sinks.changed_and_added() {
+                if new_pieces.outputs.contains_key(key)
+                    && let Some(task) = new_pieces.tasks.get(key)
+                {
+                    self.outputs_tap_metadata
+                        .insert(key.clone(), ("sink", task.typetag().to_string()));
+                }
+            }
+
             for (key, input) in &new_pieces.inputs {
                 self.inputs_tap_metadata
                     .insert(key.clone(), input.1.clone());
@@ -795,6 +855,17 @@ impl RunningTopology {
             self.setup_outputs(key, new_pieces).await;
         }
 
+        // Sinks rerouting dropped events have a `dropped` output, which must also be available
+        // before wiring up the inputs of the components consuming it.
+        for key in diff
+            .sinks
+            .changed_and_added()
+            .filter(|key| new_pieces.outputs.contains_key(key))
+        {
+            debug!(component_id = %key, "Configuring outputs for sink.");
+            self.setup_outputs(key, new_pieces).await;
+        }
+
         // Now that all possible outputs are configured, we can start wiring up inputs, starting
         // with transforms.
         for key in diff.transforms.changed_and_added() {
@@ -853,6 +924,7 @@ impl RunningTopology {
 
             let mut removals = diff.sources.to_remove.clone();
             removals.extend(diff.transforms.to_remove.iter().cloned());
+            removals.extend(diff.sinks.to_remove.iter().cloned());
             self.watch
                 .0
                 .send(TapResource {
@@ -874,8 +946,8 @@ impl RunningTopology {
                         .map(|key| key.to_string())
                         .chain(added_changed_tables.iter().map(|key| key.to_string()))
                         .collect(),
-                    // Note, only sources and transforms are relevant. Sinks do
-                    // not have outputs to tap.
+                    // Note, sinks are only relevant when they have a `dropped`
+                    // output to tap.
                     removals,
                 })
                 .expect("Couldn't broadcast config changes.");
@@ -954,6 +1026,7 @@ impl RunningTopology {
     async fn remove_inputs(&mut self, key: &ComponentKey, diff: &ConfigDiff, new_config: &Config) {
         self.inputs.remove(key);
         self.detach_triggers.remove(key);
+        self.sink_healthchecks.remove(key);
 
         let old_inputs = self.config.inputs_for_node(key).expect("node exists");
         let new_inputs = new_config
@@ -1149,6 +1222,9 @@ impl RunningTopology {
         if let Some(previous) = self.tasks.insert(key.clone(), spawned) {
             drop(previous); // detach and forget
         }
+        if let Some(healthcheck) = new_pieces.sink_healthchecks.remove(key) {
+            self.sink_healthchecks.insert(key.clone(), healthcheck);
+        }
     }
 
     fn spawn_transform(&mut self, key: &ComponentKey, new_pieces: &mut builder::TopologyPieces) {
@@ -1238,6 +1314,9 @@ impl RunningTopology {
 
         // Now spawn the actual source task.
         let source_task = new_pieces.source_tasks.remove(key).unwrap();
+        if let Some(pause) = new_pieces.source_pauses.remove(key) {
+            self.source_pauses.insert(key.clone(), pause);
+        }
         let source_task = {
             let key = key.clone();
             handle_errors(source_task, self.abort_tx.clone(), |error| {
@@ -1372,5 +1451,14 @@ fn get_changed_outputs(diff: &ConfigDiff, output_ids: Inputs<OutputId>) -> Vec<O
         );
     }
 
+    for sink_key in &diff.sinks.to_change {
+        changed_outputs.extend(
+            output_ids
+                .iter()
+                .filter(|id| &id.component == sink_key)
+                .cloned(),
+        );
+    }
+
     changed_outputs
 }
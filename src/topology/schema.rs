@@ -89,6 +89,20 @@ pub fn possible_definitions(
 
             definitions.append(&mut transform_definition);
         }
+
+        // If the input is the `dropped` output of a sink, it carries the events the sink received.
+        if let Some(inputs) = config.sink_inputs(key) {
+            let sink_definitions =
+                possible_definitions(inputs, config, enrichment_tables.clone(), cache)?;
+
+            definitions.append(
+                &mut input.with_definitions(
+                    sink_definitions
+                        .into_iter()
+                        .map(|(_, definition)| definition),
+                ),
+            );
+        }
     }
 
     Ok(definitions)
@@ -192,6 +206,19 @@ pub(super) fn expanded_definitions(
             // Append whatever number of additional pipelines we created to the existing
             // pipeline definitions.
             definitions.append(&mut transform_definition);
+
+        // The `dropped` output of a sink forwards the events of each pipeline feeding the sink.
+        } else if let Some(inputs) = config.sink_inputs(key) {
+            let sink_definitions =
+                expanded_definitions(enrichment_tables.clone(), inputs, config, cache)?;
+
+            definitions.append(
+                &mut input.with_definitions(
+                    sink_definitions
+                        .into_iter()
+                        .map(|(_, definition)| definition),
+                ),
+            );
         }
     }
 
@@ -282,6 +309,20 @@ pub(crate) fn input_definitions(
 
             definitions.append(&mut transform_definitions);
         }
+
+        // If the input is the `dropped` output of a sink, it carries the events the sink received.
+        if let Some(inputs) = config.sink_inputs(key) {
+            let sink_definitions =
+                input_definitions(inputs, config, enrichment_tables.clone(), cache)?;
+
+            definitions.append(
+                &mut input.with_definitions(
+                    sink_definitions
+                        .into_iter()
+                        .map(|(_, definition)| definition),
+                ),
+            );
+        }
     }
 
     Ok(definitions)
@@ -355,6 +396,12 @@ pub trait ComponentContainer {
         input_definitions: &[(OutputId, Definition)],
     ) -> Option<Vec<TransformOutput>>;
 
+    /// Gets the inputs of the sink with the given key, if it reroutes the events it failed to
+    /// deliver to its `dropped` output.
+    fn sink_inputs(&self, _key: &ComponentKey) -> Option<&[OutputId]> {
+        None
+    }
+
     /// Gets the transform output for the given port.
     ///
     /// Returns Err(()) if there is no transform with the given key
@@ -420,6 +467,12 @@ impl ComponentContainer for Config {
         self.transform(key).map(|transform| &transform.inputs[..])
     }
 
+    fn sink_inputs(&self, key: &ComponentKey) -> Option<&[OutputId]> {
+        self.sink(key)
+            .filter(|sink| sink.reroute_dropped)
+            .map(|sink| &sink.inputs[..])
+    }
+
     fn transform_outputs(
         &self,
         key: &ComponentKey,
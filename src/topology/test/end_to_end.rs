@@ -154,3 +154,96 @@ async fn http_to_http_delivered() {
 async fn http_to_http_failed() {
     http_to_http(StatusCode::FORBIDDEN, StatusCode::BAD_REQUEST).await;
 }
+
+#[tokio::test]
+async fn http_to_http_rerouted_dropped() {
+    test_util::trace_init();
+
+    let (_guard_1, address1) = test_util::addr::next_addr();
+    let (_guard_2, address2) = test_util::addr::next_addr();
+    let (_guard_3, address3) = test_util::addr::next_addr();
+    let config = config::load_from_str(
+        &format!(
+            r#"
+[sources.in]
+type = "http"
+address = "{address1}"
+acknowledgements.enabled = true
+
+[sinks.out]
+type = "http"
+inputs = ["in"]
+encoding.codec = "json"
+uri = "http://{address2}/"
+reroute_dropped = true
+
+[sinks.rerouted]
+type = "http"
+inputs = ["out.dropped"]
+encoding.codec = "json"
+uri = "http://{address3}/"
+"#,
+        ),
+        Format::Toml,
+    )
+    .unwrap();
+    let diff = ConfigDiff::initial(&config);
+    let pieces = TopologyPiecesBuilder::new(&config, &diff)
+        .build_or_log_errors()
+        .await
+        .unwrap();
+    let (_topology, _) = RunningTopology::start_validated(config, diff, pieces)
+        .await
+        .unwrap();
+
+    test_util::wait_for_tcp(address1).await;
+
+    // The sink rejects the event, which is then delivered by the sink consuming its `dropped`
+    // output.
+    let mutex = Arc::new(Mutex::new(()));
+    let mut rx_rejecting = http_server(address2, Arc::clone(&mutex), StatusCode::BAD_REQUEST).await;
+    let (tx_body, mut rx_body) = mpsc::channel(1);
+    let service = make_service_fn(move |_| {
+        let tx_body = tx_body.clone();
+        async move {
+            Ok::<_, Error>(service_fn(move |request: Request<Body>| {
+                let tx_body = tx_body.clone();
+                async move {
+                    let body = hyper::body::to_bytes(request.into_body()).await?;
+                    tx_body
+                        .send(body)
+                        .await
+                        .expect("Error sending received body");
+                    Ok::<_, Error>(Response::new(Body::empty()))
+                }
+            }))
+        }
+    });
+    tokio::spawn(Server::bind(&address3).serve(service));
+    test_util::wait_for_tcp(address3).await;
+
+    let (_, sender) = http_client(address1, "test");
+
+    timeout(Duration::from_secs(4), rx_rejecting.recv())
+        .await
+        .expect("Timed out waiting to receive event from HTTP sink")
+        .expect("Error receiving event from HTTP sink");
+    let body = timeout(Duration::from_secs(4), rx_body.recv())
+        .await
+        .expect("Timed out waiting to receive rerouted event")
+        .expect("Error receiving rerouted event");
+
+    let events: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(events[0]["message"], "test");
+    let dropped = &events[0]["metadata"]["dropped"];
+    assert_eq!(dropped["reason"], "rejected");
+    assert_eq!(dropped["http_status"], 400);
+    assert_eq!(dropped["component_id"], "out");
+
+    // The event is acknowledged once the rerouted copy is delivered.
+    let result = timeout(Duration::from_secs(1), sender)
+        .await
+        .expect("Timed out waiting to receive result from HTTP source")
+        .expect("Error receiving result from tokio task");
+    assert_eq!(result.status(), StatusCode::OK);
+}
@@ -878,6 +878,8 @@ async fn topology_disk_buffer_flushes_on_idle() {
     sink1_outer.buffer = BufferConfig::Single(BufferType::DiskV2 {
         max_size: std::num::NonZeroU64::new(268435488).unwrap(),
         when_full: WhenFull::DropNewest,
+        compression: Default::default(),
+        encryption: None,
     });
     config.add_sink_outer("out1", sink1_outer);
 
@@ -974,3 +976,71 @@ async fn source_metadata_reaches_sink() {
         ComponentKey::from("in2")
     );
 }
+
+#[tokio::test]
+async fn topology_pause_and_resume_source() {
+    trace_init();
+
+    let (mut in1, source1) = basic_source();
+    let (mut out1, sink1) = basic_sink(10);
+
+    let mut config = Config::builder();
+    config.add_source("in1", source1);
+    config.add_sink("out1", &["in1"], sink1);
+
+    let (topology, _) = start_topology(config.build().unwrap(), false).await;
+    let key = ComponentKey::from("in1");
+
+    topology.set_source_paused(&key, true).unwrap();
+    in1.send_event(Event::Log(LogEvent::from("this")))
+        .await
+        .unwrap();
+
+    // Events are held back while the source is paused...
+    let res = tokio::time::timeout(Duration::from_millis(100), out1.next()).await;
+    assert!(res.is_err(), "paused source should not forward events");
+
+    // ...and are forwarded once it's resumed.
+    topology.set_source_paused(&key, false).unwrap();
+    let out_event = out1.next().await.unwrap();
+    assert_eq!(
+        out_event
+            .events
+            .into_events()
+            .map(into_message)
+            .collect::<Vec<_>>(),
+        vec!["this".to_string()]
+    );
+
+    assert!(
+        topology
+            .set_source_paused(&ComponentKey::from("out1"), true)
+            .is_err()
+    );
+
+    topology.stop().await;
+}
+
+#[tokio::test]
+async fn topology_sink_healthcheck_on_demand() {
+    let (topology, _) = start_topology(basic_config_with_sink_failing_healthcheck(), false).await;
+
+    let healthcheck = topology
+        .sink_healthcheck(&ComponentKey::from("out1"))
+        .expect("sink should be running");
+    assert!(healthcheck.await.is_err());
+
+    // The outcome is shared with the healthcheck run on startup, so it can be requested again.
+    let healthcheck = topology
+        .sink_healthcheck(&ComponentKey::from("out1"))
+        .expect("sink should be running");
+    assert!(healthcheck.await.is_err());
+
+    assert!(
+        topology
+            .sink_healthcheck(&ComponentKey::from("in1"))
+            .is_err()
+    );
+
+    topology.stop().await;
+}
@@ -27,12 +27,18 @@ pub mod metric_to_log;
 pub mod remap;
 #[cfg(feature = "transforms-route")]
 pub mod route;
+#[cfg(any(feature = "transforms-tail_sample", feature = "transforms-trace_to_metric"))]
+mod spans;
 #[cfg(feature = "transforms-tag_cardinality_limit")]
 pub mod tag_cardinality_limit;
+#[cfg(feature = "transforms-tail_sample")]
+pub mod tail_sample;
 #[cfg(feature = "transforms-throttle")]
 pub mod throttle;
 #[cfg(feature = "transforms-trace_to_log")]
 pub mod trace_to_log;
+#[cfg(feature = "transforms-trace_to_metric")]
+pub mod trace_to_metric;
 #[cfg(feature = "transforms-window")]
 pub mod window;
 
@@ -1,3 +1,6 @@
+use std::time::Duration;
+
+use serde_with::serde_as;
 use snafu::Snafu;
 use vector_lib::{
     config::{LegacyKey, LogNamespace},
@@ -30,11 +33,68 @@ pub enum SampleError {
     InvalidRate,
 
     #[snafu(display(
-        "Exactly one value must be provided for either 'rate' or 'ratio', but not both"
+        "Only positive, non-zero numbers are allowed values for `dynamic.target_events_per_sec`, value: {target}"
+    ))]
+    InvalidTarget { target: f64 },
+
+    #[snafu(display("Only non-zero durations are allowed values for `dynamic.window_secs`"))]
+    InvalidWindow,
+
+    #[snafu(display("Only non-zero numbers are allowed values for `dynamic.max_keys`"))]
+    InvalidMaxKeys,
+
+    #[snafu(display(
+        "Exactly one value must be provided for either 'rate', 'ratio' or 'dynamic', but not more than one"
     ))]
     InvalidConfiguration,
 }
 
+/// Configuration for dynamic sampling.
+///
+/// Instead of applying a fixed rate, the sample rate of each bucket of events is adjusted at the end
+/// of each fixed window, from an exponential moving average of its event counts, so that each
+/// bucket forwards roughly `target_events_per_sec` events per second.
+/// Buckets which see fewer events than the target are not sampled at all, while noisy buckets are
+/// sampled down.
+#[serde_as]
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct DynamicSampleConfig {
+    /// The number of events per second to forward for each bucket of events.
+    ///
+    /// Buckets are determined by `group_by`. If `group_by` is unset, all events share a single
+    /// bucket.
+    #[configurable(metadata(docs::examples = 10.0))]
+    pub target_events_per_sec: f64,
+
+    /// The length of the window over which event counts are observed before the sample rate of
+    /// each bucket is adjusted, in seconds.
+    ///
+    /// Counts from previous windows are carried over with an exponential moving average, so
+    /// rates adjust smoothly when the volume of a bucket changes.
+    #[serde_as(as = "serde_with::DurationSecondsWithFrac<f64>")]
+    #[serde(default = "default_window_secs")]
+    #[configurable(metadata(docs::human_name = "Time Window"))]
+    pub window_secs: Duration,
+
+    /// The maximum number of buckets to track.
+    ///
+    /// Events belonging to new buckets once this limit has been reached share a single overflow
+    /// bucket until tracked buckets expire.
+    #[serde(default = "default_max_keys")]
+    #[configurable(metadata(docs::examples = 10000))]
+    pub max_keys: usize,
+}
+
+const fn default_window_secs() -> Duration {
+    Duration::from_secs(30)
+}
+
+const fn default_max_keys() -> usize {
+    10_000
+}
+
 /// Configuration for the `sample` transform.
 #[configurable_component(transform(
     "sample",
@@ -61,6 +121,15 @@ pub struct SampleConfig {
     #[configurable(validation(range(min = 0.0, max = 1.0)))]
     pub ratio: Option<f64>,
 
+    /// Adjusts the sample rate of each bucket of events to forward a target number of events per
+    /// second.
+    ///
+    /// The rate applied to each forwarded event is written to `sample_rate_key`, so downstream
+    /// components can re-weight sampled events. It is an error to provide a value for `dynamic`
+    /// together with `rate` or `ratio`.
+    #[configurable(derived)]
+    pub dynamic: Option<DynamicSampleConfig>,
+
     /// The name of the field whose value is hashed to determine if the event should be
     /// sampled.
     ///
@@ -96,15 +165,34 @@ pub struct SampleConfig {
 
 impl SampleConfig {
     fn sample_rate(&self) -> Result<SampleMode, SampleError> {
-        match (self.rate, self.ratio) {
-            (None, Some(ratio)) => {
+        match (self.rate, self.ratio, self.dynamic.as_ref()) {
+            (None, None, Some(dynamic)) => {
+                if dynamic.target_events_per_sec <= 0.0
+                    || !dynamic.target_events_per_sec.is_finite()
+                {
+                    Err(SampleError::InvalidTarget {
+                        target: dynamic.target_events_per_sec,
+                    })
+                } else if dynamic.window_secs.is_zero() {
+                    Err(SampleError::InvalidWindow)
+                } else if dynamic.max_keys == 0 {
+                    Err(SampleError::InvalidMaxKeys)
+                } else {
+                    Ok(SampleMode::new_dynamic(
+                        dynamic.target_events_per_sec,
+                        dynamic.window_secs,
+                        dynamic.max_keys,
+                    ))
+                }
+            }
+            (None, Some(ratio), None) => {
                 if ratio <= 0.0 {
                     Err(SampleError::InvalidRatio { ratio })
                 } else {
                     Ok(SampleMode::new_ratio(ratio))
                 }
             }
-            (Some(rate), None) => {
+            (Some(rate), None, None) => {
                 if rate == 0 {
                     Err(SampleError::InvalidRate)
                 } else {
@@ -121,6 +209,7 @@ impl GenerateConfig for SampleConfig {
         toml::Value::try_from(Self {
             rate: None,
             ratio: Some(0.1),
+            dynamic: None,
             key_field: None,
             group_by: None,
             exclude: None::<AnyCondition>,
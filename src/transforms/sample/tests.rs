@@ -1,3 +1,5 @@
+use std::time::{Duration, Instant};
+
 use approx::assert_relative_eq;
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::ReceiverStream;
@@ -6,15 +8,16 @@ use vrl::owned_value_path;
 
 use crate::{
     conditions::{Condition, ConditionalConfig, VrlConfig},
-    config::log_schema,
+    config::{TransformConfig, log_schema},
     event::{Event, LogEvent, TraceEvent},
+    schema,
     template::Template,
     test_util::{components::assert_transform_compliance, random_lines},
     transforms::{
         FunctionTransform, OutputBuffer,
         sample::{
             config::{SampleConfig, default_sample_rate_key},
-            transform::{Sample, SampleMode},
+            transform::{DynamicSampler, Sample, SampleMode},
         },
         test::{create_topology, transform_one},
     },
@@ -26,6 +29,7 @@ async fn emits_internal_events() {
         let config = SampleConfig {
             rate: None,
             ratio: Some(1.0),
+            dynamic: None,
             key_field: None,
             group_by: None,
             exclude: None,
@@ -71,7 +75,7 @@ fn hash_samples_at_roughly_the_configured_rate() {
         })
         .count();
     let actual = total_passed as f64 / num_events as f64;
-    assert_relative_eq!(sampler.ratio(), actual, epsilon = 0.03);
+    assert_relative_eq!(sampler.ratio(&None), actual, epsilon = 0.03);
 
     let events = random_events(num_events);
     let mut sampler = Sample::new(
@@ -94,7 +98,7 @@ fn hash_samples_at_roughly_the_configured_rate() {
         })
         .count();
     let actual = total_passed as f64 / num_events as f64;
-    assert_relative_eq!(sampler.ratio(), actual, epsilon = 0.03);
+    assert_relative_eq!(sampler.ratio(&None), actual, epsilon = 0.03);
 }
 
 #[test]
@@ -320,10 +324,159 @@ fn sample_at_rates_higher_then_half() {
                 buf.into_events().next()
             })
             .count();
-        assert_eq!(total_observed as f64, 10000.0 * sampler.ratio());
+        assert_eq!(total_observed as f64, 10000.0 * sampler.ratio(&None));
     }
 }
 
+#[test]
+fn dynamic_keeps_rare_keys_and_samples_noisy_keys() {
+    let start = Instant::now();
+    let window = Duration::from_secs(10);
+    // 1 event per second per key, i.e. 10 events per window.
+    let mut sampler = DynamicSampler::new(1.0, window, 100, start);
+
+    let rare = Some("rare".to_string());
+    let noisy = Some("noisy".to_string());
+    for window_index in 0..5 {
+        let now = start + window * window_index;
+        let rare_passed = (0..5)
+            .filter(|_| sampler.increment(&rare, &None, now))
+            .count();
+        let noisy_passed = (0..1000)
+            .filter(|_| sampler.increment(&noisy, &None, now))
+            .count();
+
+        assert_eq!(rare_passed, 5);
+        assert_eq!(sampler.current_rate(&rare), 1);
+        assert!(sampler.current_rate(&noisy) >= 50);
+        // The first window only raises the rate as the burst is observed, afterwards the rate
+        // derived from previous windows applies from the first event.
+        if window_index > 0 {
+            assert!(noisy_passed <= 20, "noisy key passed {noisy_passed} events");
+        }
+    }
+}
+
+#[test]
+fn dynamic_adjusts_rate_when_volume_drops() {
+    let start = Instant::now();
+    let window = Duration::from_secs(1);
+    let mut sampler = DynamicSampler::new(10.0, window, 100, start);
+    let key = Some("key".to_string());
+
+    for _ in 0..1000 {
+        sampler.increment(&key, &None, start);
+    }
+    assert_eq!(sampler.current_rate(&key), 100);
+
+    // After a few quiet windows the average decays and the key is no longer sampled.
+    let later = start + window * 10;
+    assert!(sampler.increment(&key, &None, later));
+    assert_eq!(sampler.current_rate(&key), 1);
+}
+
+#[test]
+fn dynamic_overflow_keys_share_a_bucket() {
+    let start = Instant::now();
+    let mut sampler = DynamicSampler::new(1.0, Duration::from_secs(1), 1, start);
+    let first = Some("first".to_string());
+
+    sampler.increment(&first, &None, start);
+    for i in 0..10 {
+        sampler.increment(&Some(format!("other-{i}")), &None, start);
+    }
+
+    assert_eq!(sampler.current_rate(&first), 1);
+    assert_eq!(sampler.current_rate(&Some("other-0".to_string())), 10);
+}
+
+#[test]
+fn dynamic_overflow_bucket_is_separate_from_ungrouped_events() {
+    let start = Instant::now();
+    let mut sampler = DynamicSampler::new(1.0, Duration::from_secs(1), 1, start);
+
+    sampler.increment(&None, &None, start);
+    for i in 0..10 {
+        sampler.increment(&Some(format!("other-{i}")), &None, start);
+    }
+
+    assert_eq!(sampler.current_rate(&None), 1);
+    assert_eq!(sampler.current_rate(&Some("other-0".to_string())), 10);
+}
+
+#[test]
+fn dynamic_reports_ratio_per_group() {
+    let mut sampler = Sample::new(
+        "sample".to_string(),
+        SampleMode::new_dynamic(1.0, Duration::from_secs(3600), 100),
+        None,
+        Some(Template::try_from("{{ other_field }}").unwrap()),
+        None,
+        default_sample_rate_key(),
+    );
+
+    let mut noisy = Event::Log(LogEvent::from("hello"));
+    noisy.as_mut_log().insert("other_field", "noisy");
+    let mut quiet = Event::Log(LogEvent::from("hello"));
+    quiet.as_mut_log().insert("other_field", "quiet");
+    for _ in 0..10_000 {
+        transform_one(&mut sampler, noisy.clone());
+    }
+    transform_one(&mut sampler, quiet);
+
+    assert!(sampler.ratio(&Some("noisy".to_string())) < 1.0);
+    assert_eq!(sampler.ratio(&Some("quiet".to_string())), 1.0);
+}
+
+#[test]
+fn dynamic_adds_applied_rate_to_event() {
+    let mut sampler = Sample::new(
+        "sample".to_string(),
+        SampleMode::new_dynamic(1.0, Duration::from_secs(3600), 100),
+        None,
+        Some(Template::try_from("{{ other_field }}").unwrap()),
+        None,
+        default_sample_rate_key(),
+    );
+
+    let mut event = Event::Log(LogEvent::from("hello"));
+    event.as_mut_log().insert("other_field", "foo");
+    let passing = (0..10_000)
+        .filter_map(|_| transform_one(&mut sampler, event.clone()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(passing[0].as_log()["sample_rate"], "1".into());
+    assert!(passing.len() < 10_000);
+    assert_ne!(passing.last().unwrap().as_log()["sample_rate"], "1".into());
+}
+
+#[test]
+fn dynamic_rejects_invalid_configuration() {
+    let config: SampleConfig = toml::from_str(
+        r#"
+        rate = 10
+        dynamic.target_events_per_sec = 10.0
+        "#,
+    )
+    .unwrap();
+    assert!(config.validate(&schema::Definition::any()).is_err());
+
+    let config: SampleConfig = toml::from_str("dynamic.target_events_per_sec = 0.0").unwrap();
+    assert!(config.validate(&schema::Definition::any()).is_err());
+
+    let config: SampleConfig = toml::from_str(
+        r#"
+        dynamic.target_events_per_sec = 5.0
+        dynamic.max_keys = 0
+        "#,
+    )
+    .unwrap();
+    assert!(config.validate(&schema::Definition::any()).is_err());
+
+    let config: SampleConfig = toml::from_str("dynamic.target_events_per_sec = 5.0").unwrap();
+    assert!(config.validate(&schema::Definition::any()).is_ok());
+}
+
 fn condition_contains(key: &str, needle: &str) -> Condition {
     let vrl_config = VrlConfig {
         source: format!(r#"contains!(."{key}", "{needle}")"#),
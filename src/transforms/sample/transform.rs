@@ -1,4 +1,8 @@
-use std::{borrow::Cow, collections::HashMap, fmt};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    time::{Duration, Instant},
+};
 
 use vector_lib::{
     config::LegacyKey,
@@ -28,6 +32,7 @@ pub enum SampleMode {
         values: HashMap<Option<String>, f64>,
         hash_ratio_threshold: u64,
     },
+    Dynamic(DynamicSampler),
 }
 
 impl SampleMode {
@@ -53,15 +58,32 @@ impl SampleMode {
         }
     }
 
+    pub fn new_dynamic(target_events_per_sec: f64, window: Duration, max_keys: usize) -> Self {
+        Self::Dynamic(DynamicSampler::new(
+            target_events_per_sec,
+            window,
+            max_keys,
+            Instant::now(),
+        ))
+    }
+
     fn increment(&mut self, group_by_key: &Option<String>, value: &Option<Cow<'_, str>>) -> bool {
-        let threshold_exceeded = match self {
+        let hash = value.as_ref().map(|value| seahash::hash(value.as_bytes()));
+        match self {
             Self::Rate { rate, counters } => {
                 let counter_value = counters.entry(group_by_key.clone()).or_default();
                 let old_counter_value = *counter_value;
                 *counter_value += 1;
-                old_counter_value % *rate == 0
+                match hash {
+                    Some(hash) => hash.is_multiple_of(*rate),
+                    None => old_counter_value % *rate == 0,
+                }
             }
-            Self::Ratio { ratio, values, .. } => {
+            Self::Ratio {
+                ratio,
+                values,
+                hash_ratio_threshold,
+            } => {
                 let value = values.entry(group_by_key.clone()).or_insert(1.0 - *ratio);
                 let increment: f64 = *value + *ratio;
                 *value = if increment >= 1.0 {
@@ -69,37 +91,168 @@ impl SampleMode {
                 } else {
                     increment
                 };
-                increment >= 1.0
+                match hash {
+                    Some(hash) => hash <= *hash_ratio_threshold,
+                    None => increment >= 1.0,
+                }
             }
-        };
-        if let Some(value) = value {
-            self.hash_within_ratio(value.as_bytes())
-        } else {
-            threshold_exceeded
+            Self::Dynamic(sampler) => sampler.increment(group_by_key, value, Instant::now()),
         }
     }
 
-    fn hash_within_ratio(&self, value: &[u8]) -> bool {
-        let hash = seahash::hash(value);
+    /// The rate that was applied to the last event of the given bucket, as written to
+    /// `sample_rate_key`.
+    ///
+    /// Avoids the print of an additional '.0' which was not performed in the previous
+    /// implementation.
+    fn applied_rate(&self, group_by_key: &Option<String>) -> String {
         match self {
-            Self::Rate { rate, .. } => hash.is_multiple_of(*rate),
-            Self::Ratio {
-                hash_ratio_threshold,
-                ..
-            } => hash <= *hash_ratio_threshold,
+            Self::Rate { rate, .. } => rate.to_string(),
+            Self::Ratio { ratio, .. } => ratio.to_string(),
+            Self::Dynamic(sampler) => sampler.current_rate(group_by_key).to_string(),
         }
     }
 }
 
-impl fmt::Display for SampleMode {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        // Avoids the print of an additional '.0' which was not performed in the previous
-        // implementation
-        match self {
-            Self::Rate { rate, .. } => write!(f, "{rate}"),
-            Self::Ratio { ratio, .. } => write!(f, "{ratio}"),
+/// Per-bucket state of the dynamic sampler.
+#[derive(Clone, Debug, Default)]
+struct DynamicBucket {
+    /// Number of events seen in the current window.
+    count: u64,
+    /// Exponential moving average of the number of events seen per window.
+    average: f64,
+    /// The rate derived from previous windows, expressed as `1/N`.
+    rate: u64,
+    /// The rate applied to the last event of this bucket.
+    current_rate: u64,
+}
+
+impl DynamicBucket {
+    fn new() -> Self {
+        Self {
+            rate: 1,
+            ..Default::default()
         }
     }
+
+    /// Carries the count of the last window over to the moving average, and derives the rate of
+    /// the next window from it. Returns whether the bucket is still active.
+    fn rotate(&mut self, decay: f64, target_per_window: f64) -> bool {
+        let average = if self.average == 0.0 {
+            self.count as f64
+        } else {
+            DynamicSampler::AVERAGE_WEIGHT * self.count as f64
+                + (1.0 - DynamicSampler::AVERAGE_WEIGHT) * self.average
+        } * decay;
+
+        self.average = average;
+        self.count = 0;
+        self.rate = ((average / target_per_window).ceil() as u64).max(1);
+        self.current_rate = self.rate;
+
+        // Buckets which have gone quiet are forgotten, making room for new ones.
+        average >= 1.0
+    }
+}
+
+/// Adjusts the sample rate of each bucket so that it forwards roughly a target number of events per
+/// second.
+///
+/// Event counts are observed over fixed windows and smoothed across windows with an exponential
+/// moving average. At the end of each window, the rate of a bucket for the next window is derived
+/// from its average count. Within a window, the rate is raised as soon as the observed count
+/// exceeds the budget, so bursts of a previously quiet bucket are sampled down without waiting for
+/// the window to end.
+#[derive(Clone, Debug)]
+pub struct DynamicSampler {
+    target_per_window: f64,
+    window: Duration,
+    window_start: Instant,
+    max_keys: usize,
+    buckets: HashMap<Option<String>, DynamicBucket>,
+    /// Bucket shared by the events of untracked buckets once `max_keys` buckets are tracked. It is
+    /// kept apart from the bucket of events without a group.
+    overflow: Option<DynamicBucket>,
+}
+
+impl DynamicSampler {
+    /// Weight given to the most recent window when updating the moving average.
+    const AVERAGE_WEIGHT: f64 = 0.5;
+
+    pub(super) fn new(
+        target_events_per_sec: f64,
+        window: Duration,
+        max_keys: usize,
+        now: Instant,
+    ) -> Self {
+        Self {
+            target_per_window: (target_events_per_sec * window.as_secs_f64()).max(1.0),
+            window,
+            window_start: now,
+            max_keys,
+            buckets: HashMap::default(),
+            overflow: None,
+        }
+    }
+
+    pub(super) fn increment(
+        &mut self,
+        group_by_key: &Option<String>,
+        value: &Option<Cow<'_, str>>,
+        now: Instant,
+    ) -> bool {
+        self.maybe_rotate(now);
+
+        let bucket =
+            if self.buckets.contains_key(group_by_key) || self.buckets.len() < self.max_keys {
+                self.buckets
+                    .entry(group_by_key.clone())
+                    .or_insert_with(DynamicBucket::new)
+            } else {
+                // Events of untracked buckets share the overflow bucket.
+                self.overflow.get_or_insert_with(DynamicBucket::new)
+            };
+        let sequence = bucket.count;
+        bucket.count += 1;
+
+        let burst_rate = (bucket.count as f64 / self.target_per_window).ceil() as u64;
+        let rate = bucket.rate.max(burst_rate).max(1);
+        bucket.current_rate = rate;
+
+        match value {
+            Some(value) => seahash::hash(value.as_bytes()).is_multiple_of(rate),
+            None => sequence.is_multiple_of(rate),
+        }
+    }
+
+    pub(super) fn current_rate(&self, group_by_key: &Option<String>) -> u64 {
+        self.buckets
+            .get(group_by_key)
+            .or(self.overflow.as_ref())
+            .map_or(1, |bucket| bucket.current_rate.max(1))
+    }
+
+    fn maybe_rotate(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.window_start);
+        if elapsed < self.window {
+            return;
+        }
+
+        // Windows without any events still decay the moving average.
+        let windows = (elapsed.as_secs_f64() / self.window.as_secs_f64()).floor() as i32;
+        let decay = (1.0 - Self::AVERAGE_WEIGHT).powi(windows - 1);
+        let target_per_window = self.target_per_window;
+
+        self.buckets
+            .retain(|_, bucket| bucket.rotate(decay, target_per_window));
+        if let Some(overflow) = &mut self.overflow
+            && !overflow.rotate(decay, target_per_window)
+        {
+            self.overflow = None;
+        }
+
+        self.window_start += self.window * windows as u32;
+    }
 }
 
 #[derive(Clone)]
@@ -134,11 +287,14 @@ impl Sample {
         }
     }
 
+    /// The ratio of events forwarded for the given bucket. Only dynamic sampling applies different
+    /// ratios to each bucket.
     #[cfg(test)]
-    pub fn ratio(&self) -> f64 {
+    pub fn ratio(&self, group_by_key: &Option<String>) -> f64 {
         match self.rate {
             SampleMode::Rate { rate, .. } => 1.0f64 / rate as f64,
             SampleMode::Ratio { ratio, .. } => ratio,
+            SampleMode::Dynamic(ref sampler) => 1.0f64 / sampler.current_rate(group_by_key) as f64,
         }
     }
 }
@@ -203,6 +359,7 @@ impl FunctionTransform for Sample {
         let should_sample = self.rate.increment(&group_by_key, &value);
         if should_sample {
             if let Some(path) = &self.sample_rate_key.path {
+                let rate = self.rate.applied_rate(&group_by_key);
                 match event {
                     Event::Log(ref mut event) => {
                         event.namespace().insert_source_metadata(
@@ -210,11 +367,11 @@ impl FunctionTransform for Sample {
                             event,
                             Some(LegacyKey::Overwrite(path)),
                             path,
-                            self.rate.to_string(),
+                            rate,
                         );
                     }
                     Event::Trace(ref mut event) => {
-                        event.insert(&OwnedTargetPath::event(path.clone()), self.rate.to_string());
+                        event.insert(&OwnedTargetPath::event(path.clone()), rate);
                     }
                     Event::Metric(_) => panic!("component can never receive metric events"),
                 };
@@ -0,0 +1,124 @@
+//! Helpers to read the spans of trace events.
+//!
+//! Trace events are produced in two shapes: the `opentelemetry` source emits one event per span,
+//! while the `datadog_agent` source emits one event per chunk of spans of the same trace, stored
+//! under `spans`. These helpers hide the differences between the two.
+
+use chrono::{DateTime, Utc};
+use vrl::value::{ObjectMap, Value};
+
+/// The OpenTelemetry status code of spans which ended with an error.
+pub(crate) const OTLP_STATUS_CODE_ERROR: i64 = 2;
+
+/// Returns the spans contained in a trace event.
+///
+/// Events from the `datadog_agent` source hold a chunk of spans under `spans`, while events from
+/// the `opentelemetry` source are a single span.
+pub(crate) fn spans(trace: &ObjectMap) -> Vec<&ObjectMap> {
+    match trace.get("spans").and_then(Value::as_array) {
+        Some(spans) => spans.iter().filter_map(Value::as_object).collect(),
+        None => vec![trace],
+    }
+}
+
+/// Returns the ID of the trace a trace event belongs to.
+#[cfg(feature = "transforms-tail_sample")]
+pub(crate) fn trace_id(trace: &ObjectMap) -> Option<String> {
+    trace
+        .get("trace_id")
+        .or_else(|| {
+            spans(trace)
+                .into_iter()
+                .find_map(|span| span.get("trace_id"))
+        })
+        .map(|trace_id| trace_id.to_string_lossy().into_owned())
+        .filter(|trace_id| !trace_id.is_empty())
+}
+
+/// Returns whether the span is the root span of its trace.
+#[cfg(feature = "transforms-tail_sample")]
+pub(crate) fn is_root_span(span: &ObjectMap) -> bool {
+    match span.get("parent_span_id") {
+        Some(Value::Bytes(parent_span_id)) => parent_span_id.is_empty(),
+        _ => matches!(span.get("parent_id"), Some(Value::Integer(0))),
+    }
+}
+
+/// Returns whether the span ended with an error.
+pub(crate) fn is_error_span(span: &ObjectMap) -> bool {
+    let otlp_error = span
+        .get("status")
+        .and_then(Value::as_object)
+        .and_then(|status| status.get("code"))
+        .and_then(Value::as_integer)
+        == Some(OTLP_STATUS_CODE_ERROR);
+    let datadog_error = span
+        .get("error")
+        .and_then(Value::as_integer)
+        .is_some_and(|error| error != 0);
+
+    otlp_error || datadog_error
+}
+
+/// Returns the name of the service which emitted the span.
+pub(crate) fn span_service(span: &ObjectMap) -> Option<String> {
+    span.get("resources")
+        .and_then(Value::as_object)
+        .and_then(|resources| resources.get("service.name"))
+        .or_else(|| span.get("service"))
+        .map(|service| service.to_string_lossy().into_owned())
+}
+
+/// Returns the start and end times of the span.
+pub(crate) fn span_time_range(span: &ObjectMap) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+    let otlp_start = span
+        .get("start_time_unix_nano")
+        .and_then(Value::as_timestamp);
+    let otlp_end = span.get("end_time_unix_nano").and_then(Value::as_timestamp);
+    if let (Some(start), Some(end)) = (otlp_start, otlp_end) {
+        return Some((*start, *end));
+    }
+
+    let start = span.get("start").and_then(Value::as_timestamp)?;
+    let duration = span.get("duration").and_then(Value::as_integer)?;
+    Some((*start, *start + chrono::Duration::nanoseconds(duration)))
+}
+
+/// Returns the name of the operation a span represents.
+#[cfg(feature = "transforms-trace_to_metric")]
+pub(crate) fn span_name(span: &ObjectMap) -> Option<String> {
+    span.get("name")
+        .map(|name| name.to_string_lossy().into_owned())
+}
+
+/// Returns the status of the span: `error`, `ok` or `unset`.
+///
+/// Datadog spans don't distinguish between an unset and an ok status, so they are reported as
+/// `ok` unless they ended with an error.
+#[cfg(feature = "transforms-trace_to_metric")]
+pub(crate) fn span_status(span: &ObjectMap) -> &'static str {
+    if is_error_span(span) {
+        return "error";
+    }
+
+    match span
+        .get("status")
+        .and_then(Value::as_object)
+        .and_then(|status| status.get("code"))
+        .and_then(Value::as_integer)
+    {
+        Some(0) => "unset",
+        _ => "ok",
+    }
+}
+
+/// Returns the value of a span attribute.
+///
+/// Attributes are looked up in the span attributes first, then in the resource attributes.
+#[cfg(feature = "transforms-trace_to_metric")]
+pub(crate) fn span_attribute<'a>(span: &'a ObjectMap, key: &str) -> Option<&'a Value> {
+    ["attributes", "meta", "metrics", "resources"]
+        .into_iter()
+        .filter_map(|field| span.get(field).and_then(Value::as_object))
+        .find_map(|attributes| attributes.get(key))
+}
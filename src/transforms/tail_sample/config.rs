@@ -0,0 +1,165 @@
+use std::{num::NonZeroUsize, time::Duration};
+
+use serde_with::serde_as;
+use vector_lib::{
+    config::{LogNamespace, clone_input_definitions},
+    configurable::configurable_component,
+};
+
+use super::{policy::PolicyConfig, transform::TailSample};
+use crate::{
+    config::{
+        DataType, GenerateConfig, Input, OutputId, TransformConfig, TransformContext,
+        TransformOutput,
+    },
+    schema,
+    transforms::Transform,
+};
+
+/// Configuration for the `tail_sample` transform.
+///
+/// Spans are expected in the shape produced by the `opentelemetry` source, with one span per
+/// event, or by the `datadog_agent` source, with a chunk of spans of the same trace stored under
+/// `spans`.
+#[serde_as]
+#[configurable_component(transform(
+    "tail_sample",
+    "Sample traces as a whole, after all of their spans have been received."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct TailSampleConfig {
+    /// The amount of time to wait for further spans of a trace after its root span has been
+    /// received, in seconds.
+    ///
+    /// Once a trace has been idle for this long, it is considered complete and the sampling
+    /// policies are applied to all of its spans.
+    #[serde_as(as = "serde_with::DurationSecondsWithFrac<f64>")]
+    #[serde(default = "default_decision_wait_secs")]
+    #[configurable(metadata(docs::human_name = "Decision Wait"))]
+    pub decision_wait_secs: Duration,
+
+    /// The maximum amount of time to buffer the spans of a trace, in seconds.
+    ///
+    /// Traces whose root span is never received, or which keep receiving spans, are decided once
+    /// they have been buffered for this long.
+    #[serde_as(as = "serde_with::DurationSecondsWithFrac<f64>")]
+    #[serde(default = "default_max_trace_duration_secs")]
+    #[configurable(metadata(docs::human_name = "Maximum Trace Duration"))]
+    pub max_trace_duration_secs: Duration,
+
+    /// The maximum number of traces to buffer at once.
+    ///
+    /// When this limit is reached, the oldest buffered trace is decided early to make room for new
+    /// traces.
+    #[serde(default = "default_max_traces")]
+    #[configurable(metadata(docs::examples = 50000))]
+    pub max_traces: NonZeroUsize,
+
+    /// The number of sampling decisions to remember after a trace has been decided.
+    ///
+    /// Spans arriving after the decision for their trace has been made are forwarded or dropped
+    /// according to the cached decision.
+    #[serde(default = "default_decision_cache_size")]
+    #[configurable(metadata(docs::examples = 100000))]
+    pub decision_cache_size: NonZeroUsize,
+
+    /// The sampling policies to apply to complete traces.
+    ///
+    /// A trace is kept if at least one of the policies decides to keep it, in which case all of its
+    /// spans are forwarded. Otherwise, all of its spans are dropped.
+    pub policies: Vec<PolicyConfig>,
+}
+
+const fn default_decision_wait_secs() -> Duration {
+    Duration::from_secs(5)
+}
+
+const fn default_max_trace_duration_secs() -> Duration {
+    Duration::from_secs(30)
+}
+
+const fn default_max_traces() -> NonZeroUsize {
+    NonZeroUsize::new(50_000).expect("static non-zero number")
+}
+
+const fn default_decision_cache_size() -> NonZeroUsize {
+    NonZeroUsize::new(100_000).expect("static non-zero number")
+}
+
+impl GenerateConfig for TailSampleConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(indoc::indoc! {r#"
+            [[policies]]
+            type = "errors"
+
+            [[policies]]
+            type = "latency"
+            threshold_ms = 500
+
+            [[policies]]
+            type = "probabilistic"
+            ratio = 0.1
+        "#})
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "tail_sample")]
+impl TransformConfig for TailSampleConfig {
+    async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
+        if self.policies.is_empty() {
+            return Err("At least one sampling policy must be configured.".into());
+        }
+
+        let policies = self
+            .policies
+            .iter()
+            .map(|policy| policy.build(context))
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        Ok(Transform::event_task(TailSample::new(self, policies)))
+    }
+
+    fn input(&self) -> Input {
+        Input::trace()
+    }
+
+    fn validate(&self, _: &schema::Definition) -> Result<(), Vec<String>> {
+        let errors = self
+            .policies
+            .iter()
+            .filter_map(|policy| policy.validate().err())
+            .collect::<Vec<_>>();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn outputs(
+        &self,
+        _: vector_lib::enrichment::TableRegistry,
+        input_definitions: &[(OutputId, schema::Definition)],
+        _: LogNamespace,
+    ) -> Vec<TransformOutput> {
+        // Spans are forwarded or dropped as-is, so the definition is passed through unchanged.
+        vec![TransformOutput::new(
+            DataType::Trace,
+            clone_input_definitions(input_definitions),
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TailSampleConfig;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<TailSampleConfig>();
+    }
+}
@@ -0,0 +1,262 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use vector_lib::configurable::configurable_component;
+
+use crate::{
+    conditions::{AnyCondition, Condition},
+    config::TransformContext,
+    event::Event,
+};
+
+/// A sampling policy applied to complete traces.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+#[configurable(metadata(docs::enum_tag_description = "The type of sampling policy."))]
+pub enum PolicyConfig {
+    /// Keep traces containing at least one span with an error status.
+    Errors,
+
+    /// Keep traces whose duration exceeds a threshold.
+    ///
+    /// The duration of a trace is the time between the earliest start and the latest end of its
+    /// spans.
+    Latency(LatencyPolicyConfig),
+
+    /// Keep a fixed proportion of traces.
+    ///
+    /// The decision is based on a hash of the trace ID, so separate instances of this transform
+    /// make the same decision for the same trace.
+    Probabilistic(ProbabilisticPolicyConfig),
+
+    /// Keep up to a fixed number of traces per second for each service.
+    ///
+    /// The service of a trace is taken from its root span, or from its first span if the root span
+    /// has not been received.
+    ServiceRate(ServiceRatePolicyConfig),
+
+    /// Keep traces containing at least one span matching a condition.
+    Condition(ConditionPolicyConfig),
+}
+
+/// Configuration for the `latency` sampling policy.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct LatencyPolicyConfig {
+    /// The duration above which traces are kept, in milliseconds.
+    #[configurable(metadata(docs::examples = 500))]
+    #[configurable(metadata(docs::human_name = "Latency Threshold"))]
+    pub threshold_ms: u64,
+}
+
+/// Configuration for the `probabilistic` sampling policy.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ProbabilisticPolicyConfig {
+    /// The proportion of traces to keep, between 0 and 1.
+    #[configurable(metadata(docs::examples = 0.1))]
+    #[configurable(validation(range(min = 0.0, max = 1.0)))]
+    pub ratio: f64,
+}
+
+/// Configuration for the `service_rate` sampling policy.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ServiceRatePolicyConfig {
+    /// The number of traces to keep per second for each service.
+    #[configurable(metadata(docs::examples = 10.0))]
+    pub traces_per_sec: f64,
+
+    /// Overrides of `traces_per_sec` for specific services.
+    #[serde(default)]
+    #[configurable(metadata(
+        docs::additional_props_description = "The number of traces to keep per second for the service."
+    ))]
+    #[configurable(metadata(docs::examples = "example_service_overrides()"))]
+    pub overrides: HashMap<String, f64>,
+}
+
+fn example_service_overrides() -> HashMap<String, f64> {
+    HashMap::from([("checkout".to_string(), 100.0)])
+}
+
+/// Configuration for the `condition` sampling policy.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ConditionPolicyConfig {
+    /// The condition to match against each span of the trace.
+    pub condition: AnyCondition,
+}
+
+impl PolicyConfig {
+    pub(super) fn validate(&self) -> Result<(), String> {
+        match self {
+            Self::Probabilistic(config) if !(0.0..=1.0).contains(&config.ratio) => Err(format!(
+                "The `ratio` of a `probabilistic` policy must be between 0 and 1, value: {}",
+                config.ratio
+            )),
+            Self::ServiceRate(config)
+                if std::iter::once(&config.traces_per_sec)
+                    .chain(config.overrides.values())
+                    .any(|rate| *rate < 0.0 || !rate.is_finite()) =>
+            {
+                Err(
+                    "The rates of a `service_rate` policy must be positive, finite numbers."
+                        .to_string(),
+                )
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub(super) fn build(&self, context: &TransformContext) -> crate::Result<Policy> {
+        self.validate()?;
+
+        Ok(match self {
+            Self::Errors => Policy::Errors,
+            Self::Latency(config) => Policy::Latency {
+                threshold: Duration::from_millis(config.threshold_ms),
+            },
+            Self::Probabilistic(config) => Policy::Probabilistic {
+                hash_threshold: (config.ratio * (u64::MAX as u128) as f64) as u64,
+            },
+            Self::ServiceRate(config) => Policy::ServiceRate {
+                traces_per_sec: config.traces_per_sec,
+                overrides: config.overrides.clone(),
+                buckets: HashMap::default(),
+            },
+            Self::Condition(config) => {
+                Policy::Condition(config.condition.build(&context.enrichment_tables)?)
+            }
+        })
+    }
+}
+
+/// The properties of a complete trace that policies make their decision on.
+#[derive(Debug)]
+pub(super) struct TraceSummary<'a> {
+    pub trace_id: &'a str,
+    pub has_error: bool,
+    pub duration: Option<Duration>,
+    pub service: Option<&'a str>,
+}
+
+/// Token bucket limiting the number of traces kept per second for a service.
+#[derive(Debug)]
+pub struct RateBucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateBucket {
+    fn refill(&mut self, rate: f64, capacity: f64, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        self.tokens = (self.tokens + elapsed.as_secs_f64() * rate).min(capacity);
+        self.last_refill = now;
+    }
+}
+
+#[derive(Debug)]
+pub enum Policy {
+    Errors,
+    Latency {
+        threshold: Duration,
+    },
+    Probabilistic {
+        hash_threshold: u64,
+    },
+    ServiceRate {
+        traces_per_sec: f64,
+        overrides: HashMap<String, f64>,
+        buckets: HashMap<String, RateBucket>,
+    },
+    Condition(Condition),
+}
+
+impl Policy {
+    /// Forgets the state kept for services which have not had a trace decided recently, so that it
+    /// doesn't grow with the number of services seen over time.
+    pub(super) fn prune(&mut self, now: Instant) {
+        if let Self::ServiceRate {
+            traces_per_sec,
+            overrides,
+            buckets,
+        } = self
+        {
+            buckets.retain(|service, bucket| {
+                let rate = overrides.get(service).copied().unwrap_or(*traces_per_sec);
+                let capacity = rate.max(1.0);
+                bucket.refill(rate, capacity, now);
+                // A full bucket behaves the same as a new one.
+                bucket.tokens < capacity
+            });
+        }
+    }
+
+    pub(super) fn should_keep(
+        &mut self,
+        summary: &TraceSummary<'_>,
+        events: &mut Vec<Event>,
+        now: Instant,
+    ) -> bool {
+        match self {
+            Self::Errors => summary.has_error,
+            Self::Latency { threshold } => summary
+                .duration
+                .is_some_and(|duration| duration > *threshold),
+            Self::Probabilistic { hash_threshold } => {
+                seahash::hash(summary.trace_id.as_bytes()) <= *hash_threshold
+            }
+            Self::ServiceRate {
+                traces_per_sec,
+                overrides,
+                buckets,
+            } => {
+                let service = summary.service.unwrap_or_default();
+                let rate = overrides.get(service).copied().unwrap_or(*traces_per_sec);
+                if rate <= 0.0 {
+                    return false;
+                }
+                // Allow bursts of up to one second worth of traces.
+                let capacity = rate.max(1.0);
+
+                let bucket = buckets
+                    .entry(service.to_string())
+                    .or_insert_with(|| RateBucket {
+                        tokens: capacity,
+                        last_refill: now,
+                    });
+                bucket.refill(rate, capacity, now);
+
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    true
+                } else {
+                    false
+                }
+            }
+            Self::Condition(condition) => {
+                let mut matched = false;
+                *events = std::mem::take(events)
+                    .into_iter()
+                    .map(|event| {
+                        if matched {
+                            return event;
+                        }
+                        let (result, event) = condition.check(event);
+                        matched = result;
+                        event
+                    })
+                    .collect();
+                matched
+            }
+        }
+    }
+}
@@ -0,0 +1,493 @@
+use std::{
+    num::NonZeroUsize,
+    pin::Pin,
+    time::{Duration, Instant},
+};
+
+use chrono::{DateTime, Utc};
+use futures::Stream;
+use indexmap::IndexMap;
+use lru::LruCache;
+use vector_lib::stream::expiration_map::{Emitter, map_with_expiration};
+
+use super::{
+    config::TailSampleConfig,
+    policy::{Policy, TraceSummary},
+};
+use crate::{
+    event::Event,
+    internal_events::{TailSampleSpansDiscarded, TailSampleTraceEvicted},
+    transforms::{
+        TaskTransform,
+        spans::{is_error_span, is_root_span, span_service, span_time_range, spans, trace_id},
+    },
+};
+
+/// Spans of a trace which has not been decided yet.
+#[derive(Debug)]
+struct TraceState {
+    events: Vec<Event>,
+    root_received: bool,
+    has_error: bool,
+    service: Option<String>,
+    start: Option<DateTime<Utc>>,
+    end: Option<DateTime<Utc>>,
+    created: Instant,
+    last_updated: Instant,
+}
+
+impl TraceState {
+    fn new(now: Instant) -> Self {
+        Self {
+            events: Vec::new(),
+            root_received: false,
+            has_error: false,
+            service: None,
+            start: None,
+            end: None,
+            created: now,
+            last_updated: now,
+        }
+    }
+
+    fn add_event(&mut self, event: Event, now: Instant) {
+        let Event::Trace(trace) = &event else {
+            unreachable!("component can only receive trace events")
+        };
+
+        for span in spans(trace.as_map()) {
+            let is_root = is_root_span(span);
+            if is_root || self.service.is_none() {
+                self.service = span_service(span).or(self.service.take());
+            }
+            self.root_received |= is_root;
+            self.has_error |= is_error_span(span);
+
+            if let Some((start, end)) = span_time_range(span) {
+                self.start = Some(self.start.map_or(start, |current| current.min(start)));
+                self.end = Some(self.end.map_or(end, |current| current.max(end)));
+            }
+        }
+
+        self.events.push(event);
+        self.last_updated = now;
+    }
+
+    fn duration(&self) -> Option<Duration> {
+        match (self.start, self.end) {
+            (Some(start), Some(end)) => (end - start).to_std().ok(),
+            _ => None,
+        }
+    }
+}
+
+pub struct TailSample {
+    decision_wait: Duration,
+    max_trace_duration: Duration,
+    max_traces: NonZeroUsize,
+    policies: Vec<Policy>,
+    traces: IndexMap<String, TraceState>,
+    decisions: LruCache<String, bool>,
+}
+
+impl TailSample {
+    pub fn new(config: &TailSampleConfig, policies: Vec<Policy>) -> Self {
+        Self {
+            decision_wait: config.decision_wait_secs,
+            max_trace_duration: config.max_trace_duration_secs,
+            max_traces: config.max_traces,
+            policies,
+            traces: IndexMap::new(),
+            decisions: LruCache::new(config.decision_cache_size),
+        }
+    }
+
+    fn flush_period(&self) -> Duration {
+        self.decision_wait
+            .min(Duration::from_secs(1))
+            .max(Duration::from_millis(100))
+    }
+
+    fn transform_one(&mut self, emitter: &mut Emitter<Event>, event: Event, now: Instant) {
+        let Some(trace_id) = trace_id(event.as_trace().as_map()) else {
+            // Spans without a trace ID can't be grouped, so they are forwarded as-is.
+            emitter.emit(event);
+            return;
+        };
+
+        if let Some(keep) = self.decisions.get(&trace_id) {
+            if *keep {
+                emitter.emit(event);
+            } else {
+                emit!(TailSampleSpansDiscarded { count: 1 });
+            }
+            return;
+        }
+
+        self.traces
+            .entry(trace_id)
+            .or_insert_with(|| TraceState::new(now))
+            .add_event(event, now);
+
+        while self.traces.len() > self.max_traces.get() {
+            if let Some((trace_id, state)) = self.traces.shift_remove_index(0) {
+                emit!(TailSampleTraceEvicted);
+                self.decide(emitter, trace_id, state, now);
+            }
+        }
+    }
+
+    fn flush_into(&mut self, emitter: &mut Emitter<Event>, now: Instant) {
+        let (decision_wait, max_trace_duration) = (self.decision_wait, self.max_trace_duration);
+        let complete = self
+            .traces
+            .extract_if(.., |_, state| {
+                let idle = now.saturating_duration_since(state.last_updated);
+                let age = now.saturating_duration_since(state.created);
+                (state.root_received && idle >= decision_wait) || age >= max_trace_duration
+            })
+            .collect::<Vec<_>>();
+        for (trace_id, state) in complete {
+            self.decide(emitter, trace_id, state, now);
+        }
+
+        for policy in &mut self.policies {
+            policy.prune(now);
+        }
+    }
+
+    fn flush_all_into(&mut self, emitter: &mut Emitter<Event>) {
+        let now = Instant::now();
+        let traces = self.traces.drain(..).collect::<Vec<_>>();
+        for (trace_id, state) in traces {
+            self.decide(emitter, trace_id, state, now);
+        }
+    }
+
+    fn decide(
+        &mut self,
+        emitter: &mut Emitter<Event>,
+        trace_id: String,
+        mut state: TraceState,
+        now: Instant,
+    ) {
+        let summary = TraceSummary {
+            trace_id: &trace_id,
+            has_error: state.has_error,
+            duration: state.duration(),
+            service: state.service.as_deref(),
+        };
+
+        let keep = self
+            .policies
+            .iter_mut()
+            .any(|policy| policy.should_keep(&summary, &mut state.events, now));
+
+        if keep {
+            state
+                .events
+                .into_iter()
+                .for_each(|event| emitter.emit(event));
+        } else {
+            emit!(TailSampleSpansDiscarded {
+                count: state.events.len()
+            });
+        }
+
+        self.decisions.put(trace_id, keep);
+    }
+}
+
+impl TaskTransform<Event> for TailSample {
+    fn transform(
+        self: Box<Self>,
+        input_rx: Pin<Box<dyn Stream<Item = Event> + Send>>,
+    ) -> Pin<Box<dyn Stream<Item = Event> + Send>>
+    where
+        Self: 'static,
+    {
+        let flush_period = self.flush_period();
+        Box::pin(map_with_expiration(
+            self,
+            input_rx,
+            flush_period,
+            |me: &mut Box<TailSample>, event, emitter: &mut Emitter<Event>| {
+                me.transform_one(emitter, event, Instant::now());
+            },
+            |me: &mut Box<TailSample>, emitter: &mut Emitter<Event>| {
+                me.flush_into(emitter, Instant::now());
+            },
+            |me: &mut Box<TailSample>, emitter: &mut Emitter<Event>| {
+                me.flush_all_into(emitter);
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::ReceiverStream;
+    use vector_lib::event::TraceEvent;
+    use vrl::btreemap;
+
+    use super::*;
+    use crate::{
+        config::TransformContext,
+        event::Value,
+        test_util::components::assert_transform_compliance,
+        transforms::{spans::OTLP_STATUS_CODE_ERROR, test::create_topology},
+    };
+
+    fn otlp_span(trace_id: &str, span_id: &str, parent: &str, error: bool, millis: i64) -> Event {
+        let start = Utc.timestamp_nanos(0);
+        Event::Trace(TraceEvent::from(btreemap! {
+            "trace_id" => trace_id,
+            "span_id" => span_id,
+            "parent_span_id" => parent,
+            "start_time_unix_nano" => start,
+            "end_time_unix_nano" => start + chrono::Duration::milliseconds(millis),
+            "status" => btreemap! {
+                "code" => if error { OTLP_STATUS_CODE_ERROR } else { 0 },
+                "message" => "",
+            },
+            "resources" => btreemap! {
+                "service.name" => "checkout",
+            },
+        }))
+    }
+
+    fn datadog_chunk(trace_id: i64, error: i64) -> Event {
+        Event::Trace(TraceEvent::from(btreemap! {
+            "spans" => vec![
+                Value::from(btreemap! {
+                    "trace_id" => trace_id,
+                    "span_id" => 1,
+                    "parent_id" => 0,
+                    "service" => "web",
+                    "error" => error,
+                    "start" => Utc.timestamp_nanos(0),
+                    "duration" => 1_000_000,
+                }),
+            ],
+        }))
+    }
+
+    fn tail_sample(config: &str) -> TailSample {
+        let config = toml::from_str::<TailSampleConfig>(config).unwrap();
+        let policies = config
+            .policies
+            .iter()
+            .map(|policy| policy.build(&TransformContext::default()).unwrap())
+            .collect();
+        TailSample::new(&config, policies)
+    }
+
+    fn trace_ids(emitter: Emitter<Event>) -> Vec<String> {
+        emitter
+            .into_values()
+            .into_iter()
+            .map(|event| trace_id(event.as_trace().as_map()).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn keeps_whole_traces_with_errors() {
+        let mut sample = tail_sample(
+            r#"
+            decision_wait_secs = 1
+            policies = [{ type = "errors" }]
+            "#,
+        );
+        let now = Instant::now();
+        let mut emitter = Emitter::new();
+
+        sample.transform_one(&mut emitter, otlp_span("a", "1", "", false, 10), now);
+        sample.transform_one(&mut emitter, otlp_span("a", "2", "1", true, 5), now);
+        sample.transform_one(&mut emitter, otlp_span("b", "1", "", false, 10), now);
+        sample.transform_one(&mut emitter, otlp_span("b", "2", "1", false, 5), now);
+        sample.flush_into(&mut emitter, now);
+        assert!(emitter.into_values().is_empty());
+
+        let mut emitter = Emitter::new();
+        sample.flush_into(&mut emitter, now + Duration::from_secs(1));
+        assert_eq!(trace_ids(emitter), vec!["a", "a"]);
+        assert!(sample.traces.is_empty());
+    }
+
+    #[test]
+    fn waits_for_root_span() {
+        let mut sample = tail_sample(
+            r#"
+            decision_wait_secs = 1
+            max_trace_duration_secs = 10
+            policies = [{ type = "latency", threshold_ms = 50 }]
+            "#,
+        );
+        let now = Instant::now();
+        let mut emitter = Emitter::new();
+
+        sample.transform_one(&mut emitter, otlp_span("a", "2", "1", false, 100), now);
+        sample.flush_into(&mut emitter, now + Duration::from_secs(5));
+        assert!(emitter.into_values().is_empty());
+
+        let mut emitter = Emitter::new();
+        sample.flush_into(&mut emitter, now + Duration::from_secs(10));
+        assert_eq!(trace_ids(emitter), vec!["a"]);
+    }
+
+    #[test]
+    fn late_spans_use_cached_decision() {
+        let mut sample = tail_sample(
+            r#"
+            decision_wait_secs = 1
+            policies = [{ type = "errors" }]
+            "#,
+        );
+        let now = Instant::now();
+        let mut emitter = Emitter::new();
+
+        sample.transform_one(&mut emitter, otlp_span("a", "1", "", true, 10), now);
+        sample.transform_one(&mut emitter, otlp_span("b", "1", "", false, 10), now);
+        sample.flush_into(&mut emitter, now + Duration::from_secs(1));
+        assert_eq!(emitter.into_values().len(), 1);
+
+        let mut emitter = Emitter::new();
+        let later = now + Duration::from_secs(2);
+        sample.transform_one(&mut emitter, otlp_span("a", "2", "1", false, 5), later);
+        sample.transform_one(&mut emitter, otlp_span("b", "2", "1", true, 5), later);
+        assert_eq!(trace_ids(emitter), vec!["a"]);
+        assert!(sample.traces.is_empty());
+    }
+
+    #[test]
+    fn evicts_oldest_trace_when_full() {
+        let mut sample = tail_sample(
+            r#"
+            max_traces = 2
+            policies = [{ type = "probabilistic", ratio = 1.0 }]
+            "#,
+        );
+        let now = Instant::now();
+        let mut emitter = Emitter::new();
+
+        sample.transform_one(&mut emitter, otlp_span("a", "1", "", false, 10), now);
+        sample.transform_one(&mut emitter, otlp_span("b", "1", "", false, 10), now);
+        sample.transform_one(&mut emitter, otlp_span("c", "1", "", false, 10), now);
+
+        assert_eq!(trace_ids(emitter), vec!["a"]);
+        assert_eq!(sample.traces.len(), 2);
+    }
+
+    #[test]
+    fn service_rate_limits_traces_per_service() {
+        let mut sample = tail_sample(
+            r#"
+            decision_wait_secs = 1
+            policies = [{ type = "service_rate", traces_per_sec = 2.0 }]
+            "#,
+        );
+        let now = Instant::now();
+        let mut emitter = Emitter::new();
+
+        for trace_id in ["a", "b", "c", "d"] {
+            sample.transform_one(&mut emitter, otlp_span(trace_id, "1", "", false, 10), now);
+        }
+        sample.flush_into(&mut emitter, now + Duration::from_secs(1));
+
+        assert_eq!(emitter.into_values().len(), 2);
+    }
+
+    #[test]
+    fn service_rate_of_zero_drops_all_traces() {
+        let mut sample = tail_sample(
+            r#"
+            decision_wait_secs = 1
+            policies = [{ type = "service_rate", traces_per_sec = 0.0 }]
+            "#,
+        );
+        let now = Instant::now();
+        let mut emitter = Emitter::new();
+
+        for trace_id in ["a", "b"] {
+            sample.transform_one(&mut emitter, otlp_span(trace_id, "1", "", false, 10), now);
+        }
+        sample.flush_into(&mut emitter, now + Duration::from_secs(1));
+
+        assert!(emitter.into_values().is_empty());
+    }
+
+    #[test]
+    fn service_rate_forgets_idle_services() {
+        let mut sample = tail_sample(
+            r#"
+            decision_wait_secs = 1
+            policies = [{ type = "service_rate", traces_per_sec = 1.0 }]
+            "#,
+        );
+        let now = Instant::now();
+        let mut emitter = Emitter::new();
+
+        sample.transform_one(&mut emitter, otlp_span("a", "1", "", false, 10), now);
+        sample.flush_into(&mut emitter, now + Duration::from_secs(1));
+        let Policy::ServiceRate { buckets, .. } = &sample.policies[0] else {
+            unreachable!("policy is a service_rate policy");
+        };
+        assert_eq!(buckets.len(), 1);
+
+        sample.flush_into(&mut emitter, now + Duration::from_secs(3));
+        let Policy::ServiceRate { buckets, .. } = &sample.policies[0] else {
+            unreachable!("policy is a service_rate policy");
+        };
+        assert!(buckets.is_empty());
+    }
+
+    #[test]
+    fn handles_datadog_chunks() {
+        let mut sample = tail_sample(
+            r#"
+            decision_wait_secs = 1
+            policies = [{ type = "errors" }]
+            "#,
+        );
+        let now = Instant::now();
+        let mut emitter = Emitter::new();
+
+        sample.transform_one(&mut emitter, datadog_chunk(1, 1), now);
+        sample.transform_one(&mut emitter, datadog_chunk(2, 0), now);
+        sample.flush_into(&mut emitter, now + Duration::from_secs(1));
+
+        assert_eq!(trace_ids(emitter), vec!["1"]);
+    }
+
+    #[tokio::test]
+    async fn emits_sampled_traces_on_shutdown() {
+        assert_transform_compliance(async {
+            let config = toml::from_str::<TailSampleConfig>(
+                r#"
+                policies = [{ type = "condition", condition = '.status.code == 2' }]
+                "#,
+            )
+            .unwrap();
+            let (tx, rx) = mpsc::channel(4);
+            let (topology, mut out) = create_topology(ReceiverStream::new(rx), config).await;
+
+            tx.send(otlp_span("a", "1", "", false, 10)).await.unwrap();
+            tx.send(otlp_span("a", "2", "1", true, 10)).await.unwrap();
+            tx.send(otlp_span("b", "1", "", false, 10)).await.unwrap();
+            drop(tx);
+
+            let mut received = Vec::new();
+            for _ in 0..2 {
+                let event = out.recv().await.unwrap();
+                received.push(trace_id(event.as_trace().as_map()).unwrap());
+            }
+            assert_eq!(received, vec!["a", "a"]);
+
+            topology.stop().await;
+            assert_eq!(out.recv().await, None);
+        })
+        .await
+    }
+}
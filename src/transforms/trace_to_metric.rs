@@ -0,0 +1,348 @@
+use std::collections::HashMap;
+
+use vector_lib::{config::LogNamespace, configurable::configurable_component};
+use vrl::value::ObjectMap;
+
+use crate::{
+    config::{
+        DataType, GenerateConfig, Input, OutputId, TransformConfig, TransformContext,
+        TransformOutput,
+    },
+    event::{
+        Event, EventMetadata,
+        metric::{Metric, MetricKind, MetricTags, MetricValue, StatisticKind},
+    },
+    schema,
+    transforms::{
+        FunctionTransform, OutputBuffer, Transform,
+        spans::{
+            is_error_span, span_attribute, span_name, span_service, span_status, span_time_range,
+            spans,
+        },
+    },
+};
+
+/// Configuration for the `trace_to_metric` transform.
+///
+/// Derives request, error and duration (RED) metrics from the spans of trace events.
+#[configurable_component(transform(
+    "trace_to_metric",
+    "Derive request, error and duration metrics from trace spans."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct TraceToMetricConfig {
+    /// The namespace of the generated metrics.
+    #[serde(default = "default_namespace")]
+    #[configurable(metadata(docs::examples = "span"))]
+    pub namespace: String,
+
+    /// Span attributes to add as tags to the generated metrics, in addition to `service`,
+    /// `operation` and `status`.
+    ///
+    /// Attributes are looked up in the span attributes first, then in the resource attributes.
+    /// Spans without the attribute don't get the tag. Each distinct combination of tag values
+    /// produces a separate metric series, so attributes with a high number of distinct values
+    /// should be avoided.
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = "http.route"))]
+    #[configurable(metadata(docs::examples = "deployment.environment"))]
+    pub dimensions: Vec<String>,
+}
+
+fn default_namespace() -> String {
+    "span".to_string()
+}
+
+impl GenerateConfig for TraceToMetricConfig {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(Self {
+            namespace: default_namespace(),
+            dimensions: vec!["http.route".to_string()],
+        })
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "trace_to_metric")]
+impl TransformConfig for TraceToMetricConfig {
+    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
+        Ok(Transform::function(TraceToMetric {
+            namespace: self.namespace.clone(),
+            dimensions: self.dimensions.clone(),
+        }))
+    }
+
+    fn input(&self) -> Input {
+        Input::trace()
+    }
+
+    fn outputs(
+        &self,
+        _: vector_lib::enrichment::TableRegistry,
+        _: &[(OutputId, schema::Definition)],
+        _: LogNamespace,
+    ) -> Vec<TransformOutput> {
+        // Converting the trace to metrics means we lose all incoming `Definition`s.
+        vec![TransformOutput::new(DataType::Metric, HashMap::new())]
+    }
+
+    fn enable_concurrency(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TraceToMetric {
+    namespace: String,
+    dimensions: Vec<String>,
+}
+
+impl TraceToMetric {
+    fn span_tags(&self, span: &ObjectMap) -> MetricTags {
+        let mut tags = MetricTags::default();
+        if let Some(service) = span_service(span) {
+            tags.replace("service".to_string(), service);
+        }
+        if let Some(operation) = span_name(span) {
+            tags.replace("operation".to_string(), operation);
+        }
+        tags.replace("status".to_string(), span_status(span).to_string());
+
+        for dimension in &self.dimensions {
+            if let Some(value) = span_attribute(span, dimension) {
+                tags.replace(dimension.clone(), value.to_string_lossy().into_owned());
+            }
+        }
+        tags
+    }
+
+    fn counter(&self, name: &str, tags: &MetricTags, metadata: &EventMetadata) -> Metric {
+        Metric::new_with_metadata(
+            name,
+            MetricKind::Incremental,
+            MetricValue::Counter { value: 1.0 },
+            metadata.clone(),
+        )
+        .with_namespace(Some(self.namespace.clone()))
+        .with_tags(Some(tags.clone()))
+    }
+}
+
+impl FunctionTransform for TraceToMetric {
+    fn transform(&mut self, output: &mut OutputBuffer, event: Event) {
+        let Event::Trace(trace) = event else {
+            return;
+        };
+
+        for span in spans(trace.as_map()) {
+            let tags = self.span_tags(span);
+            let time_range = span_time_range(span);
+            let timestamp = time_range.map(|(_, end)| end);
+
+            output.push(
+                self.counter("requests_total", &tags, trace.metadata())
+                    .with_timestamp(timestamp)
+                    .into(),
+            );
+
+            if is_error_span(span) {
+                output.push(
+                    self.counter("errors_total", &tags, trace.metadata())
+                        .with_timestamp(timestamp)
+                        .into(),
+                );
+            }
+
+            if let Some((start, end)) = time_range {
+                let duration = (end - start)
+                    .to_std()
+                    .map(|duration| duration.as_secs_f64())
+                    .unwrap_or_default();
+                output.push(
+                    Metric::new_with_metadata(
+                        "duration_seconds",
+                        MetricKind::Incremental,
+                        MetricValue::Distribution {
+                            samples: vector_lib::samples![duration => 1],
+                            statistic: StatisticKind::Histogram,
+                        },
+                        trace.metadata().clone(),
+                    )
+                    .with_namespace(Some(self.namespace.clone()))
+                    .with_tags(Some(tags))
+                    .with_timestamp(timestamp)
+                    .into(),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::ReceiverStream;
+    use vector_lib::{event::TraceEvent, metric_tags};
+    use vrl::btreemap;
+
+    use super::*;
+    use crate::{
+        event::Value,
+        test_util::components::assert_transform_compliance,
+        transforms::{spans::OTLP_STATUS_CODE_ERROR, test::create_topology},
+    };
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<TraceToMetricConfig>();
+    }
+
+    fn otlp_span(error: bool) -> TraceEvent {
+        let start = Utc.timestamp_nanos(0);
+        TraceEvent::from(btreemap! {
+            "trace_id" => "abc",
+            "span_id" => "1",
+            "parent_span_id" => "",
+            "name" => "GET /users",
+            "start_time_unix_nano" => start,
+            "end_time_unix_nano" => start + chrono::Duration::milliseconds(250),
+            "status" => btreemap! {
+                "code" => if error { OTLP_STATUS_CODE_ERROR } else { 1 },
+                "message" => "",
+            },
+            "attributes" => btreemap! {
+                "http.route" => "/users",
+            },
+            "resources" => btreemap! {
+                "service.name" => "users",
+                "deployment.environment" => "prod",
+            },
+        })
+    }
+
+    async fn do_transform(config: TraceToMetricConfig, trace: TraceEvent) -> Vec<Metric> {
+        assert_transform_compliance(async move {
+            let (tx, rx) = mpsc::channel(1);
+            let (topology, mut out) = create_topology(ReceiverStream::new(rx), config).await;
+
+            tx.send(trace.into()).await.unwrap();
+            drop(tx);
+
+            let mut metrics = Vec::new();
+            while let Some(event) = out.recv().await {
+                metrics.push(event.into_metric());
+            }
+
+            topology.stop().await;
+            metrics
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn otlp_span_to_metrics() {
+        let config = TraceToMetricConfig {
+            namespace: default_namespace(),
+            dimensions: vec![
+                "http.route".to_string(),
+                "deployment.environment".to_string(),
+            ],
+        };
+        let metrics = do_transform(config, otlp_span(false)).await;
+
+        let expected_tags = metric_tags!(
+            "service" => "users",
+            "operation" => "GET /users",
+            "status" => "ok",
+            "http.route" => "/users",
+            "deployment.environment" => "prod",
+        );
+        assert_eq!(metrics.len(), 2);
+        assert_eq!(metrics[0].name(), "requests_total");
+        assert_eq!(metrics[0].namespace(), Some("span"));
+        assert_eq!(metrics[0].tags(), Some(&expected_tags));
+        assert_eq!(metrics[0].value(), &MetricValue::Counter { value: 1.0 });
+        assert_eq!(
+            metrics[0].timestamp(),
+            Some(Utc.timestamp_nanos(250_000_000))
+        );
+
+        assert_eq!(metrics[1].name(), "duration_seconds");
+        assert_eq!(
+            metrics[1].value(),
+            &MetricValue::Distribution {
+                samples: vector_lib::samples![0.25 => 1],
+                statistic: StatisticKind::Histogram,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn error_span_to_metrics() {
+        let config = TraceToMetricConfig {
+            namespace: "traces".to_string(),
+            dimensions: vec!["missing".to_string()],
+        };
+        let metrics = do_transform(config, otlp_span(true)).await;
+
+        let names = metrics.iter().map(Metric::name).collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec!["requests_total", "errors_total", "duration_seconds"]
+        );
+        assert!(metrics.iter().all(
+            |metric| metric.tags().unwrap().get("status") == Some("error")
+                && metric.tags().unwrap().get("missing").is_none()
+        ));
+    }
+
+    #[tokio::test]
+    async fn datadog_chunk_to_metrics() {
+        let span = |error: i64| {
+            Value::from(btreemap! {
+                "trace_id" => 1,
+                "span_id" => 1,
+                "parent_id" => 0,
+                "service" => "web",
+                "name" => "rack.request",
+                "error" => error,
+                "start" => Utc.timestamp_nanos(0),
+                "duration" => 1_000_000_000,
+                "meta" => btreemap! {
+                    "http.route" => "/",
+                },
+            })
+        };
+        let trace = TraceEvent::from(btreemap! {
+            "spans" => vec![span(0), span(1)],
+        });
+        let config = TraceToMetricConfig {
+            namespace: default_namespace(),
+            dimensions: vec!["http.route".to_string()],
+        };
+        let metrics = do_transform(config, trace).await;
+
+        let requests = metrics
+            .iter()
+            .filter(|metric| metric.name() == "requests_total")
+            .count();
+        let errors = metrics
+            .iter()
+            .filter(|metric| metric.name() == "errors_total")
+            .collect::<Vec<_>>();
+        assert_eq!(requests, 2);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].tags(),
+            Some(&metric_tags!(
+                "service" => "web",
+                "operation" => "rack.request",
+                "status" => "error",
+                "http.route" => "/",
+            ))
+        );
+    }
+}
@@ -25,6 +25,11 @@ api: {
 				description: """
 					Main endpoint for receiving and processing
 					GraphQL queries.
+
+					Mutations, such as `reloadConfig`, `reloadEnrichmentTables`,
+					`pauseSource`, `resumeSource` and `runHealthcheck`, change the
+					running topology, and are only available when authentication
+					is configured with the `auth` option.
 					"""
 				responses: {
 					"200": {
@@ -204,6 +204,59 @@ cli: {
 	options: _core_options
 
 	commands: {
+		"buffer": {
+			description: """
+				Inspect the disk buffer of a sink, then exit. The buffer can only be inspected
+				while Vector is stopped. The `info` subcommand shows the state of the buffer,
+				`dump` outputs every record as JSON, `validate` checks that every record is
+				valid, and `export` outputs the unacknowledged events in the `native_json`
+				format, which can be replayed with the `stdin` source.
+				"""
+
+			example: "vector buffer export my_sink | vector --config replay.yaml"
+
+			flags: _default_flags
+
+			options: {
+				"data-dir": {
+					description: "The data directory of the Vector process the buffer belongs to"
+					type:        "string"
+					default:     "/var/lib/vector/"
+				}
+				"encryption-key": {
+					description: "The base64-encoded key the buffer is encrypted with, if any"
+					type:        "string"
+					env_var:     "VECTOR_BUFFER_ENCRYPTION_KEY"
+				}
+				"output": {
+					_short:      "o"
+					description: "The file to write records or events to, for `dump` and `export`. Defaults to stdout."
+					type:        "string"
+				}
+				"format": {
+					description: "Format of the buffer state, for `info`"
+					default:     "text"
+					enum: {
+						json: "Output the buffer state as JSON"
+						text: "Output the buffer state as text"
+					}
+				}
+			}
+
+			args: {
+				subcommand: {
+					description: "The operation to perform: `info`, `dump`, `validate`, or `export`"
+					required:    true
+					type:        "string"
+				}
+				sink_id: {
+					description: "The ID of the sink whose disk buffer should be inspected"
+					required:    true
+					type:        "string"
+				}
+			}
+		}
+
 		"graph": {
 			description: """
 				Generate a visual representation of topologies. The output is in the [DOT format](\(urls.dot_format)),
@@ -337,6 +390,25 @@ cli: {
 					description: "Vector GraphQL API server endpoint"
 					type:        "string"
 				}
+				"token": {
+					description: "Bearer token used to authenticate with the API server"
+					type:        "string"
+					env_var:     "VECTOR_API_TOKEN"
+				}
+				"username": {
+					description: "Username used to authenticate with the API server, using basic authentication"
+					type:        "string"
+					env_var:     "VECTOR_API_USERNAME"
+				}
+				"password": {
+					description: "Password used to authenticate with the API server, using basic authentication"
+					type:        "string"
+					env_var:     "VECTOR_API_PASSWORD"
+				}
+				"ca-file": {
+					description: "Path to a PEM-encoded CA certificate used to verify the API server's certificate, in addition to the system's trusted certificates"
+					type:        "string"
+				}
 				"limit": {
 					_short:      "l"
 					description: "Maximum number of events to sample each interval"
@@ -362,6 +434,18 @@ cli: {
 					description: "Components (sources, transforms) to observe for their inputs (comma-separated; accepts glob patterns)"
 					type:        "list"
 				}
+				"filter": {
+					description: "VRL boolean expression to filter events with, such as `.status >= 500`. Filtering happens in the tapped Vector instance, so only matching events are sent. Requires authentication to be configured for the API."
+					type:        "string"
+				}
+				"transform": {
+					description: "VRL program to transform events with before they're sent, such as `. = {\"message\": .message}`. Applied after the filter. Requires authentication to be configured for the API."
+					type:        "string"
+				}
+				"record": {
+					description: "Record events, losslessly, to a capture file at the given path instead of printing them. Capture files can be replayed with the `tap_replay` source, or used as unit test inputs."
+					type:        "string"
+				}
 			}
 
 			args: {
@@ -406,6 +490,11 @@ cli: {
 					type:        "list"
 					default:     "*"
 				}
+				"history": {
+					description: "How far back the throughput history shown in sparklines goes, in seconds"
+					type:        "integer"
+					default:     300
+				}
 				"interval": {
 					_short:      "i"
 					description: "Interval to sample metrics at, in milliseconds"
@@ -417,6 +506,25 @@ cli: {
 					description: "Vector GraphQL API server endpoint"
 					type:        "string"
 				}
+				"token": {
+					description: "Bearer token used to authenticate with the API server"
+					type:        "string"
+					env_var:     "VECTOR_API_TOKEN"
+				}
+				"username": {
+					description: "Username used to authenticate with the API server, using basic authentication"
+					type:        "string"
+					env_var:     "VECTOR_API_USERNAME"
+				}
+				"password": {
+					description: "Password used to authenticate with the API server, using basic authentication"
+					type:        "string"
+					env_var:     "VECTOR_API_PASSWORD"
+				}
+				"ca-file": {
+					description: "Path to a PEM-encoded CA certificate used to verify the API server's certificate, in addition to the system's trusted certificates"
+					type:        "string"
+				}
 			}
 		}
 
@@ -664,6 +772,8 @@ cli: {
 
 				`recommend` - recommended event based watcher for host OS
 				`poll` - `poll` watcher can be used in cases where event based watcher doesn't work, e.g., when attaching the configuration via NFS.
+
+				The files of enrichment tables are watched with the same method, even when `--watch-config` isn't enabled.
 				"""
 			type: string: default: "recommended"
 		}
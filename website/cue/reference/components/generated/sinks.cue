@@ -12,6 +12,40 @@ generated: components: sinks: configuration: {
 			"""
 		required: false
 		type: object: options: {
+			compression: {
+				description:   "Compression applied to the records written to a disk buffer."
+				relevant_when: "type = \"disk\""
+				required:      false
+				type: string: {
+					default: "none"
+					enum: {
+						none: "Records are written uncompressed."
+						zstd: """
+							Records are compressed with [Zstandard][zstd].
+
+							[zstd]: https://facebook.github.io/zstd/
+							"""
+					}
+				}
+			}
+			encryption: {
+				description:   "At-rest encryption of the records written to a disk buffer."
+				relevant_when: "type = \"disk\""
+				required:      false
+				type: object: options: key: {
+					description: """
+						The key used to encrypt records with AES-256-GCM, as a base64-encoded 256-bit key.
+
+						A secret reference, such as `SECRET[backend.buffer_key]`, can be used to load the key from
+						a secrets backend.
+
+						The key must not change while encrypted records are still in the buffer, as they can no
+						longer be read after the key has changed.
+						"""
+					required: true
+					type: string: examples: ["SECRET[backend.buffer_key]"]
+				}
+			}
 			max_events: {
 				description:   "The maximum number of events allowed in the buffer."
 				relevant_when: "type = \"memory\""
@@ -70,6 +104,18 @@ generated: components: sinks: configuration: {
 														highest priority, and it is preferable to temporarily lose events rather than cause a
 														slowdown in the acceptance/consumption of events.
 														"""
+						drop_oldest: """
+														Evicts the oldest buffered events to make room for the event.
+
+														The oldest events that have not yet been read out of the buffer are intentionally dropped.
+														This mode is typically used when fresh data is more valuable than stale data, such that a
+														sink recovering from an outage resumes with recent events instead of working through a
+														backlog of old ones.
+
+														Disk buffers evict entire data files at a time, and only those that have not yet started
+														being read. If no such data files exist, the event waits for free space as it would when
+														blocking.
+														"""
 					}
 				}
 			}
@@ -206,4 +252,25 @@ generated: components: sinks: configuration: {
 			}
 		}
 	}
+	reroute_dropped: {
+		description: """
+			Reroutes events that the sink failed to deliver to a named output instead of dropping them.
+
+			Events are normally dropped once a request fails permanently, for example when the
+			service rejects it with a non-retriable error or all retries are exhausted. In some cases,
+			it may be desirable to keep these events around, to store them for later replay.
+
+			In these cases, `reroute_dropped` can be set to `true` which forwards the original events
+			to a specially-named output, `dropped`. The events are annotated with additional fields
+			describing why they were dropped, including the HTTP status and the number of attempts
+			when they are known.
+
+			Events the sink drops without delivering them, such as events that fail to be encoded, are
+			rerouted as well. Events are only tracked while a component consumes the `dropped` output.
+
+			Only sinks that acknowledge the delivery of each request support this option.
+			"""
+		required: false
+		type: bool: default: false
+	}
 }
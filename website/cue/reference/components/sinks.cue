@@ -30,6 +30,10 @@ components: sinks: [Name=string]: {
 			}
 		}
 
+		if features.acknowledgements {
+			reroute_dropped: generated.components.sinks.configuration.reroute_dropped
+		}
+
 		if !features.auto_generated {
 			if features.acknowledgements {
 				acknowledgements: {
@@ -30,6 +30,18 @@ generated: components: sinks: opentelemetry: configuration: protocol: {
 				type: bool: {}
 			}
 		}
+		address: {
+			description: """
+				The address of the Arrow Flight server to which to connect.
+
+				Both IP address and hostname are accepted formats.
+
+				The address _must_ include a port.
+				"""
+			relevant_when: "type = \"arrow_flight\""
+			required:      true
+			type: string: examples: ["92.12.333.224:4319", "https://somehost:4319"]
+		}
 		auth: {
 			description: """
 				Configuration of the authentication strategy for HTTP requests.
@@ -37,7 +49,8 @@ generated: components: sinks: opentelemetry: configuration: protocol: {
 				HTTP authentication should be used with HTTPS only, as the authentication credentials are passed as an
 				HTTP header without any additional encryption beyond what is provided by the transport itself.
 				"""
-			required: false
+			relevant_when: "type = \"http\""
+			required:      false
 			type: object: options: {
 				auth: {
 					description:   "The AWS authentication configuration."
@@ -286,7 +299,8 @@ generated: components: sinks: opentelemetry: configuration: protocol: {
 				Configures how events are encoded into raw bytes.
 				The selected encoding also determines which input types (logs, metrics, traces) are supported.
 				"""
-			required: true
+			relevant_when: "type = \"http\""
+			required:      true
 			type: object: options: {
 				avro: {
 					description:   "Apache Avro-specific encoder options."
@@ -664,8 +678,9 @@ generated: components: sinks: opentelemetry: configuration: protocol: {
 			}
 		}
 		framing: {
-			description: "Framing configuration."
-			required:    false
+			description:   "Framing configuration."
+			relevant_when: "type = \"http\""
+			required:      false
 			type: object: options: {
 				character_delimited: {
 					description:   "Options for the character delimited encoder."
@@ -735,6 +750,7 @@ generated: components: sinks: opentelemetry: configuration: protocol: {
 			deprecated:         true
 			deprecated_message: "This option has been deprecated, use `request.headers` instead."
 			description:        "A list of custom headers to add to each request."
+			relevant_when:      "type = \"http\""
 			required:           false
 			type: object: options: "*": {
 				description: "An HTTP request header and it's value."
@@ -743,8 +759,9 @@ generated: components: sinks: opentelemetry: configuration: protocol: {
 			}
 		}
 		method: {
-			description: "The HTTP method to use when making the request."
-			required:    false
+			description:   "The HTTP method to use when making the request."
+			relevant_when: "type = \"http\""
+			required:      false
 			type: string: {
 				default: "post"
 				enum: {
@@ -767,7 +784,8 @@ generated: components: sinks: opentelemetry: configuration: protocol: {
 
 				If specified, the `payload_suffix` must also be specified and together they must produce a valid JSON object.
 				"""
-			required: false
+			relevant_when: "type = \"http\""
+			required:      false
 			type: string: {
 				default: ""
 				examples: ["{\"data\":"]
@@ -781,7 +799,8 @@ generated: components: sinks: opentelemetry: configuration: protocol: {
 
 				If specified, the `payload_prefix` must also be specified and together they must produce a valid JSON object.
 				"""
-			required: false
+			relevant_when: "type = \"http\""
+			required:      false
 			type: string: {
 				default: ""
 				examples: ["}"]
@@ -1081,7 +1100,15 @@ generated: components: sinks: opentelemetry: configuration: protocol: {
 		type: {
 			description: "The communication protocol."
 			required:    true
-			type: string: enum: http: "Send data over HTTP."
+			type: string: enum: {
+				arrow_flight: """
+					Send log and trace events as Arrow record batches over Arrow Flight.
+
+					The receiving end must be an `opentelemetry` source with `arrow_flight` enabled, or another
+					Arrow Flight server accepting `DoPut` streams with a `logs` or `traces` descriptor path.
+					"""
+				http: "Send data over HTTP."
+			}
 		}
 		uri: {
 			description: """
@@ -1089,7 +1116,8 @@ generated: components: sinks: opentelemetry: configuration: protocol: {
 
 				This should include the protocol and host, but can also include the port, path, and any other valid part of a URI.
 				"""
-			required: true
+			relevant_when: "type = \"http\""
+			required:      true
 			type: string: {
 				examples: ["https://10.22.212.22:9000/endpoint"]
 				syntax: "template"
@@ -232,5 +232,19 @@ components: sinks: opentelemetry: {
 
 				"""
 		}
+		arrow_flight: {
+			title: "Arrow Flight"
+			body: """
+				With the `arrow_flight` protocol, the sink sends log and trace events as Arrow record batches over
+				[Arrow Flight](\(urls.apache_arrow_flight)) `DoPut` streams instead of OTLP, which is better suited for
+				high volume Vector-to-Vector hops. The receiving end is typically an `opentelemetry` source with the
+				`arrow_flight` option set.
+
+				Each batch of events is sent as one stream for logs, with the `logs` descriptor path, and one stream for
+				traces, with the `traces` descriptor path. The schema of each stream is inferred from the events of the
+				batch: top-level fields become columns, and objects and arrays are encoded as JSON strings. Metrics are not
+				supported.
+				"""
+		}
 	}
 }
@@ -285,7 +285,8 @@ generated: components: sources: datadog_agent: configuration: {
 					performance when you know the expected signal types. For example, if you only receive
 					traces, set this to `["traces"]` to avoid attempting to parse as logs or metrics first.
 
-					If not specified, defaults to trying all types in order: logs, metrics, traces.
+					If not specified, defaults to trying logs, metrics and traces, in that order. Profiles are
+					only parsed when explicitly listed.
 					Duplicate signal types are automatically removed while preserving order.
 					"""
 				relevant_when: "codec = \"otlp\""
@@ -293,9 +294,10 @@ generated: components: sources: datadog_agent: configuration: {
 				type: array: {
 					default: ["logs", "metrics", "traces"]
 					items: type: string: enum: {
-						logs:    "OTLP logs signal (ExportLogsServiceRequest)"
-						metrics: "OTLP metrics signal (ExportMetricsServiceRequest)"
-						traces:  "OTLP traces signal (ExportTraceServiceRequest)"
+						logs:     "OTLP logs signal (ExportLogsServiceRequest)"
+						metrics:  "OTLP metrics signal (ExportMetricsServiceRequest)"
+						profiles: "OTLP profiles signal (ExportProfilesServiceRequest)"
+						traces:   "OTLP traces signal (ExportTraceServiceRequest)"
 					}
 				}
 			}
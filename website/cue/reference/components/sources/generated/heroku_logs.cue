@@ -59,6 +59,11 @@ generated: components: sources: heroku_logs: configuration: {
 
 						[base64]: https://en.wikipedia.org/wiki/Base64
 						"""
+					bearer: """
+						Bearer authentication.
+
+						The token is passed in the `Authorization` header, using the `Bearer` scheme.
+						"""
 					custom: """
 						Custom authentication using VRL code.
 
@@ -66,6 +71,12 @@ generated: components: sources: heroku_logs: configuration: {
 						"""
 				}
 			}
+			token: {
+				description:   "The bearer authentication token."
+				relevant_when: "strategy = \"bearer\""
+				required:      true
+				type: string: examples: ["${TOKEN}"]
+			}
 			username: {
 				description:   "The basic authentication username."
 				relevant_when: "strategy = \"basic\""
@@ -328,7 +339,8 @@ generated: components: sources: heroku_logs: configuration: {
 					performance when you know the expected signal types. For example, if you only receive
 					traces, set this to `["traces"]` to avoid attempting to parse as logs or metrics first.
 
-					If not specified, defaults to trying all types in order: logs, metrics, traces.
+					If not specified, defaults to trying logs, metrics and traces, in that order. Profiles are
+					only parsed when explicitly listed.
 					Duplicate signal types are automatically removed while preserving order.
 					"""
 				relevant_when: "codec = \"otlp\""
@@ -336,9 +348,10 @@ generated: components: sources: heroku_logs: configuration: {
 				type: array: {
 					default: ["logs", "metrics", "traces"]
 					items: type: string: enum: {
-						logs:    "OTLP logs signal (ExportLogsServiceRequest)"
-						metrics: "OTLP metrics signal (ExportMetricsServiceRequest)"
-						traces:  "OTLP traces signal (ExportTraceServiceRequest)"
+						logs:     "OTLP logs signal (ExportLogsServiceRequest)"
+						metrics:  "OTLP metrics signal (ExportMetricsServiceRequest)"
+						profiles: "OTLP profiles signal (ExportProfilesServiceRequest)"
+						traces:   "OTLP traces signal (ExportTraceServiceRequest)"
 					}
 				}
 			}
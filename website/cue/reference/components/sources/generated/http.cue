@@ -63,6 +63,11 @@ generated: components: sources: http: configuration: {
 
 						[base64]: https://en.wikipedia.org/wiki/Base64
 						"""
+					bearer: """
+						Bearer authentication.
+
+						The token is passed in the `Authorization` header, using the `Bearer` scheme.
+						"""
 					custom: """
 						Custom authentication using VRL code.
 
@@ -70,6 +75,12 @@ generated: components: sources: http: configuration: {
 						"""
 				}
 			}
+			token: {
+				description:   "The bearer authentication token."
+				relevant_when: "strategy = \"bearer\""
+				required:      true
+				type: string: examples: ["${TOKEN}"]
+			}
 			username: {
 				description:   "The basic authentication username."
 				relevant_when: "strategy = \"basic\""
@@ -329,7 +340,8 @@ generated: components: sources: http: configuration: {
 					performance when you know the expected signal types. For example, if you only receive
 					traces, set this to `["traces"]` to avoid attempting to parse as logs or metrics first.
 
-					If not specified, defaults to trying all types in order: logs, metrics, traces.
+					If not specified, defaults to trying logs, metrics and traces, in that order. Profiles are
+					only parsed when explicitly listed.
 					Duplicate signal types are automatically removed while preserving order.
 					"""
 				relevant_when: "codec = \"otlp\""
@@ -337,9 +349,10 @@ generated: components: sources: http: configuration: {
 				type: array: {
 					default: ["logs", "metrics", "traces"]
 					items: type: string: enum: {
-						logs:    "OTLP logs signal (ExportLogsServiceRequest)"
-						metrics: "OTLP metrics signal (ExportMetricsServiceRequest)"
-						traces:  "OTLP traces signal (ExportTraceServiceRequest)"
+						logs:     "OTLP logs signal (ExportLogsServiceRequest)"
+						metrics:  "OTLP metrics signal (ExportMetricsServiceRequest)"
+						profiles: "OTLP profiles signal (ExportProfilesServiceRequest)"
+						traces:   "OTLP traces signal (ExportTraceServiceRequest)"
 					}
 				}
 			}
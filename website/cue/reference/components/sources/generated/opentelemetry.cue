@@ -22,6 +22,137 @@ generated: components: sources: opentelemetry: configuration: {
 			type: bool: {}
 		}
 	}
+	arrow_flight: {
+		description: """
+			Configuration for the `opentelemetry` Arrow Flight server.
+
+			When set, the source also accepts log and trace events sent in the Arrow columnar format through
+			Arrow Flight `DoPut` streams, such as those sent by the `opentelemetry` sink with the
+			`arrow_flight` protocol. The descriptor path of each stream, `logs` or `traces`, selects the
+			output the events are sent to.
+			"""
+		required: false
+		type: object: {
+			examples: [{
+				address: "0.0.0.0:4319"
+			}]
+			options: {
+				address: {
+					description: """
+						The socket address to listen for connections on.
+
+						It _must_ include a port.
+						"""
+					required: true
+					type: string: examples: ["0.0.0.0:4319", "localhost:4319"]
+				}
+				tls: {
+					description: "Configures the TLS options for incoming/outgoing connections."
+					required:    false
+					type: object: options: {
+						alpn_protocols: {
+							description: """
+																Sets the list of supported ALPN protocols.
+
+																Declare the supported ALPN protocols, which are used during negotiation with a peer. They are prioritized in the order
+																that they are defined.
+																"""
+							required: false
+							type: array: items: type: string: examples: ["h2"]
+						}
+						ca_file: {
+							description: """
+																Absolute path to an additional CA certificate file.
+
+																The certificate must be in the DER or PEM (X.509) format. Additionally, the certificate can be provided as an inline string in PEM format.
+																"""
+							required: false
+							type: string: examples: ["/path/to/certificate_authority.crt"]
+						}
+						crt_file: {
+							description: """
+																Absolute path to a certificate file used to identify this server.
+
+																The certificate must be in DER, PEM (X.509), or PKCS#12 format. Additionally, the certificate can be provided as
+																an inline string in PEM format.
+
+																If this is set _and_ is not a PKCS#12 archive, `key_file` must also be set.
+																"""
+							required: false
+							type: string: examples: ["/path/to/host_certificate.crt"]
+						}
+						enabled: {
+							description: """
+																Whether to require TLS for incoming or outgoing connections.
+
+																When enabled and used for incoming connections, an identity certificate is also required. See `tls.crt_file` for
+																more information.
+																"""
+							required: false
+							type: bool: {}
+						}
+						key_file: {
+							description: """
+																Absolute path to a private key file used to identify this server.
+
+																The key must be in DER or PEM (PKCS#8) format. Additionally, the key can be provided as an inline string in PEM format.
+																"""
+							required: false
+							type: string: examples: ["/path/to/host_certificate.key"]
+						}
+						key_pass: {
+							description: """
+																Passphrase used to unlock the encrypted key file.
+
+																This has no effect unless `key_file` is set.
+																"""
+							required: false
+							type: string: examples: ["${KEY_PASS_ENV_VAR}", "PassWord1"]
+						}
+						server_name: {
+							description: """
+																Server name to use when using Server Name Indication (SNI).
+
+																Only relevant for outgoing connections.
+																"""
+							required: false
+							type: string: examples: ["www.example.com"]
+						}
+						verify_certificate: {
+							description: """
+																Enables certificate verification. For components that create a server, this requires that the
+																client connections have a valid client certificate. For components that initiate requests,
+																this validates that the upstream has a valid certificate.
+
+																If enabled, certificates must not be expired and must be issued by a trusted
+																issuer. This verification operates in a hierarchical manner, checking that the leaf certificate (the
+																certificate presented by the client/server) is not only valid, but that the issuer of that certificate is also valid, and
+																so on, until the verification process reaches a root certificate.
+
+																Do NOT set this to `false` unless you understand the risks of not verifying the validity of certificates.
+																"""
+							required: false
+							type: bool: {}
+						}
+						verify_hostname: {
+							description: """
+																Enables hostname verification.
+
+																If enabled, the hostname used to connect to the remote host must be present in the TLS certificate presented by
+																the remote host, either as the Common Name or as an entry in the Subject Alternative Name extension.
+
+																Only relevant for outgoing connections.
+
+																Do NOT set this to `false` unless you understand the risks of not verifying the remote hostname.
+																"""
+							required: false
+							type: bool: {}
+						}
+					}
+				}
+			}
+		}
+	}
 	grpc: {
 		description: "Configuration for the `opentelemetry` gRPC server."
 		required:    true
@@ -332,6 +463,9 @@ generated: components: sources: opentelemetry: configuration: {
 			One major caveat here is that the incoming metrics will be parsed as logs but they will preserve the OTLP format.
 			This means that components that work on metrics, will not be compatible with this output.
 			However, these events can be forwarded directly to a downstream OTEL collector.
+
+			Profiles are always decoded this way, regardless of this setting, as they have no
+			equivalent Vector event type.
 			"""
 		required: false
 		type: bool: default: false
@@ -73,6 +73,11 @@ generated: components: sources: prometheus_pushgateway: configuration: {
 
 						[base64]: https://en.wikipedia.org/wiki/Base64
 						"""
+					bearer: """
+						Bearer authentication.
+
+						The token is passed in the `Authorization` header, using the `Bearer` scheme.
+						"""
 					custom: """
 						Custom authentication using VRL code.
 
@@ -80,6 +85,12 @@ generated: components: sources: prometheus_pushgateway: configuration: {
 						"""
 				}
 			}
+			token: {
+				description:   "The bearer authentication token."
+				relevant_when: "strategy = \"bearer\""
+				required:      true
+				type: string: examples: ["${TOKEN}"]
+			}
 			username: {
 				description:   "The basic authentication username."
 				relevant_when: "strategy = \"basic\""
@@ -0,0 +1,37 @@
+package metadata
+
+generated: components: sources: tap_replay: configuration: {
+	outputs: {
+		description: """
+			Named outputs to replay events on.
+
+			Events captured from a component output listed here are replayed on the output of this
+			source with the same name, so that events captured from different outputs, such as the
+			routes of a `route` transform, can be told apart. Events captured from any other output are
+			replayed on the default output.
+			"""
+		required: false
+		type: array: {
+			default: []
+			items: type: string: examples: ["errors"]
+		}
+	}
+	path: {
+		description: "The path of the capture file to replay."
+		required:    true
+		type: string: examples: ["/var/lib/vector/capture.ndjson"]
+	}
+	speed: {
+		description: """
+			How fast to replay events, relative to the timing they were recorded with.
+
+			The default replays events at their original timing, `2.0` replays them twice as fast. To
+			replay all events as quickly as possible, set `speed` to `0.0`.
+			"""
+		required: false
+		type: float: {
+			default: 1.0
+			examples: [1.0, 10.0, 0.0]
+		}
+	}
+}
@@ -68,6 +68,12 @@ components: sources: opentelemetry: {
 				Received metric events will go to this output stream. Use `<component_id>.metrics` as an input to downstream transforms and sinks.
 				"""
 		},
+		{
+			name: "profiles"
+			description: """
+				Received profiles will go to this output stream as OTLP-structured log events. Use `<component_id>.profiles` as an input to downstream transforms and sinks.
+				"""
+		},
 	]
 
 	output: {
@@ -246,6 +252,9 @@ components: sources: opentelemetry: {
 		traces: "": {
 			description: "Trace events that may be emitted by this source."
 		}
+		profiles: "": {
+			description: "Log events containing OTLP profiles that may be emitted by this source."
+		}
 	}
 
 	how_it_works: {
@@ -289,6 +298,32 @@ components: sources: opentelemetry: {
 				Trace support is experimental and subject to change as Vector has no strongly-typed structure for traces internally. Instead traces are stored as a key/value map similar to logs. This may change in the future to be a structured format.
 				"""
 		}
+		profiles: {
+			title: "Ingest OTLP profiles"
+			body: """
+				Profiles support is experimental, as the OTLP profiles signal is itself still in development. Profiles
+				are accepted through the `ProfilesService` gRPC service and on the `/v1development/profiles` HTTP path.
+
+				Vector has no internal representation of profiles, so each export request is emitted on the `profiles`
+				output as a single log event preserving the OTLP structure, as if `use_otlp_decoding` was enabled. These
+				events can be routed to object storage, or forwarded to another OTLP endpoint using the `opentelemetry`
+				sink with the `otlp` codec.
+				"""
+		}
+		arrow_flight: {
+			title: "Receive events over Arrow Flight"
+			body: """
+				When the `arrow_flight` option is set, the source also runs an [Arrow Flight](\(urls.apache_arrow_flight))
+				server accepting `DoPut` streams of Arrow record batches, for example from another Vector instance using the
+				`opentelemetry` sink with the `arrow_flight` protocol. The columnar encoding compresses well and is cheaper to
+				decode than OTLP, which makes it a better fit for high volume Vector-to-Vector hops.
+
+				The descriptor path of each stream must be either `logs` or `traces`, and selects the output the events are
+				sent to. Each row of a record batch becomes an event with one field per column. Object and array fields
+				encoded as JSON strings by the sink are decoded back into objects and arrays. Other Arrow Flight methods are
+				not supported.
+				"""
+		}
 		metrics: {
 			title: "Ingest metrics"
 			body: """
@@ -0,0 +1,80 @@
+package metadata
+
+components: sources: tap_replay: {
+	title: "Tap Replay"
+
+	description: """
+		Replays events recorded with `vector tap --record`, at their original timing or faster.
+		Events are replayed as they were recorded, including their metadata, which can be useful
+		to feed real traffic into a staging pipeline.
+		"""
+
+	classes: {
+		commonly_used: false
+		delivery:      "at_least_once"
+		deployment_roles: ["aggregator", "daemon", "sidecar"]
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      false
+	}
+
+	features: {
+		acknowledgements: false
+		collect: {
+			checkpoint: enabled: false
+			from: service:       services.vector
+		}
+		multiline: enabled: false
+	}
+
+	support: {
+		notices: []
+		requirements: []
+		warnings: []
+	}
+
+	installation: {
+		platform_name: null
+	}
+
+	configuration: generated.components.sources.tap_replay.configuration
+
+	output: {
+		logs: event: {
+			description: "A recorded event"
+			fields: {
+				"*": {
+					description: "Recorded events are replayed as is. The `tap_replay` source will not modify or add fields."
+					required:    true
+					type: "*": {}
+				}
+			}
+		}
+		metrics: {
+			counter:      output._passthrough_counter
+			distribution: output._passthrough_distribution
+			gauge:        output._passthrough_gauge
+			histogram:    output._passthrough_histogram
+			set:          output._passthrough_set
+		}
+		traces: "": {
+			description: "Recorded trace events."
+		}
+	}
+
+	how_it_works: {
+		capture_files: {
+			title: "Capture files"
+			body: """
+				Capture files are recorded with `vector tap --record <path>`. They hold one event
+				per line, encoded with Vector's native protobuf encoding, along with the component and
+				output it was tapped from and when it was captured. Events are read from the capture
+				file as they're replayed, so captures of any size can be replayed. Since events are sampled by `vector tap`,
+				their timing is only as precise as the tap's `--interval`.
+
+				Capture files can also be used as unit test inputs, with an input `type` of
+				`capture`.
+				"""
+		}
+	}
+}
@@ -1,6 +1,56 @@
 package metadata
 
 generated: components: transforms: sample: configuration: {
+	dynamic: {
+		description: """
+			Adjusts the sample rate of each bucket of events to forward a target number of events per
+			second.
+
+			The rate applied to each forwarded event is written to `sample_rate_key`, so downstream
+			components can re-weight sampled events. It is an error to provide a value for `dynamic`
+			together with `rate` or `ratio`.
+			"""
+		required: false
+		type: object: options: {
+			max_keys: {
+				description: """
+					The maximum number of buckets to track.
+
+					Events belonging to new buckets once this limit has been reached share a single overflow
+					bucket until tracked buckets expire.
+					"""
+				required: false
+				type: uint: {
+					default: 10000
+					examples: [10000]
+				}
+			}
+			target_events_per_sec: {
+				description: """
+					The number of events per second to forward for each bucket of events.
+
+					Buckets are determined by `group_by`. If `group_by` is unset, all events share a single
+					bucket.
+					"""
+				required: true
+				type: float: examples: [10.0]
+			}
+			window_secs: {
+				description: """
+					The length of the window over which event counts are observed before the sample rate of
+					each bucket is adjusted, in seconds.
+
+					Counts from previous windows are carried over with an exponential moving average, so
+					rates adjust smoothly when the volume of a bucket changes.
+					"""
+				required: false
+				type: float: {
+					default: 30.0
+					unit:    "seconds"
+				}
+			}
+		}
+	}
 	exclude: {
 		description: "A logical condition used to exclude events from sampling."
 		required:    false
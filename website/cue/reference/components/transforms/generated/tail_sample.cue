@@ -0,0 +1,133 @@
+package metadata
+
+generated: components: transforms: tail_sample: configuration: {
+	decision_cache_size: {
+		description: """
+			The number of sampling decisions to remember after a trace has been decided.
+
+			Spans arriving after the decision for their trace has been made are forwarded or dropped
+			according to the cached decision.
+			"""
+		required: false
+		type: uint: {
+			default: 100000
+			examples: [100000]
+		}
+	}
+	decision_wait_secs: {
+		description: """
+			The amount of time to wait for further spans of a trace after its root span has been
+			received, in seconds.
+
+			Once a trace has been idle for this long, it is considered complete and the sampling
+			policies are applied to all of its spans.
+			"""
+		required: false
+		type: float: {
+			default: 5.0
+			unit:    "seconds"
+		}
+	}
+	max_trace_duration_secs: {
+		description: """
+			The maximum amount of time to buffer the spans of a trace, in seconds.
+
+			Traces whose root span is never received, or which keep receiving spans, are decided once
+			they have been buffered for this long.
+			"""
+		required: false
+		type: float: {
+			default: 30.0
+			unit:    "seconds"
+		}
+	}
+	max_traces: {
+		description: """
+			The maximum number of traces to buffer at once.
+
+			When this limit is reached, the oldest buffered trace is decided early to make room for new
+			traces.
+			"""
+		required: false
+		type: uint: {
+			default: 50000
+			examples: [50000]
+		}
+	}
+	policies: {
+		description: """
+			The sampling policies to apply to complete traces.
+
+			A trace is kept if at least one of the policies decides to keep it, in which case all of its
+			spans are forwarded. Otherwise, all of its spans are dropped.
+			"""
+		required: true
+		type: array: items: type: object: options: {
+			condition: {
+				description:   "The condition to match against each span of the trace."
+				relevant_when: "type = \"condition\""
+				required:      true
+				type: condition: {}
+			}
+			overrides: {
+				description:   "Overrides of `traces_per_sec` for specific services."
+				relevant_when: "type = \"service_rate\""
+				required:      false
+				type: object: {
+					examples: [{
+						checkout: 100.0
+					}]
+					options: "*": {
+						description: "The number of traces to keep per second for the service."
+						required:    true
+						type: float: {}
+					}
+				}
+			}
+			ratio: {
+				description:   "The proportion of traces to keep, between 0 and 1."
+				relevant_when: "type = \"probabilistic\""
+				required:      true
+				type: float: examples: [0.1]
+			}
+			threshold_ms: {
+				description:   "The duration above which traces are kept, in milliseconds."
+				relevant_when: "type = \"latency\""
+				required:      true
+				type: uint: examples: [500]
+			}
+			traces_per_sec: {
+				description:   "The number of traces to keep per second for each service."
+				relevant_when: "type = \"service_rate\""
+				required:      true
+				type: float: examples: [10.0]
+			}
+			type: {
+				description: "The type of sampling policy."
+				required:    true
+				type: string: enum: {
+					condition: "Keep traces containing at least one span matching a condition."
+					errors:    "Keep traces containing at least one span with an error status."
+					latency: """
+						Keep traces whose duration exceeds a threshold.
+
+						The duration of a trace is the time between the earliest start and the latest end of its
+						spans.
+						"""
+					probabilistic: """
+						Keep a fixed proportion of traces.
+
+						The decision is based on a hash of the trace ID, so separate instances of this transform
+						make the same decision for the same trace.
+						"""
+					service_rate: """
+						Keep up to a fixed number of traces per second for each service.
+
+						The service of a trace is taken from its root span, or from its first span if the root span
+						has not been received.
+						"""
+				}
+			}
+		}
+	}
+}
@@ -0,0 +1,28 @@
+package metadata
+
+generated: components: transforms: trace_to_metric: configuration: {
+	dimensions: {
+		description: """
+			Span attributes to add as tags to the generated metrics, in addition to `service`,
+			`operation` and `status`.
+
+			Attributes are looked up in the span attributes first, then in the resource attributes.
+			Spans without the attribute don't get the tag. Each distinct combination of tag values
+			produces a separate metric series, so attributes with a high number of distinct values
+			should be avoided.
+			"""
+		required: false
+		type: array: {
+			default: []
+			items: type: string: examples: ["http.route", "deployment.environment"]
+		}
+	}
+	namespace: {
+		description: "The namespace of the generated metrics."
+		required:    false
+		type: string: {
+			default: "span"
+			examples: ["span"]
+		}
+	}
+}
@@ -0,0 +1,74 @@
+package metadata
+
+components: transforms: tail_sample: {
+	title: "Tail Sample"
+
+	description: """
+		Buffers the spans of each trace until the trace is complete, then applies sampling policies
+		to the trace as a whole, so that all spans of a trace are either forwarded or dropped together.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      true
+	}
+
+	features: {
+		filter: {}
+	}
+
+	support: {
+		requirements: []
+		warnings: [
+			"""
+				All spans of a trace must be routed to the same instance of this transform, otherwise
+				decisions are made on partial traces.
+				""",
+		]
+		notices: []
+	}
+
+	configuration: generated.components.transforms.tail_sample.configuration
+
+	input: {
+		logs:    false
+		metrics: null
+		traces:  true
+	}
+
+	output: {
+		traces: "": {
+			description: "The spans of sampled traces."
+		}
+	}
+
+	telemetry: metrics: {
+		component_discarded_events_total: components.sources.internal_metrics.output.metrics.component_discarded_events_total
+		tail_sample_evicted_traces_total: components.sources.internal_metrics.output.metrics.tail_sample_evicted_traces_total
+	}
+
+	how_it_works: {
+		trace_completion: {
+			title: "Trace completion"
+			body: """
+				Spans are grouped by their `trace_id`. A trace is considered complete once its root span
+				has been received and no further spans have been received for `decision_wait_secs`.
+				Traces whose root span never arrives are decided after `max_trace_duration_secs`.
+
+				Spans arriving after their trace has been decided are forwarded or dropped according to
+				the cached decision, for up to `decision_cache_size` traces.
+				"""
+		}
+		policies: {
+			title: "Sampling policies"
+			body: """
+				A trace is kept if any of the configured policies decides to keep it. Policies are
+				evaluated in order, and evaluation stops at the first policy that keeps the trace, so
+				rate limiting policies placed after other policies only account for the traces which
+				were not already kept.
+				"""
+		}
+	}
+}
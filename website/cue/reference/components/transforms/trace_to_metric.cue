@@ -0,0 +1,61 @@
+package metadata
+
+components: transforms: trace_to_metric: {
+	title: "Trace to Metric"
+
+	description: """
+		Derives request, error and duration (RED) metrics from the spans of trace events. Combined
+		with the `aggregate` transform, this produces service dashboards from existing trace traffic.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      false
+	}
+
+	features: {
+		convert: {}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: generated.components.transforms.trace_to_metric.configuration
+
+	input: {
+		logs:    false
+		metrics: null
+		traces:  true
+	}
+
+	output: metrics: {
+		counter:      output._passthrough_counter
+		distribution: output._passthrough_distribution
+	}
+
+	how_it_works: {
+		span_shapes: {
+			title: "Span shapes"
+			body: """
+				Spans are read from trace events produced by the `opentelemetry` source, which contain
+				a single span, and by the `datadog_agent` source, which contain a chunk of spans under
+				`spans`. Each span produces one `requests_total` counter increment, one
+				`errors_total` counter increment if it ended with an error, and one sample of the
+				`duration_seconds` histogram.
+				"""
+		}
+		tags: {
+			title: "Metric tags"
+			body: """
+				All metrics are tagged with the `service` which emitted the span, the `operation`
+				name of the span, and its `status`, one of `ok`, `error` or `unset`. Additional tags
+				are taken from the span attributes listed in `dimensions`.
+				"""
+		}
+	}
+}
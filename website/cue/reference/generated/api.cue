@@ -14,6 +14,66 @@ generated: api: configuration: api: {
 			examples: ["0.0.0.0:8686", "127.0.0.1:1234"]
 		}
 	}
+	auth: {
+		common: false
+		description: """
+			Configuration of the authentication strategy for the API.
+
+			Authentication applies to the `/graphql` and `/playground` endpoints. The `/health`
+			endpoint remains unauthenticated, so that it can be used for health checks. Use
+			authentication together with `tls`, as the credentials are otherwise sent unencrypted.
+			"""
+		required: false
+		type: object: options: {
+			password: {
+				description:   "The basic authentication password."
+				relevant_when: "strategy = \"basic\""
+				required:      true
+				type: string: examples: ["${PASSWORD}", "password"]
+			}
+			source: {
+				description:   "The VRL boolean expression."
+				relevant_when: "strategy = \"custom\""
+				required:      true
+				type: string: {}
+			}
+			strategy: {
+				description: "The authentication strategy to use."
+				required:    true
+				type: string: enum: {
+					basic: """
+						Basic authentication.
+
+						The username and password are concatenated and encoded using [base64][base64].
+
+						[base64]: https://en.wikipedia.org/wiki/Base64
+						"""
+					bearer: """
+						Bearer authentication.
+
+						The token is passed in the `Authorization` header, using the `Bearer` scheme.
+						"""
+					custom: """
+						Custom authentication using VRL code.
+
+						Takes in request and validates it using VRL code.
+						"""
+				}
+			}
+			token: {
+				description:   "The bearer authentication token."
+				relevant_when: "strategy = \"bearer\""
+				required:      true
+				type: string: examples: ["${TOKEN}"]
+			}
+			username: {
+				description:   "The basic authentication username."
+				relevant_when: "strategy = \"basic\""
+				required:      true
+				type: string: examples: ["${USERNAME}", "username"]
+			}
+		}
+	}
 	enabled: {
 		common:      true
 		description: "Whether the GraphQL API is enabled for this Vector instance."
@@ -41,4 +101,46 @@ generated: api: configuration: api: {
 		required: false
 		type: bool: default: true
 	}
+	tls: {
+		common: false
+		description: """
+			Configures the TLS options for the API.
+
+			When TLS is enabled, clients such as `vector top` and `vector tap` must connect using
+			an `https://` URL.
+			"""
+		required: false
+		type: object: options: {
+			ca_file: {
+				description: "Absolute path to an additional CA certificate file, used to verify client certificates."
+				required:    false
+				type: string: examples: ["/path/to/certificate_authority.crt"]
+			}
+			crt_file: {
+				description: "Absolute path to the certificate file used to identify the API server, in DER or PEM (X.509) format."
+				required:    false
+				type: string: examples: ["/path/to/host_certificate.crt"]
+			}
+			enabled: {
+				description: "Whether or not to require TLS for incoming connections."
+				required:    false
+				type: bool: {}
+			}
+			key_file: {
+				description: "Absolute path to the private key file matching `crt_file`, in DER or PEM (PKCS#8) format."
+				required:    false
+				type: string: examples: ["/path/to/host_certificate.key"]
+			}
+			key_pass: {
+				description: "Passphrase used to unlock the encrypted key file."
+				required:    false
+				type: string: examples: ["${KEY_PASS_ENV_VAR}", "PassWord1"]
+			}
+			verify_certificate: {
+				description: "Whether to require clients to present a valid certificate."
+				required:    false
+				type: bool: {}
+			}
+		}
+	}
 }
@@ -65,7 +65,7 @@ remap: {
 		This function returns the rows that match the provided condition(s). _All_ fields need to
 		match for rows to be returned; if any fields do not match, then no rows are returned.
 
-		There are currently three forms of search criteria:
+		There are currently six forms of search criteria:
 
 		1. **Exact match search**. The given field must match the value exactly. Case sensitivity
 		   can be specified using the `case_sensitive` argument. An exact match search can use an
@@ -83,6 +83,22 @@ remap: {
 		   match criteria. Therefore, use date ranges as the _only_ criteria when the enrichment
 		   data set is very small.
 
+		4. **CIDR search**. The given field must hold an IP network in CIDR notation, or a single
+		   IP address, that contains the IP address given as `cidr`. Example:
+		   `{"network": {"cidr": .client_ip}}`. Rows are returned with the most specific network
+		   first.
+
+		5. **Prefix search**. The given field must hold a prefix of the value given as `prefix`.
+		   Example: `{"dialing_code": {"prefix": .phone_number}}`. Case sensitivity can be specified
+		   using the `case_sensitive` argument. Rows are returned with the longest prefix first.
+
+		6. **Numeric range search**. The given field must hold a number greater than or equal to
+		   `from` and/or less than or equal to `to`. Example: `{"size": {"range": {"from": 0, "to": 1024}}}`.
+
+		CIDR, prefix and numeric range searches use an index into the dataset when they are the
+		only criteria, otherwise they sequentially scan through the rows located by the exact match
+		criteria.
+
 		For `geoip` and `mmdb` enrichment tables, this condition needs to be a VRL object with a single key-value pair
 		whose value needs to be a valid IP address. Example: `{"ip": .ip }`. If a return field is expected
 		and without a value, `null` is used. This table can return the following fields: